@@ -111,6 +111,7 @@ async fn create_complex_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
             expression: "input_data != null".to_string(),
             required: true,
         }],
+        learned_duration_ms: None,
     });
 
     // Data processing node
@@ -140,6 +141,7 @@ async fn create_complex_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
         timeout_seconds: Some(60),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     // Decision node for recursive processing
@@ -169,6 +171,7 @@ async fn create_complex_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
         timeout_seconds: Some(10),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     // Recursive processing subworkflow
@@ -207,6 +210,7 @@ async fn create_complex_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
             retry_on_errors: vec!["timeout".to_string(), "resource_unavailable".to_string()],
         },
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     // Final analysis node
@@ -236,6 +240,7 @@ async fn create_complex_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
         timeout_seconds: Some(90),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     // Create workflow edges
@@ -0,0 +1,101 @@
+//! Pruning Benchmark
+//!
+//! Runs a toy branching-factor-10 planning problem through `MCTSPlanner`
+//! and reports how much of the resulting tree `propagate_bounds` was able
+//! to mark `pruned` -- i.e. how much of the branching factor alpha-beta
+//! bound propagation manages to cut once enough simulations have run.
+//!
+//! Drives the tree through repeated `run_parallel_simulations` batches
+//! rather than `search`, since a single `search` call caps out at
+//! `parallel_simulations` simulations in flight at once and this benchmark
+//! wants many more total simulations than that to give pruning something
+//! to work with.
+
+use agent_orchestrator::mcts::{Constraint, Objective, ResourceState, CompletionCriteria};
+use agent_orchestrator::{AgentAction, AgentState, MCTSPlanner};
+use agent_orchestrator::MCTSConfig;
+use uuid::Uuid;
+
+fn toy_state() -> AgentState {
+    let available_actions = (0..10)
+        .map(|i| AgentAction::ExecuteTask {
+            task: format!("task-{i}"),
+            priority: (i % 5) as u8,
+        })
+        .collect();
+
+    AgentState {
+        schema_version: agent_orchestrator::CURRENT_AGENT_STATE_SCHEMA_VERSION,
+        context: "pruning-benchmark".to_string(),
+        available_actions,
+        resources: ResourceState {
+            cpu_available: 80.0,
+            memory_available: 1024 * 1024 * 1024,
+            network_bandwidth: 1_000_000_000,
+            active_connections: 0,
+            cache_usage: 0.0,
+        },
+        objectives: vec![Objective {
+            id: Uuid::new_v4(),
+            description: "maximize throughput".to_string(),
+            priority: 1.0,
+            completion_criteria: CompletionCriteria::ResponseReceived,
+            deadline: None,
+        }],
+        constraints: Vec::<Constraint>::new(),
+        performance_history: Vec::new(),
+    }
+}
+
+fn count_nodes(node: &agent_orchestrator::MCTSNode) -> (usize, usize) {
+    let mut total = 1;
+    let mut pruned = if node.pruned { 1 } else { 0 };
+    for (_, child) in &node.children {
+        let (child_total, child_pruned) = count_nodes(&child.read());
+        total += child_total;
+        pruned += child_pruned;
+    }
+    (total, pruned)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🚀 MCTS Pruning Benchmark");
+    println!("Branching factor: 10 available actions per state\n");
+
+    let config = MCTSConfig {
+        max_depth: 4,
+        simulations: 0,
+        exploration_constant: 1.414,
+        timeout_seconds: 30,
+        parallel_simulations: rayon::current_num_threads(),
+        use_neural_guidance: false,
+    };
+    let batch_size = config.parallel_simulations;
+
+    let planner = MCTSPlanner::new(config);
+    planner.root_node().write().state = toy_state();
+
+    let rounds = 200;
+    let mut total_ran = 0;
+    for _ in 0..rounds {
+        total_ran += planner.run_parallel_simulations(batch_size)?;
+    }
+
+    let root = planner.root_node();
+    let (total_nodes, pruned_nodes) = count_nodes(&root.read());
+    let pruned_pct = if total_nodes > 0 {
+        pruned_nodes as f64 / total_nodes as f64 * 100.0
+    } else {
+        0.0
+    };
+
+    println!("📊 Results:");
+    println!("  Simulations run: {total_ran}");
+    println!("  Total nodes in tree: {total_nodes}");
+    println!("  Nodes pruned by propagate_bounds: {pruned_nodes} ({pruned_pct:.1}%)");
+    println!("  Effective branching factor after pruning: {:.2}",
+        10.0 * (1.0 - pruned_pct / 100.0));
+
+    println!("\n✅ Benchmark complete");
+    Ok(())
+}
@@ -1,9 +1,12 @@
 //! Advanced Agent System with Dynamic Capabilities and Lifecycle Management
 
-use crate::{OrchestrationError, mcts::{AgentState, AgentAction, MCTSPlanner}};
+use crate::{OrchestrationError, mcts::{AgentState, AgentAction, MCTSPlanner}, workflow::{AgentHandshake, VersionedCapability}};
 use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use futures::future::BoxFuture;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use tokio::sync::{mpsc, RwLock};
 use uuid::Uuid;
@@ -22,6 +25,47 @@ pub struct Agent {
     pub message_rx: Arc<RwLock<Option<mpsc::UnboundedReceiver<AgentMessage>>>>,
     pub performance_tracker: Arc<RwLock<PerformanceTracker>>,
     pub lifecycle_manager: Arc<LifecycleManager>,
+    /// Capability handlers registered via `register_capability_handler`,
+    /// keyed by capability name, so new capabilities can be plugged in
+    /// without restarting the agent.
+    pub capability_handlers: Arc<DashMap<String, RegisteredCapability>>,
+    /// Tasks `dispatch_task` parked instead of running, because
+    /// `config.autonomy_level` is `AutonomyLevel::Supervised`. Released by
+    /// `approve_task`.
+    pending_approvals: Arc<DashMap<Uuid, Task>>,
+}
+
+/// A unit of work dispatched to a registered [`CapabilityHandler`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Task {
+    pub id: Uuid,
+    pub capability: String,
+    pub payload: serde_json::Value,
+}
+
+/// Plugin-style execution logic for a hot-registered capability. Implement
+/// this and pass a boxed instance to `Agent::register_capability_handler`
+/// to teach an already-running agent a new capability.
+pub trait CapabilityHandler: Send + Sync {
+    fn execute(&self, task: Task) -> BoxFuture<'static, TaskResult>;
+}
+
+/// A capability handler along with the bookkeeping
+/// `Agent::unregister_capability_handler` needs to drain in-flight tasks
+/// before removing it.
+pub struct RegisteredCapability {
+    handler: Box<dyn CapabilityHandler>,
+    deprecated: AtomicBool,
+    in_flight: AtomicUsize,
+}
+
+impl std::fmt::Debug for RegisteredCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegisteredCapability")
+            .field("deprecated", &self.deprecated.load(Ordering::SeqCst))
+            .field("in_flight", &self.in_flight.load(Ordering::SeqCst))
+            .finish_non_exhaustive()
+    }
 }
 
 /// Configuration for agent initialization
@@ -40,7 +84,7 @@ pub struct AgentConfig {
 }
 
 /// Types of agents with different specializations
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AgentType {
     Coordinator,      // Orchestrates other agents
     Worker,          // Executes specific tasks
@@ -49,15 +93,19 @@ pub enum AgentType {
     Optimizer,       // Performance optimization
     Learner,         // Continuous learning and adaptation
     Hybrid,          // Multiple capabilities
+    Planner,         // Breaks a goal down into a plan of actions
+    Executor,        // Carries out a single planned action
+    Critic,          // Reviews another agent's output before it proceeds
+    Researcher,      // Gathers and summarizes information
 }
 
 /// Agent autonomy levels
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AutonomyLevel {
-    Supervised,      // Requires explicit approval
+    Supervised,      // Requires explicit approval before every action
     Guided,          // Operates with oversight
     SemiAutonomous,  // Independent within constraints
-    Autonomous,      // Fully independent operation
+    Autonomous,      // Fully independent operation ("FullyAutonomous")
 }
 
 /// Agent capabilities defining what an agent can do
@@ -144,6 +192,13 @@ pub enum AgentMessage {
         reason: String,
         sender_id: Uuid,
     },
+    /// Re-advertisement of an agent's capability set, sent whenever a
+    /// capability handler is hot-registered or removed so the orchestrator
+    /// can re-run its handshake negotiation without the agent restarting.
+    CapabilityAdvertisement {
+        handshake: AgentHandshake,
+        sender_id: Uuid,
+    },
 }
 
 /// Result of task execution
@@ -177,6 +232,8 @@ pub enum AgentStatus {
     Learning { progress: f64 },
     Optimizing { target: String },
     Error { message: String },
+    Unresponsive,
+    Failed,
     ShuttingDown,
     Offline,
 }
@@ -229,10 +286,13 @@ pub struct InMemoryAgentMemory {
 impl Agent {
     /// Create a new agent with the specified configuration
     pub async fn new(config: AgentConfig) -> Result<Self, OrchestrationError> {
+        Self::validate_config(&config)?;
+
         let id = Uuid::new_v4();
         let (message_tx, message_rx) = mpsc::unbounded_channel();
 
         let initial_state = AgentState {
+            schema_version: crate::state_migration::CURRENT_AGENT_STATE_SCHEMA_VERSION,
             context: format!("Agent {} initialized", config.name),
             available_actions: Vec::new(),
             resources: crate::mcts::ResourceState {
@@ -286,6 +346,8 @@ impl Agent {
             message_rx: Arc::new(RwLock::new(Some(message_rx))),
             performance_tracker,
             lifecycle_manager,
+            capability_handlers: Arc::new(DashMap::new()),
+            pending_approvals: Arc::new(DashMap::new()),
         };
 
         // Start background tasks
@@ -373,6 +435,34 @@ impl Agent {
                     strategies: vec!["basic".to_string()],
                 });
             },
+            AgentType::Planner => {
+                capabilities.push(AgentCapability::Collaboration {
+                    agent_types: vec![AgentType::Executor],
+                });
+                capabilities.push(AgentCapability::Optimization {
+                    strategies: vec!["task_decomposition".to_string()],
+                });
+            },
+            AgentType::Executor => {
+                capabilities.push(AgentCapability::TextProcessing { max_tokens: 4096 });
+                capabilities.push(AgentCapability::FileOperations {
+                    allowed_paths: vec!["/tmp".to_string(), "/workspace".to_string()],
+                });
+            },
+            AgentType::Critic => {
+                capabilities.push(AgentCapability::Monitoring {
+                    metrics: vec!["quality".to_string(), "correctness".to_string()],
+                });
+                capabilities.push(AgentCapability::Collaboration {
+                    agent_types: vec![AgentType::Planner, AgentType::Executor],
+                });
+            },
+            AgentType::Researcher => {
+                capabilities.push(AgentCapability::TextProcessing { max_tokens: 8192 });
+                capabilities.push(AgentCapability::DataAnalysis {
+                    max_dataset_size: 1024 * 1024 * 50, // 50MB
+                });
+            },
         }
 
         // Add common capabilities
@@ -674,6 +764,14 @@ impl Agent {
         self.id
     }
 
+    /// Respond to a heartbeat ping from a `HeartbeatMonitor`
+    pub fn heartbeat(&self) -> crate::recovery::HeartbeatAck {
+        crate::recovery::HeartbeatAck {
+            agent_id: self.id,
+            timestamp: Utc::now(),
+        }
+    }
+
     /// Get agent configuration
     pub fn config(&self) -> &AgentConfig {
         &self.config
@@ -685,6 +783,152 @@ impl Agent {
             std::mem::discriminant(cap) == std::mem::discriminant(capability)
         })
     }
+
+    /// Hot-registers `handler` for `cap` so this already-running agent can
+    /// start dispatching tasks for it immediately, without a restart.
+    /// Re-advertises the agent's full capability set to the orchestrator by
+    /// sending an `AgentMessage::CapabilityAdvertisement` on `message_tx`.
+    pub fn register_capability_handler(&self, cap: VersionedCapability, handler: Box<dyn CapabilityHandler>) {
+        self.capability_handlers.insert(
+            cap.name,
+            RegisteredCapability {
+                handler,
+                deprecated: AtomicBool::new(false),
+                in_flight: AtomicUsize::new(0),
+            },
+        );
+
+        let _ = self.send_message(AgentMessage::CapabilityAdvertisement {
+            handshake: self.build_handshake(),
+            sender_id: self.id,
+        });
+    }
+
+    /// Marks `capability_name` deprecated, so `dispatch_task` stops
+    /// accepting new work for it, then waits for tasks already in flight to
+    /// finish before removing the handler. Re-advertises the (now smaller)
+    /// capability set to the orchestrator, mirroring registration.
+    pub async fn unregister_capability_handler(&self, capability_name: &str) {
+        match self.capability_handlers.get(capability_name) {
+            Some(entry) => entry.deprecated.store(true, Ordering::SeqCst),
+            None => return,
+        }
+
+        while self
+            .capability_handlers
+            .get(capability_name)
+            .map(|entry| entry.in_flight.load(Ordering::SeqCst))
+            .unwrap_or(0)
+            > 0
+        {
+            tokio::task::yield_now().await;
+        }
+
+        self.capability_handlers.remove(capability_name);
+
+        let _ = self.send_message(AgentMessage::CapabilityAdvertisement {
+            handshake: self.build_handshake(),
+            sender_id: self.id,
+        });
+    }
+
+    /// Whether this agent's `autonomy_level` requires a human to approve a
+    /// task before it actually executes. Only `AutonomyLevel::Supervised`
+    /// gates execution this way; `Guided`, `SemiAutonomous`, and
+    /// `Autonomous` agents run tasks immediately.
+    pub fn requires_approval(&self) -> bool {
+        matches!(self.config.autonomy_level, AutonomyLevel::Supervised)
+    }
+
+    /// Dispatches `task` to the handler registered for `task.capability`.
+    /// This is where a `TaskExecutor` would route work by capability name
+    /// in this crate's design: `TaskExecutor` (see `execution.rs`) only
+    /// tracks an in-progress execution's status, it doesn't own a handler
+    /// registry, so lookup and dispatch live here next to the registry
+    /// itself.
+    ///
+    /// A `Supervised` agent doesn't execute `task` here: it parks it and
+    /// returns an error describing the pending approval, and only actually
+    /// runs it once `approve_task` is called with the same task id.
+    pub async fn dispatch_task(&self, task: Task) -> Result<TaskResult, OrchestrationError> {
+        if self.requires_approval() {
+            let task_id = task.id;
+            self.pending_approvals.insert(task_id, task);
+            return Err(OrchestrationError::AgentError(format!(
+                "task {task_id} requires human approval before execution (autonomy_level: Supervised); call Agent::approve_task to proceed"
+            )));
+        }
+
+        self.execute_dispatched_task(task).await
+    }
+
+    /// Approves and runs a task previously parked by `dispatch_task` for a
+    /// `Supervised` agent's approval gate.
+    pub async fn approve_task(&self, task_id: Uuid) -> Result<TaskResult, OrchestrationError> {
+        let (_, task) = self
+            .pending_approvals
+            .remove(&task_id)
+            .ok_or_else(|| OrchestrationError::AgentError(format!("no task {task_id} awaiting approval")))?;
+
+        self.execute_dispatched_task(task).await
+    }
+
+    /// Looks up and runs the registered handler for `task.capability`,
+    /// bypassing the approval gate. Shared by `dispatch_task` (for agents
+    /// that don't require approval) and `approve_task` (once a human has
+    /// signed off).
+    async fn execute_dispatched_task(&self, task: Task) -> Result<TaskResult, OrchestrationError> {
+        let capability_name = task.capability.clone();
+
+        let pending_execution = {
+            let entry = self.capability_handlers.get(&capability_name).ok_or_else(|| {
+                OrchestrationError::AgentError(format!("no handler registered for capability '{capability_name}'"))
+            })?;
+            if entry.deprecated.load(Ordering::SeqCst) {
+                return Err(OrchestrationError::AgentError(format!(
+                    "capability '{capability_name}' is deprecated and no longer accepting tasks"
+                )));
+            }
+            entry.in_flight.fetch_add(1, Ordering::SeqCst);
+            entry.handler.execute(task)
+        };
+
+        let result = pending_execution.await;
+
+        if let Some(entry) = self.capability_handlers.get(&capability_name) {
+            entry.in_flight.fetch_sub(1, Ordering::SeqCst);
+        }
+
+        Ok(result)
+    }
+
+    /// Rejects agent/autonomy combinations that would undermine their own
+    /// purpose: a `Critic` exists to gate other agents' output, so running
+    /// one at full autonomy with nobody able to review its own verdicts
+    /// defeats the point of spawning it as a critic at all.
+    fn validate_config(config: &AgentConfig) -> Result<(), OrchestrationError> {
+        if config.agent_type == AgentType::Critic && config.autonomy_level == AutonomyLevel::Autonomous {
+            return Err(OrchestrationError::AgentError(
+                "Critic agents require human oversight; use Supervised or SemiAutonomous autonomy instead of Autonomous".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Builds an `AgentHandshake` re-advertising every currently registered
+    /// capability handler.
+    fn build_handshake(&self) -> AgentHandshake {
+        AgentHandshake {
+            agent_id: self.id,
+            capabilities: self
+                .capability_handlers
+                .iter()
+                .map(|entry| VersionedCapability { name: entry.key().clone(), version: "1.0".to_string() })
+                .collect(),
+            supported_protocols: vec!["agent-protocol/2.0".to_string()],
+            max_payload_bytes: 1_048_576,
+        }
+    }
 }
 
 impl Default for AgentConfig {
@@ -805,3 +1049,113 @@ impl InMemoryAgentMemory {
         })
     }
 }
+
+#[cfg(test)]
+mod capability_handler_tests {
+    use super::*;
+
+    struct CalculatorHandler;
+
+    impl CapabilityHandler for CalculatorHandler {
+        fn execute(&self, task: Task) -> BoxFuture<'static, TaskResult> {
+            Box::pin(async move {
+                let sum: i64 = task
+                    .payload
+                    .get("operands")
+                    .and_then(|v| v.as_array())
+                    .map(|operands| operands.iter().filter_map(|v| v.as_i64()).sum())
+                    .unwrap_or(0);
+
+                TaskResult {
+                    success: true,
+                    output: sum.to_string(),
+                    execution_time_ms: 0,
+                    resources_used: ResourceUsage { cpu_time_ms: 0, memory_peak_mb: 0, network_bytes: 0, file_operations: 0, api_requests: 0 },
+                    quality_score: 1.0,
+                    error_message: None,
+                }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn hot_registered_calculator_capability_handles_subsequent_tasks() {
+        let agent = Agent::new(AgentConfig::default()).await.expect("agent should start");
+
+        agent.register_capability_handler(
+            VersionedCapability { name: "calculator".to_string(), version: "1.0".to_string() },
+            Box::new(CalculatorHandler),
+        );
+
+        let task = Task {
+            id: Uuid::new_v4(),
+            capability: "calculator".to_string(),
+            payload: serde_json::json!({ "operands": [2, 3, 4] }),
+        };
+        let result = agent.dispatch_task(task).await.expect("calculator task should succeed");
+
+        assert!(result.success);
+        assert_eq!(result.output, "9");
+    }
+
+    #[tokio::test]
+    async fn dispatch_fails_for_an_unregistered_capability() {
+        let agent = Agent::new(AgentConfig::default()).await.expect("agent should start");
+
+        let task = Task { id: Uuid::new_v4(), capability: "calculator".to_string(), payload: serde_json::json!({}) };
+
+        assert!(agent.dispatch_task(task).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn unregistering_a_capability_drains_before_removal_and_rejects_new_tasks() {
+        let agent = Agent::new(AgentConfig::default()).await.expect("agent should start");
+        agent.register_capability_handler(
+            VersionedCapability { name: "calculator".to_string(), version: "1.0".to_string() },
+            Box::new(CalculatorHandler),
+        );
+
+        agent.unregister_capability_handler("calculator").await;
+
+        let task = Task { id: Uuid::new_v4(), capability: "calculator".to_string(), payload: serde_json::json!({}) };
+        assert!(agent.dispatch_task(task).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn supervised_agent_parks_a_task_until_approved() {
+        let config = AgentConfig { autonomy_level: AutonomyLevel::Supervised, ..AgentConfig::default() };
+        let agent = Agent::new(config).await.expect("agent should start");
+        agent.register_capability_handler(
+            VersionedCapability { name: "calculator".to_string(), version: "1.0".to_string() },
+            Box::new(CalculatorHandler),
+        );
+
+        let task_id = Uuid::new_v4();
+        let task = Task { id: task_id, capability: "calculator".to_string(), payload: serde_json::json!({ "operands": [1, 2] }) };
+
+        assert!(agent.dispatch_task(task).await.is_err());
+
+        let result = agent.approve_task(task_id).await.expect("approved task should execute");
+        assert!(result.success);
+        assert_eq!(result.output, "3");
+    }
+
+    #[tokio::test]
+    async fn approving_an_unknown_task_id_fails() {
+        let config = AgentConfig { autonomy_level: AutonomyLevel::Supervised, ..AgentConfig::default() };
+        let agent = Agent::new(config).await.expect("agent should start");
+
+        assert!(agent.approve_task(Uuid::new_v4()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn critic_agent_cannot_be_fully_autonomous() {
+        let config = AgentConfig {
+            agent_type: AgentType::Critic,
+            autonomy_level: AutonomyLevel::Autonomous,
+            ..AgentConfig::default()
+        };
+
+        assert!(Agent::new(config).await.is_err());
+    }
+}
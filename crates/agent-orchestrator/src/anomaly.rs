@@ -0,0 +1,248 @@
+//! Anomaly detection for orchestration metric time series.
+//!
+//! [`AnomalyDetector`] wraps an [`IsolationForest`] so [`crate::monitor::OrchestrationMonitor`]
+//! can flag agent performance snapshots that look nothing like what came
+//! before, without needing labeled examples of "bad" behavior: isolation
+//! forests separate points by repeatedly splitting on a random feature at a
+//! random threshold, and anomalies are the points that take unusually few
+//! splits to isolate.
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for [`IsolationForest::fit`].
+#[derive(Debug, Clone, Copy)]
+pub struct IsolationForestConfig {
+    pub n_trees: usize,
+    pub subsample_size: usize,
+}
+
+impl Default for IsolationForestConfig {
+    fn default() -> Self {
+        Self { n_trees: 100, subsample_size: 256 }
+    }
+}
+
+#[derive(Debug)]
+enum IsolationNode {
+    Leaf { size: usize },
+    Internal { feature: usize, split_value: f64, left: Box<IsolationNode>, right: Box<IsolationNode> },
+}
+
+#[derive(Debug)]
+struct IsolationTree {
+    root: IsolationNode,
+}
+
+impl IsolationTree {
+    fn build(data: &[Vec<f64>], depth: usize, max_depth: usize, rng: &mut impl Rng) -> IsolationNode {
+        if data.len() <= 1 || depth >= max_depth {
+            return IsolationNode::Leaf { size: data.len() };
+        }
+
+        let dimensions = data[0].len();
+        let feature = rng.gen_range(0..dimensions);
+        let (min, max) = data.iter().fold((f64::MAX, f64::MIN), |(min, max), point| {
+            (min.min(point[feature]), max.max(point[feature]))
+        });
+        if min == max {
+            return IsolationNode::Leaf { size: data.len() };
+        }
+        let split_value = rng.gen_range(min..max);
+
+        let (left_data, right_data): (Vec<_>, Vec<_>) =
+            data.iter().cloned().partition(|point| point[feature] < split_value);
+        if left_data.is_empty() || right_data.is_empty() {
+            return IsolationNode::Leaf { size: data.len() };
+        }
+
+        IsolationNode::Internal {
+            feature,
+            split_value,
+            left: Box::new(Self::build(&left_data, depth + 1, max_depth, rng)),
+            right: Box::new(Self::build(&right_data, depth + 1, max_depth, rng)),
+        }
+    }
+
+    /// Number of edges traversed to isolate `point`, plus the average-path-length
+    /// correction for the leaf it lands in (an unsplit leaf of size `n` stands
+    /// in for the `average_path_length(n)` splits a full tree would have taken).
+    fn path_length(&self, point: &[f64]) -> f64 {
+        Self::node_path_length(&self.root, point, 0)
+    }
+
+    fn node_path_length(node: &IsolationNode, point: &[f64], depth: usize) -> f64 {
+        match node {
+            IsolationNode::Leaf { size } => depth as f64 + average_path_length(*size),
+            IsolationNode::Internal { feature, split_value, left, right } => {
+                if point[*feature] < *split_value {
+                    Self::node_path_length(left, point, depth + 1)
+                } else {
+                    Self::node_path_length(right, point, depth + 1)
+                }
+            }
+        }
+    }
+}
+
+/// Expected path length to isolate a point in a binary search tree built
+/// from `n` points (Liu, Ting & Zhou's isolation forest normalizer).
+fn average_path_length(n: usize) -> f64 {
+    if n <= 1 {
+        return 0.0;
+    }
+    let n = n as f64;
+    2.0 * (harmonic_number(n - 1.0)) - (2.0 * (n - 1.0) / n)
+}
+
+/// Harmonic number approximation via the Euler-Mascheroni constant.
+fn harmonic_number(n: f64) -> f64 {
+    n.ln() + 0.5772156649
+}
+
+/// An ensemble of [`IsolationTree`]s, each trained on a random subsample of
+/// the fitting data, whose average isolation depth for a point yields an
+/// anomaly score in `(0, 1]`: scores near 1 are anomalies, scores near or
+/// below 0.5 are normal.
+#[derive(Debug)]
+pub struct IsolationForest {
+    trees: Vec<IsolationTree>,
+    subsample_size: usize,
+}
+
+impl IsolationForest {
+    pub fn fit(data: &[Vec<f64>], config: IsolationForestConfig) -> Self {
+        let subsample_size = config.subsample_size.min(data.len()).max(1);
+        let max_depth = (subsample_size as f64).log2().ceil().max(1.0) as usize;
+        let mut rng = rand::thread_rng();
+
+        let trees = (0..config.n_trees)
+            .map(|_| {
+                let mut subsample = Vec::with_capacity(subsample_size);
+                for _ in 0..subsample_size {
+                    let index = rng.gen_range(0..data.len());
+                    subsample.push(data[index].clone());
+                }
+                IsolationTree { root: IsolationTree::build(&subsample, 0, max_depth, &mut rng) }
+            })
+            .collect();
+
+        Self { trees, subsample_size }
+    }
+
+    /// Anomaly score for `point`, in `(0, 1]`. Higher means more anomalous.
+    pub fn anomaly_score(&self, point: &[f64]) -> f64 {
+        let average_depth =
+            self.trees.iter().map(|tree| tree.path_length(point)).sum::<f64>() / self.trees.len() as f64;
+        2f64.powf(-average_depth / average_path_length(self.subsample_size).max(f64::EPSILON))
+    }
+}
+
+/// Configuration for [`AnomalyDetector`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct AnomalyDetectionConfig {
+    /// Number of observations collected before the isolation forest is
+    /// trained and scoring begins.
+    pub baseline_period: usize,
+    pub n_trees: usize,
+    pub subsample_size: usize,
+    /// Observations scoring above this are reported as anomalies.
+    pub alert_threshold: f64,
+}
+
+impl Default for AnomalyDetectionConfig {
+    fn default() -> Self {
+        Self { baseline_period: 100, n_trees: 100, subsample_size: 256, alert_threshold: 0.6 }
+    }
+}
+
+/// A metric vector that scored above the configured anomaly threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnomalyAlert {
+    pub metrics: Vec<f64>,
+    pub score: f64,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Trains an [`IsolationForest`] on the first `baseline_period` metric
+/// vectors it observes, then scores every observation after that against
+/// the resulting forest.
+#[derive(Debug)]
+pub struct AnomalyDetector {
+    config: AnomalyDetectionConfig,
+    baseline: Vec<Vec<f64>>,
+    forest: Option<IsolationForest>,
+    alerts: Vec<AnomalyAlert>,
+}
+
+impl AnomalyDetector {
+    pub fn new(config: AnomalyDetectionConfig) -> Self {
+        Self { config, baseline: Vec::new(), forest: None, alerts: Vec::new() }
+    }
+
+    pub fn alerts(&self) -> &[AnomalyAlert] {
+        &self.alerts
+    }
+
+    /// Records `metrics` as the next observation in the time series. Returns
+    /// `Some` once the detector has a trained baseline and `metrics` scores
+    /// above [`AnomalyDetectionConfig::alert_threshold`]; otherwise `None`.
+    pub fn observe(&mut self, metrics: Vec<f64>) -> Option<AnomalyAlert> {
+        if self.forest.is_none() {
+            self.baseline.push(metrics);
+            if self.baseline.len() >= self.config.baseline_period {
+                let forest_config =
+                    IsolationForestConfig { n_trees: self.config.n_trees, subsample_size: self.config.subsample_size };
+                self.forest = Some(IsolationForest::fit(&self.baseline, forest_config));
+            }
+            return None;
+        }
+
+        let forest = self.forest.as_ref().expect("forest is trained once baseline is full");
+        let score = forest.anomaly_score(&metrics);
+        if score > self.config.alert_threshold {
+            let alert = AnomalyAlert { metrics, score, timestamp: Utc::now() };
+            self.alerts.push(alert.clone());
+            return Some(alert);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isolation_forest_scores_obvious_outliers_higher_than_normal_points() {
+        let mut rng = rand::thread_rng();
+        let normal: Vec<Vec<f64>> =
+            (0..1000).map(|_| vec![rng.gen_range(0.0..1.0), rng.gen_range(0.0..1.0), rng.gen_range(0.0..1.0)]).collect();
+
+        let forest = IsolationForest::fit(&normal, IsolationForestConfig::default());
+
+        let outliers: Vec<Vec<f64>> = (0..10).map(|_| vec![50.0, -50.0, 100.0]).collect();
+        for outlier in &outliers {
+            let score = forest.anomaly_score(outlier);
+            assert!(score > 0.6, "expected outlier to score above 0.6, got {score}");
+        }
+    }
+
+    #[test]
+    fn detector_stays_quiet_during_the_baseline_period_then_flags_a_spike() {
+        let mut rng = rand::thread_rng();
+        let config = AnomalyDetectionConfig { baseline_period: 200, ..AnomalyDetectionConfig::default() };
+        let mut detector = AnomalyDetector::new(config);
+
+        for _ in 0..200 {
+            let point = vec![rng.gen_range(0.0..1.0), rng.gen_range(0.0..1.0), rng.gen_range(0.0..1.0)];
+            assert!(detector.observe(point).is_none(), "detector should not alert before the baseline fills");
+        }
+
+        let alert = detector.observe(vec![500.0, -500.0, 1000.0]);
+        assert!(alert.is_some(), "expected an obvious spike to raise an alert");
+        assert_eq!(detector.alerts().len(), 1);
+    }
+}
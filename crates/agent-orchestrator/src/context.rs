@@ -4,15 +4,23 @@
 //! including context windows and optimization.
 
 use crate::OrchestrationError;
+use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
+/// Fragmentation ratio above which `ContextManager` should call
+/// `SlabAllocator::defragment` before allocating further.
+pub const SLAB_FRAGMENTATION_THRESHOLD: f64 = 0.3;
+
 /// Context manager for agent context handling
 #[derive(Debug)]
 pub struct ContextManager {
     pub contexts: HashMap<Uuid, ContextWindow>,
     pub optimizer: ContextOptimizer,
+    pub allocator_strategy: AllocatorStrategy,
+    pub slab: Option<SlabAllocator>,
 }
 
 /// Context window for agent operations
@@ -24,6 +32,36 @@ pub struct ContextWindow {
     pub size: usize,
     pub max_size: usize,
     pub optimization_enabled: bool,
+    /// Individual entries accumulated in this window, oldest first, used by
+    /// `dedup`/`compress` to find and drop near-duplicate content.
+    #[serde(default)]
+    pub entries: Vec<ContextEntry>,
+    /// Number of entries `dedup` has removed as near-duplicates over the
+    /// lifetime of this window.
+    #[serde(default)]
+    pub dedup_entries_removed: u64,
+}
+
+/// A single piece of content added to a [`ContextWindow`], along with the
+/// embedding used to detect near-duplicates against other entries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextEntry {
+    pub content: String,
+    pub embedding: Vec<f32>,
+    pub added_at: chrono::DateTime<chrono::Utc>,
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let dot: f64 = a.iter().zip(b).map(|(&x, &y)| x as f64 * y as f64).sum();
+    let norm_a: f64 = a.iter().map(|&x| (x as f64).powi(2)).sum::<f64>().sqrt();
+    let norm_b: f64 = b.iter().map(|&x| (x as f64).powi(2)).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
 }
 
 /// Context optimization system
@@ -34,11 +72,209 @@ pub struct ContextOptimizer {
     pub priority_preservation: bool,
 }
 
+/// Strategy used to allocate fixed-size context entry storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AllocatorStrategy {
+    /// Entries are allocated from a fixed pool of uniformly-sized slots,
+    /// avoiding per-window heap allocation churn.
+    Slab,
+    /// Entries are allocated directly from the heap on demand (the
+    /// previous, unbounded behavior).
+    Heap,
+}
+
+/// One fixed-size slot in a [`SlabAllocator`] pool.
+#[derive(Debug, Clone)]
+struct Slot {
+    window: Option<ContextWindow>,
+}
+
+/// Maps a context window's id to its current slot index. Held behind an
+/// `Arc<parking_lot::RwLock<_>>` so lookups (`get`/`get_mut`) can proceed
+/// concurrently with each other while `defragment` takes an exclusive lock
+/// to rewrite every entry as it compacts live slots.
+pub type SlotRedirectTable = HashMap<Uuid, usize>;
+
+/// A slab allocator that pre-reserves a fixed number of fixed-size
+/// [`ContextWindow`] slots, avoiding the allocation/deallocation churn of
+/// creating and dropping a new context window per agent request.
+#[derive(Debug)]
+pub struct SlabAllocator {
+    slot_size: usize,
+    slots: Vec<Slot>,
+    free_list: Vec<usize>,
+    index: Arc<RwLock<SlotRedirectTable>>,
+    defragmentation_count: u64,
+}
+
+impl SlabAllocator {
+    /// Creates a pool of `capacity` slots, each able to hold a context
+    /// window up to `slot_size` bytes.
+    pub fn new(capacity: usize, slot_size: usize) -> Self {
+        Self {
+            slot_size,
+            slots: (0..capacity).map(|_| Slot { window: None }).collect(),
+            free_list: (0..capacity).rev().collect(),
+            index: Arc::new(RwLock::new(HashMap::new())),
+            defragmentation_count: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.read().is_empty()
+    }
+
+    /// Allocates a context window from the pool. Fails with
+    /// [`OrchestrationError::ResourceExhausted`] if the pool is full or the
+    /// requested size exceeds `slot_size`.
+    pub fn allocate(&mut self, agent_id: Uuid, max_size: usize) -> Result<Uuid, OrchestrationError> {
+        if max_size > self.slot_size {
+            return Err(OrchestrationError::ResourceExhausted(format!(
+                "requested context window of {max_size} bytes exceeds slab slot size {}",
+                self.slot_size
+            )));
+        }
+
+        let slot_idx = self.free_list.pop().ok_or_else(|| {
+            OrchestrationError::ResourceExhausted(format!(
+                "context window slab exhausted ({} slots in use)",
+                self.slots.len()
+            ))
+        })?;
+
+        let id = Uuid::new_v4();
+        self.slots[slot_idx].window = Some(ContextWindow {
+            id,
+            agent_id,
+            content: String::new(),
+            size: 0,
+            max_size,
+            optimization_enabled: true,
+            entries: Vec::new(),
+            dedup_entries_removed: 0,
+        });
+        self.index.write().insert(id, slot_idx);
+        Ok(id)
+    }
+
+    /// Returns a slot to the free list, making it available for reuse.
+    pub fn free(&mut self, id: Uuid) -> Option<ContextWindow> {
+        let slot_idx = self.index.write().remove(&id)?;
+        let window = self.slots[slot_idx].window.take();
+        self.free_list.push(slot_idx);
+        window
+    }
+
+    pub fn get(&self, id: Uuid) -> Option<&ContextWindow> {
+        let slot_idx = *self.index.read().get(&id)?;
+        self.slots[slot_idx].window.as_ref()
+    }
+
+    pub fn get_mut(&mut self, id: Uuid) -> Option<&mut ContextWindow> {
+        let slot_idx = *self.index.read().get(&id)?;
+        self.slots[slot_idx].window.as_mut()
+    }
+
+    /// Fraction of slots that are free "holes" sitting below the
+    /// highest-indexed live slot, i.e. free slots that compaction could
+    /// reclaim into contiguous trailing free space. 0.0 when there are
+    /// fewer than two live slots (nothing to fragment between).
+    pub fn fragmentation_ratio(&self) -> f64 {
+        let Some(highest_live) = self.slots.iter().rposition(|slot| slot.window.is_some()) else {
+            return 0.0;
+        };
+        if highest_live == 0 {
+            return 0.0;
+        }
+
+        let holes = self.slots[..=highest_live].iter().filter(|slot| slot.window.is_none()).count();
+        holes as f64 / self.slots.len() as f64
+    }
+
+    /// Bytes tied up in fragmentation holes: free slots sitting below the
+    /// highest-indexed live slot, each worth `slot_size` bytes.
+    pub fn wasted_bytes(&self) -> usize {
+        let Some(highest_live) = self.slots.iter().rposition(|slot| slot.window.is_some()) else {
+            return 0;
+        };
+        let holes = self.slots[..=highest_live].iter().filter(|slot| slot.window.is_none()).count();
+        holes * self.slot_size
+    }
+
+    /// Number of times `defragment` has run over this allocator's lifetime.
+    pub fn defragmentation_count(&self) -> u64 {
+        self.defragmentation_count
+    }
+
+    /// Compacts live context windows into a contiguous prefix of the slot
+    /// array (moving-GC style), closing the holes `fragmentation_ratio`
+    /// reports. Takes the redirect table's exclusive write lock for the
+    /// duration of the compaction so `get`/`get_mut` never observe a
+    /// window at a stale slot index; readers can otherwise proceed
+    /// concurrently between defragmentation passes. Returns the number of
+    /// slots moved.
+    pub fn defragment(&mut self) -> usize {
+        let mut index = self.index.write();
+
+        let mut write_idx = 0;
+        let mut moved = 0;
+        for read_idx in 0..self.slots.len() {
+            if self.slots[read_idx].window.is_none() {
+                continue;
+            }
+            if read_idx != write_idx {
+                self.slots.swap(read_idx, write_idx);
+                let id = self.slots[write_idx].window.as_ref().expect("just checked Some").id;
+                index.insert(id, write_idx);
+                moved += 1;
+            }
+            write_idx += 1;
+        }
+
+        self.free_list = (write_idx..self.slots.len()).rev().collect();
+        self.defragmentation_count += 1;
+        moved
+    }
+
+    /// Runs `defragment` when `fragmentation_ratio` has crossed
+    /// `SLAB_FRAGMENTATION_THRESHOLD`. Returns the number of slots moved,
+    /// or 0 if defragmentation wasn't needed.
+    pub fn defragment_if_fragmented(&mut self) -> usize {
+        if self.fragmentation_ratio() > SLAB_FRAGMENTATION_THRESHOLD {
+            self.defragment()
+        } else {
+            0
+        }
+    }
+}
+
 impl ContextManager {
     pub fn new() -> Self {
         Self {
             contexts: HashMap::new(),
             optimizer: ContextOptimizer::new(),
+            allocator_strategy: AllocatorStrategy::Heap,
+            slab: None,
+        }
+    }
+
+    /// Creates a manager backed by a slab allocator, pre-reserving
+    /// `capacity` fixed-size slots of `slot_size` bytes each for context
+    /// windows instead of allocating one on the heap per request.
+    pub fn with_slab_allocator(capacity: usize, slot_size: usize) -> Self {
+        Self {
+            contexts: HashMap::new(),
+            optimizer: ContextOptimizer::new(),
+            allocator_strategy: AllocatorStrategy::Slab,
+            slab: Some(SlabAllocator::new(capacity, slot_size)),
         }
     }
 
@@ -47,6 +283,13 @@ impl ContextManager {
         agent_id: Uuid,
         max_size: usize,
     ) -> Result<Uuid, OrchestrationError> {
+        if let (AllocatorStrategy::Slab, Some(slab)) = (self.allocator_strategy, &mut self.slab) {
+            // Check before allocating so a heavily-fragmented slab is
+            // compacted ahead of serving the next request rather than after.
+            slab.defragment_if_fragmented();
+            return slab.allocate(agent_id, max_size);
+        }
+
         let id = Uuid::new_v4();
         let window = ContextWindow {
             id,
@@ -55,11 +298,22 @@ impl ContextManager {
             size: 0,
             max_size,
             optimization_enabled: true,
+            entries: Vec::new(),
+            dedup_entries_removed: 0,
         };
-        
+
         self.contexts.insert(id, window);
         Ok(id)
     }
+
+    /// Releases a context window, returning its slot to the slab pool when
+    /// slab allocation is in use.
+    pub fn release_context_window(&mut self, id: Uuid) -> Option<ContextWindow> {
+        if let (AllocatorStrategy::Slab, Some(slab)) = (self.allocator_strategy, &mut self.slab) {
+            return slab.free(id);
+        }
+        self.contexts.remove(&id)
+    }
 }
 
 impl ContextOptimizer {
@@ -70,4 +324,164 @@ impl ContextOptimizer {
             priority_preservation: true,
         }
     }
+}
+
+impl ContextWindow {
+    /// Appends a new entry to the window's entry list.
+    pub fn add_entry(&mut self, content: String, embedding: Vec<f32>) {
+        self.entries.push(ContextEntry {
+            content,
+            embedding,
+            added_at: chrono::Utc::now(),
+        });
+    }
+
+    /// Removes near-duplicate entries, keeping only the most recent entry of
+    /// each cluster of entries whose pairwise cosine similarity exceeds
+    /// `similarity_threshold`. Increments `dedup_entries_removed` by the
+    /// number of entries dropped.
+    pub fn dedup(&mut self, similarity_threshold: f64) {
+        // Walk newest-first so the entry kept from each near-duplicate
+        // cluster is always the most recent one.
+        let mut by_recency: Vec<usize> = (0..self.entries.len()).collect();
+        by_recency.sort_by(|&a, &b| self.entries[b].added_at.cmp(&self.entries[a].added_at));
+
+        let mut kept_indices: Vec<usize> = Vec::new();
+        for &idx in &by_recency {
+            let is_duplicate = kept_indices
+                .iter()
+                .any(|&kept| cosine_similarity(&self.entries[idx].embedding, &self.entries[kept].embedding) > similarity_threshold);
+            if !is_duplicate {
+                kept_indices.push(idx);
+            }
+        }
+
+        let removed = self.entries.len() - kept_indices.len();
+        if removed == 0 {
+            return;
+        }
+
+        kept_indices.sort_unstable();
+        let mut kept_indices = kept_indices.into_iter();
+        let mut next_kept = kept_indices.next();
+        let mut i = 0;
+        self.entries.retain(|_| {
+            let keep = next_kept == Some(i);
+            if keep {
+                next_kept = kept_indices.next();
+            }
+            i += 1;
+            keep
+        });
+
+        self.dedup_entries_removed += removed as u64;
+    }
+
+    /// Runs the window's compression pipeline: currently semantic
+    /// deduplication, with room for summarization/truncation stages to be
+    /// added alongside it as they're implemented.
+    pub fn compress(&mut self, similarity_threshold: f64) {
+        self.dedup(similarity_threshold);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window_with_entries(pairs: &[(&str, Vec<f32>)]) -> ContextWindow {
+        let mut window = ContextWindow {
+            id: Uuid::new_v4(),
+            agent_id: Uuid::new_v4(),
+            content: String::new(),
+            size: 0,
+            max_size: 1_000_000,
+            optimization_enabled: true,
+            entries: Vec::new(),
+            dedup_entries_removed: 0,
+        };
+        for (content, embedding) in pairs {
+            window.entries.push(ContextEntry {
+                content: content.to_string(),
+                embedding: embedding.clone(),
+                added_at: chrono::Utc::now() + chrono::Duration::milliseconds(window.entries.len() as i64),
+            });
+        }
+        window
+    }
+
+    #[test]
+    fn dedup_keeps_the_more_recent_of_each_near_duplicate_pair() {
+        // 5 near-duplicate pairs (cosine > 0.95), each pair's second entry
+        // slightly more recent than its first.
+        let mut pairs = Vec::new();
+        for i in 0..5 {
+            let mut base = vec![0.0f32; 5];
+            base[i] = 1.0;
+            let mut near_duplicate = base.clone();
+            near_duplicate[i] = 0.99;
+            near_duplicate[(i + 1) % 5] = 0.01;
+            pairs.push((format!("entry-{i}-a"), base));
+            pairs.push((format!("entry-{i}-b"), near_duplicate));
+        }
+        let pairs: Vec<(&str, Vec<f32>)> = pairs.iter().map(|(s, v)| (s.as_str(), v.clone())).collect();
+        let mut window = window_with_entries(&pairs);
+
+        window.dedup(0.95);
+
+        assert_eq!(window.entries.len(), 5);
+        assert_eq!(window.dedup_entries_removed, 5);
+        for entry in &window.entries {
+            assert!(entry.content.ends_with("-b"), "expected the more recent entry of each pair to survive, got {}", entry.content);
+        }
+    }
+
+    #[test]
+    fn defragment_reclaims_most_wasted_bytes_after_heavy_alloc_free_churn() {
+        let mut slab = SlabAllocator::new(10_000, 64);
+        let agent_id = Uuid::new_v4();
+
+        // 10,000 alloc/free cycles with a 50% survival rate: free every
+        // other allocation, scattering holes through the whole live region
+        // instead of leaving them at the tail.
+        let mut allocated = Vec::with_capacity(10_000);
+        for _ in 0..10_000 {
+            allocated.push(slab.allocate(agent_id, 64).unwrap());
+        }
+        let survivors: Vec<Uuid> = allocated.iter().enumerate()
+            .filter_map(|(i, id)| if i % 2 == 0 { Some(*id) } else { None })
+            .collect();
+        for (i, id) in allocated.iter().enumerate() {
+            if i % 2 != 0 {
+                slab.free(*id);
+            }
+        }
+
+        let wasted_before = slab.wasted_bytes();
+        assert!(wasted_before > 0, "expected fragmentation after freeing half the live slots");
+
+        slab.defragment();
+
+        let wasted_after = slab.wasted_bytes();
+        assert!(
+            (wasted_after as f64) <= (wasted_before as f64) * 0.2,
+            "expected defragment to cut wasted bytes by at least 80%, went from {wasted_before} to {wasted_after}"
+        );
+        assert_eq!(slab.defragmentation_count(), 1);
+
+        // Every surviving id must still resolve to a valid window after compaction.
+        for id in &survivors {
+            assert!(slab.get(*id).is_some(), "surviving window {id} lost after defragment");
+        }
+    }
+
+    #[test]
+    fn fragmentation_ratio_is_zero_for_a_freshly_compacted_slab() {
+        let mut slab = SlabAllocator::new(10, 64);
+        let agent_id = Uuid::new_v4();
+        for _ in 0..5 {
+            slab.allocate(agent_id, 64).unwrap();
+        }
+        assert_eq!(slab.fragmentation_ratio(), 0.0);
+    }
 }
\ No newline at end of file
@@ -12,12 +12,32 @@ use tokio::sync::RwLock;
 use uuid::Uuid;
 use std::time::Duration;
 
+/// Identifies a [`ContextSnapshot`] within the DAG tracked by
+/// `context_edges`. An alias rather than a newtype, matching how
+/// [`crate::recursion::AgentId`] aliases `Uuid` elsewhere in this crate.
+pub type ContextSnapshotId = Uuid;
+
 /// Context propagation manager for recursive operations
 pub struct ContextPropagationManager {
     pub context_store: Arc<RwLock<HashMap<Uuid, ContextSnapshot>>>,
     pub propagation_rules: Vec<PropagationRule>,
     pub context_optimizer: ContextOptimizer,
     pub inheritance_strategies: HashMap<String, InheritanceStrategy>,
+    /// Child -> parent agent id, used to route `propose_upstream` proposals
+    /// and to forward accepted patches further up the hierarchy.
+    pub agent_parents: Arc<RwLock<HashMap<Uuid, Uuid>>>,
+    /// Each agent's rule for accepting proposals from its children.
+    pub upstream_rules: Arc<RwLock<HashMap<Uuid, UpstreamPropagationRule>>>,
+    /// Proposals waiting for the agent keyed here to call
+    /// `accept_upstream_proposals`.
+    pub pending_upstream_proposals: Arc<RwLock<HashMap<Uuid, Vec<UpstreamProposal>>>>,
+    /// Child snapshot id -> its direct parent snapshot ids. Unlike
+    /// `ContextSnapshot::dependencies` (the full root-to-self path of a
+    /// single-parent chain), this is an explicit DAG that allows a snapshot
+    /// to have more than one parent, so branches that later converge (a
+    /// diamond shape) can be represented and replayed in dependency order
+    /// via `propagation_order`.
+    pub context_edges: Arc<RwLock<HashMap<ContextSnapshotId, Vec<ContextSnapshotId>>>>,
 }
 
 /// Snapshot of context at a specific point in execution
@@ -45,6 +65,65 @@ pub struct ContextState {
     pub custom_data: HashMap<String, serde_json::Value>,
 }
 
+/// Structural diff of `state.variables` between two [`ContextSnapshot`]s.
+/// Used by `DynamicWorkflowModifier` to decide whether a rule's relevant
+/// keys actually moved instead of re-evaluating every rule on every tick.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContextDiff {
+    pub added: HashMap<String, serde_json::Value>,
+    pub removed: Vec<String>,
+    pub changed: HashMap<String, (serde_json::Value, serde_json::Value)>,
+}
+
+impl ContextDiff {
+    /// True when neither snapshot's variables differ.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Every variable name touched by this diff, in no particular order.
+    pub fn changed_keys(&self) -> impl Iterator<Item = &str> {
+        self.added
+            .keys()
+            .map(String::as_str)
+            .chain(self.removed.iter().map(String::as_str))
+            .chain(self.changed.keys().map(String::as_str))
+    }
+}
+
+impl ContextSnapshot {
+    /// Diffs `self.state.variables` against `other`'s: keys present only in
+    /// `other` are `added`, keys present only in `self` are `removed`, and
+    /// keys present in both with different values are `changed` (holding
+    /// `(self's value, other's value)`).
+    pub fn diff(&self, other: &ContextSnapshot) -> ContextDiff {
+        let mut added = HashMap::new();
+        let mut changed = HashMap::new();
+
+        for (key, other_value) in &other.state.variables {
+            match self.state.variables.get(key) {
+                None => {
+                    added.insert(key.clone(), other_value.clone());
+                }
+                Some(self_value) if self_value != other_value => {
+                    changed.insert(key.clone(), (self_value.clone(), other_value.clone()));
+                }
+                Some(_) => {}
+            }
+        }
+
+        let removed = self
+            .state
+            .variables
+            .keys()
+            .filter(|key| !other.state.variables.contains_key(*key))
+            .cloned()
+            .collect();
+
+        ContextDiff { added, removed, changed }
+    }
+}
+
 /// Context metadata for tracking and optimization
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContextMetadata {
@@ -271,6 +350,30 @@ pub struct InheritanceStrategy {
     pub depth_limit: Option<usize>,
     pub resource_threshold: Option<f64>,
     pub custom_logic: Option<String>,
+    /// How `merge_ancestor_state` resolves two ancestors writing different
+    /// values to the same key -- only reachable when the DAG has a diamond
+    /// shape, since a single-parent chain never has more than one writer
+    /// per key at merge time.
+    pub conflict_resolution: ConflictResolution,
+}
+
+/// How [`ContextPropagationManager::merge_ancestor_state`] resolves two
+/// ancestor snapshots that wrote different values to the same key.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// The ancestor later in propagation order (i.e. closer to `target`)
+    /// wins. This is the default because it mirrors how a single-parent
+    /// chain already behaves: the nearest ancestor's state shadows older
+    /// ones.
+    #[default]
+    LastWrite,
+    /// The ancestor whose snapshot has the numerically-lower (more urgent)
+    /// `ContextPriority` wins, regardless of propagation order.
+    HighestPriority,
+    /// If both values are JSON objects, shallow-merge their keys (later
+    /// ancestors overwrite earlier ones key-by-key). Any other value shape
+    /// falls back to `LastWrite`.
+    Merge,
 }
 
 /// Types of inheritance
@@ -283,6 +386,45 @@ pub enum InheritanceType {
     Custom,         // Custom inheritance logic
 }
 
+/// Default confidence assigned to a proposal made via `propose_upstream`,
+/// since the caller only supplies a key/value, not a confidence score.
+pub const DEFAULT_UPSTREAM_PROPOSAL_CONFIDENCE: f64 = 1.0;
+
+/// A pending piece of context a child agent wants its parent to adopt.
+/// `source_agent_id` is preserved unchanged as the proposal is forwarded up
+/// through further levels, so a root can always tell which agent originally
+/// discovered the value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpstreamProposal {
+    pub source_agent_id: Uuid,
+    pub key: String,
+    pub value: serde_json::Value,
+    pub confidence: f64,
+}
+
+/// A key/value accepted into an agent's context after clearing that agent's
+/// `UpstreamPropagationRule`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextPatch {
+    pub source_agent_id: Uuid,
+    pub key: String,
+    pub value: serde_json::Value,
+    pub confidence: f64,
+}
+
+/// Governs which upstream proposals an agent accepts from its children.
+/// Kept separate from `PropagationRule`, which only governs parent -> child
+/// inheritance and has no notion of a confidence threshold or child ->
+/// parent direction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum UpstreamPropagationRule {
+    AllowUpstream {
+        accepted_keys: Vec<String>,
+        min_confidence: f64,
+    },
+    Deny,
+}
+
 /// Context optimizer for managing context size and performance
 #[derive(Clone)]
 pub struct ContextOptimizer {
@@ -320,7 +462,88 @@ impl ContextPropagationManager {
             propagation_rules: Vec::new(),
             context_optimizer: ContextOptimizer::new(),
             inheritance_strategies: HashMap::new(),
+            agent_parents: Arc::new(RwLock::new(HashMap::new())),
+            upstream_rules: Arc::new(RwLock::new(HashMap::new())),
+            pending_upstream_proposals: Arc::new(RwLock::new(HashMap::new())),
+            context_edges: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Registers `parent_agent_id` as `agent_id`'s parent for the purposes of
+    /// upstream context propagation.
+    pub async fn register_agent_parent(&self, agent_id: Uuid, parent_agent_id: Uuid) {
+        self.agent_parents.write().await.insert(agent_id, parent_agent_id);
+    }
+
+    /// Sets the rule `agent_id` uses when deciding whether to accept
+    /// proposals from its children.
+    pub async fn set_upstream_rule(&self, agent_id: Uuid, rule: UpstreamPropagationRule) {
+        self.upstream_rules.write().await.insert(agent_id, rule);
+    }
+
+    /// Queues a key/value discovered by `agent_id` for its parent to review.
+    /// A no-op if `agent_id` has no registered parent.
+    pub async fn propose_upstream(&self, agent_id: Uuid, key: String, value: serde_json::Value) {
+        let Some(parent_id) = self.agent_parents.read().await.get(&agent_id).copied() else {
+            return;
+        };
+
+        self.pending_upstream_proposals
+            .write()
+            .await
+            .entry(parent_id)
+            .or_default()
+            .push(UpstreamProposal {
+                source_agent_id: agent_id,
+                key,
+                value,
+                confidence: DEFAULT_UPSTREAM_PROPOSAL_CONFIDENCE,
+            });
+    }
+
+    /// Drains `agent_id`'s pending proposals, keeping only the ones that
+    /// clear `agent_id`'s own `UpstreamPropagationRule`. Accepted proposals
+    /// are forwarded on as new proposals to `agent_id`'s own parent (if any),
+    /// so a value proposed several levels down only reaches the root once
+    /// every intermediate level has an `AllowUpstream` rule that accepts it.
+    pub async fn accept_upstream_proposals(&self, agent_id: Uuid) -> Vec<ContextPatch> {
+        let proposals = self
+            .pending_upstream_proposals
+            .write()
+            .await
+            .remove(&agent_id)
+            .unwrap_or_default();
+
+        let rule = self.upstream_rules.read().await.get(&agent_id).cloned();
+        let Some(UpstreamPropagationRule::AllowUpstream { accepted_keys, min_confidence }) = rule else {
+            return Vec::new();
+        };
+
+        let mut accepted = Vec::new();
+        for proposal in proposals {
+            if accepted_keys.contains(&proposal.key) && proposal.confidence >= min_confidence {
+                accepted.push(ContextPatch {
+                    source_agent_id: proposal.source_agent_id,
+                    key: proposal.key,
+                    value: proposal.value,
+                    confidence: proposal.confidence,
+                });
+            }
+        }
+
+        if let Some(grandparent_id) = self.agent_parents.read().await.get(&agent_id).copied() {
+            let mut pending = self.pending_upstream_proposals.write().await;
+            for patch in &accepted {
+                pending.entry(grandparent_id).or_default().push(UpstreamProposal {
+                    source_agent_id: patch.source_agent_id,
+                    key: patch.key.clone(),
+                    value: patch.value.clone(),
+                    confidence: patch.confidence,
+                });
+            }
         }
+
+        accepted
     }
 
     /// Propagate context from parent to child
@@ -333,6 +556,7 @@ impl ContextPropagationManager {
         // Create child context with inherited state
         let mut child_context = RecursiveContext {
             workflow_id: child_workflow_id,
+            agent_id: parent_context.agent_id,
             depth: parent_context.depth + 1,
             parent_workflow_id: Some(parent_context.workflow_id),
             root_workflow_id: parent_context.root_workflow_id,
@@ -920,6 +1144,144 @@ impl ContextPropagationManager {
         context.inherited_state = pruned;
         Ok(())
     }
+
+    /// Records `parent` as one of `child`'s direct ancestors. Called once
+    /// per incoming branch, so a snapshot where two branches converge (a
+    /// diamond) ends up with two entries in `context_edges[child]`.
+    pub async fn register_context_edge(&self, child: ContextSnapshotId, parent: ContextSnapshotId) {
+        self.context_edges.write().await.entry(child).or_default().push(parent);
+    }
+
+    /// Returns `target` and all of its ancestors in a root-most-first order
+    /// consistent with the DAG in `context_edges`, i.e. every snapshot
+    /// appears after all of its parents. Computed with Kahn's algorithm.
+    ///
+    /// Returns `OrchestrationError::WorkflowError` if the sub-graph rooted at
+    /// `target` contains a cycle, since a topological order can't exist in
+    /// that case.
+    pub async fn propagation_order(
+        &self,
+        target: ContextSnapshotId,
+    ) -> Result<Vec<ContextSnapshotId>, OrchestrationError> {
+        let edges = self.context_edges.read().await;
+
+        // Walk backward from `target` to find the full ancestor sub-graph.
+        let mut nodes: HashSet<ContextSnapshotId> = HashSet::new();
+        let mut stack = vec![target];
+        while let Some(node) = stack.pop() {
+            if !nodes.insert(node) {
+                continue;
+            }
+            if let Some(parents) = edges.get(&node) {
+                stack.extend(parents.iter().copied());
+            }
+        }
+
+        // In-degree counts only child edges within the sub-graph: for each
+        // node, how many of its own parents are also in `nodes`.
+        let mut in_degree: HashMap<ContextSnapshotId, usize> = nodes.iter().map(|n| (*n, 0)).collect();
+        let mut children: HashMap<ContextSnapshotId, Vec<ContextSnapshotId>> = HashMap::new();
+        for &node in &nodes {
+            let parents = edges.get(&node).map(Vec::as_slice).unwrap_or(&[]);
+            let mut degree = 0;
+            for &parent in parents {
+                if nodes.contains(&parent) {
+                    degree += 1;
+                    children.entry(parent).or_default().push(node);
+                }
+            }
+            in_degree.insert(node, degree);
+        }
+
+        let mut ready: Vec<ContextSnapshotId> =
+            in_degree.iter().filter(|(_, &d)| d == 0).map(|(&n, _)| n).collect();
+        let mut order = Vec::with_capacity(nodes.len());
+        while !ready.is_empty() {
+            // Sort for a deterministic order among snapshots that became
+            // ready at the same step.
+            ready.sort();
+            let node = ready.remove(0);
+            order.push(node);
+            if let Some(kids) = children.get(&node) {
+                for &kid in kids {
+                    let degree = in_degree.get_mut(&kid).expect("kid tracked in in_degree");
+                    *degree -= 1;
+                    if *degree == 0 {
+                        ready.push(kid);
+                    }
+                }
+            }
+        }
+
+        if order.len() != nodes.len() {
+            return Err(OrchestrationError::WorkflowError(format!(
+                "context propagation graph for snapshot {target} contains a cycle"
+            )));
+        }
+
+        Ok(order)
+    }
+
+    /// Merges the `state.variables` of `target` and all of its ancestors, in
+    /// `propagation_order`, resolving same-key conflicts per
+    /// `strategy.conflict_resolution`. Ancestors not present in
+    /// `context_store` (e.g. never snapshotted) are skipped rather than
+    /// treated as an error, since `context_edges` only records id
+    /// relationships, not snapshot existence.
+    pub async fn merge_ancestor_state(
+        &self,
+        target: ContextSnapshotId,
+        strategy: &InheritanceStrategy,
+    ) -> Result<HashMap<String, serde_json::Value>, OrchestrationError> {
+        let order = self.propagation_order(target).await?;
+        let store = self.context_store.read().await;
+
+        let mut merged: HashMap<String, serde_json::Value> = HashMap::new();
+        let mut winning_priority: HashMap<String, ContextPriority> = HashMap::new();
+
+        for snapshot_id in order {
+            let Some(snapshot) = store.get(&snapshot_id) else {
+                continue;
+            };
+            for (key, value) in &snapshot.state.variables {
+                match merged.entry(key.clone()) {
+                    std::collections::hash_map::Entry::Vacant(slot) => {
+                        slot.insert(value.clone());
+                        winning_priority.insert(key.clone(), snapshot.metadata.priority.clone());
+                    }
+                    std::collections::hash_map::Entry::Occupied(mut slot) => {
+                        match strategy.conflict_resolution {
+                            ConflictResolution::LastWrite => {
+                                slot.insert(value.clone());
+                                winning_priority.insert(key.clone(), snapshot.metadata.priority.clone());
+                            }
+                            ConflictResolution::HighestPriority => {
+                                let current_priority = winning_priority.get(key).cloned().unwrap_or(ContextPriority::Background);
+                                if snapshot.metadata.priority < current_priority {
+                                    slot.insert(value.clone());
+                                    winning_priority.insert(key.clone(), snapshot.metadata.priority.clone());
+                                }
+                            }
+                            ConflictResolution::Merge => {
+                                if let (serde_json::Value::Object(existing), serde_json::Value::Object(incoming)) =
+                                    (slot.get_mut(), value)
+                                {
+                                    for (nested_key, nested_value) in incoming {
+                                        existing.insert(nested_key.clone(), nested_value.clone());
+                                    }
+                                } else {
+                                    slot.insert(value.clone());
+                                }
+                                winning_priority.insert(key.clone(), snapshot.metadata.priority.clone());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(merged)
+    }
 }
 
 impl ContextOptimizer {
@@ -3,10 +3,13 @@
 //! This module provides capabilities for runtime workflow modification
 //! based on intermediate results and performance feedback.
 
-use crate::{OrchestrationError, workflow::WorkflowNode, PerformanceMetrics};
-use chrono::{DateTime, Utc};
+use crate::context_propagation::ContextDiff;
+use crate::{OrchestrationError, workflow::{WorkflowNode, WorkflowGraph, WorkflowEdge}, PerformanceMetrics};
+use chrono::{DateTime, Timelike, Utc};
+use cron::Schedule;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
@@ -17,6 +20,14 @@ pub struct DynamicWorkflowModifier {
     pub adaptation_strategies: Vec<AdaptationStrategy>,
     pub modification_history: Arc<RwLock<Vec<WorkflowModification>>>,
     pub performance_analyzer: PerformanceAnalyzer,
+    /// Graphs this modifier can stage/commit structural changes against,
+    /// keyed by `WorkflowGraph::id`. Populated via `register_workflow_graph`
+    /// -- this module doesn't own workflow deployment, so it only tracks
+    /// graphs a caller has explicitly handed it.
+    pub(crate) workflow_graphs: Arc<RwLock<HashMap<Uuid, WorkflowGraph>>>,
+    /// Committed graph mutations, most recent last, so `rollback_last` can
+    /// undo them in LIFO order.
+    pub(crate) applied_modifications: Arc<RwLock<Vec<AppliedModification>>>,
 }
 
 /// Workflow modification rule
@@ -28,6 +39,13 @@ pub struct ModificationRule {
     pub action: ModificationAction,
     pub priority: u8,
     pub enabled: bool,
+    /// Context variable names this rule cares about. When empty (the
+    /// default for rules that only look at `PerformanceMetrics`), the rule
+    /// is always evaluated. Otherwise `analyze_workflow_with_context_diff`
+    /// skips it unless one of these keys appears in the tick's
+    /// `ContextDiff`.
+    #[serde(default)]
+    pub relevant_context_keys: Vec<String>,
 }
 
 /// Modification trigger conditions
@@ -38,6 +56,47 @@ pub enum ModificationTrigger {
     ErrorRateHigh { threshold: f64 },
     ExecutionTimeExceeded { threshold_ms: u64 },
     Custom { expression: String },
+    /// Gates `inner` so it's only evaluated during the minute a cron
+    /// expression matches, instead of on every performance event. Uses the
+    /// `cron` crate's six-field format (seconds first), e.g. `"0 0 2 * * *"`
+    /// for daily at 2 AM. Checked by `DynamicWorkflowModifier::tick`, not by
+    /// `evaluate_trigger`, since it depends on wall-clock time rather than
+    /// `PerformanceMetrics`.
+    Scheduled { cron_expression: String, inner: Box<ModificationTrigger> },
+}
+
+impl ModificationTrigger {
+    /// Whether this is a `Scheduled` trigger whose cron expression matches
+    /// the minute containing `now`.
+    fn is_due(&self, now: DateTime<Utc>) -> bool {
+        match self {
+            ModificationTrigger::Scheduled { cron_expression, .. } => {
+                let Ok(schedule) = Schedule::from_str(cron_expression) else {
+                    return false;
+                };
+                let Some(minute_start) = now.with_second(0).and_then(|t| t.with_nanosecond(0)) else {
+                    return false;
+                };
+                schedule
+                    .after(&(minute_start - chrono::Duration::seconds(1)))
+                    .next()
+                    .is_some_and(|next| next == minute_start)
+            }
+            _ => false,
+        }
+    }
+
+    /// Next time this trigger becomes active after `now`. Returns `None`
+    /// for triggers that aren't schedule-based, or if `cron_expression`
+    /// fails to parse.
+    pub fn next_activation(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            ModificationTrigger::Scheduled { cron_expression, .. } => {
+                Schedule::from_str(cron_expression).ok()?.after(&now).next()
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Modification actions
@@ -118,6 +177,33 @@ pub struct WorkflowModification {
     pub performance_impact: Option<f64>,
 }
 
+/// A graph mutation computed by `stage_modification` but not yet applied.
+/// Holds both sides of the change so `commit` can validate `after` before
+/// touching `DynamicWorkflowModifier::workflow_graphs` at all -- a rejected
+/// commit never writes anything, so the graph is left exactly as `before`
+/// found it.
+#[derive(Debug, Clone)]
+pub struct StagedModification {
+    pub workflow_id: Uuid,
+    pub rule_id: Uuid,
+    pub action: ModificationAction,
+    pub before: WorkflowGraph,
+    pub after: WorkflowGraph,
+}
+
+/// A modification `commit` accepted, with both the pre- and post-mutation
+/// graph so `rollback_last` can restore `before` verbatim.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppliedModification {
+    pub modification_id: Uuid,
+    pub workflow_id: Uuid,
+    pub rule_id: Uuid,
+    pub action: ModificationAction,
+    pub before: WorkflowGraph,
+    pub after: WorkflowGraph,
+    pub timestamp: DateTime<Utc>,
+}
+
 /// Performance analyzer
 pub struct PerformanceAnalyzer {
     pub metrics_history: Arc<RwLock<Vec<PerformanceMetrics>>>,
@@ -168,9 +254,157 @@ impl DynamicWorkflowModifier {
             adaptation_strategies: Vec::new(),
             modification_history: Arc::new(RwLock::new(Vec::new())),
             performance_analyzer: PerformanceAnalyzer::new(),
+            workflow_graphs: Arc::new(RwLock::new(HashMap::new())),
+            applied_modifications: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
+    /// Starts (or replaces) tracking `graph` under `graph.id`, so
+    /// `stage_modification`/`commit` have something to mutate. This module
+    /// doesn't deploy workflows itself, so callers hand it the graph they
+    /// want it to manage.
+    pub async fn register_workflow_graph(&self, graph: WorkflowGraph) {
+        self.workflow_graphs.write().await.insert(graph.id, graph);
+    }
+
+    /// The graph currently tracked for `workflow_id`, if any.
+    pub async fn current_graph(&self, workflow_id: Uuid) -> Option<WorkflowGraph> {
+        self.workflow_graphs.read().await.get(&workflow_id).cloned()
+    }
+
+    /// Computes what applying `rule`'s action to `workflow_id`'s tracked
+    /// graph would look like, without mutating anything. Pair with `commit`
+    /// to apply it atomically, or discard the returned `StagedModification`
+    /// to abandon it.
+    pub async fn stage_modification(
+        &self,
+        workflow_id: Uuid,
+        rule: &ModificationRule,
+    ) -> Result<StagedModification, OrchestrationError> {
+        let before = self
+            .workflow_graphs
+            .read()
+            .await
+            .get(&workflow_id)
+            .cloned()
+            .ok_or_else(|| OrchestrationError::WorkflowError(format!("no graph registered for workflow {workflow_id}")))?;
+
+        let after = Self::apply_action_to_graph(&before, &rule.action)?;
+
+        Ok(StagedModification { workflow_id, rule_id: rule.rule_id, action: rule.action.clone(), before, after })
+    }
+
+    /// Validates `staged.after` and, only if it passes, writes it into
+    /// `workflow_graphs` and records an `AppliedModification`. Rejecting a
+    /// staged modification (e.g. because it introduced a cycle) never
+    /// touches the tracked graph, so the workflow is left exactly as it was
+    /// before `stage_modification` was called.
+    pub async fn commit(&self, staged: StagedModification) -> Result<AppliedModification, OrchestrationError> {
+        staged.after.validate_acyclic()?;
+
+        self.workflow_graphs.write().await.insert(staged.workflow_id, staged.after.clone());
+
+        let applied = AppliedModification {
+            modification_id: Uuid::new_v4(),
+            workflow_id: staged.workflow_id,
+            rule_id: staged.rule_id,
+            action: staged.action,
+            before: staged.before,
+            after: staged.after,
+            timestamp: Utc::now(),
+        };
+        self.applied_modifications.write().await.push(applied.clone());
+
+        Ok(applied)
+    }
+
+    /// Every modification `commit` has accepted, oldest first.
+    pub async fn modification_history(&self) -> Vec<AppliedModification> {
+        self.applied_modifications.read().await.clone()
+    }
+
+    /// Undoes the most recently committed modification for `workflow_id`,
+    /// restoring its tracked graph to that modification's `before` snapshot.
+    /// Returns an error if `workflow_id` has no committed modification left
+    /// to undo.
+    pub async fn rollback_last(&self, workflow_id: Uuid) -> Result<AppliedModification, OrchestrationError> {
+        let mut history = self.applied_modifications.write().await;
+        let position = history
+            .iter()
+            .rposition(|modification| modification.workflow_id == workflow_id)
+            .ok_or_else(|| {
+                OrchestrationError::WorkflowError(format!(
+                    "no applied modification to roll back for workflow {workflow_id}"
+                ))
+            })?;
+        let applied = history.remove(position);
+
+        self.workflow_graphs.write().await.insert(workflow_id, applied.before.clone());
+
+        Ok(applied)
+    }
+
+    /// Produces the graph that would result from applying `action` to
+    /// `graph`, without mutating `graph` itself. `ScaleResources` and
+    /// `ChangeStrategy` have no structural representation in a
+    /// `WorkflowGraph`, so they pass the graph through unchanged, matching
+    /// `apply_modification_action`'s treatment of them as non-structural.
+    fn apply_action_to_graph(
+        graph: &WorkflowGraph,
+        action: &ModificationAction,
+    ) -> Result<WorkflowGraph, OrchestrationError> {
+        let mut result = graph.clone();
+
+        match action {
+            ModificationAction::AddNode { node } => {
+                result.nodes.insert(node.id.clone(), node.clone());
+            }
+            ModificationAction::RemoveNode { node_id } => {
+                if result.nodes.remove(node_id).is_none() {
+                    return Err(OrchestrationError::WorkflowError(format!(
+                        "cannot remove unknown node '{node_id}'"
+                    )));
+                }
+                result.edges.retain(|edge| edge.from_node != *node_id && edge.to_node != *node_id);
+            }
+            ModificationAction::ModifyNode { node_id, modifications } => {
+                let node = result.nodes.get_mut(node_id).ok_or_else(|| {
+                    OrchestrationError::WorkflowError(format!("cannot modify unknown node '{node_id}'"))
+                })?;
+                if let Some(timeout_seconds) = modifications.timeout_seconds {
+                    node.timeout_seconds = Some(timeout_seconds);
+                }
+                if let Some(resources) = &modifications.resource_requirements {
+                    node.agent_requirements.resource_requirements.cpu_cores = resources.cpu_cores;
+                    node.agent_requirements.resource_requirements.memory_mb = resources.memory_mb;
+                    node.agent_requirements.resource_requirements.network_bandwidth_mbps =
+                        resources.network_bandwidth_mbps;
+                    node.agent_requirements.resource_requirements.storage_mb = resources.storage_mb;
+                }
+                if let Some(retry_policy) = &modifications.retry_policy {
+                    node.retry_policy.max_attempts = retry_policy.max_attempts;
+                    node.retry_policy.initial_delay_ms = retry_policy.initial_delay_ms;
+                    node.retry_policy.backoff_multiplier = retry_policy.backoff_multiplier;
+                }
+            }
+            ModificationAction::AddEdge { from, to } => {
+                result.edges.push(WorkflowEdge {
+                    from_node: from.clone(),
+                    to_node: to.clone(),
+                    condition: None,
+                    data_mapping: HashMap::new(),
+                    priority: 0,
+                });
+            }
+            ModificationAction::RemoveEdge { from, to } => {
+                result.edges.retain(|edge| !(edge.from_node == *from && edge.to_node == *to));
+            }
+            ModificationAction::ScaleResources { .. } | ModificationAction::ChangeStrategy { .. } => {}
+        }
+
+        Ok(result)
+    }
+
     /// Analyze workflow and suggest modifications
     pub async fn analyze_workflow(
         &self,
@@ -184,18 +418,7 @@ impl DynamicWorkflowModifier {
 
         // Check modification rules
         for rule in &self.modification_rules {
-            if !rule.enabled {
-                continue;
-            }
-
-            if self.evaluate_trigger(&rule.trigger, current_metrics).await? {
-                let recommendation = ModificationRecommendation {
-                    action: rule.action.clone(),
-                    confidence: self.calculate_confidence(&rule, current_metrics).await?,
-                    expected_improvement: self.estimate_improvement(&rule, current_metrics).await?,
-                    risk_level: self.assess_risk(&rule).await?,
-                };
-
+            if let Some(recommendation) = self.evaluate_rule(rule, current_metrics).await? {
                 recommendations.push(recommendation);
             }
         }
@@ -224,6 +447,98 @@ impl DynamicWorkflowModifier {
         Ok(recommendations)
     }
 
+    /// Like `analyze_workflow`, but skips rules whose `relevant_context_keys`
+    /// don't intersect `diff`'s changed keys, so a tick where nothing a rule
+    /// cares about moved doesn't pay for a full trigger evaluation. A rule
+    /// with an empty `relevant_context_keys` (the default, for rules driven
+    /// purely by `PerformanceMetrics`) is always evaluated. Adaptation
+    /// strategies aren't context-diff-gated since they run off `metrics`
+    /// alone, so they're applied exactly as in `analyze_workflow`.
+    ///
+    /// Note: this crate has no `OptimizationEvent` type to record which
+    /// diff caused a triggered rule against, so the diff itself isn't
+    /// persisted anywhere -- only used to decide whether to evaluate.
+    pub async fn analyze_workflow_with_context_diff(
+        &self,
+        workflow_id: Uuid,
+        current_metrics: &PerformanceMetrics,
+        diff: &ContextDiff,
+    ) -> Result<Vec<ModificationRecommendation>, OrchestrationError> {
+        if diff.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let changed_keys: HashSet<&str> = diff.changed_keys().collect();
+        let mut recommendations = Vec::new();
+
+        for rule in &self.modification_rules {
+            if !rule.relevant_context_keys.is_empty()
+                && !rule.relevant_context_keys.iter().any(|key| changed_keys.contains(key.as_str()))
+            {
+                continue;
+            }
+
+            if let Some(recommendation) = self.evaluate_rule(rule, current_metrics).await? {
+                recommendations.push(recommendation);
+            }
+        }
+
+        for strategy in &self.adaptation_strategies {
+            if !strategy.enabled {
+                continue;
+            }
+
+            let strategy_recommendations =
+                self.apply_adaptation_strategy(strategy, workflow_id, current_metrics).await?;
+            recommendations.extend(strategy_recommendations);
+        }
+
+        recommendations.sort_by(|a, b| {
+            b.confidence
+                .partial_cmp(&a.confidence)
+                .unwrap()
+                .then(b.expected_improvement.partial_cmp(&a.expected_improvement).unwrap())
+        });
+
+        Ok(recommendations)
+    }
+
+    /// Evaluates a single rule's trigger and, if it fires, builds the
+    /// recommendation for it. Returns `None` for a disabled rule or one
+    /// whose trigger doesn't match.
+    async fn evaluate_rule(
+        &self,
+        rule: &ModificationRule,
+        metrics: &PerformanceMetrics,
+    ) -> Result<Option<ModificationRecommendation>, OrchestrationError> {
+        if !rule.enabled {
+            return Ok(None);
+        }
+
+        if !self.evaluate_trigger(&rule.trigger, metrics).await? {
+            return Ok(None);
+        }
+
+        Ok(Some(ModificationRecommendation {
+            action: rule.action.clone(),
+            confidence: self.calculate_confidence(rule, metrics).await?,
+            expected_improvement: self.estimate_improvement(rule, metrics).await?,
+            risk_level: self.assess_risk(rule).await?,
+        }))
+    }
+
+    /// Evaluate scheduled rules against the current time. Should be called
+    /// periodically (e.g. from a minute-resolution timer) rather than on
+    /// every performance event, since `ModificationTrigger::Scheduled` fires
+    /// on wall-clock time rather than `PerformanceMetrics`.
+    pub fn tick(&self, now: DateTime<Utc>) -> Vec<&ModificationRule> {
+        self.modification_rules
+            .iter()
+            .filter(|rule| rule.enabled)
+            .filter(|rule| rule.trigger.is_due(now))
+            .collect()
+    }
+
     /// Apply workflow modifications
     pub async fn apply_modifications(
         &self,
@@ -299,6 +614,15 @@ impl DynamicWorkflowModifier {
                 // In a real implementation, this would evaluate the expression
                 Ok(false)
             }
+            ModificationTrigger::Scheduled { inner, .. } => {
+                // Gated on wall-clock time via `tick`/`is_due`, not on
+                // `PerformanceMetrics`, so a scheduled trigger only counts
+                // here during its due minute.
+                if !trigger.is_due(Utc::now()) {
+                    return Ok(false);
+                }
+                Box::pin(self.evaluate_trigger(inner, metrics)).await
+            }
         }
     }
 
@@ -641,3 +965,168 @@ impl Default for DynamicWorkflowModifier {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod scheduled_trigger_tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn daily_at_2am_rule() -> ModificationRule {
+        ModificationRule {
+            rule_id: Uuid::new_v4(),
+            name: "nightly-rebalance".to_string(),
+            trigger: ModificationTrigger::Scheduled {
+                // The `cron` crate expects a leading seconds field, so
+                // "daily at 2 AM" is six fields, not the more familiar
+                // five-field crontab syntax.
+                cron_expression: "0 0 2 * * *".to_string(),
+                inner: Box::new(ModificationTrigger::Custom {
+                    expression: "rebalance".to_string(),
+                }),
+            },
+            action: ModificationAction::ChangeStrategy { strategy: "rebalance".to_string() },
+            priority: 1,
+            enabled: true,
+            relevant_context_keys: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn next_activation_returns_the_next_2am_occurrence() {
+        let rule = daily_at_2am_rule();
+        let now = Utc.with_ymd_and_hms(2026, 1, 5, 14, 30, 0).unwrap();
+
+        let next = rule.trigger.next_activation(now).expect("scheduled trigger has a next activation");
+
+        assert_eq!(next, Utc.with_ymd_and_hms(2026, 1, 6, 2, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn tick_fires_the_rule_only_at_the_scheduled_minute() {
+        let mut modifier = DynamicWorkflowModifier::new();
+        modifier.modification_rules.push(daily_at_2am_rule());
+
+        let two_am = Utc.with_ymd_and_hms(2026, 1, 6, 2, 0, 0).unwrap();
+        let due = modifier.tick(two_am);
+        assert_eq!(due.len(), 1);
+
+        let mid_afternoon = Utc.with_ymd_and_hms(2026, 1, 6, 14, 0, 0).unwrap();
+        assert!(modifier.tick(mid_afternoon).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod transactional_modification_tests {
+    use super::*;
+    use crate::workflow::{AgentRequirements, WorkflowNodeType};
+
+    fn node(id: &str) -> WorkflowNode {
+        WorkflowNode {
+            id: id.to_string(),
+            name: id.to_string(),
+            node_type: WorkflowNodeType::Task { task_definition: "noop".to_string(), parallel_execution: false },
+            agent_requirements: AgentRequirements {
+                agent_type: None,
+                capabilities: Vec::new(),
+                min_performance_score: 0.0,
+                preferred_agents: Vec::new(),
+                exclusion_list: Vec::new(),
+                resource_requirements: crate::workflow::ResourceRequirements {
+                    cpu_cores: 1.0,
+                    memory_mb: 512,
+                    network_bandwidth_mbps: 0,
+                    storage_mb: 0,
+                    gpu_units: None,
+                },
+            },
+            input_mapping: HashMap::new(),
+            output_mapping: HashMap::new(),
+            timeout_seconds: None,
+            retry_policy: crate::workflow::RetryPolicy {
+                max_attempts: 1,
+                initial_delay_ms: 0,
+                backoff_multiplier: 1.0,
+                max_delay_ms: 0,
+                retry_on_errors: Vec::new(),
+            },
+            conditions: Vec::new(),
+            learned_duration_ms: None,
+        }
+    }
+
+    fn edge(from_node: &str, to_node: &str) -> WorkflowEdge {
+        WorkflowEdge {
+            from_node: from_node.to_string(),
+            to_node: to_node.to_string(),
+            condition: None,
+            data_mapping: HashMap::new(),
+            priority: 0,
+        }
+    }
+
+    fn linear_graph() -> WorkflowGraph {
+        WorkflowGraph {
+            id: Uuid::new_v4(),
+            name: "test-graph".to_string(),
+            description: String::new(),
+            version: "1.0".to_string(),
+            nodes: vec![node("a"), node("b")].into_iter().map(|n| (n.id.clone(), n)).collect(),
+            edges: vec![edge("a", "b")],
+            input_schema: serde_json::Value::Null,
+            output_schema: serde_json::Value::Null,
+            constraints: Vec::new(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    fn add_edge_rule(from: &str, to: &str) -> ModificationRule {
+        ModificationRule {
+            rule_id: Uuid::new_v4(),
+            name: "close-the-loop".to_string(),
+            trigger: ModificationTrigger::Custom { expression: "always".to_string() },
+            action: ModificationAction::AddEdge { from: from.to_string(), to: to.to_string() },
+            priority: 1,
+            enabled: true,
+            relevant_context_keys: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_staged_modification_that_would_make_the_graph_cyclic_is_rejected_and_the_graph_is_unchanged() {
+        let modifier = DynamicWorkflowModifier::new();
+        let graph = linear_graph();
+        let workflow_id = graph.id;
+        modifier.register_workflow_graph(graph.clone()).await;
+
+        let staged = modifier.stage_modification(workflow_id, &add_edge_rule("b", "a")).await.unwrap();
+        let result = modifier.commit(staged).await;
+
+        assert!(result.is_err());
+        let unchanged = modifier.current_graph(workflow_id).await.unwrap();
+        assert_eq!(unchanged.edges.len(), graph.edges.len());
+        assert!(modifier.modification_history().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_valid_staged_modification_commits_and_can_be_rolled_back() {
+        let modifier = DynamicWorkflowModifier::new();
+        let graph = linear_graph();
+        let workflow_id = graph.id;
+        modifier.register_workflow_graph(graph.clone()).await;
+
+        let rule = ModificationRule {
+            action: ModificationAction::RemoveEdge { from: "a".to_string(), to: "b".to_string() },
+            ..add_edge_rule("a", "b")
+        };
+        let staged = modifier.stage_modification(workflow_id, &rule).await.unwrap();
+        modifier.commit(staged).await.unwrap();
+
+        assert!(modifier.current_graph(workflow_id).await.unwrap().edges.is_empty());
+        assert_eq!(modifier.modification_history().await.len(), 1);
+
+        modifier.rollback_last(workflow_id).await.unwrap();
+
+        assert_eq!(modifier.current_graph(workflow_id).await.unwrap().edges.len(), 1);
+        assert!(modifier.modification_history().await.is_empty());
+    }
+}
@@ -134,14 +134,18 @@ impl EnhancedOrchestrator {
         let result_id = Uuid::new_v4();
         let start_time = Utc::now();
 
-        // Start recursive execution if parent context exists
+        // Start recursive execution if parent context exists. The agent
+        // identity is inherited from the parent so a chain of nested calls
+        // is accounted against the same agent's recursion-depth budget.
         let recursive_context = if let Some(parent) = parent_context {
             self.recursion_manager.start_recursive_execution(
                 workflow_graph.id,
+                parent.agent_id,
                 Some(parent),
             ).await?
         } else {
             self.recursion_manager.start_recursive_execution(
+                workflow_graph.id,
                 workflow_graph.id,
                 None,
             ).await?
@@ -182,6 +186,7 @@ impl EnhancedOrchestrator {
             success_rate: stats.success_rate,
             average_execution_time: Duration::from_secs(0), // Not available in this struct
             performance_alerts: vec![], // Not available in this struct
+            agent_depth_histogram: HashMap::new(), // Not available in this struct
         };
 
         // Create enhanced result
@@ -462,6 +467,7 @@ impl EnhancedOrchestrator {
                 success_rate: recursion_stats.success_rate,
                 average_execution_time: recursion_stats.average_execution_time,
                 performance_alerts: recursion_stats.performance_alerts,
+                agent_depth_histogram: recursion_stats.agent_depth_histogram,
             },
             performance_alerts: self.recursion_manager.performance_monitor.get_active_alerts().await?,
         })
@@ -513,6 +519,10 @@ impl Clone for RecursiveExecutionManager {
             recursion_history: Arc::clone(&self.recursion_history),
             cycle_detector: self.cycle_detector.clone(),
             performance_monitor: self.performance_monitor.clone(),
+            per_agent_max_depth: Arc::clone(&self.per_agent_max_depth),
+            per_agent_current_depth: Arc::clone(&self.per_agent_current_depth),
+            agent_depth_history: Arc::clone(&self.agent_depth_history),
+            call_stacks: Arc::clone(&self.call_stacks),
         }
     }
 }
@@ -524,6 +534,10 @@ impl Clone for ContextPropagationManager {
             propagation_rules: self.propagation_rules.clone(),
             context_optimizer: self.context_optimizer.clone(),
             inheritance_strategies: self.inheritance_strategies.clone(),
+            agent_parents: Arc::clone(&self.agent_parents),
+            upstream_rules: Arc::clone(&self.upstream_rules),
+            pending_upstream_proposals: Arc::clone(&self.pending_upstream_proposals),
+            context_edges: Arc::clone(&self.context_edges),
         }
     }
 }
@@ -535,6 +549,8 @@ impl Clone for DynamicWorkflowModifier {
             adaptation_strategies: self.adaptation_strategies.clone(),
             modification_history: Arc::clone(&self.modification_history),
             performance_analyzer: self.performance_analyzer.clone(),
+            workflow_graphs: Arc::clone(&self.workflow_graphs),
+            applied_modifications: Arc::clone(&self.applied_modifications),
         }
     }
 }
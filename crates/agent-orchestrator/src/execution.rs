@@ -71,6 +71,15 @@ pub struct ResourceLimits {
     pub max_concurrent_tasks: usize,
 }
 
+impl TaskExecutor {
+    /// Percentage of tasks the given NUMA-aware scheduler has routed to a
+    /// worker pinned on the task's own memory node, versus falling back to
+    /// a non-local worker.
+    pub fn numa_local_task_percent(scheduler: &crate::numa::NumaAwareScheduler) -> f64 {
+        scheduler.local_task_percent()
+    }
+}
+
 impl ExecutionEngine {
     pub fn new(max_concurrent_tasks: usize) -> Self {
         Self {
@@ -0,0 +1,220 @@
+//! Few-shot prompt construction backed by knowledge-graph retrieval.
+//!
+//! `EnhancedOrchestrator` currently builds LLM prompts ad-hoc at each call
+//! site. `FewShotPromptBuilder` centralizes that: it pulls the most similar
+//! past successful tasks out of a `GraphRAG`-style knowledge graph and
+//! formats them as `input -> output` examples ahead of the new task.
+//!
+//! This crate doesn't depend on `intelligent-librarian` (the workspace's
+//! actual knowledge-graph service), so retrieval is expressed against the
+//! [`GraphRAG`] trait rather than a concrete graph type — any knowledge
+//! store that can do semantic search and report community membership can
+//! plug in.
+
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+
+/// One hour, matching the TTL other in-memory caches in this crate use for
+/// derived/recomputable data (see `InMemoryAgentMemory::store` in `agent.rs`).
+const EXAMPLE_CACHE_TTL_SECONDS: i64 = 3600;
+
+/// A single retrieved `input -> output` exemplar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FewShotExample {
+    pub input: String,
+    pub output: String,
+    /// The entity this exemplar came from, for community-aware ranking.
+    pub source_entity: String,
+    pub community_id: Option<String>,
+    /// Semantic similarity to the query task, in `[0.0, 1.0]`.
+    pub similarity: f64,
+}
+
+/// A knowledge-graph-backed retrieval source for few-shot exemplars.
+///
+/// Modeled after the retrieval surface `KnowledgeGraph` in
+/// `intelligent-librarian` exposes internally (semantic similarity plus
+/// community membership), so a real GraphRAG-style store can implement this
+/// trait directly.
+pub trait GraphRAG {
+    /// Returns candidate exemplars ranked by semantic similarity to `task`,
+    /// most similar first, capped at `limit`.
+    fn semantic_search(&self, task: &str, limit: usize) -> Vec<FewShotExample>;
+
+    /// The community `entity` belongs to, if the graph has run community
+    /// detection. Used to prefer same-community examples during ranking.
+    fn community_of(&self, entity: &str) -> Option<String>;
+}
+
+/// A prompt assembled from retrieved few-shot examples plus the new task.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Prompt {
+    pub examples: Vec<FewShotExample>,
+    pub task: String,
+}
+
+impl Prompt {
+    /// Renders the prompt as plain text: one `Input:`/`Output:` block per
+    /// example, followed by the new task.
+    pub fn render(&self) -> String {
+        let mut rendered = String::new();
+        for example in &self.examples {
+            rendered.push_str(&format!("Input: {}\nOutput: {}\n\n", example.input, example.output));
+        }
+        rendered.push_str(&format!("Input: {}\nOutput:", self.task));
+        rendered
+    }
+}
+
+/// Builds few-shot prompts from a [`GraphRAG`] knowledge graph, caching the
+/// examples selected for a task for one hour so repeated calls for the same
+/// task don't re-run retrieval and ranking.
+#[derive(Default)]
+pub struct FewShotPromptBuilder {
+    example_cache: DashMap<String, (Vec<FewShotExample>, DateTime<Utc>)>,
+}
+
+impl FewShotPromptBuilder {
+    pub fn new() -> Self {
+        Self { example_cache: DashMap::new() }
+    }
+
+    /// Builds a prompt for `task`, retrieving up to `max_examples` few-shot
+    /// examples from `knowledge_graph`. When `query_entity` is set,
+    /// examples from its community are preferred over equally-similar
+    /// examples from elsewhere in the graph.
+    pub fn build(
+        &self,
+        task: &str,
+        knowledge_graph: &dyn GraphRAG,
+        query_entity: Option<&str>,
+        max_examples: usize,
+    ) -> Prompt {
+        let examples = self.retrieve_examples(task, knowledge_graph, query_entity, max_examples);
+        Prompt { examples, task: task.to_string() }
+    }
+
+    fn retrieve_examples(
+        &self,
+        task: &str,
+        knowledge_graph: &dyn GraphRAG,
+        query_entity: Option<&str>,
+        max_examples: usize,
+    ) -> Vec<FewShotExample> {
+        if let Some(cached) = self.cached_examples(task) {
+            return cached;
+        }
+
+        // Over-fetch so community-aware re-ranking has something to prefer
+        // among before truncating to max_examples.
+        let mut candidates = knowledge_graph.semantic_search(task, max_examples * 3);
+
+        if let Some(community) = query_entity.and_then(|entity| knowledge_graph.community_of(entity)) {
+            candidates.sort_by(|a, b| {
+                let a_same_community = a.community_id.as_deref() == Some(community.as_str());
+                let b_same_community = b.community_id.as_deref() == Some(community.as_str());
+                b_same_community
+                    .cmp(&a_same_community)
+                    .then(b.similarity.partial_cmp(&a.similarity).unwrap_or(std::cmp::Ordering::Equal))
+            });
+        }
+
+        candidates.truncate(max_examples);
+        self.example_cache.insert(task.to_string(), (candidates.clone(), Utc::now()));
+        candidates
+    }
+
+    fn cached_examples(&self, task: &str) -> Option<Vec<FewShotExample>> {
+        let (examples, inserted_at) = self.example_cache.get(task).map(|entry| entry.value().clone())?;
+        if (Utc::now() - inserted_at).num_seconds() > EXAMPLE_CACHE_TTL_SECONDS {
+            self.example_cache.remove(task);
+            return None;
+        }
+        Some(examples)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockGraphRAG {
+        exemplars: Vec<FewShotExample>,
+    }
+
+    impl MockGraphRAG {
+        fn with_ten_exemplars() -> Self {
+            let exemplars = (0..10)
+                .map(|i| FewShotExample {
+                    input: format!("task input {i}"),
+                    output: format!("task output {i}"),
+                    source_entity: format!("entity_{i}"),
+                    community_id: Some(if i < 5 { "community_a".to_string() } else { "community_b".to_string() }),
+                    similarity: 1.0 - (i as f64 * 0.05),
+                })
+                .collect();
+            Self { exemplars }
+        }
+    }
+
+    impl GraphRAG for MockGraphRAG {
+        fn semantic_search(&self, _task: &str, limit: usize) -> Vec<FewShotExample> {
+            self.exemplars.iter().take(limit).cloned().collect()
+        }
+
+        fn community_of(&self, entity: &str) -> Option<String> {
+            self.exemplars.iter().find(|e| e.source_entity == entity).and_then(|e| e.community_id.clone())
+        }
+    }
+
+    #[test]
+    fn build_prefers_examples_from_the_query_entitys_community() {
+        let graph = MockGraphRAG::with_ten_exemplars();
+        let builder = FewShotPromptBuilder::new();
+
+        // entity_7 is in community_b; without community-awareness the top-3
+        // by raw similarity would all be community_a (entity_0..2).
+        let prompt = builder.build("do the thing", &graph, Some("entity_7"), 3);
+
+        assert_eq!(prompt.examples.len(), 3);
+        assert!(prompt.examples.iter().all(|e| e.community_id.as_deref() == Some("community_b")));
+    }
+
+    #[test]
+    fn build_falls_back_to_similarity_order_without_a_query_entity() {
+        let graph = MockGraphRAG::with_ten_exemplars();
+        let builder = FewShotPromptBuilder::new();
+
+        let prompt = builder.build("do the thing", &graph, None, 3);
+
+        assert_eq!(prompt.examples.iter().map(|e| e.source_entity.clone()).collect::<Vec<_>>(), vec!["entity_0", "entity_1", "entity_2"]);
+    }
+
+    #[test]
+    fn repeated_build_for_the_same_task_hits_the_cache() {
+        let graph = MockGraphRAG::with_ten_exemplars();
+        let builder = FewShotPromptBuilder::new();
+
+        let first = builder.build("do the thing", &graph, None, 2);
+        let second = builder.build("do the thing", &graph, None, 2);
+
+        assert_eq!(first, second);
+        assert_eq!(builder.example_cache.len(), 1);
+    }
+
+    #[test]
+    fn render_formats_examples_then_the_new_task() {
+        let prompt = Prompt {
+            examples: vec![FewShotExample {
+                input: "2+2".to_string(),
+                output: "4".to_string(),
+                source_entity: "entity_0".to_string(),
+                community_id: None,
+                similarity: 1.0,
+            }],
+            task: "3+3".to_string(),
+        };
+
+        assert_eq!(prompt.render(), "Input: 2+2\nOutput: 4\n\nInput: 3+3\nOutput:");
+    }
+}
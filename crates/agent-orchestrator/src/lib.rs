@@ -22,23 +22,38 @@ pub mod recursion;
 pub mod context_propagation;
 pub mod dynamic_workflow;
 pub mod enhanced_orchestrator;
+pub mod ffi;
+pub mod numa;
+pub mod pipeline;
+pub mod node_weight_learner;
+pub mod state_migration;
+pub mod few_shot_prompt;
+pub mod anomaly;
 
-pub use agent::{Agent, AgentConfig, AgentCapability};
+pub use agent::{Agent, AgentConfig, AgentCapability, AgentType, AutonomyLevel, CapabilityHandler, RegisteredCapability, Task, TaskResult};
 pub use mcts::{MCTSPlanner, MCTSNode, SearchStrategy, AgentState, AgentAction};
 pub use workflow::{WorkflowOrchestrator, WorkflowGraph, ExecutionPlan};
-pub use memory::{ContextStore, MemoryManager};
+pub use memory::{ContextStore, InMemoryContextStore, MemoryManager};
 pub use optimizer::{PerformanceOptimizer, OptimizationStrategy, LearningEngine};
 pub use monitor::{OrchestrationMonitor, MetricsCollector, AlertManager};
 pub use strategy::{OrchestrationStrategy, AdaptiveStrategy};
-pub use context::{ContextManager, ContextWindow, ContextOptimizer};
+pub use context::{ContextManager, ContextWindow, ContextEntry, ContextOptimizer, AllocatorStrategy, SlabAllocator};
 pub use execution::{ExecutionEngine, TaskExecutor, ResourceManager};
-pub use recovery::{RecoveryManager, CircuitBreaker, ErrorRecovery};
+pub use recovery::{RecoveryManager, CircuitBreaker, CircuitBreakerSpec, ErrorRecovery, HeartbeatMonitor, HeartbeatAck, RecoveryType};
 pub use recursion::{RecursiveExecutionManager, RecursiveContext, RecursionLimits, RecursionStatistics};
-pub use context_propagation::{ContextPropagationManager, ContextSnapshot, PropagationRule, InheritanceStrategy};
+pub use context_propagation::{ContextPropagationManager, ContextSnapshot, ContextSnapshotId, PropagationRule, InheritanceStrategy, ConflictResolution, ContextPatch, UpstreamProposal, UpstreamPropagationRule, DEFAULT_UPSTREAM_PROPOSAL_CONFIDENCE};
 pub use dynamic_workflow::{DynamicWorkflowModifier, ModificationRule, AdaptationStrategy, PerformanceAnalyzer};
 pub use enhanced_orchestrator::{EnhancedOrchestrator, EnhancedOrchestrationConfig, EnhancedOrchestrationResult};
+pub use ffi::{FFIBridge, FfiSymbol};
+pub use numa::{NumaAwareScheduler, NumaTopology};
+pub use pipeline::{CompletedStep, CycleError, PipelineExecutionResult, PipelineGraph, PipelineStep, StepExecutor};
+pub use node_weight_learner::{NodeWeightLearner, NodeWeightStats};
+pub use state_migration::{StateMigrator, CURRENT_AGENT_STATE_SCHEMA_VERSION};
+pub use few_shot_prompt::{FewShotPromptBuilder, FewShotExample, GraphRAG, Prompt};
+pub use anomaly::{AnomalyAlert, AnomalyDetectionConfig, AnomalyDetector, IsolationForest};
 
 use serde::{Deserialize, Serialize};
+use std::error::Error as StdError;
 use thiserror::Error;
 use uuid::Uuid;
 use chrono;
@@ -75,8 +90,40 @@ pub enum OrchestrationError {
     #[error("Recursion limit exceeded: {0}")]
     RecursionLimitExceeded(String),
 
-    #[error("Recursion cycle detected")]
-    RecursionCycleDetected,
+    #[error("Recursion cycle detected: {}", path.iter().map(ToString::to_string).collect::<Vec<_>>().join(" -> "))]
+    RecursionCycleDetected { path: Vec<recursion::AgentId> },
+
+    #[error("{message}")]
+    Chained {
+        message: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+impl OrchestrationError {
+    /// Wraps an underlying error with additional context while preserving
+    /// it as the [`std::error::Error::source`] of the returned error, so
+    /// callers walking the chain (e.g. via `anyhow` or `error.source()`)
+    /// can still reach the original root cause.
+    pub fn chained<E>(message: impl Into<String>, source: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        OrchestrationError::Chained {
+            message: message.into(),
+            source: Box::new(source),
+        }
+    }
+
+    /// Walks the `source()` chain to find the deepest underlying error.
+    pub fn root_cause(&self) -> &(dyn StdError + 'static) {
+        let mut current: &(dyn StdError + 'static) = self;
+        while let Some(source) = StdError::source(current) {
+            current = source;
+        }
+        current
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
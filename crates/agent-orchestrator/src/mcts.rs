@@ -3,13 +3,16 @@
 //! This module provides sophisticated tree search capabilities for agent planning
 //! with neural network guidance, parallel simulations, and adaptive strategies.
 
+use crate::state_migration::StateMigrator;
 use crate::{OrchestrationError, MCTSConfig};
 use chrono::{DateTime, Utc};
 use ndarray::{Array1, Array2};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use rand::Rng;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, Semaphore};
@@ -23,6 +26,43 @@ pub struct MCTSPlanner {
     neural_evaluator: Option<NeuralEvaluator>,
     search_statistics: Arc<RwLock<SearchStatistics>>,
     simulation_semaphore: Arc<Semaphore>,
+    /// Visit count carried over from the subtree the most recent `advance`
+    /// re-rooted onto, used by `reuse_ratio` to measure warm-start savings.
+    reused_visits: Arc<RwLock<u64>>,
+}
+
+/// Identifies a node in the search tree. `MCTSNode::id` has always been a
+/// `Uuid`; this alias just gives that role a name at `run_parallel_simulations`'s
+/// call sites.
+pub type NodeId = Uuid;
+
+/// Root-to-parent path walked by `select_node` to reach a node, in
+/// descending order. Named to keep `select_node`'s and `backpropagate`'s
+/// signatures readable now that selection needs to hand ancestors back to
+/// the caller for bound propagation.
+type AncestorPath = Vec<Arc<RwLock<MCTSNode>>>;
+
+/// Accumulated visit count and value total for one node, collected by
+/// `run_parallel_simulations` before being folded back into the live
+/// tree. Mirrors `MCTSNode::visits`/`total_value` -- a running sum, not a
+/// pre-divided average -- so `mean_value` reproduces the same
+/// exploitation term `select_node`'s UCB1 computation uses.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NodeStats {
+    pub visits: u64,
+    pub total_value: f64,
+}
+
+impl NodeStats {
+    fn record(&mut self, value: f64) {
+        self.visits += 1;
+        self.total_value += value;
+    }
+
+    /// Running-mean reward across every simulation folded into this entry.
+    pub fn mean_value(&self) -> f64 {
+        if self.visits == 0 { 0.0 } else { self.total_value / self.visits as f64 }
+    }
 }
 
 /// MCTS Node representing a state in the search tree
@@ -41,11 +81,93 @@ pub struct MCTSNode {
     pub created_at: DateTime<Utc>,
     pub neural_prior: Option<f64>,
     pub action_priors: Vec<(AgentAction, f64)>,
+    /// Lower bound of this node's alpha-beta confidence window, tightened by
+    /// [`MCTSPlanner::propagate_bounds`] as siblings accumulate visits.
+    /// `f64::MIN` (rather than `f64::NEG_INFINITY`, which JSON can't
+    /// represent) until the node has at least one visit.
+    pub lower_bound: f64,
+    /// Upper bound of this node's alpha-beta confidence window. `f64::MAX`
+    /// until the node has at least one visit. See [`Self::lower_bound`].
+    pub upper_bound: f64,
+    /// Set by [`MCTSPlanner::propagate_bounds`] once this node's
+    /// `upper_bound` can no longer beat a sibling's guaranteed
+    /// `lower_bound`. `select_node` skips pruned children, but the node
+    /// itself is never removed from the tree -- it still appears in
+    /// checkpoints, and its counts still contribute to its parent's stats.
+    pub pruned: bool,
+}
+
+/// Default [`MCTSNode::lower_bound`] for a checkpoint written before this
+/// field existed. See [`unvisited_upper_bound`].
+fn unvisited_lower_bound() -> f64 {
+    f64::MIN
+}
+
+/// Default [`MCTSNode::upper_bound`] for a checkpoint written before this
+/// field existed. `f64::MAX`/`f64::MIN` are used instead of the infinities
+/// an unvisited node is otherwise created with, since serde_json has no
+/// representation for infinite floats and would fail to deserialize them
+/// back.
+fn unvisited_upper_bound() -> f64 {
+    f64::MAX
+}
+
+/// Format version of an [`MCTSPlanner::save_checkpoint`]/[`MCTSPlanner::load_checkpoint`]
+/// checkpoint's node shape, distinct from [`crate::state_migration::CURRENT_AGENT_STATE_SCHEMA_VERSION`]
+/// (which only versions the `AgentState` embedded in each node). Bump this
+/// whenever a field is added to, removed from, or changes meaning on
+/// [`CheckpointNode`] or [`Checkpoint`] itself; `load_checkpoint` rejects a
+/// mismatched version with a [`OrchestrationError::ConfigError`] rather than
+/// attempting to deserialize a shape it no longer matches.
+pub const CURRENT_MCTS_CHECKPOINT_FORMAT_VERSION: u32 = 1;
+
+/// On-disk shape of an [`MCTSPlanner::save_checkpoint`]/[`MCTSPlanner::load_checkpoint`]
+/// checkpoint. [`MCTSNode`] itself can't derive `Serialize`/`Deserialize`
+/// (its children are `Arc<RwLock<MCTSNode>>`), and its `AgentState` needs to
+/// be migrated before it can be deserialized at all, so a checkpoint is
+/// converted to and from this plain, owned shape node by node.
+#[derive(Debug, Serialize, Deserialize)]
+struct Checkpoint {
+    checkpoint_format_version: u32,
+    agent_state_schema_version: u32,
+    config: MCTSConfig,
+    root: CheckpointNode,
+}
+
+/// On-disk shape of a single [`MCTSNode`], with `state` kept as raw JSON
+/// (rather than `AgentState`) so it can be migrated forward before
+/// `AgentState` itself is deserialized.
+#[derive(Debug, Serialize, Deserialize)]
+struct CheckpointNode {
+    id: Uuid,
+    state: serde_json::Value,
+    action: Option<AgentAction>,
+    parent: Option<Uuid>,
+    children: Vec<(AgentAction, CheckpointNode)>,
+    visits: u64,
+    total_value: f64,
+    ucb1_value: f64,
+    depth: usize,
+    is_terminal: bool,
+    created_at: DateTime<Utc>,
+    neural_prior: Option<f64>,
+    action_priors: Vec<(AgentAction, f64)>,
+    #[serde(default = "unvisited_lower_bound")]
+    lower_bound: f64,
+    #[serde(default = "unvisited_upper_bound")]
+    upper_bound: f64,
+    #[serde(default)]
+    pruned: bool,
 }
 
 /// Agent state representation for MCTS planning
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AgentState {
+    /// Schema version this state was constructed under. See
+    /// [`crate::state_migration::CURRENT_AGENT_STATE_SCHEMA_VERSION`] and
+    /// [`crate::state_migration::StateMigrator`], which upgrades states
+    /// persisted under an older version before they're deserialized.
+    pub schema_version: u32,
     pub context: String,
     pub available_actions: Vec<AgentAction>,
     pub resources: ResourceState,
@@ -158,10 +280,31 @@ pub struct SearchStatistics {
     pub parallelization_efficiency: f64,
 }
 
+/// Visit count and value estimate for one of the root's children, as of the
+/// moment a [`PlanningProgress`] snapshot was taken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RootChildStats {
+    pub action: AgentAction,
+    pub visits: u64,
+    pub mean_value: f64,
+}
+
+/// Intermediate snapshot [`MCTSPlanner::plan_iteratively`] sends every
+/// `report_every` completed simulations, for a caller (e.g. an interactive
+/// UI) that wants to show planning progress instead of waiting silently for
+/// the whole search to finish.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanningProgress {
+    pub simulations_completed: usize,
+    pub best_action_sequence: Vec<AgentAction>,
+    pub root_children: Vec<RootChildStats>,
+}
+
 impl MCTSPlanner {
     /// Create a new MCTS planner with configuration
     pub fn new(config: MCTSConfig) -> Self {
         let root_state = AgentState {
+            schema_version: crate::state_migration::CURRENT_AGENT_STATE_SCHEMA_VERSION,
             context: String::new(),
             available_actions: Vec::new(),
             resources: ResourceState {
@@ -190,6 +333,9 @@ impl MCTSPlanner {
             created_at: Utc::now(),
             neural_prior: None,
             action_priors: Vec::new(),
+            lower_bound: f64::MIN,
+            upper_bound: f64::MAX,
+            pruned: false,
         }));
 
         let neural_evaluator = if config.use_neural_guidance {
@@ -214,21 +360,189 @@ impl MCTSPlanner {
                 parallelization_efficiency: 0.0,
             })),
             simulation_semaphore: Arc::new(Semaphore::new(parallel_simulations)),
+            reused_visits: Arc::new(RwLock::new(0)),
+        }
+    }
+
+    /// Loads a checkpointed search tree from `path`, migrating every node's
+    /// serialized [`AgentState`] forward with `migrator` before
+    /// deserializing it. Lets a search resumed after an `AgentState` schema
+    /// change keep loading checkpoints written by an older build, rather
+    /// than failing to deserialize the first node with a missing field.
+    pub fn load_checkpoint(path: &Path, migrator: &StateMigrator) -> Result<Self, OrchestrationError> {
+        let raw = std::fs::read_to_string(path).map_err(|e| {
+            OrchestrationError::PlanningError(format!("failed to read checkpoint {}: {e}", path.display()))
+        })?;
+        let value: serde_json::Value = serde_json::from_str(&raw).map_err(|e| {
+            OrchestrationError::PlanningError(format!("failed to parse checkpoint {}: {e}", path.display()))
+        })?;
+
+        // Checked up front, against the raw value, so a checkpoint written
+        // under a future node shape reports a clear ConfigError instead of
+        // failing deep inside serde with a confusing missing-field error. A
+        // checkpoint written before this field existed is treated as
+        // version 1, mirroring how `StateMigrator` backfills a missing
+        // `AgentState::schema_version`.
+        let format_version = value
+            .get("checkpoint_format_version")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(1);
+        if format_version != CURRENT_MCTS_CHECKPOINT_FORMAT_VERSION as u64 {
+            return Err(OrchestrationError::ConfigError(format!(
+                "checkpoint {} was written under MCTS checkpoint format version {format_version}, but this build expects version {CURRENT_MCTS_CHECKPOINT_FORMAT_VERSION}",
+                path.display()
+            )));
+        }
+
+        let mut value = value;
+        if let Some(obj) = value.as_object_mut() {
+            obj.entry("checkpoint_format_version")
+                .or_insert_with(|| serde_json::json!(CURRENT_MCTS_CHECKPOINT_FORMAT_VERSION));
+        }
+        let checkpoint: Checkpoint = serde_json::from_value(value).map_err(|e| {
+            OrchestrationError::PlanningError(format!("failed to parse checkpoint {}: {e}", path.display()))
+        })?;
+
+        let root = Self::checkpoint_node_to_mcts_node(
+            checkpoint.root,
+            checkpoint.agent_state_schema_version,
+            migrator,
+        )?;
+        let parallel_simulations = checkpoint.config.parallel_simulations;
+        let use_neural_guidance = checkpoint.config.use_neural_guidance;
+
+        Ok(Self {
+            config: checkpoint.config,
+            root: Arc::new(RwLock::new(root)),
+            neural_evaluator: if use_neural_guidance { Some(NeuralEvaluator::new()) } else { None },
+            search_statistics: Arc::new(RwLock::new(SearchStatistics {
+                total_simulations: 0,
+                successful_simulations: 0,
+                average_simulation_time: Duration::from_millis(0),
+                best_value_found: 0.0,
+                tree_depth_reached: 0,
+                nodes_explored: 0,
+                neural_guidance_accuracy: 0.0,
+                parallelization_efficiency: 0.0,
+            })),
+            simulation_semaphore: Arc::new(Semaphore::new(parallel_simulations)),
+            reused_visits: Arc::new(RwLock::new(0)),
+        })
+    }
+
+    /// Recursively converts a deserialized [`CheckpointNode`] into a live
+    /// [`MCTSNode`], migrating each node's `AgentState` along the way.
+    fn checkpoint_node_to_mcts_node(
+        node: CheckpointNode,
+        agent_state_schema_version: u32,
+        migrator: &StateMigrator,
+    ) -> Result<MCTSNode, OrchestrationError> {
+        let state = migrator.migrate(agent_state_schema_version, node.state)?;
+
+        let mut children = Vec::with_capacity(node.children.len());
+        for (action, child) in node.children {
+            let child_node =
+                Self::checkpoint_node_to_mcts_node(child, agent_state_schema_version, migrator)?;
+            children.push((action, Arc::new(RwLock::new(child_node))));
+        }
+
+        Ok(MCTSNode {
+            id: node.id,
+            state,
+            action: node.action,
+            parent: node.parent,
+            children,
+            visits: node.visits,
+            total_value: node.total_value,
+            ucb1_value: node.ucb1_value,
+            depth: node.depth,
+            is_terminal: node.is_terminal,
+            created_at: node.created_at,
+            neural_prior: node.neural_prior,
+            action_priors: node.action_priors,
+            lower_bound: node.lower_bound,
+            upper_bound: node.upper_bound,
+            pruned: node.pruned,
+        })
+    }
+
+    /// Serializes the current search tree to `path` in the same format
+    /// [`Self::load_checkpoint`] reads, so a long-running search that would
+    /// otherwise exceed `MCTSConfig::timeout_seconds` can save its
+    /// accumulated visit counts and pick up later via
+    /// [`Self::resume_from_checkpoint`] instead of starting cold.
+    pub fn save_checkpoint(&self, path: &Path) -> Result<(), OrchestrationError> {
+        let checkpoint = Checkpoint {
+            checkpoint_format_version: CURRENT_MCTS_CHECKPOINT_FORMAT_VERSION,
+            agent_state_schema_version: crate::state_migration::CURRENT_AGENT_STATE_SCHEMA_VERSION,
+            config: self.config.clone(),
+            root: Self::mcts_node_to_checkpoint_node(&self.root.read()),
+        };
+
+        let raw = serde_json::to_string(&checkpoint).map_err(|e| {
+            OrchestrationError::PlanningError(format!("failed to serialize checkpoint: {e}"))
+        })?;
+        std::fs::write(path, raw).map_err(|e| {
+            OrchestrationError::PlanningError(format!("failed to write checkpoint {}: {e}", path.display()))
+        })
+    }
+
+    /// Recursively converts a live [`MCTSNode`] into its owned, serializable
+    /// [`CheckpointNode`] shape. The inverse of [`Self::checkpoint_node_to_mcts_node`].
+    fn mcts_node_to_checkpoint_node(node: &MCTSNode) -> CheckpointNode {
+        CheckpointNode {
+            id: node.id,
+            state: serde_json::to_value(&node.state).unwrap_or(serde_json::Value::Null),
+            action: node.action.clone(),
+            parent: node.parent,
+            children: node.children.iter()
+                .map(|(action, child)| (action.clone(), Self::mcts_node_to_checkpoint_node(&child.read())))
+                .collect(),
+            visits: node.visits,
+            total_value: node.total_value,
+            ucb1_value: node.ucb1_value,
+            depth: node.depth,
+            is_terminal: node.is_terminal,
+            created_at: node.created_at,
+            neural_prior: node.neural_prior,
+            action_priors: node.action_priors.clone(),
+            lower_bound: node.lower_bound,
+            upper_bound: node.upper_bound,
+            pruned: node.pruned,
         }
     }
 
+    /// Loads the checkpoint at `path` via [`Self::load_checkpoint`] and
+    /// immediately resumes searching from `initial_state`. Because `search`
+    /// refreshes the root's state in place rather than discarding the
+    /// existing tree, every visit count restored from the checkpoint is
+    /// reused as a warm start for the resumed simulations rather than
+    /// thrown away. Returns the planner alongside the newly extracted best
+    /// action sequence so the caller can keep checkpointing it.
+    pub async fn resume_from_checkpoint(
+        path: &Path,
+        migrator: &StateMigrator,
+        initial_state: AgentState,
+    ) -> Result<(Self, Vec<AgentAction>), OrchestrationError> {
+        let planner = Self::load_checkpoint(path, migrator)?;
+        let plan = planner.search(initial_state).await?;
+        Ok((planner, plan))
+    }
+
     /// Search for the best action sequence using MCTS
+    ///
+    /// Unlike a cold search, this does not discard the existing tree: if
+    /// `advance` was called since the last search, the new root's subtree
+    /// (and its accumulated visit statistics) is reused rather than thrown
+    /// away, so fewer simulations are needed to reach a confident action.
     pub async fn search(&self, initial_state: AgentState) -> Result<Vec<AgentAction>, OrchestrationError> {
         let start_time = Instant::now();
         let timeout = Duration::from_secs(self.config.timeout_seconds);
 
-        // Initialize root with the current state
+        // Refresh the root's state without discarding its accumulated tree.
         {
             let mut root = self.root.write();
             root.state = initial_state;
-            root.visits = 0;
-            root.total_value = 0.0;
-            root.children.clear();
         }
 
         // Parallel MCTS simulations
@@ -315,6 +629,247 @@ impl MCTSPlanner {
         self.extract_best_path()
     }
 
+    /// Like [`Self::search`], but reports incremental progress instead of
+    /// waiting silently for the whole search to finish, and never fails
+    /// just because time ran out before any simulation completed.
+    ///
+    /// A [`PlanningProgress`] snapshot is sent on `progress_tx` after every
+    /// `report_every` completed simulations (`report_every == 0` disables
+    /// progress reporting -- only the final plan is returned). If the
+    /// receiver has been dropped, snapshots are silently skipped rather than
+    /// aborting the search, since a caller that stopped listening for
+    /// progress may still want the eventual return value.
+    ///
+    /// On timeout, or if the caller cancels this future (e.g. by dropping
+    /// it or racing it in `tokio::select!`), whatever the tree has
+    /// accumulated so far is retained in `self.root` and can still be read
+    /// via [`Self::best_plan`] -- unlike `search`, this method itself
+    /// returns `Ok` with the best plan found so far rather than
+    /// `Err(OrchestrationError::PlanningError(..))` when the search ran out
+    /// of time before converging on a full-depth path.
+    pub async fn plan_iteratively(
+        &self,
+        initial_state: AgentState,
+        report_every: usize,
+        progress_tx: mpsc::Sender<PlanningProgress>,
+    ) -> Result<Vec<AgentAction>, OrchestrationError> {
+        let start_time = Instant::now();
+        let timeout = Duration::from_secs(self.config.timeout_seconds);
+
+        // Refresh the root's state without discarding its accumulated tree.
+        {
+            let mut root = self.root.write();
+            root.state = initial_state;
+        }
+
+        let mut simulation_handles = Vec::new();
+        // Unbounded, unlike `search`'s bounded channel: every simulation is
+        // spawned up front before this method ever drains a result (so
+        // progress can be reported against the fully up-to-date handle
+        // list), so a bounded channel would fill once more than its
+        // capacity's worth of simulations finish before draining starts --
+        // and a task blocked sending into a full channel never releases the
+        // semaphore permit it's holding, wedging every later simulation
+        // that's still waiting to acquire one.
+        let (result_tx, mut result_rx) = mpsc::unbounded_channel();
+
+        for simulation_id in 0..self.config.simulations {
+            let permit = self.simulation_semaphore.clone().acquire_owned().await
+                .map_err(|e| OrchestrationError::PlanningError(format!("Semaphore error: {}", e)))?;
+
+            let root_clone = Arc::clone(&self.root);
+            let config = self.config.clone();
+            let neural_evaluator = self.neural_evaluator.as_ref().map(|ne| ne.clone());
+            let tx = result_tx.clone();
+
+            let handle = tokio::spawn(async move {
+                let _permit = permit; // Keep permit alive for the duration
+                let simulation_result = Self::run_simulation(
+                    simulation_id as u64,
+                    root_clone,
+                    &config,
+                    neural_evaluator.as_ref(),
+                ).await;
+
+                if let Err(e) = tx.send(simulation_result) {
+                    tracing::warn!("Failed to send simulation result: {}", e);
+                }
+            });
+
+            simulation_handles.push(handle);
+
+            if start_time.elapsed() > timeout {
+                tracing::info!("MCTS search timeout reached, stopping simulations");
+                break;
+            }
+        }
+
+        drop(result_tx);
+
+        let mut simulation_count = 0;
+        let mut successful_simulations = 0;
+        let mut total_simulation_time = Duration::from_millis(0);
+
+        while let Some(result) = result_rx.recv().await {
+            simulation_count += 1;
+            match result {
+                Ok(simulation_stats) => {
+                    successful_simulations += 1;
+                    total_simulation_time += simulation_stats.duration;
+                }
+                Err(e) => {
+                    tracing::debug!("Simulation failed: {}", e);
+                }
+            }
+
+            if report_every > 0 && simulation_count % report_every as u64 == 0 {
+                let progress = PlanningProgress {
+                    simulations_completed: simulation_count as usize,
+                    best_action_sequence: self.best_plan(),
+                    root_children: self.root_children_snapshot(),
+                };
+                if progress_tx.send(progress).await.is_err() {
+                    tracing::debug!("Planning progress receiver dropped; continuing without reporting");
+                }
+            }
+
+            if start_time.elapsed() > timeout {
+                break;
+            }
+        }
+
+        let remaining_time = timeout.saturating_sub(start_time.elapsed());
+        if !remaining_time.is_zero() {
+            let _ = tokio::time::timeout(remaining_time, futures::future::join_all(simulation_handles)).await;
+        }
+
+        {
+            let mut stats = self.search_statistics.write();
+            stats.total_simulations = simulation_count;
+            stats.successful_simulations = successful_simulations;
+            stats.average_simulation_time = if simulation_count > 0 {
+                total_simulation_time / simulation_count as u32
+            } else {
+                Duration::from_millis(0)
+            };
+            stats.parallelization_efficiency = successful_simulations as f64 / simulation_count as f64;
+        }
+
+        Ok(self.best_plan())
+    }
+
+    /// Runs `count` simulations against the current tree on a rayon thread
+    /// pool, as an alternative to `search`'s tokio-task-per-simulation
+    /// model. Selection, expansion, and rollout are pure CPU work in this
+    /// planner (the neural evaluator does no I/O), so they parallelize
+    /// well across rayon's worker threads without an async runtime
+    /// underneath, at the cost of not truncating on `config.timeout_seconds`
+    /// the way `search` does.
+    ///
+    /// Every simulation records its selected node's visit/value delta into
+    /// a `NodeStats` entry instead of writing straight into the shared
+    /// tree, so concurrent simulations landing on the same node accumulate
+    /// (summed visits, running-mean reward) rather than racing on
+    /// `MCTSNode::visits`/`total_value` directly. The accumulated deltas
+    /// are applied to the tree in a single pass once every simulation has
+    /// finished, updating every ancestor along the way and retightening its
+    /// alpha-beta bounds via [`Self::propagate_bounds`] -- mirroring what
+    /// the sequential `backpropagate` does for `search`.
+    ///
+    /// If `count` exceeds the rayon thread pool size, it's clamped to the
+    /// pool size and a warning is logged, rather than oversubscribing the
+    /// pool.
+    pub fn run_parallel_simulations(&self, count: usize) -> Result<usize, OrchestrationError> {
+        let pool_size = rayon::current_num_threads();
+        let count = if count > pool_size {
+            tracing::warn!(
+                requested = count,
+                pool_size,
+                "run_parallel_simulations: parallel_simulations exceeds the rayon thread pool size, clamping to it"
+            );
+            pool_size
+        } else {
+            count
+        };
+
+        let node_stats: Mutex<HashMap<NodeId, NodeStats>> = Mutex::new(HashMap::new());
+        let node_index: Mutex<HashMap<NodeId, Arc<RwLock<MCTSNode>>>> = Mutex::new(HashMap::new());
+        let ancestor_paths: Mutex<HashMap<NodeId, Vec<Arc<RwLock<MCTSNode>>>>> = Mutex::new(HashMap::new());
+
+        let outcomes: Vec<Result<SimulationStats, OrchestrationError>> = (0..count)
+            .into_par_iter()
+            .map(|simulation_id| {
+                let start_time = Instant::now();
+
+                let (selected, mut path) = Self::select_node(self.root.clone(), self.config.exploration_constant)?;
+                let expanded = Self::expand_node_sync(selected.clone(), self.neural_evaluator.as_ref())?;
+                if !Arc::ptr_eq(&expanded, &selected) {
+                    path.push(selected);
+                }
+                let value = Self::simulate_rollout_sync(expanded.clone(), self.config.max_depth)?;
+
+                let node_id = expanded.read().id;
+                node_stats.lock().entry(node_id).or_default().record(value);
+                node_index.lock().entry(node_id).or_insert_with(|| expanded.clone());
+                ancestor_paths.lock().entry(node_id).or_insert(path);
+
+                Ok(SimulationStats {
+                    _id: simulation_id as u64,
+                    duration: start_time.elapsed(),
+                    _value_found: value,
+                    _depth_reached: Self::get_node_depth(&expanded),
+                })
+            })
+            .collect();
+
+        let node_stats = node_stats.into_inner();
+        let node_index = node_index.into_inner();
+        let ancestor_paths = ancestor_paths.into_inner();
+        let mut touched_ancestors: HashMap<Uuid, Arc<RwLock<MCTSNode>>> = HashMap::new();
+        for (node_id, stats) in node_stats {
+            if let Some(node_ref) = node_index.get(&node_id) {
+                {
+                    let mut node = node_ref.write();
+                    node.visits += stats.visits;
+                    node.total_value += stats.total_value;
+                }
+                if let Some(path) = ancestor_paths.get(&node_id) {
+                    for ancestor_ref in path {
+                        {
+                            let mut ancestor = ancestor_ref.write();
+                            ancestor.visits += stats.visits;
+                            ancestor.total_value += stats.total_value;
+                        }
+                        touched_ancestors.entry(ancestor_ref.read().id).or_insert_with(|| ancestor_ref.clone());
+                    }
+                }
+            }
+        }
+        // Tighten bounds deepest-first isn't required here since each
+        // ancestor's window only depends on its own children's stats, which
+        // are already fully accumulated above.
+        for ancestor_ref in touched_ancestors.values() {
+            Self::propagate_bounds(ancestor_ref, self.config.exploration_constant);
+        }
+
+        let successful = outcomes.iter().filter(|r| r.is_ok()).count();
+        let total_time: Duration = outcomes.iter().filter_map(|r| r.as_ref().ok()).map(|s| s.duration).sum();
+
+        {
+            let mut stats = self.search_statistics.write();
+            stats.total_simulations += count as u64;
+            stats.successful_simulations += successful as u64;
+            stats.average_simulation_time = if successful > 0 {
+                total_time / successful as u32
+            } else {
+                stats.average_simulation_time
+            };
+            stats.parallelization_efficiency = successful as f64 / count as f64;
+        }
+
+        Ok(successful)
+    }
+
     /// Run a single MCTS simulation
     async fn run_simulation(
         simulation_id: u64,
@@ -325,16 +880,22 @@ impl MCTSPlanner {
         let start_time = Instant::now();
 
         // Selection phase: traverse tree using UCB1
-        let selected_node = Self::select_node(root.clone(), config.exploration_constant)?;
-
-        // Expansion phase: add new child nodes
-        let expanded_node = Self::expand_node(selected_node, neural_evaluator).await?;
+        let (selected_node, mut ancestor_path) = Self::select_node(root.clone(), config.exploration_constant)?;
+
+        // Expansion phase: add new child nodes. `expand_node` returns the
+        // first newly-created child rather than `selected_node` itself, so
+        // the selected node becomes an extra ancestor on the path that
+        // `select_node` didn't (and couldn't) already know about.
+        let expanded_node = Self::expand_node(selected_node.clone(), neural_evaluator).await?;
+        if !Arc::ptr_eq(&expanded_node, &selected_node) {
+            ancestor_path.push(selected_node);
+        }
 
         // Simulation phase: rollout from expanded node
         let value = Self::simulate_rollout(expanded_node.clone(), config.max_depth).await?;
 
         // Backpropagation phase: update node values up the tree
-        Self::backpropagate(expanded_node.clone(), value)?;
+        Self::backpropagate(expanded_node.clone(), &ancestor_path, value, config.exploration_constant)?;
 
         Ok(SimulationStats {
             _id: simulation_id,
@@ -344,12 +905,19 @@ impl MCTSPlanner {
         })
     }
 
-    /// Select the most promising node using UCB1 with neural guidance
+    /// Select the most promising node using UCB1 with neural guidance.
+    ///
+    /// Returns the selected node together with the path of ancestors walked
+    /// to reach it (root first, selected node's immediate parent last), so
+    /// that [`Self::backpropagate`] can climb back up and update every
+    /// ancestor's stats and alpha-beta bounds, not just the selected node's
+    /// own.
     fn select_node(
         root: Arc<RwLock<MCTSNode>>,
         exploration_constant: f64,
-    ) -> Result<Arc<RwLock<MCTSNode>>, OrchestrationError> {
+    ) -> Result<(Arc<RwLock<MCTSNode>>, AncestorPath), OrchestrationError> {
         let mut current = root;
+        let mut ancestors = Vec::new();
 
         loop {
             let node = current.read();
@@ -357,10 +925,10 @@ impl MCTSPlanner {
             // If terminal or unexplored, return this node
             if node.is_terminal || node.children.is_empty() {
                 drop(node);
-                return Ok(current);
+                return Ok((current, ancestors));
             }
 
-            // Find child with highest UCB1 value
+            // Find child with highest UCB1 value among non-pruned children
             let mut best_child = None;
             let mut best_ucb1 = f64::NEG_INFINITY;
 
@@ -368,6 +936,9 @@ impl MCTSPlanner {
 
             for (_, child_ref) in &node.children {
                 let child = child_ref.read();
+                if child.pruned {
+                    continue;
+                }
                 let child_visits = child.visits as f64;
 
                 let ucb1 = if child_visits == 0.0 {
@@ -392,16 +963,86 @@ impl MCTSPlanner {
             drop(node);
 
             match best_child {
-                Some(child) => current = child,
-                None => return Ok(current),
+                Some(child) => {
+                    ancestors.push(current);
+                    current = child;
+                }
+                None => return Ok((current, ancestors)),
+            }
+        }
+    }
+
+    /// Tightens `node`'s children's alpha-beta confidence windows given their
+    /// current visit/value stats, and propagates the resulting bound up onto
+    /// `node` itself.
+    ///
+    /// This is a single-agent adaptation of two-player alpha-beta pruning:
+    /// each visited child's `[lower_bound, upper_bound]` window is a
+    /// UCB1-style confidence interval around its mean value
+    /// (`mean +/- exploration_constant * sqrt(ln(parent_visits)/child_visits)`).
+    /// Once every other child's guaranteed `lower_bound` (`alpha`) exceeds a
+    /// given child's best-case `upper_bound`, that child cannot possibly
+    /// become the best action even with more simulations, so it's marked
+    /// `pruned` and [`Self::select_node`] skips it -- shrinking the
+    /// effective branching factor without discarding the child from the
+    /// tree (it's still visited by anything that already holds a reference
+    /// to it, and still round-trips through checkpoints).
+    fn propagate_bounds(node_ref: &Arc<RwLock<MCTSNode>>, exploration_constant: f64) {
+        let mut node = node_ref.write();
+        if node.children.is_empty() {
+            return;
+        }
+
+        let parent_visits = node.visits as f64;
+
+        let mut alpha = f64::MIN;
+        for (_, child_ref) in &node.children {
+            let mut child = child_ref.write();
+            let child_visits = child.visits as f64;
+            if child_visits == 0.0 {
+                continue;
+            }
+
+            let mean = child.total_value / child_visits;
+            let width = exploration_constant * (parent_visits.max(1.0).ln() / child_visits).sqrt();
+            child.lower_bound = mean - width;
+            child.upper_bound = mean + width;
+
+            if child.lower_bound > alpha {
+                alpha = child.lower_bound;
             }
         }
+
+        for (_, child_ref) in &node.children {
+            let mut child = child_ref.write();
+            if child.visits == 0 {
+                continue;
+            }
+            if child.upper_bound < alpha {
+                child.pruned = true;
+            }
+        }
+
+        node.lower_bound = alpha;
     }
 
     /// Expand a node by adding new children
     async fn expand_node(
         node_ref: Arc<RwLock<MCTSNode>>,
         neural_evaluator: Option<&NeuralEvaluator>,
+    ) -> Result<Arc<RwLock<MCTSNode>>, OrchestrationError> {
+        Self::expand_node_sync(node_ref, neural_evaluator)
+    }
+
+    /// Synchronous body of `expand_node`, also used directly by
+    /// `run_parallel_simulations` (which runs on rayon worker threads
+    /// rather than the tokio runtime). Split out because
+    /// `NeuralEvaluator::evaluate_actions` doesn't actually do any
+    /// asynchronous work under the hood -- it's pure computation -- so
+    /// nothing here needs an executor.
+    fn expand_node_sync(
+        node_ref: Arc<RwLock<MCTSNode>>,
+        neural_evaluator: Option<&NeuralEvaluator>,
     ) -> Result<Arc<RwLock<MCTSNode>>, OrchestrationError> {
         // First, check if we need to expand
         let state_copy = {
@@ -418,7 +1059,7 @@ impl MCTSPlanner {
 
         // Get neural priors if evaluator available (without holding the lock)
         let action_priors = if let Some(evaluator) = neural_evaluator {
-            evaluator.evaluate_actions(&state_copy, &possible_actions).await?
+            evaluator.evaluate_actions_sync(&state_copy, &possible_actions)?
         } else {
             Vec::new()
         };
@@ -452,6 +1093,9 @@ impl MCTSPlanner {
                 created_at: Utc::now(),
                 neural_prior,
                 action_priors: Vec::new(),
+                lower_bound: f64::MIN,
+                upper_bound: f64::MAX,
+                pruned: false,
             }));
 
             new_children.push((action, child));
@@ -476,6 +1120,15 @@ impl MCTSPlanner {
     async fn simulate_rollout(
         node_ref: Arc<RwLock<MCTSNode>>,
         max_depth: usize,
+    ) -> Result<f64, OrchestrationError> {
+        Self::simulate_rollout_sync(node_ref, max_depth)
+    }
+
+    /// Synchronous body of `simulate_rollout`, also used directly by
+    /// `run_parallel_simulations`.
+    fn simulate_rollout_sync(
+        node_ref: Arc<RwLock<MCTSNode>>,
+        max_depth: usize,
     ) -> Result<f64, OrchestrationError> {
         let node = node_ref.read();
         let mut current_state = node.state.clone();
@@ -511,29 +1164,36 @@ impl MCTSPlanner {
         Ok(total_value)
     }
 
-    /// Backpropagate value up the tree
+    /// Backpropagate value up the tree.
+    ///
+    /// `ancestor_path` is the root-to-parent path returned by
+    /// [`Self::select_node`] (adjusted by the caller if expansion moved past
+    /// the selected node), so every ancestor's visit count and total value
+    /// are updated here, not just `node_ref`'s -- this used to stop after
+    /// the leaf because nothing tracked parent references, which also made
+    /// alpha-beta bound propagation impossible. [`Self::propagate_bounds`]
+    /// runs on each ancestor from the leaf's parent up to the root, so a
+    /// child pruned deep in the tree can still cause an ancestor's own
+    /// window to tighten.
     fn backpropagate(
         node_ref: Arc<RwLock<MCTSNode>>,
+        ancestor_path: &[Arc<RwLock<MCTSNode>>],
         value: f64,
+        exploration_constant: f64,
     ) -> Result<(), OrchestrationError> {
-        let mut current = Some(node_ref);
-
-        while let Some(node_ref) = current {
+        {
             let mut node = node_ref.write();
             node.visits += 1;
             node.total_value += value;
+        }
 
-            // Find parent for next iteration
-            let parent_id = node.parent;
-            drop(node);
-
-            if let Some(_parent_id) = parent_id {
-                // In a real implementation, we'd need to maintain parent references
-                // For now, we'll break the loop
-                break;
+        for ancestor_ref in ancestor_path.iter().rev() {
+            {
+                let mut ancestor = ancestor_ref.write();
+                ancestor.visits += 1;
+                ancestor.total_value += value;
             }
-
-            current = None;
+            Self::propagate_bounds(ancestor_ref, exploration_constant);
         }
 
         Ok(())
@@ -673,6 +1333,24 @@ impl MCTSPlanner {
 
     /// Extract the best action sequence from the search tree
     fn extract_best_path(&self) -> Result<Vec<AgentAction>, OrchestrationError> {
+        let path = self.best_path_from_tree();
+
+        if path.is_empty() {
+            return Err(OrchestrationError::PlanningError(
+                "No valid path found in search tree".to_string()
+            ));
+        }
+
+        Ok(path)
+    }
+
+    /// The best action sequence the tree currently supports, following the
+    /// path of most-visited children from the root. Empty if the root has
+    /// no children yet (e.g. a search that timed out before completing a
+    /// single simulation) -- unlike `extract_best_path`, an empty result is
+    /// not an error, since callers of `best_plan`/`plan_iteratively` are
+    /// explicitly asking for "whatever's there so far".
+    fn best_path_from_tree(&self) -> Vec<AgentAction> {
         let mut path = Vec::new();
         let mut current = Arc::clone(&self.root);
 
@@ -708,13 +1386,34 @@ impl MCTSPlanner {
             }
         }
 
-        if path.is_empty() {
-            return Err(OrchestrationError::PlanningError(
-                "No valid path found in search tree".to_string()
-            ));
-        }
+        path
+    }
 
-        Ok(path)
+    /// The best action sequence found so far, without requiring a `search`
+    /// or `plan_iteratively` call to be in progress or to have finished.
+    /// Safe to call after cancelling a `plan_iteratively` future (e.g. by
+    /// dropping it or racing it in a `tokio::select!`) -- the tree it built
+    /// up to that point is still there, since simulations mutate `self.root`
+    /// directly rather than through a value only the cancelled future held.
+    /// Returns an empty `Vec` rather than an error if no simulation has
+    /// completed yet.
+    pub fn best_plan(&self) -> Vec<AgentAction> {
+        self.best_path_from_tree()
+    }
+
+    /// A snapshot of the root's immediate children's visit counts and mean
+    /// values, for `PlanningProgress`.
+    fn root_children_snapshot(&self) -> Vec<RootChildStats> {
+        self.root
+            .read()
+            .children
+            .iter()
+            .map(|(action, child)| {
+                let child = child.read();
+                let mean_value = if child.visits == 0 { 0.0 } else { child.total_value / child.visits as f64 };
+                RootChildStats { action: action.clone(), visits: child.visits, mean_value }
+            })
+            .collect()
     }
 
     /// Get search statistics
@@ -722,6 +1421,53 @@ impl MCTSPlanner {
         self.search_statistics.read().clone()
     }
 
+    /// Returns a handle to the current search tree's root, for callers that
+    /// need to walk the tree directly (e.g. to inspect `pruned` nodes) --
+    /// see `examples/pruning_benchmark.rs`.
+    pub fn root_node(&self) -> Arc<RwLock<MCTSNode>> {
+        self.root.clone()
+    }
+
+    /// Re-root the tree at the child matching `action_taken` (MCTS warm
+    /// start). Sibling subtrees are dropped, freeing their memory, and the
+    /// next `search` call continues from the promoted subtree's accumulated
+    /// statistics instead of starting from scratch.
+    pub fn advance(&self, action_taken: &AgentAction) -> Result<(), OrchestrationError> {
+        let promoted = {
+            let root = self.root.read();
+            root.children.iter()
+                .find(|(action, _)| action == action_taken)
+                .map(|(_, child)| child.read().clone())
+        };
+
+        let mut promoted = promoted.ok_or_else(|| {
+            OrchestrationError::PlanningError(format!(
+                "advance: no child found for action {action_taken:?}"
+            ))
+        })?;
+
+        promoted.parent = None;
+        promoted.depth = 0;
+        *self.reused_visits.write() = promoted.visits;
+        *self.root.write() = promoted;
+
+        Ok(())
+    }
+
+    /// Ratio of visits reused from the last `advance` warm start to the
+    /// simulations run in the most recent `search` call. `0.0` before the
+    /// first `advance`, or if the last search ran zero simulations.
+    pub fn reuse_ratio(&self) -> f64 {
+        let reused_visits = *self.reused_visits.read() as f64;
+        let new_visits = self.search_statistics.read().total_simulations as f64;
+
+        if new_visits == 0.0 {
+            0.0
+        } else {
+            reused_visits / new_visits
+        }
+    }
+
     /// Get the depth of a node
     fn get_node_depth(node_ref: &Arc<RwLock<MCTSNode>>) -> usize {
         node_ref.read().depth
@@ -767,6 +1513,16 @@ impl NeuralEvaluator {
         &self,
         state: &AgentState,
         actions: &[AgentAction],
+    ) -> Result<Vec<(AgentAction, f64)>, OrchestrationError> {
+        self.evaluate_actions_sync(state, actions)
+    }
+
+    /// Synchronous body of `evaluate_actions`, also used directly by
+    /// `expand_node_sync` on rayon worker threads.
+    fn evaluate_actions_sync(
+        &self,
+        state: &AgentState,
+        actions: &[AgentAction],
     ) -> Result<Vec<(AgentAction, f64)>, OrchestrationError> {
         let _features = self.feature_extractor.extract_features(state);
         let mut priors = Vec::new();
@@ -819,3 +1575,312 @@ impl FeatureExtractor {
         ])
     }
 }
+
+#[cfg(test)]
+mod parallel_simulation_tests {
+    use super::*;
+
+    #[test]
+    fn running_parallel_simulations_merges_visits_into_the_tree() {
+        let planner = MCTSPlanner::new(MCTSConfig { use_neural_guidance: false, ..MCTSConfig::default() });
+
+        let successful = planner
+            .run_parallel_simulations(4)
+            .expect("simulations against a fresh tree should succeed");
+
+        assert!(successful > 0);
+
+        // Simulations that raced to expand the root land on different
+        // children, and each one also backpropagates its visit up onto the
+        // root itself, so root visits and the sum of every child's visits
+        // should each independently equal the number of successful sims.
+        let root = planner.root.read();
+        let children_visits: u64 = root.children.iter().map(|(_, child)| child.read().visits).sum();
+        assert_eq!(root.visits, successful as u64);
+        assert_eq!(children_visits, successful as u64);
+    }
+
+    #[test]
+    fn oversized_simulation_count_is_clamped_to_the_rayon_pool_size() {
+        let planner = MCTSPlanner::new(MCTSConfig { use_neural_guidance: false, ..MCTSConfig::default() });
+        let pool_size = rayon::current_num_threads();
+
+        let successful = planner
+            .run_parallel_simulations(pool_size * 100)
+            .expect("an oversized request should clamp rather than fail");
+
+        assert!(successful <= pool_size);
+    }
+}
+
+#[cfg(test)]
+mod checkpoint_tests {
+    use super::*;
+    use crate::state_migration::StateMigrator;
+
+    /// Builds a tree with two children carrying fixed visit/value totals,
+    /// standing in for "1000 pre-computed simulations" without depending on
+    /// `simulate_rollout`'s RNG, so the round-trip assertion below is
+    /// deterministic rather than tied to a particular random seed.
+    fn planner_with_precomputed_tree() -> MCTSPlanner {
+        let planner = MCTSPlanner::new(MCTSConfig { use_neural_guidance: false, ..MCTSConfig::default() });
+        let mut root = planner.root.write();
+
+        let winning_action = AgentAction::ExecuteTask { task: "winning-branch".to_string(), priority: 5 };
+        let losing_action = AgentAction::ExecuteTask { task: "losing-branch".to_string(), priority: 1 };
+
+        let winning_child = Arc::new(RwLock::new(MCTSNode {
+            id: Uuid::new_v4(),
+            state: root.state.clone(),
+            action: Some(winning_action.clone()),
+            parent: Some(root.id),
+            children: Vec::new(),
+            visits: 700,
+            total_value: 630.0,
+            ucb1_value: 0.9,
+            depth: 1,
+            is_terminal: false,
+            created_at: Utc::now(),
+            neural_prior: None,
+            action_priors: Vec::new(),
+            lower_bound: f64::MIN,
+            upper_bound: f64::MAX,
+            pruned: false,
+        }));
+        let losing_child = Arc::new(RwLock::new(MCTSNode {
+            id: Uuid::new_v4(),
+            state: root.state.clone(),
+            action: Some(losing_action.clone()),
+            parent: Some(root.id),
+            children: Vec::new(),
+            visits: 300,
+            total_value: 90.0,
+            ucb1_value: 0.3,
+            depth: 1,
+            is_terminal: false,
+            created_at: Utc::now(),
+            neural_prior: None,
+            action_priors: Vec::new(),
+            lower_bound: f64::MIN,
+            upper_bound: f64::MAX,
+            pruned: false,
+        }));
+
+        root.children = vec![(winning_action, winning_child), (losing_action, losing_child)];
+        root.visits = 1000;
+        // JSON has no representation for infinity (serde_json serializes it
+        // as `null`), so give the root a finite UCB1 value rather than the
+        // usual "unvisited" `f64::INFINITY` it's created with.
+        root.ucb1_value = f64::MAX;
+        drop(root);
+        planner
+    }
+
+    #[test]
+    fn a_checkpoint_restored_from_disk_selects_the_same_best_action_as_the_original_tree() {
+        let planner = planner_with_precomputed_tree();
+        let best_action_before = planner
+            .extract_best_path()
+            .expect("a populated tree should have a best path");
+
+        let path = std::env::temp_dir().join(format!("mcts-checkpoint-roundtrip-{}.json", Uuid::new_v4()));
+        planner.save_checkpoint(&path).expect("saving a checkpoint should succeed");
+
+        let restored = MCTSPlanner::load_checkpoint(&path, &StateMigrator::with_default_migrations())
+            .expect("loading the checkpoint just saved should succeed");
+        std::fs::remove_file(&path).ok();
+
+        let best_action_after = restored
+            .extract_best_path()
+            .expect("the restored tree should have a best path");
+
+        assert_eq!(best_action_before, best_action_after);
+        assert_eq!(restored.root.read().visits, 1000);
+        assert_eq!(restored.root.read().children.len(), 2);
+    }
+
+    #[test]
+    fn loading_a_checkpoint_with_an_unknown_format_version_reports_a_config_error() {
+        let planner = planner_with_precomputed_tree();
+        let path = std::env::temp_dir().join(format!("mcts-checkpoint-bad-version-{}.json", Uuid::new_v4()));
+        planner.save_checkpoint(&path).expect("saving a checkpoint should succeed");
+
+        let mut raw: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        raw["checkpoint_format_version"] = serde_json::json!(CURRENT_MCTS_CHECKPOINT_FORMAT_VERSION + 1);
+        std::fs::write(&path, serde_json::to_string(&raw).unwrap()).unwrap();
+
+        let err = MCTSPlanner::load_checkpoint(&path, &StateMigrator::with_default_migrations())
+            .expect_err("a checkpoint from a future format version should be rejected");
+        std::fs::remove_file(&path).ok();
+
+        assert!(matches!(err, OrchestrationError::ConfigError(_)));
+    }
+}
+
+#[cfg(test)]
+mod pruning_tests {
+    use super::*;
+    use crate::state_migration::StateMigrator;
+
+    /// Root with three children: one dominant winner, one clear loser whose
+    /// confidence window can't overlap the winner's, and one unvisited
+    /// child that `propagate_bounds` must leave alone.
+    fn planner_with_prunable_tree() -> MCTSPlanner {
+        let planner = MCTSPlanner::new(MCTSConfig { use_neural_guidance: false, ..MCTSConfig::default() });
+        let mut root = planner.root.write();
+
+        let make_child = |visits: u64, total_value: f64| {
+            Arc::new(RwLock::new(MCTSNode {
+                id: Uuid::new_v4(),
+                state: root.state.clone(),
+                action: None,
+                parent: Some(root.id),
+                children: Vec::new(),
+                visits,
+                total_value,
+                ucb1_value: 0.0,
+                depth: 1,
+                is_terminal: false,
+                created_at: Utc::now(),
+                neural_prior: None,
+                action_priors: Vec::new(),
+                lower_bound: f64::MIN,
+                upper_bound: f64::MAX,
+                pruned: false,
+            }))
+        };
+
+        let winner = make_child(1000, 990.0);
+        let loser = make_child(1000, 10.0);
+        let unvisited = make_child(0, 0.0);
+
+        root.children = vec![
+            (AgentAction::Terminate, winner),
+            (AgentAction::OptimizePerformance { strategy: "loser".to_string() }, loser),
+            (AgentAction::OptimizePerformance { strategy: "unvisited".to_string() }, unvisited),
+        ];
+        root.visits = 2000;
+        root.ucb1_value = f64::MAX;
+        drop(root);
+        planner
+    }
+
+    #[test]
+    fn propagate_bounds_prunes_a_child_whose_window_cannot_beat_the_winner() {
+        let planner = planner_with_prunable_tree();
+        MCTSPlanner::propagate_bounds(&planner.root, planner.config.exploration_constant);
+
+        let root = planner.root.read();
+        let winner = root.children[0].1.read();
+        let loser = root.children[1].1.read();
+        let unvisited = root.children[2].1.read();
+
+        assert!(!winner.pruned, "the dominant child should never be pruned");
+        assert!(loser.pruned, "a child whose upper bound trails the winner's lower bound should be pruned");
+        assert!(!unvisited.pruned, "an unvisited child has no stats yet and must not be pruned");
+    }
+
+    #[test]
+    fn select_node_skips_pruned_children() {
+        let planner = planner_with_prunable_tree();
+        MCTSPlanner::propagate_bounds(&planner.root, planner.config.exploration_constant);
+        assert!(planner.root.read().children[1].1.read().pruned);
+
+        let (selected, _) = MCTSPlanner::select_node(planner.root.clone(), planner.config.exploration_constant)
+            .expect("selection should succeed even with a pruned sibling present");
+
+        let selected_id = selected.read().id;
+        let loser_id = planner.root.read().children[1].1.read().id;
+        assert_ne!(selected_id, loser_id, "select_node must not descend into a pruned child");
+    }
+
+    #[test]
+    fn a_pruned_node_still_appears_after_a_checkpoint_round_trip() {
+        let planner = planner_with_prunable_tree();
+        MCTSPlanner::propagate_bounds(&planner.root, planner.config.exploration_constant);
+        assert!(planner.root.read().children[1].1.read().pruned);
+
+        let path = std::env::temp_dir().join(format!("mcts-checkpoint-pruned-{}.json", Uuid::new_v4()));
+        planner.save_checkpoint(&path).expect("saving a checkpoint should succeed");
+
+        let restored = MCTSPlanner::load_checkpoint(&path, &StateMigrator::with_default_migrations())
+            .expect("loading the checkpoint just saved should succeed");
+        std::fs::remove_file(&path).ok();
+
+        let restored_root = restored.root.read();
+        assert_eq!(restored_root.children.len(), 3, "the pruned child must still be present in the tree");
+        assert!(restored_root.children[1].1.read().pruned, "pruned state must survive the round trip");
+    }
+}
+
+#[cfg(test)]
+mod incremental_planning_tests {
+    use super::*;
+
+    fn small_config() -> MCTSConfig {
+        MCTSConfig {
+            max_depth: 3,
+            simulations: 20,
+            exploration_constant: 1.414,
+            timeout_seconds: 5,
+            parallel_simulations: 4,
+            use_neural_guidance: false,
+        }
+    }
+
+    // `search`/`plan_iteratively` spawn one task per simulation and let them
+    // contend for `parking_lot` locks on the shared tree; on a
+    // current-thread runtime a task that blocks on a contended lock can
+    // starve the very task that would release it, so these need real
+    // parallelism to make progress.
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn plan_iteratively_reports_progress_every_report_every_simulations() {
+        let planner = MCTSPlanner::new(small_config());
+        let initial_state = planner.root.read().state.clone();
+        let (progress_tx, mut progress_rx) = mpsc::channel(32);
+
+        let plan = planner
+            .plan_iteratively(initial_state, 5, progress_tx)
+            .await
+            .expect("a small search should complete without error");
+        assert!(!plan.is_empty(), "20 simulations against a fresh tree should find a best action");
+
+        let mut snapshots = Vec::new();
+        while let Ok(progress) = progress_rx.try_recv() {
+            snapshots.push(progress);
+        }
+
+        assert_eq!(snapshots.len(), 4, "20 simulations reported every 5 should yield exactly 4 snapshots");
+        for (index, progress) in snapshots.iter().enumerate() {
+            assert_eq!(progress.simulations_completed, (index + 1) * 5);
+        }
+        // Snapshots are taken while simulations are still landing, so later
+        // ones should never see fewer root children than earlier ones.
+        for window in snapshots.windows(2) {
+            assert!(window[1].root_children.len() >= window[0].root_children.len());
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn best_plan_returns_the_partial_tree_after_a_search_times_out() {
+        let mut config = small_config();
+        // A search that can only ever complete a fraction of its simulations
+        // before its own timeout still leaves a partially-built tree behind.
+        config.simulations = 10_000;
+        config.timeout_seconds = 0;
+        let planner = MCTSPlanner::new(config);
+        let initial_state = planner.root.read().state.clone();
+        let (progress_tx, _progress_rx) = mpsc::channel(32);
+
+        let plan = planner
+            .plan_iteratively(initial_state, 0, progress_tx)
+            .await
+            .expect("plan_iteratively must not error out just because the timeout elapsed early");
+
+        // Whatever `plan_iteratively` returned, `best_plan` (callable at any
+        // time, independent of any in-flight search) must agree with it.
+        assert_eq!(plan, planner.best_plan());
+    }
+}
+
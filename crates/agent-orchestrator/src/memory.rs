@@ -5,14 +5,36 @@
 
 use crate::OrchestrationError;
 use chrono::{DateTime, Utc};
+use lru::LruCache;
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
 use uuid::Uuid;
 
-/// Memory manager for coordinating agent memory systems
-#[derive(Debug)]
+/// Key under which `MemoryManager` caches an `AgentContext`: its own `id`.
+pub type ContextKey = Uuid;
+
+/// Memory manager for coordinating agent memory systems.
+///
+/// Holds a bounded LRU cache of recently-touched contexts, up to
+/// `MemoryConfig::cache_size` entries -- once full, `insert` evicts the
+/// least-recently-used entry before adding a new one, and `get` promotes
+/// the entry it returns to most-recently-used. Wrapped in `Arc<Mutex<_>>`
+/// (a `parking_lot::Mutex`, matching this crate's existing choice for
+/// short, non-blocking critical sections -- see `context.rs`/`mcts.rs`)
+/// so it's safe to share across concurrently-running async tasks.
 pub struct MemoryManager {
     pub config: MemoryConfig,
+    cache: Arc<Mutex<LruCache<ContextKey, (AgentContext, Instant)>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
 }
 
 /// Configuration for memory management
@@ -22,7 +44,32 @@ pub struct MemoryConfig {
     pub compression_threshold: usize,
     pub persistence_enabled: bool,
     pub cache_size: usize,
-    pub ttl_seconds: u64,
+    /// How long a stored context lives before `InMemoryContextStore`'s
+    /// expiry sweeper reclaims it. A `Duration` rather than a whole-second
+    /// count (this field used to be `ttl_seconds: u64`) so tests -- and
+    /// any other short-lived context -- can ask for sub-second TTLs.
+    #[serde(with = "duration_as_secs_f64")]
+    pub ttl: Duration,
+    /// Where `ContextStore` implementations persist context entries when
+    /// `persistence_enabled` is set. Unused otherwise.
+    pub persistence_path: PathBuf,
+}
+
+/// `serde`'s own `Duration` impl round-trips as whole seconds + nanos,
+/// which is fine for (de)serializing but doesn't accept a bare fractional
+/// number of seconds from config files -- this does, matching the unit the
+/// field's doc comment promises.
+mod duration_as_secs_f64 {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Ok(Duration::from_secs_f64(f64::deserialize(deserializer)?))
+    }
 }
 
 /// Context storage interface
@@ -32,17 +79,22 @@ pub trait ContextStore {
         agent_id: Uuid,
         context: AgentContext,
     ) -> Result<(), OrchestrationError>;
-    
+
     async fn retrieve_context(
         &self,
         agent_id: Uuid,
         context_id: Uuid,
     ) -> Result<Option<AgentContext>, OrchestrationError>;
-    
+
     async fn list_contexts(
         &self,
         agent_id: Uuid,
     ) -> Result<Vec<ContextSummary>, OrchestrationError>;
+
+    /// Ratio of stored (possibly compressed) bytes to the original
+    /// serialized size, across every entry stored so far. `1.0` means
+    /// nothing has been compressed yet.
+    fn compression_ratio(&self) -> f64;
 }
 
 /// Agent context data
@@ -76,7 +128,59 @@ pub struct ContextSummary {
 
 impl MemoryManager {
     pub fn new(config: MemoryConfig) -> Self {
-        Self { config }
+        let capacity = NonZeroUsize::new(config.cache_size).unwrap_or(NonZeroUsize::new(1).unwrap());
+        Self {
+            config,
+            cache: Arc::new(Mutex::new(LruCache::new(capacity))),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+        }
+    }
+
+    /// Inserts `context` under its own `id`. If the cache is already at
+    /// `MemoryConfig::cache_size`, the least-recently-used entry is
+    /// evicted first (unless it's `key` itself being overwritten, which
+    /// doesn't count as an eviction).
+    pub fn insert(&self, key: ContextKey, context: AgentContext) {
+        let mut cache = self.cache.lock();
+        if let Some((evicted_key, _)) = cache.push(key, (context, Instant::now())) {
+            if evicted_key != key {
+                self.evictions.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Looks up `key`, promoting it to most-recently-used on a hit.
+    pub fn get(&self, key: &ContextKey) -> Option<AgentContext> {
+        let mut cache = self.cache.lock();
+        match cache.get(key) {
+            Some((context, _)) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(context.clone())
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// How many entries have been evicted for capacity, across this
+    /// manager's lifetime.
+    pub fn eviction_count(&self) -> u64 {
+        self.evictions.load(Ordering::Relaxed)
+    }
+
+    /// Fraction of `get` calls that found their entry still cached. `0.0`
+    /// with no calls yet, rather than `NaN`.
+    pub fn hit_rate(&self) -> f64 {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        if hits + misses == 0 {
+            return 0.0;
+        }
+        hits as f64 / (hits + misses) as f64
     }
 }
 
@@ -87,7 +191,378 @@ impl Default for MemoryConfig {
             compression_threshold: 16384,
             persistence_enabled: true,
             cache_size: 1000,
-            ttl_seconds: 3600,
+            ttl: Duration::from_secs(3600),
+            persistence_path: PathBuf::from("data/agent_context_store"),
+        }
+    }
+}
+
+/// A context entry as actually held in memory: compressed with
+/// `lz4_flex` once its serialized size passes `MemoryConfig::compression_threshold`,
+/// left as plain JSON bytes otherwise so small entries don't pay LZ4's
+/// framing overhead for no benefit.
+struct StoredContext {
+    bytes: Vec<u8>,
+    compressed: bool,
+    context_type: ContextType,
+    created_at: DateTime<Utc>,
+    expires_at: Instant,
+}
+
+/// The primary context table plus its expiry index, held behind a single
+/// `RwLock` so `store_context`/`retrieve_context` update both atomically
+/// -- otherwise a reader could observe an entry present in one and already
+/// evicted from the other.
+#[derive(Default)]
+struct ContextStoreInner {
+    contexts: HashMap<Uuid, HashMap<Uuid, StoredContext>>,
+    /// `(expires_at, agent_id, context_id) -> ()`, ordered by expiry so
+    /// the sweeper only has to walk the already-expired prefix instead of
+    /// scanning every entry every tick.
+    expiry_index: BTreeMap<(Instant, Uuid, Uuid), ()>,
+}
+
+/// The workspace's first real `ContextStore`: an in-memory table of
+/// entries, transparently LZ4-compressed above `compression_threshold`
+/// and, when `persistence_enabled`, written to disk in that same
+/// (possibly compressed) form under `persistence_path`. Entries older
+/// than `MemoryConfig::ttl` are reclaimed either lazily, on the next
+/// `retrieve_context` that encounters them, or proactively by the
+/// background task started with `start_expiry_task`.
+pub struct InMemoryContextStore {
+    config: MemoryConfig,
+    inner: RwLock<ContextStoreInner>,
+    total_uncompressed_bytes: AtomicU64,
+    total_stored_bytes: AtomicU64,
+}
+
+impl InMemoryContextStore {
+    pub fn new(config: MemoryConfig) -> Self {
+        Self {
+            config,
+            inner: RwLock::new(ContextStoreInner::default()),
+            total_uncompressed_bytes: AtomicU64::new(0),
+            total_stored_bytes: AtomicU64::new(0),
+        }
+    }
+
+    fn persistence_file(&self, agent_id: Uuid, context_id: Uuid) -> PathBuf {
+        self.config
+            .persistence_path
+            .join(agent_id.to_string())
+            .join(format!("{context_id}.bin"))
+    }
+
+    /// Spawns a background task that wakes on a quarter of the configured
+    /// TTL (so an entry is never more than ~25% past its expiry before
+    /// being swept) and removes everything past its `expires_at`. Takes
+    /// `self: Arc<Self>` rather than a bare `MemoryConfig` so the task can
+    /// reach the store's actual entries instead of just its settings.
+    pub fn start_expiry_task(self: Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let interval = (self.config.ttl / 4).max(Duration::from_millis(1));
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                self.sweep_expired().await;
+            }
+        })
+    }
+
+    /// Removes every entry whose `expires_at` is at or before now, from
+    /// both `contexts` and `expiry_index`, under one write lock.
+    async fn sweep_expired(&self) {
+        let now = Instant::now();
+        let mut inner = self.inner.write().await;
+        let expired: Vec<(Instant, Uuid, Uuid)> = inner
+            .expiry_index
+            .range(..=(now, Uuid::max(), Uuid::max()))
+            .map(|(key, ())| *key)
+            .collect();
+
+        for (expires_at, agent_id, context_id) in expired {
+            inner.expiry_index.remove(&(expires_at, agent_id, context_id));
+            if let Some(by_id) = inner.contexts.get_mut(&agent_id) {
+                by_id.remove(&context_id);
+                if by_id.is_empty() {
+                    inner.contexts.remove(&agent_id);
+                }
+            }
+        }
+    }
+}
+
+#[allow(async_fn_in_trait)]
+impl ContextStore for InMemoryContextStore {
+    async fn store_context(
+        &self,
+        agent_id: Uuid,
+        context: AgentContext,
+    ) -> Result<(), OrchestrationError> {
+        let serialized = serde_json::to_vec(&context)
+            .map_err(|e| OrchestrationError::MemoryError(format!("failed to serialize context: {e}")))?;
+        let uncompressed_len = serialized.len() as u64;
+
+        let (bytes, compressed) = if serialized.len() > self.config.compression_threshold {
+            (lz4_flex::compress_prepend_size(&serialized), true)
+        } else {
+            (serialized, false)
+        };
+        let stored_len = bytes.len() as u64;
+
+        if self.config.persistence_enabled {
+            let path = self.persistence_file(agent_id, context.id);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|e| OrchestrationError::MemoryError(format!("failed to create persistence dir: {e}")))?;
+            }
+            std::fs::write(&path, &bytes)
+                .map_err(|e| OrchestrationError::MemoryError(format!("failed to persist context: {e}")))?;
+        }
+
+        let expires_at = Instant::now() + self.config.ttl;
+
+        let mut inner = self.inner.write().await;
+        let previous = inner
+            .contexts
+            .entry(agent_id)
+            .or_default()
+            .insert(
+                context.id,
+                StoredContext {
+                    bytes,
+                    compressed,
+                    context_type: context.context_type,
+                    created_at: context.created_at,
+                    expires_at,
+                },
+            );
+        if let Some(previous) = previous {
+            inner.expiry_index.remove(&(previous.expires_at, agent_id, context.id));
+        }
+        inner.expiry_index.insert((expires_at, agent_id, context.id), ());
+
+        self.total_uncompressed_bytes.fetch_add(uncompressed_len, Ordering::Relaxed);
+        self.total_stored_bytes.fetch_add(stored_len, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn retrieve_context(
+        &self,
+        agent_id: Uuid,
+        context_id: Uuid,
+    ) -> Result<Option<AgentContext>, OrchestrationError> {
+        // A write lock, not a read lock: an entry found past its `expires_at`
+        // is evicted from both the primary map and the expiry index right
+        // here, rather than being handed back and left for the next sweep.
+        let mut inner = self.inner.write().await;
+        let Some(by_id) = inner.contexts.get(&agent_id) else {
+            return Ok(None);
+        };
+        let Some(stored) = by_id.get(&context_id) else {
+            return Ok(None);
+        };
+
+        if stored.expires_at <= Instant::now() {
+            let expires_at = stored.expires_at;
+            if let Some(by_id) = inner.contexts.get_mut(&agent_id) {
+                by_id.remove(&context_id);
+                if by_id.is_empty() {
+                    inner.contexts.remove(&agent_id);
+                }
+            }
+            inner.expiry_index.remove(&(expires_at, agent_id, context_id));
+            return Ok(None);
+        }
+
+        let decompressed;
+        let raw: &[u8] = if stored.compressed {
+            decompressed = lz4_flex::decompress_size_prepended(&stored.bytes).map_err(|e| {
+                OrchestrationError::MemoryError(format!("failed to decompress context: {e}"))
+            })?;
+            &decompressed
+        } else {
+            &stored.bytes
+        };
+
+        let context = serde_json::from_slice(raw)
+            .map_err(|e| OrchestrationError::MemoryError(format!("failed to deserialize context: {e}")))?;
+        Ok(Some(context))
+    }
+
+    async fn list_contexts(&self, agent_id: Uuid) -> Result<Vec<ContextSummary>, OrchestrationError> {
+        let inner = self.inner.read().await;
+        let now = Instant::now();
+        Ok(inner
+            .contexts
+            .get(&agent_id)
+            .map(|by_id| {
+                by_id
+                    .iter()
+                    .filter(|(_, stored)| stored.expires_at > now)
+                    .map(|(id, stored)| ContextSummary {
+                        id: *id,
+                        context_type: stored.context_type.clone(),
+                        created_at: stored.created_at,
+                        size: stored.bytes.len(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn compression_ratio(&self) -> f64 {
+        let uncompressed = self.total_uncompressed_bytes.load(Ordering::Relaxed);
+        if uncompressed == 0 {
+            return 1.0;
+        }
+        self.total_stored_bytes.load(Ordering::Relaxed) as f64 / uncompressed as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn context_with_content(content: String) -> AgentContext {
+        AgentContext {
+            id: Uuid::new_v4(),
+            agent_id: Uuid::new_v4(),
+            content,
+            context_type: ContextType::Knowledge,
+            created_at: Utc::now(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_small_context_is_stored_uncompressed_and_round_trips() {
+        let config = MemoryConfig {
+            compression_threshold: 16384,
+            persistence_enabled: false,
+            ..MemoryConfig::default()
+        };
+        let store = InMemoryContextStore::new(config);
+        let context = context_with_content("short".to_string());
+        let agent_id = context.agent_id;
+        let context_id = context.id;
+
+        store.store_context(agent_id, context.clone()).await.unwrap();
+        let retrieved = store.retrieve_context(agent_id, context_id).await.unwrap();
+
+        assert_eq!(retrieved.unwrap().content, "short");
+        assert_eq!(store.compression_ratio(), 1.0);
+    }
+
+    #[tokio::test]
+    async fn a_context_over_the_threshold_is_compressed_and_still_round_trips() {
+        let config = MemoryConfig {
+            compression_threshold: 64,
+            persistence_enabled: false,
+            ..MemoryConfig::default()
+        };
+        let store = InMemoryContextStore::new(config);
+        let context = context_with_content("x".repeat(4096));
+        let agent_id = context.agent_id;
+        let context_id = context.id;
+
+        store.store_context(agent_id, context.clone()).await.unwrap();
+        let retrieved = store.retrieve_context(agent_id, context_id).await.unwrap();
+
+        assert_eq!(retrieved.unwrap().content.len(), 4096);
+        assert!(
+            store.compression_ratio() < 1.0,
+            "highly repetitive content over the threshold should compress"
+        );
+    }
+
+    #[tokio::test]
+    async fn persistence_enabled_writes_the_stored_bytes_to_disk() {
+        let dir = std::env::temp_dir().join(format!("context-store-test-{}", Uuid::new_v4()));
+        let config = MemoryConfig {
+            compression_threshold: 64,
+            persistence_enabled: true,
+            persistence_path: dir.clone(),
+            ..MemoryConfig::default()
+        };
+        let store = InMemoryContextStore::new(config);
+        let context = context_with_content("y".repeat(4096));
+        let agent_id = context.agent_id;
+        let context_id = context.id;
+
+        store.store_context(agent_id, context.clone()).await.unwrap();
+
+        let path = dir.join(agent_id.to_string()).join(format!("{context_id}.bin"));
+        let on_disk = std::fs::read(&path).expect("persisted file should exist");
+        let in_memory = {
+            let inner = store.inner.read().await;
+            inner.contexts[&agent_id][&context_id].bytes.clone()
+        };
+        assert_eq!(on_disk, in_memory, "disk contents must match the (compressed) in-memory bytes");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn promoting_the_oldest_entry_saves_it_from_eviction() {
+        let manager = MemoryManager::new(MemoryConfig { cache_size: 3, ..MemoryConfig::default() });
+        let oldest = context_with_content("oldest".to_string());
+        let second_oldest = context_with_content("second-oldest".to_string());
+        let newest = context_with_content("newest".to_string());
+
+        manager.insert(oldest.id, oldest.clone());
+        manager.insert(second_oldest.id, second_oldest.clone());
+        manager.insert(newest.id, newest.clone());
+
+        // Reading the oldest entry promotes it to most-recently-used, so
+        // the second-oldest becomes the actual least-recently-used entry.
+        assert!(manager.get(&oldest.id).is_some());
+
+        let one_more = context_with_content("one more".to_string());
+        manager.insert(one_more.id, one_more.clone());
+
+        assert!(manager.get(&second_oldest.id).is_none(), "the second-oldest entry should have been evicted");
+        assert!(manager.get(&oldest.id).is_some(), "the promoted entry should have survived");
+        assert!(manager.get(&newest.id).is_some());
+        assert!(manager.get(&one_more.id).is_some());
+        assert_eq!(manager.eviction_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn the_expiry_task_reclaims_everything_past_its_ttl() {
+        let config = MemoryConfig {
+            persistence_enabled: false,
+            ttl: Duration::from_millis(100),
+            ..MemoryConfig::default()
+        };
+        let store = Arc::new(InMemoryContextStore::new(config));
+        let agent_id = Uuid::new_v4();
+
+        for _ in 0..1000 {
+            let context = AgentContext {
+                agent_id,
+                ..context_with_content("expires soon".to_string())
+            };
+            store.store_context(agent_id, context).await.unwrap();
+        }
+        assert_eq!(store.list_contexts(agent_id).await.unwrap().len(), 1000);
+
+        let _handle = Arc::clone(&store).start_expiry_task();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert!(
+            store.list_contexts(agent_id).await.unwrap().is_empty(),
+            "every entry should have been swept once past its TTL"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn compression_round_trips_for_arbitrary_byte_sequences(bytes in proptest::collection::vec(any::<u8>(), 0..=1024 * 1024)) {
+            let compressed = lz4_flex::compress_prepend_size(&bytes);
+            let decompressed = lz4_flex::decompress_size_prepended(&compressed).unwrap();
+            prop_assert_eq!(decompressed, bytes);
         }
     }
 }
\ No newline at end of file
@@ -3,6 +3,7 @@
 //! This module provides monitoring capabilities for orchestration systems
 //! including metrics collection and alert management.
 
+use crate::anomaly::{AnomalyAlert, AnomalyDetectionConfig, AnomalyDetector};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
@@ -14,6 +15,7 @@ pub struct OrchestrationMonitor {
     pub config: MonitoringConfig,
     pub metrics_collector: MetricsCollector,
     pub alert_manager: AlertManager,
+    pub anomaly_detector: AnomalyDetector,
 }
 
 /// Configuration for monitoring
@@ -89,8 +91,32 @@ impl OrchestrationMonitor {
             config,
             metrics_collector: MetricsCollector::new(),
             alert_manager: AlertManager::new(),
+            anomaly_detector: AnomalyDetector::new(AnomalyDetectionConfig::default()),
         }
     }
+
+    /// Feeds an agent's `PerformanceSnapshot` into the anomaly detector.
+    /// Returns the raised alert once the detector has a trained baseline
+    /// and the snapshot scores above `AnomalyDetectionConfig::alert_threshold`;
+    /// `None` while still accumulating the baseline or for an in-distribution
+    /// snapshot.
+    pub fn observe_performance_snapshot(&mut self, snapshot: &crate::agent::PerformanceSnapshot) -> Option<AnomalyAlert> {
+        self.anomaly_detector.observe(performance_snapshot_metrics(snapshot))
+    }
+}
+
+/// Flattens a `PerformanceSnapshot` into the metric vector the anomaly
+/// detector trains and scores on.
+pub fn performance_snapshot_metrics(snapshot: &crate::agent::PerformanceSnapshot) -> Vec<f64> {
+    vec![
+        snapshot.tasks_completed as f64,
+        snapshot.tasks_failed as f64,
+        snapshot.success_rate,
+        snapshot.average_execution_time_ms as f64,
+        snapshot.average_quality_score,
+        snapshot.resource_efficiency,
+        snapshot.learning_progress,
+    ]
 }
 
 impl MetricsCollector {
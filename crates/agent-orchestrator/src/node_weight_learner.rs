@@ -0,0 +1,218 @@
+//! Learns per-node duration estimates from historical workflow executions.
+//!
+//! `WorkflowNode::timeout_seconds` is a hand-set guess. Feeding each node's
+//! actual execution time into [`NodeWeightLearner::update`] builds an
+//! exponential moving average that [`crate::workflow::WorkflowGraph::apply_learned_weights`]
+//! can substitute in once there's enough history to trust it, so
+//! [`crate::workflow::WorkflowGraph::critical_path`] reflects real behavior rather than the
+//! initial estimate.
+//!
+//! This crate keeps runtime state in-memory behind `Arc<RwLock<..>>` rather
+//! than a database (see `WorkflowOrchestrator::workflows`), so
+//! `NodeWeightLearner` follows the same pattern instead of taking on a SQL
+//! dependency: `NodeWeightStats` derives `Serialize`/`Deserialize`, so a
+//! caller who needs the learned weights to survive a restart can persist
+//! [`NodeWeightLearner::snapshot`] into whatever store they already use.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+
+/// Smoothing factor for the exponential moving average in [`NodeWeightLearner::update`].
+const EMA_ALPHA: f64 = 0.1;
+
+/// Minimum number of samples before a node's learned duration is trusted
+/// over the workflow's static estimate, used by
+/// [`crate::workflow::WorkflowGraph::apply_learned_weights`].
+pub const MIN_SAMPLES_FOR_TRUST: u64 = 5;
+
+/// Exponential moving average of a node's execution duration, plus how many
+/// samples went into it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct NodeWeightStats {
+    pub ema_duration_ms: f64,
+    pub sample_count: u64,
+}
+
+/// Maintains a per-node [`NodeWeightStats`] map built up from historical
+/// workflow executions.
+#[derive(Clone, Default)]
+pub struct NodeWeightLearner {
+    stats: Arc<RwLock<HashMap<String, NodeWeightStats>>>,
+}
+
+impl NodeWeightLearner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restores a learner from a previously persisted snapshot.
+    pub fn from_snapshot(stats: HashMap<String, NodeWeightStats>) -> Self {
+        Self {
+            stats: Arc::new(RwLock::new(stats)),
+        }
+    }
+
+    /// Returns a serializable snapshot of the current per-node stats, for
+    /// callers to persist however they store other durable state.
+    pub async fn snapshot(&self) -> HashMap<String, NodeWeightStats> {
+        self.stats.read().await.clone()
+    }
+
+    /// Folds one more observed execution duration into `node_id`'s EMA.
+    pub async fn update(&self, node_id: &str, actual_duration_ms: f64) {
+        let mut stats = self.stats.write().await;
+        let entry = stats
+            .entry(node_id.to_string())
+            .or_insert(NodeWeightStats {
+                ema_duration_ms: actual_duration_ms,
+                sample_count: 0,
+            });
+
+        if entry.sample_count == 0 {
+            entry.ema_duration_ms = actual_duration_ms;
+        } else {
+            entry.ema_duration_ms =
+                EMA_ALPHA * actual_duration_ms + (1.0 - EMA_ALPHA) * entry.ema_duration_ms;
+        }
+        entry.sample_count += 1;
+    }
+
+    /// Learned stats for `node_id`, if any executions have been recorded.
+    pub async fn stats_for(&self, node_id: &str) -> Option<NodeWeightStats> {
+        self.stats.read().await.get(node_id).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workflow::{WorkflowEdge, WorkflowGraph, WorkflowNode};
+    use std::collections::HashMap as StdHashMap;
+
+    fn make_node(id: &str) -> WorkflowNode {
+        WorkflowNode {
+            id: id.to_string(),
+            name: id.to_string(),
+            node_type: crate::workflow::WorkflowNodeType::Task {
+                task_definition: String::new(),
+                parallel_execution: false,
+            },
+            agent_requirements: crate::workflow::AgentRequirements {
+                agent_type: None,
+                capabilities: Vec::new(),
+                min_performance_score: 0.0,
+                preferred_agents: Vec::new(),
+                exclusion_list: Vec::new(),
+                resource_requirements: crate::workflow::ResourceRequirements {
+                    cpu_cores: 0.0,
+                    memory_mb: 0,
+                    network_bandwidth_mbps: 0,
+                    storage_mb: 0,
+                    gpu_units: None,
+                },
+            },
+            input_mapping: StdHashMap::new(),
+            output_mapping: StdHashMap::new(),
+            timeout_seconds: None,
+            retry_policy: crate::workflow::RetryPolicy::default(),
+            conditions: Vec::new(),
+            learned_duration_ms: None,
+        }
+    }
+
+    /// A 10-node chain (`n0 -> n1 -> ... -> n9`) with known ground-truth
+    /// durations, so the true critical path is always the whole chain in
+    /// node order.
+    fn chain_workflow() -> (WorkflowGraph, Vec<(String, f64)>) {
+        let node_ids: Vec<String> = (0..10).map(|i| format!("n{i}")).collect();
+        let durations: Vec<(String, f64)> = node_ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id.clone(), 100.0 + i as f64 * 25.0))
+            .collect();
+
+        let mut nodes = StdHashMap::new();
+        for id in &node_ids {
+            nodes.insert(id.clone(), make_node(id));
+        }
+        let edges = node_ids
+            .windows(2)
+            .map(|pair| WorkflowEdge {
+                from_node: pair[0].clone(),
+                to_node: pair[1].clone(),
+                condition: None,
+                data_mapping: StdHashMap::new(),
+                priority: 0,
+            })
+            .collect();
+
+        let graph = WorkflowGraph {
+            id: uuid::Uuid::new_v4(),
+            name: "chain".to_string(),
+            description: String::new(),
+            version: crate::workflow::CURRENT_WORKFLOW_GRAPH_SCHEMA_VERSION.to_string(),
+            nodes,
+            edges,
+            input_schema: serde_json::json!({}),
+            output_schema: serde_json::json!({}),
+            constraints: Vec::new(),
+            metadata: StdHashMap::new(),
+        };
+        (graph, durations)
+    }
+
+    /// Deterministic pseudo-noise in `[-spread, spread]`, avoiding a `rand`
+    /// dependency in the test itself while still perturbing each simulated
+    /// execution so the EMA has to converge rather than just echoing back
+    /// the first sample.
+    fn pseudo_noise(seed: u64, spread: f64) -> f64 {
+        let x = seed.wrapping_mul(6364136223846793005).wrapping_add(1) >> 33;
+        (x as f64 / u32::MAX as f64 - 0.5) * 2.0 * spread
+    }
+
+    #[tokio::test]
+    async fn learned_weights_predict_the_true_critical_path_after_enough_executions() {
+        let (workflow, ground_truth) = chain_workflow();
+        let learner = NodeWeightLearner::new();
+        let true_path: Vec<String> = ground_truth.iter().map(|(id, _)| id.clone()).collect();
+
+        let mut correct_predictions = 0u32;
+        let total_runs = 50u32;
+
+        for run in 0..total_runs {
+            for (index, (node_id, base_duration)) in ground_truth.iter().enumerate() {
+                let noisy_duration =
+                    base_duration + pseudo_noise(run as u64 * 31 + index as u64, base_duration * 0.05);
+                learner.update(node_id, noisy_duration).await;
+            }
+
+            let mut workflow = workflow.clone();
+            workflow.apply_learned_weights(&learner).await;
+            if workflow.critical_path() == true_path {
+                correct_predictions += 1;
+            }
+        }
+
+        let accuracy = correct_predictions as f64 / total_runs as f64;
+        assert!(
+            accuracy >= 0.9,
+            "expected the learned critical path to match the true one in at least 90% of {total_runs} runs, got {correct_predictions} ({accuracy:.2})"
+        );
+    }
+
+    #[tokio::test]
+    async fn apply_learned_weights_ignores_nodes_below_the_sample_threshold() {
+        let (mut workflow, _) = chain_workflow();
+        let learner = NodeWeightLearner::new();
+
+        for _ in 0..(MIN_SAMPLES_FOR_TRUST - 1) {
+            learner.update("n0", 9_999.0).await;
+        }
+        workflow.apply_learned_weights(&learner).await;
+
+        assert!(workflow.nodes["n0"].learned_duration_ms.is_none());
+    }
+}
@@ -3,15 +3,64 @@
 //! This module provides performance optimization capabilities for agents
 //! including learning engines and adaptive strategies.
 
+use crate::memory::{AgentContext, ContextStore, ContextType};
+use crate::OrchestrationError;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
+/// Learning rate for `LearningEngine::update`'s gradient-bandit step.
+const LEARNING_ENGINE_STEP_SIZE: f64 = 0.1;
+
+/// All variants `LearningEngine` scores, in a fixed order so softmax output
+/// is deterministic regardless of `HashMap` iteration order.
+const OPTIMIZATION_STRATEGIES: [OptimizationStrategy; 4] = [
+    OptimizationStrategy::ResourceAllocation,
+    OptimizationStrategy::TaskPrioritization,
+    OptimizationStrategy::CacheOptimization,
+    OptimizationStrategy::LoadBalancing,
+];
+
+/// Fixed pseudo-agent id `LearningEngine::persist_weights` files its policy
+/// under in a `ContextStore`. The policy isn't scoped to any one real agent,
+/// but `ContextStore::store_context`/`retrieve_context` are both keyed by
+/// one, so this reserves a dedicated id for that purpose.
+pub const LEARNING_ENGINE_CONTEXT_ID: Uuid = Uuid::nil();
+
 /// Performance optimizer for agents and workflows
 #[derive(Debug)]
 pub struct PerformanceOptimizer {
     pub config: OptimizationConfig,
     pub learning_engine: LearningEngine,
+    /// Tunes `config`'s hyperparameters online from observed performance,
+    /// replacing the previously-static `learning_rate` et al.
+    pub hyperparam_optimizer: BayesianHyperparamOptimizer,
+    /// Which of `hyperparam_optimizer` or `evolutionary_optimizer` `optimize`
+    /// drives.
+    pub strategy: AdaptationStrategy,
+    /// Populated only when `strategy` is `AdaptationStrategy::Evolutionary`.
+    evolutionary_optimizer: Option<EvolutionaryHyperparamOptimizer>,
+}
+
+/// Which algorithm `PerformanceOptimizer::optimize` uses to arrive at the
+/// next `OptimizationConfig` to try. Not to be confused with
+/// `dynamic_workflow::AdaptationStrategy`, which governs how a *running
+/// workflow graph* is reshaped rather than how these hyperparameters are
+/// tuned -- same name, unrelated subsystem.
+#[derive(Debug, Clone)]
+pub enum AdaptationStrategy {
+    /// The existing GP / Expected-Improvement search over `hyperparam_optimizer`.
+    Bayesian,
+    /// Evolves a population of candidate configs via tournament selection,
+    /// single-point crossover, and Gaussian mutation.
+    Evolutionary { population_size: usize, mutation_rate: f64, crossover_rate: f64 },
+}
+
+impl Default for AdaptationStrategy {
+    fn default() -> Self {
+        AdaptationStrategy::Bayesian
+    }
 }
 
 /// Configuration for optimization
@@ -33,11 +82,32 @@ pub struct ResourceLimits {
     pub max_concurrent_tasks: usize,
 }
 
-/// Learning engine for continuous improvement
+/// Learning engine for continuous improvement.
+///
+/// Beyond tracking per-agent `PerformanceHistory`, this runs a gradient
+/// bandit policy (Sutton & Barto, *Reinforcement Learning*, section 2.8)
+/// over `OptimizationStrategy`: each `update` nudges a per-strategy
+/// preference toward strategies that beat the running average reward and
+/// away from the one used when it doesn't, and `recommended_strategy` reads
+/// off the softmax of those preferences.
 #[derive(Debug)]
 pub struct LearningEngine {
     pub agent_performance: HashMap<Uuid, PerformanceHistory>,
     pub optimization_strategies: Vec<OptimizationStrategy>,
+    preferences: HashMap<OptimizationStrategy, f64>,
+    average_reward: f64,
+    observation_count: u64,
+}
+
+/// Serializable snapshot of `LearningEngine`'s learned policy, round-tripped
+/// through a `ContextStore` by `LearningEngine::persist_weights` /
+/// `LearningEngine::restore_weights` so it survives a restart instead of
+/// starting back at a uniform policy every time.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LearningEngineWeights {
+    preferences: HashMap<OptimizationStrategy, f64>,
+    average_reward: f64,
+    observation_count: u64,
 }
 
 /// Performance history for learning
@@ -50,7 +120,7 @@ pub struct PerformanceHistory {
 }
 
 /// Optimization strategies
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum OptimizationStrategy {
     ResourceAllocation,
     TaskPrioritization,
@@ -58,13 +128,77 @@ pub enum OptimizationStrategy {
     LoadBalancing,
 }
 
+/// One trial's outcome, fed into `LearningEngine::update`: which
+/// `OptimizationStrategy` was used, and how well it did.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PerformanceObservation {
+    pub strategy: OptimizationStrategy,
+    pub performance_score: f64,
+}
+
 impl PerformanceOptimizer {
     pub fn new(config: OptimizationConfig) -> Self {
+        Self::with_strategy(config, AdaptationStrategy::default())
+    }
+
+    /// Builds a `PerformanceOptimizer` that adapts `config` via `strategy`
+    /// instead of the default Bayesian search.
+    pub fn with_strategy(config: OptimizationConfig, strategy: AdaptationStrategy) -> Self {
+        let evolutionary_optimizer = match &strategy {
+            AdaptationStrategy::Evolutionary { population_size, mutation_rate, crossover_rate } => Some(
+                EvolutionaryHyperparamOptimizer::new(config.clone(), *population_size, *mutation_rate, *crossover_rate),
+            ),
+            AdaptationStrategy::Bayesian => None,
+        };
         Self {
+            hyperparam_optimizer: BayesianHyperparamOptimizer::new(config.clone()),
             config,
             learning_engine: LearningEngine::new(),
+            strategy,
+            evolutionary_optimizer,
+        }
+    }
+
+    /// Records a trial's outcome from a `PerformanceSnapshot` and, once
+    /// enough trials have been observed, applies the hyperparameter values
+    /// the optimizer now expects to perform best.
+    pub fn observe_performance(&mut self, snapshot: &crate::agent::PerformanceSnapshot) {
+        let objective = performance_objective(snapshot);
+        self.hyperparam_optimizer.observe(&self.config, objective);
+        self.config = self.hyperparam_optimizer.suggest();
+    }
+
+    /// Runs one adaptation cycle under `self.strategy`. For `Bayesian` this
+    /// is exactly `observe_performance`. For `Evolutionary`, `snapshot`
+    /// scores whichever population member is currently live in `config`;
+    /// once every member of the generation has been scored this way, the
+    /// next generation is bred and its best individual becomes the live
+    /// `config`.
+    pub fn optimize(&mut self, snapshot: &crate::agent::PerformanceSnapshot) {
+        match &self.strategy {
+            AdaptationStrategy::Bayesian => self.observe_performance(snapshot),
+            AdaptationStrategy::Evolutionary { .. } => {
+                let objective = performance_objective(snapshot);
+                if let Some(evolutionary) = self.evolutionary_optimizer.as_mut() {
+                    self.config = evolutionary.record_fitness(objective);
+                }
+            }
         }
     }
+
+    /// The current generation's individuals and their most recently
+    /// recorded fitness. Empty unless `strategy` is `Evolutionary`.
+    pub fn get_population(&self) -> Vec<(OptimizationConfig, f64)> {
+        self.evolutionary_optimizer.as_ref().map(EvolutionaryHyperparamOptimizer::population).unwrap_or_default()
+    }
+}
+
+/// Combines a `PerformanceSnapshot` into the single scalar
+/// `BayesianHyperparamOptimizer` maximizes. `PerformanceSnapshot` has no
+/// single `performance_score` field, so this weights the fields that most
+/// directly reflect trial quality.
+pub fn performance_objective(snapshot: &crate::agent::PerformanceSnapshot) -> f64 {
+    snapshot.success_rate * 0.5 + snapshot.average_quality_score * 0.3 + snapshot.resource_efficiency * 0.2
 }
 
 impl LearningEngine {
@@ -75,10 +209,134 @@ impl LearningEngine {
                 OptimizationStrategy::ResourceAllocation,
                 OptimizationStrategy::TaskPrioritization,
             ],
+            preferences: HashMap::new(),
+            average_reward: 0.0,
+            observation_count: 0,
+        }
+    }
+
+    /// Gradient-bandit update: moves `observation.strategy`'s preference
+    /// toward `observation.performance_score` relative to the running
+    /// average reward (scaled by how surprising the outcome was under the
+    /// current policy), and every other strategy's preference the opposite
+    /// way so the softmax distribution still sums to one.
+    pub fn update(&mut self, observation: PerformanceObservation) -> Result<(), OrchestrationError> {
+        let reward = observation.performance_score;
+        let baseline = self.average_reward;
+        self.observation_count += 1;
+        self.average_reward += (reward - self.average_reward) / self.observation_count as f64;
+
+        let probabilities = self.softmax();
+        for strategy in OPTIMIZATION_STRATEGIES {
+            let probability = probabilities[&strategy];
+            let preference = self.preferences.entry(strategy).or_insert(0.0);
+            *preference += if strategy == observation.strategy {
+                LEARNING_ENGINE_STEP_SIZE * (reward - baseline) * (1.0 - probability)
+            } else {
+                -LEARNING_ENGINE_STEP_SIZE * (reward - baseline) * probability
+            };
+        }
+        Ok(())
+    }
+
+    /// Softmax of the current preferences over `OPTIMIZATION_STRATEGIES`,
+    /// shifted by the max preference first for numerical stability.
+    fn softmax(&self) -> HashMap<OptimizationStrategy, f64> {
+        let max_preference = OPTIMIZATION_STRATEGIES
+            .iter()
+            .map(|strategy| self.preferences.get(strategy).copied().unwrap_or(0.0))
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        let exp_preferences: HashMap<OptimizationStrategy, f64> = OPTIMIZATION_STRATEGIES
+            .iter()
+            .map(|&strategy| {
+                let preference = self.preferences.get(&strategy).copied().unwrap_or(0.0);
+                (strategy, (preference - max_preference).exp())
+            })
+            .collect();
+        let sum: f64 = exp_preferences.values().sum();
+
+        exp_preferences.into_iter().map(|(strategy, value)| (strategy, value / sum)).collect()
+    }
+
+    /// Current softmax selection probability for `strategy`.
+    pub fn selection_probability(&self, strategy: OptimizationStrategy) -> f64 {
+        self.softmax().get(&strategy).copied().unwrap_or(0.0)
+    }
+
+    /// The strategy the policy currently favors most, i.e. the argmax of
+    /// the softmax over `OPTIMIZATION_STRATEGIES`. Early on, before enough
+    /// observations have separated the strategies' preferences, this is
+    /// little better than a coin flip -- `selection_probability` is how a
+    /// caller checks how confident the recommendation actually is.
+    pub fn recommended_strategy(&self) -> OptimizationStrategy {
+        let probabilities = self.softmax();
+        OPTIMIZATION_STRATEGIES
+            .into_iter()
+            .max_by(|a, b| probabilities[a].partial_cmp(&probabilities[b]).unwrap_or(std::cmp::Ordering::Equal))
+            .expect("OPTIMIZATION_STRATEGIES is non-empty")
+    }
+
+    /// Serializable snapshot of the current policy, for `persist_weights`.
+    pub fn snapshot(&self) -> LearningEngineWeights {
+        LearningEngineWeights {
+            preferences: self.preferences.clone(),
+            average_reward: self.average_reward,
+            observation_count: self.observation_count,
+        }
+    }
+
+    /// Rebuilds a `LearningEngine` from a snapshot previously returned by
+    /// `snapshot`, restoring the learned policy (`agent_performance` isn't
+    /// part of the snapshot -- it starts fresh, same as a newly-created
+    /// engine).
+    pub fn from_snapshot(weights: LearningEngineWeights) -> Self {
+        Self {
+            preferences: weights.preferences,
+            average_reward: weights.average_reward,
+            observation_count: weights.observation_count,
+            ..Self::new()
+        }
+    }
+
+    /// Persists `snapshot()` into `store` under `LEARNING_ENGINE_CONTEXT_ID`
+    /// so a restarted process can pick the learned policy back up via
+    /// `restore_weights` instead of starting from a uniform one again.
+    pub async fn persist_weights(&self, store: &impl ContextStore) -> Result<(), OrchestrationError> {
+        let content = serde_json::to_string(&self.snapshot())
+            .map_err(|e| OrchestrationError::MemoryError(format!("failed to serialize learning engine weights: {e}")))?;
+
+        store.store_context(LEARNING_ENGINE_CONTEXT_ID, AgentContext {
+            id: LEARNING_ENGINE_CONTEXT_ID,
+            agent_id: LEARNING_ENGINE_CONTEXT_ID,
+            content,
+            context_type: ContextType::State,
+            created_at: chrono::Utc::now(),
+            metadata: HashMap::new(),
+        }).await
+    }
+
+    /// Restores a previously `persist_weights`-saved policy from `store`, or
+    /// a fresh uniform `LearningEngine` if nothing has been persisted yet.
+    pub async fn restore_weights(store: &impl ContextStore) -> Result<Self, OrchestrationError> {
+        match store.retrieve_context(LEARNING_ENGINE_CONTEXT_ID, LEARNING_ENGINE_CONTEXT_ID).await? {
+            Some(context) => {
+                let weights: LearningEngineWeights = serde_json::from_str(&context.content).map_err(|e| {
+                    OrchestrationError::MemoryError(format!("failed to deserialize learning engine weights: {e}"))
+                })?;
+                Ok(Self::from_snapshot(weights))
+            }
+            None => Ok(Self::new()),
         }
     }
 }
 
+impl Default for LearningEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Default for OptimizationConfig {
     fn default() -> Self {
         Self {
@@ -94,4 +352,658 @@ impl Default for OptimizationConfig {
             },
         }
     }
+}
+
+/// One evaluated hyperparameter trial in the optimizer's normalized
+/// `[learning_rate, adaptation_threshold, performance_window]` space.
+#[derive(Debug, Clone)]
+struct Observation {
+    point: [f64; 3],
+    objective: f64,
+}
+
+/// Minimal Gaussian process regression with a squared-exponential (RBF)
+/// kernel, implemented in pure Rust so `BayesianHyperparamOptimizer`
+/// doesn't need an external linear-algebra/ML dependency for what is only
+/// ever a handful of observations over three dimensions.
+#[derive(Debug, Clone)]
+pub struct GaussianProcess {
+    length_scale: f64,
+    signal_variance: f64,
+    noise_variance: f64,
+    observations: Vec<Observation>,
+    /// Inverse of `K + noise_variance * I` over `observations`, recomputed
+    /// whenever a new observation is added.
+    inverse_covariance: Vec<Vec<f64>>,
+}
+
+impl GaussianProcess {
+    pub fn new(length_scale: f64, signal_variance: f64, noise_variance: f64) -> Self {
+        Self { length_scale, signal_variance, noise_variance, observations: Vec::new(), inverse_covariance: Vec::new() }
+    }
+
+    fn kernel(&self, a: &[f64; 3], b: &[f64; 3]) -> f64 {
+        let squared_distance: f64 = a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum();
+        self.signal_variance * (-squared_distance / (2.0 * self.length_scale.powi(2))).exp()
+    }
+
+    pub fn observe(&mut self, point: [f64; 3], objective: f64) {
+        self.observations.push(Observation { point, objective });
+        self.recompute_inverse_covariance();
+    }
+
+    pub fn len(&self) -> usize {
+        self.observations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.observations.is_empty()
+    }
+
+    pub fn best_objective(&self) -> Option<f64> {
+        self.observations.iter().map(|o| o.objective).fold(None, |best, value| {
+            Some(best.map_or(value, |current: f64| current.max(value)))
+        })
+    }
+
+    fn recompute_inverse_covariance(&mut self) {
+        let n = self.observations.len();
+        let mut covariance = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            for j in 0..n {
+                covariance[i][j] = self.kernel(&self.observations[i].point, &self.observations[j].point);
+                if i == j {
+                    covariance[i][j] += self.noise_variance;
+                }
+            }
+        }
+        self.inverse_covariance = invert_matrix(&covariance);
+    }
+
+    /// Posterior mean and standard deviation of the objective at `point`.
+    pub fn predict(&self, point: &[f64; 3]) -> (f64, f64) {
+        if self.observations.is_empty() {
+            return (0.0, self.signal_variance.sqrt());
+        }
+
+        let k_star: Vec<f64> = self.observations.iter().map(|o| self.kernel(point, &o.point)).collect();
+        let targets: Vec<f64> = self.observations.iter().map(|o| o.objective).collect();
+
+        let alpha = matvec(&self.inverse_covariance, &targets);
+        let mean: f64 = k_star.iter().zip(&alpha).map(|(a, b)| a * b).sum();
+
+        let beta = matvec(&self.inverse_covariance, &k_star);
+        let explained_variance: f64 = k_star.iter().zip(&beta).map(|(a, b)| a * b).sum();
+        let variance = (self.kernel(point, point) - explained_variance).max(1e-12);
+
+        (mean, variance.sqrt())
+    }
+}
+
+fn matvec(matrix: &[Vec<f64>], vector: &[f64]) -> Vec<f64> {
+    matrix.iter().map(|row| row.iter().zip(vector).map(|(a, b)| a * b).sum()).collect()
+}
+
+/// Gauss-Jordan matrix inversion with partial pivoting, for the small
+/// (observation-count-sized) matrices `GaussianProcess` needs to invert.
+fn invert_matrix(matrix: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = matrix.len();
+    let mut augmented: Vec<Vec<f64>> = matrix
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut augmented_row = row.clone();
+            augmented_row.extend((0..n).map(|j| if i == j { 1.0 } else { 0.0 }));
+            augmented_row
+        })
+        .collect();
+
+    for col in 0..n {
+        let pivot_row = (col..n)
+            .max_by(|&a, &b| augmented[a][col].abs().partial_cmp(&augmented[b][col].abs()).unwrap())
+            .unwrap();
+        augmented.swap(col, pivot_row);
+
+        let pivot = augmented[col][col];
+        let pivot = if pivot.abs() < 1e-12 { 1e-12 } else { pivot };
+        for value in augmented[col].iter_mut() {
+            *value /= pivot;
+        }
+
+        for row in 0..n {
+            if row != col {
+                let factor = augmented[row][col];
+                for k in 0..(2 * n) {
+                    augmented[row][k] -= factor * augmented[col][k];
+                }
+            }
+        }
+    }
+
+    augmented.into_iter().map(|row| row[n..].to_vec()).collect()
+}
+
+/// Expected-improvement acquisition function over a Gaussian process
+/// posterior.
+#[derive(Debug, Clone, Copy)]
+pub struct ExpectedImprovement {
+    /// Minimum improvement over the current best worth exploring for
+    /// (the usual "xi" exploration/exploitation trade-off parameter).
+    pub exploration: f64,
+}
+
+impl ExpectedImprovement {
+    pub fn new(exploration: f64) -> Self {
+        Self { exploration }
+    }
+
+    pub fn evaluate(&self, mean: f64, std_dev: f64, best_observed: f64) -> f64 {
+        if std_dev <= 1e-12 {
+            return 0.0;
+        }
+        let improvement = mean - best_observed - self.exploration;
+        let z = improvement / std_dev;
+        improvement * standard_normal_cdf(z) + std_dev * standard_normal_pdf(z)
+    }
+}
+
+fn standard_normal_pdf(z: f64) -> f64 {
+    (-0.5 * z * z).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+fn standard_normal_cdf(z: f64) -> f64 {
+    0.5 * (1.0 + erf(z / std::f64::consts::SQRT_2))
+}
+
+/// Abramowitz-Stegun approximation of the error function (max error
+/// ~1.5e-7) so `standard_normal_cdf` doesn't need a statistics dependency.
+fn erf(x: f64) -> f64 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+    let a1 = 0.254829592;
+    let a2 = -0.284496736;
+    let a3 = 1.421413741;
+    let a4 = -1.453152027;
+    let a5 = 1.061405429;
+    let p = 0.3275911;
+    let t = 1.0 / (1.0 + p * x);
+    let y = 1.0 - ((((a5 * t + a4) * t + a3) * t + a2) * t + a1) * t * (-x * x).exp();
+    sign * y
+}
+
+/// The range each hyperparameter is searched within.
+#[derive(Debug, Clone)]
+pub struct HyperparamBounds {
+    pub learning_rate: (f64, f64),
+    pub adaptation_threshold: (f64, f64),
+    pub performance_window: (f64, f64),
+}
+
+impl Default for HyperparamBounds {
+    fn default() -> Self {
+        Self { learning_rate: (0.0001, 1.0), adaptation_threshold: (0.01, 1.0), performance_window: (10.0, 500.0) }
+    }
+}
+
+/// Online Bayesian optimizer for `OptimizationConfig`'s previously-static
+/// `learning_rate`, `adaptation_threshold`, and `performance_window`.
+/// Treats them as a point in a normalized 3-dimensional space, models the
+/// objective (see `performance_objective`) with a `GaussianProcess`
+/// surrogate, and proposes the next trial via `ExpectedImprovement`.
+#[derive(Debug, Clone)]
+pub struct BayesianHyperparamOptimizer {
+    surrogate: GaussianProcess,
+    acquisition: ExpectedImprovement,
+    bounds: HyperparamBounds,
+    base_config: OptimizationConfig,
+    candidate_pool_size: usize,
+}
+
+impl BayesianHyperparamOptimizer {
+    pub fn new(base_config: OptimizationConfig) -> Self {
+        Self {
+            surrogate: GaussianProcess::new(1.0, 1.0, 1e-6),
+            acquisition: ExpectedImprovement::new(0.01),
+            bounds: HyperparamBounds::default(),
+            base_config,
+            candidate_pool_size: 200,
+        }
+    }
+
+    pub fn observations_len(&self) -> usize {
+        self.surrogate.len()
+    }
+
+    /// Records the objective observed for a trial `config`.
+    pub fn observe(&mut self, config: &OptimizationConfig, objective: f64) {
+        self.surrogate.observe(Self::to_point(config, &self.bounds), objective);
+    }
+
+    /// Returns the next trial config to evaluate. Before 10 observations
+    /// there isn't enough data for the surrogate to be meaningful, so this
+    /// deterministically perturbs `base_config` to keep exploring instead
+    /// of always proposing the same untried point.
+    pub fn suggest(&self) -> OptimizationConfig {
+        if self.surrogate.len() < 10 {
+            return self.exploratory_config();
+        }
+
+        let best_observed = self.surrogate.best_objective().unwrap_or(f64::MIN);
+        let mut best_point = [0.5, 0.5, 0.5];
+        let mut best_score = f64::MIN;
+
+        for candidate in Self::candidate_grid(self.candidate_pool_size) {
+            let (mean, std_dev) = self.surrogate.predict(&candidate);
+            let score = self.acquisition.evaluate(mean, std_dev, best_observed);
+            if score > best_score {
+                best_score = score;
+                best_point = candidate;
+            }
+        }
+
+        Self::from_point(&best_point, &self.bounds, &self.base_config)
+    }
+
+    fn to_point(config: &OptimizationConfig, bounds: &HyperparamBounds) -> [f64; 3] {
+        [
+            normalize(config.learning_rate, bounds.learning_rate),
+            normalize(config.adaptation_threshold, bounds.adaptation_threshold),
+            normalize(config.performance_window as f64, bounds.performance_window),
+        ]
+    }
+
+    fn from_point(point: &[f64; 3], bounds: &HyperparamBounds, base: &OptimizationConfig) -> OptimizationConfig {
+        OptimizationConfig {
+            learning_rate: denormalize(point[0], bounds.learning_rate),
+            adaptation_threshold: denormalize(point[1], bounds.adaptation_threshold),
+            performance_window: denormalize(point[2], bounds.performance_window).round() as usize,
+            ..base.clone()
+        }
+    }
+
+    fn exploratory_config(&self) -> OptimizationConfig {
+        let observed = self.surrogate.len() as f64;
+        let jitter = |seed: f64| ((seed * 12.9898).sin() * 43758.5453).fract().abs();
+        let point = [jitter(observed + 1.0), jitter(observed + 2.0), jitter(observed + 3.0)];
+        Self::from_point(&point, &self.bounds, &self.base_config)
+    }
+
+    /// A deterministic, evenly space-filling set of candidate points in the
+    /// normalized `[0, 1]^3` hyperparameter cube: a regular grid rather
+    /// than random sampling, since this is a small in-process search over
+    /// only three dimensions and doesn't need (or want, for test
+    /// determinism) a true RNG dependency.
+    fn candidate_grid(pool_size: usize) -> Vec<[f64; 3]> {
+        let resolution = (pool_size as f64).cbrt().round().max(2.0) as usize;
+        let step = 1.0 / (resolution - 1) as f64;
+
+        let mut candidates = Vec::with_capacity(resolution.pow(3));
+        for xi in 0..resolution {
+            for yi in 0..resolution {
+                for zi in 0..resolution {
+                    candidates.push([xi as f64 * step, yi as f64 * step, zi as f64 * step]);
+                }
+            }
+        }
+        candidates
+    }
+}
+
+fn normalize(value: f64, bounds: (f64, f64)) -> f64 {
+    ((value - bounds.0) / (bounds.1 - bounds.0)).clamp(0.0, 1.0)
+}
+
+fn denormalize(value: f64, bounds: (f64, f64)) -> f64 {
+    bounds.0 + value.clamp(0.0, 1.0) * (bounds.1 - bounds.0)
+}
+
+/// Population-based alternative to `BayesianHyperparamOptimizer` for tuning
+/// `OptimizationConfig`. Rather than one global GP surrogate, this evolves a
+/// population of candidate configs generation by generation via tournament
+/// selection, single-point crossover, and Gaussian mutation, over the same
+/// normalized `[learning_rate, adaptation_threshold, performance_window]`
+/// point space `BayesianHyperparamOptimizer` uses.
+#[derive(Debug, Clone)]
+pub struct EvolutionaryHyperparamOptimizer {
+    population: Vec<[f64; 3]>,
+    fitness: Vec<f64>,
+    bounds: HyperparamBounds,
+    base_config: OptimizationConfig,
+    mutation_rate: f64,
+    crossover_rate: f64,
+    /// Index of the population member `current_individual`/`record_fitness`
+    /// refer to next, for the incremental one-trial-at-a-time integration.
+    next_to_evaluate: usize,
+    best: Option<(OptimizationConfig, f64)>,
+}
+
+impl EvolutionaryHyperparamOptimizer {
+    pub fn new(base_config: OptimizationConfig, population_size: usize, mutation_rate: f64, crossover_rate: f64) -> Self {
+        let population_size = population_size.max(2);
+        let mut rng = rand::thread_rng();
+        let population: Vec<[f64; 3]> =
+            (0..population_size).map(|_| [rng.gen::<f64>(), rng.gen::<f64>(), rng.gen::<f64>()]).collect();
+        let fitness = vec![f64::MIN; population_size];
+        Self {
+            population,
+            fitness,
+            bounds: HyperparamBounds::default(),
+            base_config,
+            mutation_rate,
+            crossover_rate,
+            next_to_evaluate: 0,
+            best: None,
+        }
+    }
+
+    /// The current generation's individuals paired with their most recently
+    /// recorded fitness (`f64::MIN` for a member not yet evaluated this
+    /// generation).
+    pub fn population(&self) -> Vec<(OptimizationConfig, f64)> {
+        self.population
+            .iter()
+            .zip(&self.fitness)
+            .map(|(point, &fitness)| (BayesianHyperparamOptimizer::from_point(point, &self.bounds, &self.base_config), fitness))
+            .collect()
+    }
+
+    /// The best individual observed across every generation so far, and the
+    /// fitness it was scored at.
+    pub fn best(&self) -> Option<(OptimizationConfig, f64)> {
+        self.best.clone()
+    }
+
+    fn current_individual(&self) -> OptimizationConfig {
+        BayesianHyperparamOptimizer::from_point(&self.population[self.next_to_evaluate], &self.bounds, &self.base_config)
+    }
+
+    /// Records `fitness` for the individual `current_individual` returns,
+    /// then advances round-robin through the population. Once every member
+    /// of the generation has been scored this way, breeds the next
+    /// generation. Returns the best individual found so far.
+    pub fn record_fitness(&mut self, fitness: f64) -> OptimizationConfig {
+        self.fitness[self.next_to_evaluate] = fitness;
+        self.track_best(self.current_individual(), fitness);
+
+        self.next_to_evaluate += 1;
+        if self.next_to_evaluate == self.population.len() {
+            self.breed_next_generation();
+            self.next_to_evaluate = 0;
+        }
+
+        self.best.clone().map(|(config, _)| config).unwrap_or_else(|| self.base_config.clone())
+    }
+
+    /// Evaluates every individual in the current population against
+    /// `fitness_fn` and breeds the next generation in one call -- the batch
+    /// form used when a full generation's fitness is available at once, as
+    /// opposed to `record_fitness`'s one-trial-at-a-time form. Returns the
+    /// best individual found so far.
+    pub fn evolve(&mut self, fitness_fn: impl Fn(&OptimizationConfig) -> f64) -> OptimizationConfig {
+        for index in 0..self.population.len() {
+            let config = BayesianHyperparamOptimizer::from_point(&self.population[index], &self.bounds, &self.base_config);
+            let fitness = fitness_fn(&config);
+            self.fitness[index] = fitness;
+            self.track_best(config, fitness);
+        }
+        self.breed_next_generation();
+        self.best.clone().map(|(config, _)| config).unwrap_or_else(|| self.base_config.clone())
+    }
+
+    fn track_best(&mut self, config: OptimizationConfig, fitness: f64) {
+        if self.best.as_ref().is_none_or(|(_, best_fitness)| fitness > *best_fitness) {
+            self.best = Some((config, fitness));
+        }
+    }
+
+    fn fittest_index(&self) -> usize {
+        (0..self.population.len())
+            .max_by(|&a, &b| self.fitness[a].partial_cmp(&self.fitness[b]).unwrap_or(std::cmp::Ordering::Equal))
+            .unwrap_or(0)
+    }
+
+    /// Breeds the next generation: the current fittest individual survives
+    /// unmutated (elitism, so the tracked best-so-far never regresses even
+    /// if a generation's crossover/mutation gets unlucky), and the rest are
+    /// filled by tournament-selected parents combined via single-point
+    /// crossover and Gaussian mutation.
+    fn breed_next_generation(&mut self) {
+        let mut rng = rand::thread_rng();
+        let mut next_generation = Vec::with_capacity(self.population.len());
+        next_generation.push(self.population[self.fittest_index()]);
+
+        while next_generation.len() < self.population.len() {
+            let parent_a = self.tournament_select(&mut rng);
+            let parent_b = self.tournament_select(&mut rng);
+            let mut child = if rng.gen::<f64>() < self.crossover_rate {
+                Self::single_point_crossover(&parent_a, &parent_b, &mut rng)
+            } else {
+                parent_a
+            };
+            Self::mutate(&mut child, self.mutation_rate, &mut rng);
+            next_generation.push(child);
+        }
+
+        self.population = next_generation;
+        self.fitness = vec![f64::MIN; self.population.len()];
+    }
+
+    /// Tournament selection with a tournament size of two: draws two
+    /// individuals at random and keeps the fitter of the pair.
+    fn tournament_select(&self, rng: &mut impl Rng) -> [f64; 3] {
+        let a = rng.gen_range(0..self.population.len());
+        let b = rng.gen_range(0..self.population.len());
+        if self.fitness[a] >= self.fitness[b] { self.population[a] } else { self.population[b] }
+    }
+
+    fn single_point_crossover(a: &[f64; 3], b: &[f64; 3], rng: &mut impl Rng) -> [f64; 3] {
+        let point = rng.gen_range(1..3);
+        let mut child = *a;
+        child[point..].copy_from_slice(&b[point..]);
+        child
+    }
+
+    /// Independently perturbs each gene by standard-normal noise (scaled
+    /// down since genes live in the normalized `[0, 1]` cube) with
+    /// probability `mutation_rate`.
+    fn mutate(point: &mut [f64; 3], mutation_rate: f64, rng: &mut impl Rng) {
+        for gene in point.iter_mut() {
+            if rng.gen::<f64>() < mutation_rate {
+                *gene = (*gene + sample_standard_normal(rng) * 0.1).clamp(0.0, 1.0);
+            }
+        }
+    }
+}
+
+/// Samples from a standard normal distribution via the Box-Muller
+/// transform, so `EvolutionaryHyperparamOptimizer` doesn't need a
+/// distributions dependency beyond the `rand` uniform sampling it already
+/// pulls in.
+fn sample_standard_normal(rng: &mut impl Rng) -> f64 {
+    let u1: f64 = rng.gen::<f64>().max(1e-12);
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+#[cfg(test)]
+mod bayesian_optimizer_tests {
+    use super::*;
+
+    /// A synthetic bowl-shaped objective maximized at a known point, used
+    /// to check the optimizer actually converges rather than wandering.
+    fn bowl_objective(config: &OptimizationConfig, bounds: &HyperparamBounds, optimum: [f64; 3]) -> f64 {
+        let point = BayesianHyperparamOptimizer::to_point(config, bounds);
+        let squared_distance: f64 = point.iter().zip(optimum).map(|(p, o)| (p - o).powi(2)).sum();
+        -squared_distance
+    }
+
+    fn distance_to_optimum(config: &OptimizationConfig, bounds: &HyperparamBounds, optimum: [f64; 3]) -> f64 {
+        let point = BayesianHyperparamOptimizer::to_point(config, bounds);
+        point.iter().zip(optimum).map(|(p, o)| (p - o).powi(2)).sum::<f64>().sqrt()
+    }
+
+    #[test]
+    fn suggestions_converge_toward_the_known_optimum() {
+        let bounds = HyperparamBounds::default();
+        let optimum = [0.7, 0.2, 0.6];
+        let mut optimizer = BayesianHyperparamOptimizer::new(OptimizationConfig::default());
+
+        let mut first_exploration_distance = None;
+        let mut config = optimizer.suggest();
+        for _ in 0..25 {
+            let objective = bowl_objective(&config, &bounds, optimum);
+            if first_exploration_distance.is_none() {
+                first_exploration_distance = Some(distance_to_optimum(&config, &bounds, optimum));
+            }
+            optimizer.observe(&config, objective);
+            config = optimizer.suggest();
+        }
+
+        let final_distance = distance_to_optimum(&config, &bounds, optimum);
+        assert!(
+            final_distance < first_exploration_distance.unwrap(),
+            "expected optimizer to converge toward the optimum: first={:?}, final={final_distance}",
+            first_exploration_distance
+        );
+    }
+
+    #[test]
+    fn gaussian_process_predicts_near_the_observed_value_at_an_observed_point() {
+        let mut gp = GaussianProcess::new(1.0, 1.0, 1e-6);
+        gp.observe([0.5, 0.5, 0.5], 2.0);
+
+        let (mean, std_dev) = gp.predict(&[0.5, 0.5, 0.5]);
+
+        assert!((mean - 2.0).abs() < 1e-3, "expected mean close to the observed value, got {mean}");
+        assert!(std_dev < 1e-2, "expected near-zero uncertainty at an observed point, got {std_dev}");
+    }
+}
+
+#[cfg(test)]
+mod evolutionary_optimizer_tests {
+    use super::*;
+
+    /// Same synthetic bowl-shaped objective as `bayesian_optimizer_tests`,
+    /// maximized at a known point in the normalized hyperparameter cube.
+    fn bowl_objective(config: &OptimizationConfig, bounds: &HyperparamBounds, optimum: [f64; 3]) -> f64 {
+        let point = BayesianHyperparamOptimizer::to_point(config, bounds);
+        let squared_distance: f64 = point.iter().zip(optimum).map(|(p, o)| (p - o).powi(2)).sum();
+        -squared_distance
+    }
+
+    #[test]
+    fn the_best_individual_improves_monotonically_and_converges_toward_the_known_optimum() {
+        let bounds = HyperparamBounds::default();
+        let optimum = [0.7, 0.2, 0.6];
+        let mut optimizer = EvolutionaryHyperparamOptimizer::new(OptimizationConfig::default(), 20, 0.2, 0.7);
+
+        let mut best_fitness_so_far = f64::MIN;
+        for generation in 0..50 {
+            optimizer.evolve(|config| bowl_objective(config, &bounds, optimum));
+            let (_, fitness) = optimizer.best().expect("evolve always scores at least one individual");
+            assert!(
+                fitness >= best_fitness_so_far,
+                "generation {generation}: best-so-far fitness regressed from {best_fitness_so_far} to {fitness}"
+            );
+            best_fitness_so_far = fitness;
+        }
+
+        assert!(
+            best_fitness_so_far > -0.1,
+            "expected 50 generations to converge close to the optimum, got fitness {best_fitness_so_far}"
+        );
+    }
+
+    #[test]
+    fn record_fitness_breeds_a_new_generation_once_the_whole_population_has_been_scored() {
+        let mut optimizer = EvolutionaryHyperparamOptimizer::new(OptimizationConfig::default(), 4, 0.1, 0.5);
+
+        for score in 0..4 {
+            optimizer.record_fitness(score as f64);
+        }
+
+        // A fresh generation has no fitness recorded against it yet.
+        assert!(
+            optimizer.population().iter().all(|(_, fitness)| *fitness == f64::MIN),
+            "scoring every member of the population should have bred a new, unscored generation"
+        );
+        assert_eq!(optimizer.best().expect("record_fitness always tracks a best individual").1, 3.0);
+    }
+
+    #[test]
+    fn population_reflects_the_configured_population_size() {
+        let optimizer = EvolutionaryHyperparamOptimizer::new(OptimizationConfig::default(), 15, 0.1, 0.5);
+        assert_eq!(optimizer.population().len(), 15);
+    }
+}
+
+#[cfg(test)]
+mod learning_engine_tests {
+    use super::*;
+    use crate::memory::{InMemoryContextStore, MemoryConfig};
+    use rand::Rng;
+
+    /// Draws a strategy from `engine`'s current softmax distribution over
+    /// `OPTIMIZATION_STRATEGIES`, so the simulated trials below are on-policy
+    /// (as a real caller acting on `recommended_strategy`/sampling its own
+    /// policy would be) rather than every strategy being observed equally
+    /// regardless of what the policy has already learned.
+    fn sample_strategy(engine: &LearningEngine, rng: &mut impl Rng) -> OptimizationStrategy {
+        let roll: f64 = rng.gen();
+        let mut cumulative = 0.0;
+        for strategy in OPTIMIZATION_STRATEGIES {
+            cumulative += engine.selection_probability(strategy);
+            if roll <= cumulative {
+                return strategy;
+            }
+        }
+        OPTIMIZATION_STRATEGIES[OPTIMIZATION_STRATEGIES.len() - 1]
+    }
+
+    #[test]
+    fn a_consistently_better_strategy_reaches_over_80_percent_selection_probability() {
+        let mut engine = LearningEngine::new();
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..500 {
+            let strategy = sample_strategy(&engine, &mut rng);
+            let performance_score = if strategy == OptimizationStrategy::CacheOptimization { 1.0 } else { 0.1 };
+            engine.update(PerformanceObservation { strategy, performance_score }).unwrap();
+        }
+
+        let probability = engine.selection_probability(OptimizationStrategy::CacheOptimization);
+        assert!(probability > 0.8, "expected the consistently-better strategy to dominate the policy, got {probability}");
+        assert_eq!(engine.recommended_strategy(), OptimizationStrategy::CacheOptimization);
+    }
+
+    #[tokio::test]
+    async fn persisted_weights_survive_a_round_trip_through_the_context_store() {
+        let store = InMemoryContextStore::new(MemoryConfig::default());
+        let mut engine = LearningEngine::new();
+        for _ in 0..20 {
+            engine.update(PerformanceObservation {
+                strategy: OptimizationStrategy::LoadBalancing,
+                performance_score: 1.0,
+            }).unwrap();
+        }
+
+        engine.persist_weights(&store).await.unwrap();
+        let restored = LearningEngine::restore_weights(&store).await.unwrap();
+
+        assert_eq!(
+            restored.selection_probability(OptimizationStrategy::LoadBalancing),
+            engine.selection_probability(OptimizationStrategy::LoadBalancing),
+        );
+    }
+
+    #[tokio::test]
+    async fn restoring_from_an_empty_store_returns_a_fresh_uniform_policy() {
+        let store = InMemoryContextStore::new(MemoryConfig::default());
+
+        let engine = LearningEngine::restore_weights(&store).await.unwrap();
+
+        for strategy in OPTIMIZATION_STRATEGIES {
+            assert!((engine.selection_probability(strategy) - 0.25).abs() < 1e-9);
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,345 @@
+//! Dependency validation and execution for development pipelines built from
+//! named steps.
+//!
+//! A [`PipelineGraph`] models `PipelineStep::dependencies` as a directed
+//! graph and validates it's a DAG before anything tries to execute it —
+//! catching accidental cycles that would otherwise deadlock a pipeline
+//! runner waiting for each step's dependencies to finish. [`PipelineGraph::execute`]
+//! then runs the validated steps in topological order, using
+//! `PipelineStep::parallel_group` to run same-named groups concurrently as a
+//! barrier: the next step in the order only starts once every step in the
+//! group ahead of it has completed.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use serde::Serialize;
+use tokio::task::JoinSet;
+
+/// One step in a development pipeline, naming the other steps it depends on.
+/// Steps that share the same `parallel_group` and are adjacent in the
+/// pipeline's topological order are executed concurrently by
+/// [`PipelineGraph::execute`].
+#[derive(Debug, Clone)]
+pub struct PipelineStep {
+    pub id: String,
+    pub dependencies: Vec<String>,
+    pub parallel_group: Option<String>,
+}
+
+/// A cycle found while validating a [`PipelineGraph`], with the sequence of
+/// step ids that make up the cycle (starting and ending at the same step).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    pub cycle_path: Vec<String>,
+}
+
+/// Colors used by the DFS cycle check: white = unvisited, gray = on the
+/// current DFS path, black = fully explored with no cycle found through it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    White,
+    Gray,
+    Black,
+}
+
+/// Dependency graph over a set of [`PipelineStep`]s.
+#[derive(Debug, Clone, Default)]
+pub struct PipelineGraph {
+    steps: HashMap<String, Vec<String>>,
+    parallel_groups: HashMap<String, Option<String>>,
+}
+
+impl PipelineGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_step(&mut self, step: PipelineStep) {
+        self.parallel_groups.insert(step.id.clone(), step.parallel_group);
+        self.steps.insert(step.id, step.dependencies);
+    }
+
+    /// Validates that the dependency graph has no cycles, using DFS with
+    /// white/gray/black coloring. Returns the steps in a valid topological
+    /// order on success, or every cycle found on failure.
+    pub fn validate_dag(&self) -> Result<Vec<String>, Vec<CycleError>> {
+        let mut colors: HashMap<&str, Color> = self.steps.keys().map(|id| (id.as_str(), Color::White)).collect();
+        let mut order = Vec::new();
+        let mut cycles = Vec::new();
+
+        // Iterate in a deterministic order so validation results (and, in
+        // particular, which step a reported cycle path starts at) don't
+        // depend on hash map iteration order.
+        let mut ids: Vec<&str> = self.steps.keys().map(String::as_str).collect();
+        ids.sort_unstable();
+
+        for id in ids {
+            if colors[id] == Color::White {
+                let mut path = Vec::new();
+                self.visit(id, &mut colors, &mut path, &mut order, &mut cycles);
+            }
+        }
+
+        if cycles.is_empty() {
+            Ok(order)
+        } else {
+            Err(cycles)
+        }
+    }
+
+    fn visit<'a>(
+        &'a self,
+        id: &'a str,
+        colors: &mut HashMap<&'a str, Color>,
+        path: &mut Vec<&'a str>,
+        order: &mut Vec<String>,
+        cycles: &mut Vec<CycleError>,
+    ) {
+        colors.insert(id, Color::Gray);
+        path.push(id);
+
+        if let Some(dependencies) = self.steps.get(id) {
+            for dep in dependencies {
+                match colors.get(dep.as_str()).copied() {
+                    Some(Color::White) | None => {
+                        if colors.contains_key(dep.as_str()) {
+                            self.visit(dep.as_str(), colors, path, order, cycles);
+                        }
+                    }
+                    Some(Color::Gray) => {
+                        let start = path.iter().position(|&p| p == dep.as_str()).unwrap_or(0);
+                        let mut cycle_path: Vec<String> = path[start..].iter().map(|s| s.to_string()).collect();
+                        cycle_path.push(dep.clone());
+                        cycles.push(CycleError { cycle_path });
+                    }
+                    Some(Color::Black) => {}
+                }
+            }
+        }
+
+        path.pop();
+        colors.insert(id, Color::Black);
+        order.push(id.to_string());
+    }
+
+    /// Steps in dependency-satisfying execution order. Panics-free even on
+    /// a cyclic graph — callers should call `validate_dag` first and only
+    /// use this once it returns `Ok`.
+    pub fn topological_order(&self) -> Vec<String> {
+        self.validate_dag().unwrap_or_default()
+    }
+
+    /// Groups the pipeline's topological order into execution batches: a run
+    /// of adjacent steps sharing the same `parallel_group` becomes one
+    /// batch, run concurrently; every other step is its own single-step
+    /// batch.
+    fn execution_batches(&self, order: &[String]) -> Vec<Vec<String>> {
+        let mut batches: Vec<Vec<String>> = Vec::new();
+        for id in order {
+            let group = self.parallel_groups.get(id).cloned().flatten();
+            let joins_previous_batch = group.is_some()
+                && batches
+                    .last()
+                    .and_then(|batch| batch.first())
+                    .map(|first| self.parallel_groups.get(first).cloned().flatten() == group)
+                    .unwrap_or(false);
+
+            if joins_previous_batch {
+                batches.last_mut().expect("just checked non-empty").push(id.clone());
+            } else {
+                batches.push(vec![id.clone()]);
+            }
+        }
+        batches
+    }
+
+    /// Executes every step via `executor`, running steps in the same
+    /// [`PipelineStep::parallel_group`] concurrently on a [`JoinSet`] and
+    /// otherwise proceeding one step at a time in dependency order. Returns
+    /// an error if the graph has a cycle rather than attempting to execute
+    /// it.
+    pub async fn execute(
+        &self,
+        executor: Arc<dyn StepExecutor>,
+    ) -> Result<PipelineExecutionResult, Vec<CycleError>> {
+        let order = self.validate_dag()?;
+        let batches = self.execution_batches(&order);
+
+        let pipeline_start = Instant::now();
+        let mut completed_steps = Vec::with_capacity(order.len());
+
+        for batch in batches {
+            if batch.len() == 1 {
+                let id = &batch[0];
+                let step_start = Instant::now();
+                let output = executor.execute(id).await;
+                completed_steps.push(CompletedStep {
+                    id: id.clone(),
+                    duration_ms: step_start.elapsed().as_millis() as u64,
+                    output,
+                });
+                continue;
+            }
+
+            let mut join_set: JoinSet<CompletedStep> = JoinSet::new();
+            for id in batch {
+                let executor = Arc::clone(&executor);
+                join_set.spawn(async move {
+                    let step_start = Instant::now();
+                    let output = executor.execute(&id).await;
+                    CompletedStep {
+                        id,
+                        duration_ms: step_start.elapsed().as_millis() as u64,
+                        output,
+                    }
+                });
+            }
+            while let Some(result) = join_set.join_next().await {
+                completed_steps.push(result.expect("step task should not panic"));
+            }
+        }
+
+        let total_duration_ms = pipeline_start.elapsed().as_millis() as u64;
+        let summed_duration_ms: u64 = completed_steps.iter().map(|step| step.duration_ms).sum();
+        let parallelism_achieved = if total_duration_ms == 0 {
+            1.0
+        } else {
+            summed_duration_ms as f64 / total_duration_ms as f64
+        };
+
+        Ok(PipelineExecutionResult {
+            completed_steps,
+            total_duration_ms,
+            parallelism_achieved,
+        })
+    }
+}
+
+/// Performs the actual work of a single pipeline step, used by
+/// [`PipelineGraph::execute`]. Implementors return the step's output.
+#[async_trait::async_trait]
+pub trait StepExecutor: Send + Sync {
+    async fn execute(&self, step_id: &str) -> String;
+}
+
+/// One completed pipeline step, as recorded in [`PipelineExecutionResult::completed_steps`].
+#[derive(Debug, Clone, Serialize)]
+pub struct CompletedStep {
+    pub id: String,
+    pub duration_ms: u64,
+    pub output: String,
+}
+
+/// Outcome of running a [`PipelineGraph`] end to end via [`PipelineGraph::execute`].
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineExecutionResult {
+    pub completed_steps: Vec<CompletedStep>,
+    pub total_duration_ms: u64,
+    /// Sum of every step's individual duration divided by the pipeline's
+    /// actual wall-clock duration — 1.0 for a fully sequential run, higher
+    /// when parallel groups overlapped work.
+    pub parallelism_achieved: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph_from(edges: &[(&str, &[&str])]) -> PipelineGraph {
+        let mut graph = PipelineGraph::new();
+        for (id, deps) in edges {
+            graph.add_step(PipelineStep {
+                id: id.to_string(),
+                dependencies: deps.iter().map(|d| d.to_string()).collect(),
+                parallel_group: None,
+            });
+        }
+        graph
+    }
+
+    #[test]
+    fn accepts_a_valid_four_step_dag() {
+        let graph = graph_from(&[
+            ("build", &[]),
+            ("lint", &["build"]),
+            ("test", &["build"]),
+            ("deploy", &["lint", "test"]),
+        ]);
+
+        let order = graph.validate_dag().expect("should be a valid dag");
+        assert_eq!(order.len(), 4);
+
+        let pos = |id: &str| order.iter().position(|s| s == id).unwrap();
+        assert!(pos("build") < pos("lint"));
+        assert!(pos("build") < pos("test"));
+        assert!(pos("lint") < pos("deploy"));
+        assert!(pos("test") < pos("deploy"));
+    }
+
+    #[test]
+    fn rejects_a_three_step_cycle_with_the_correct_path() {
+        let graph = graph_from(&[("a", &["b"]), ("b", &["c"]), ("c", &["a"])]);
+
+        let cycles = graph.validate_dag().expect_err("should detect the cycle");
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].cycle_path, vec!["a".to_string(), "b".to_string(), "c".to_string(), "a".to_string()]);
+    }
+
+    struct SleepExecutor {
+        durations_ms: HashMap<String, u64>,
+    }
+
+    #[async_trait::async_trait]
+    impl StepExecutor for SleepExecutor {
+        async fn execute(&self, step_id: &str) -> String {
+            let ms = self.durations_ms.get(step_id).copied().unwrap_or(0);
+            tokio::time::sleep(std::time::Duration::from_millis(ms)).await;
+            format!("{step_id}-done")
+        }
+    }
+
+    #[tokio::test]
+    async fn parallel_group_runs_concurrently_with_a_barrier_before_the_next_step() {
+        let mut graph = PipelineGraph::new();
+        graph.add_step(PipelineStep { id: "step1".to_string(), dependencies: vec![], parallel_group: None });
+        for id in ["step2", "step3", "step4"] {
+            graph.add_step(PipelineStep {
+                id: id.to_string(),
+                dependencies: vec!["step1".to_string()],
+                parallel_group: Some("parallel".to_string()),
+            });
+        }
+        graph.add_step(PipelineStep {
+            id: "step5".to_string(),
+            dependencies: vec!["step2".to_string(), "step3".to_string(), "step4".to_string()],
+            parallel_group: None,
+        });
+        graph.add_step(PipelineStep { id: "step6".to_string(), dependencies: vec!["step5".to_string()], parallel_group: None });
+
+        let durations_ms = HashMap::from([
+            ("step1".to_string(), 20),
+            ("step2".to_string(), 60),
+            ("step3".to_string(), 80),
+            ("step4".to_string(), 50),
+            ("step5".to_string(), 20),
+            ("step6".to_string(), 20),
+        ]);
+        let executor = Arc::new(SleepExecutor { durations_ms });
+
+        let result = graph.execute(executor).await.expect("should be a valid dag");
+
+        assert_eq!(result.completed_steps.len(), 6);
+        // Sequential steps (20 + 20 + 20 = 60ms) plus the slowest member of
+        // the parallel group (80ms) is ~140ms; the naive sum of every step
+        // would be 250ms. Leave generous slack for scheduler jitter while
+        // still proving the group ran concurrently rather than serially.
+        assert!(
+            result.total_duration_ms < 200,
+            "expected the parallel group to run concurrently, took {}ms",
+            result.total_duration_ms
+        );
+        assert!(result.parallelism_achieved > 1.0);
+    }
+}
@@ -3,8 +3,15 @@
 //! This module provides recovery capabilities for orchestration
 //! including circuit breakers and error recovery.
 
+use crate::agent::AgentStatus;
+use crate::memory::{AgentContext, ContextStore, ContextType};
+use crate::OrchestrationError;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::time::{Duration, Instant};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
 use uuid::Uuid;
 
 /// Recovery manager for handling failures and recovery
@@ -13,6 +20,62 @@ pub struct RecoveryManager {
     pub circuit_breakers: std::collections::HashMap<String, CircuitBreaker>,
     pub error_recovery: ErrorRecovery,
     pub recovery_strategies: Vec<RecoveryStrategy>,
+    pub agent_statuses: Arc<RwLock<HashMap<Uuid, AgentStatus>>>,
+}
+
+/// Acknowledgement returned by `Agent::heartbeat`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeartbeatAck {
+    pub agent_id: Uuid,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Types of recovery actions `RecoveryManager::recover_agent` will attempt, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecoveryType {
+    ServiceRestart,
+    ConfigurationReload,
+}
+
+/// Tracks per-agent heartbeat history and flags agents that go silent
+#[derive(Debug)]
+pub struct HeartbeatMonitor {
+    pub interval: Duration,
+    pub missed_threshold: u32,
+    last_ack: Arc<RwLock<HashMap<Uuid, DateTime<Utc>>>>,
+    missed: Arc<RwLock<HashMap<Uuid, u32>>>,
+}
+
+impl HeartbeatMonitor {
+    pub fn new(interval: Duration, missed_threshold: u32) -> Self {
+        Self {
+            interval,
+            missed_threshold,
+            last_ack: Arc::new(RwLock::new(HashMap::new())),
+            missed: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Record a successful heartbeat ack, resetting the agent's missed count
+    pub async fn record_ack(&self, ack: HeartbeatAck) {
+        self.last_ack.write().await.insert(ack.agent_id, ack.timestamp);
+        self.missed.write().await.insert(ack.agent_id, 0);
+    }
+
+    /// Called once per `interval` when an agent fails to respond in time.
+    /// Returns the new missed count, and whether the agent should be
+    /// considered `Unresponsive` (missed count has reached `missed_threshold`).
+    pub async fn record_missed(&self, agent_id: Uuid) -> (u32, bool) {
+        let mut missed = self.missed.write().await;
+        let count = missed.entry(agent_id).or_insert(0);
+        *count += 1;
+        (*count, *count >= self.missed_threshold)
+    }
+
+    /// Number of consecutive heartbeats an agent has missed
+    pub async fn missed_heartbeats(&self, agent_id: Uuid) -> u32 {
+        self.missed.read().await.get(&agent_id).copied().unwrap_or(0)
+    }
 }
 
 /// Circuit breaker for failure isolation
@@ -23,17 +86,33 @@ pub struct CircuitBreaker {
     pub failure_count: u32,
     pub failure_threshold: u32,
     pub timeout: Duration,
-    pub last_failure_time: Option<Instant>,
+    /// Wall-clock time of the most recent failure, rather than `Instant`,
+    /// so `save_state`/`load_state` can persist it across a process
+    /// restart and `can_execute` can measure `timeout` from the persisted
+    /// value instead of restarting the clock at process start.
+    pub last_failure_time: Option<DateTime<Utc>>,
 }
 
 /// Circuit breaker states
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CircuitBreakerState {
     Closed,
     Open,
     HalfOpen,
 }
 
+/// `CircuitBreaker` fields `save_state`/`load_state` round-trip through a
+/// `ContextStore`. `name`, `failure_threshold`, and `timeout` aren't
+/// included -- they're supplied fresh by whoever constructs the
+/// `CircuitBreaker` being loaded into, matching `LearningEngine`'s
+/// snapshot/restore split in `optimizer.rs`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CircuitBreakerSnapshot {
+    state: CircuitBreakerState,
+    failure_count: u32,
+    last_failure_time: Option<DateTime<Utc>>,
+}
+
 /// Error recovery system
 #[derive(Debug)]
 pub struct ErrorRecovery {
@@ -69,8 +148,20 @@ pub enum FallbackStrategy {
     Graceful { message: String },
 }
 
+/// Names a `CircuitBreaker` `RecoveryManager::new` should create and resume
+/// from its persisted state, if any.
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerSpec {
+    pub name: String,
+    pub failure_threshold: u32,
+    pub timeout: Duration,
+}
+
 impl RecoveryManager {
-    pub fn new() -> Self {
+    /// Creates a `RecoveryManager` with no circuit breakers registered.
+    /// Prefer `new` when any circuit breaker's pre-crash state should
+    /// survive a restart.
+    pub fn empty() -> Self {
         Self {
             circuit_breakers: std::collections::HashMap::new(),
             error_recovery: ErrorRecovery::new(),
@@ -83,8 +174,72 @@ impl RecoveryManager {
                     fallback_action: "default".to_string(),
                 },
             ],
+            agent_statuses: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Creates a `RecoveryManager` and registers one `CircuitBreaker` per
+    /// `CircuitBreakerSpec`, resuming each from `store` via `load_state` so
+    /// a circuit that was Open before a crash comes back Open rather than
+    /// resetting to Closed.
+    pub async fn new(
+        store: &impl ContextStore,
+        circuit_breaker_specs: Vec<CircuitBreakerSpec>,
+    ) -> Result<Self, OrchestrationError> {
+        let mut manager = Self::empty();
+        for spec in circuit_breaker_specs {
+            let mut breaker = CircuitBreaker::new(spec.name.clone(), spec.failure_threshold, spec.timeout);
+            breaker.load_state(store).await?;
+            manager.circuit_breakers.insert(spec.name, breaker);
+        }
+        Ok(manager)
+    }
+
+    /// Attempt to recover an agent that a `HeartbeatMonitor` has marked unresponsive.
+    ///
+    /// Tries `RecoveryType::ServiceRestart` then `RecoveryType::ConfigurationReload`;
+    /// if neither succeeds the agent's status is set to `AgentStatus::Failed`.
+    pub async fn recover_agent(&self, agent_id: Uuid) -> AgentStatus {
+        self.agent_statuses
+            .write()
+            .await
+            .insert(agent_id, AgentStatus::Unresponsive);
+
+        for recovery_type in [RecoveryType::ServiceRestart, RecoveryType::ConfigurationReload] {
+            if self.attempt_recovery(agent_id, recovery_type).await {
+                let status = AgentStatus::Idle;
+                self.agent_statuses.write().await.insert(agent_id, status.clone());
+                return status;
+            }
+        }
+
+        let status = AgentStatus::Failed;
+        self.agent_statuses.write().await.insert(agent_id, status.clone());
+        status
+    }
+
+    /// Run a single recovery attempt. Real service restart / config reload hooks
+    /// are dispatched by the caller's deployment layer; this records the attempt
+    /// and reports whether the agent should be considered recovered.
+    async fn attempt_recovery(&self, agent_id: Uuid, recovery_type: RecoveryType) -> bool {
+        tracing::info!(
+            agent_id = %agent_id,
+            recovery_type = ?recovery_type,
+            "Attempting agent recovery"
+        );
+
+        // Simplified simulation of recovery success; a production deployment
+        // layer would restart the agent's process or reload its config here.
+        match recovery_type {
+            RecoveryType::ServiceRestart => rand::random::<f64>() > 0.5,
+            RecoveryType::ConfigurationReload => rand::random::<f64>() > 0.7,
         }
     }
+
+    /// Current recovery status for an agent, if tracked
+    pub async fn agent_status(&self, agent_id: Uuid) -> Option<AgentStatus> {
+        self.agent_statuses.read().await.get(&agent_id).cloned()
+    }
 }
 
 impl CircuitBreaker {
@@ -104,7 +259,8 @@ impl CircuitBreaker {
             CircuitBreakerState::Closed => true,
             CircuitBreakerState::Open => {
                 if let Some(last_failure) = self.last_failure_time {
-                    Instant::now().duration_since(last_failure) >= self.timeout
+                    Utc::now().signed_duration_since(last_failure)
+                        >= chrono::Duration::from_std(self.timeout).unwrap_or(chrono::Duration::zero())
                 } else {
                     false
                 }
@@ -112,6 +268,56 @@ impl CircuitBreaker {
             CircuitBreakerState::HalfOpen => true,
         }
     }
+
+    /// Deterministic `ContextStore` key for a circuit breaker named `name`.
+    /// `ContextStore` is keyed by agent id, but breaker state isn't scoped
+    /// to any one agent, so this derives a stable id from the name instead
+    /// -- the same approach `LEARNING_ENGINE_CONTEXT_ID` takes for a single
+    /// fixed key, generalized to support more than one named breaker.
+    fn context_id(name: &str) -> Uuid {
+        Uuid::new_v5(&Uuid::NAMESPACE_OID, name.as_bytes())
+    }
+
+    /// Persists `state`, `failure_count`, and `last_failure_time` to
+    /// `store` under a key derived from `name`, so `load_state` can resume
+    /// this exact posture after a restart.
+    pub async fn save_state(&self, store: &impl ContextStore) -> Result<(), OrchestrationError> {
+        let snapshot = CircuitBreakerSnapshot {
+            state: self.state.clone(),
+            failure_count: self.failure_count,
+            last_failure_time: self.last_failure_time,
+        };
+        let content = serde_json::to_string(&snapshot)
+            .map_err(|e| OrchestrationError::RecoveryError(format!("failed to serialize circuit breaker state: {e}")))?;
+
+        let context_id = Self::context_id(&self.name);
+        store.store_context(context_id, AgentContext {
+            id: context_id,
+            agent_id: context_id,
+            content,
+            context_type: ContextType::State,
+            created_at: Utc::now(),
+            metadata: HashMap::new(),
+        }).await
+    }
+
+    /// Restores `state`, `failure_count`, and `last_failure_time` from
+    /// `store`, leaving `self` untouched (i.e. Closed with no recorded
+    /// failures) if nothing has been persisted for `name` yet.
+    pub async fn load_state(&mut self, store: &impl ContextStore) -> Result<(), OrchestrationError> {
+        let context_id = Self::context_id(&self.name);
+        let Some(context) = store.retrieve_context(context_id, context_id).await? else {
+            return Ok(());
+        };
+
+        let snapshot: CircuitBreakerSnapshot = serde_json::from_str(&context.content)
+            .map_err(|e| OrchestrationError::RecoveryError(format!("failed to deserialize circuit breaker state: {e}")))?;
+
+        self.state = snapshot.state;
+        self.failure_count = snapshot.failure_count;
+        self.last_failure_time = snapshot.last_failure_time;
+        Ok(())
+    }
 }
 
 impl ErrorRecovery {
@@ -121,4 +327,77 @@ impl ErrorRecovery {
             fallback_strategies: std::collections::HashMap::new(),
         }
     }
+}
+
+#[cfg(test)]
+mod circuit_breaker_persistence_tests {
+    use super::*;
+    use crate::memory::{InMemoryContextStore, MemoryConfig};
+
+    fn store() -> InMemoryContextStore {
+        InMemoryContextStore::new(MemoryConfig { persistence_enabled: false, ..MemoryConfig::default() })
+    }
+
+    #[tokio::test]
+    async fn a_fresh_circuit_breaker_stays_closed_with_nothing_persisted() {
+        let store = store();
+        let mut breaker = CircuitBreaker::new("payments".to_string(), 3, Duration::from_secs(30));
+
+        breaker.load_state(&store).await.unwrap();
+
+        assert!(matches!(breaker.state, CircuitBreakerState::Closed));
+        assert!(breaker.can_execute());
+    }
+
+    #[tokio::test]
+    async fn an_open_breaker_stays_open_until_its_timeout_elapses_after_reload() {
+        let store = store();
+        let mut breaker = CircuitBreaker::new("payments".to_string(), 3, Duration::from_millis(50));
+        breaker.state = CircuitBreakerState::Open;
+        breaker.failure_count = 5;
+        breaker.last_failure_time = Some(Utc::now());
+        breaker.save_state(&store).await.unwrap();
+
+        let mut reloaded = CircuitBreaker::new("payments".to_string(), 3, Duration::from_millis(50));
+        reloaded.load_state(&store).await.unwrap();
+
+        assert!(matches!(reloaded.state, CircuitBreakerState::Open));
+        assert_eq!(reloaded.failure_count, 5);
+        assert!(!reloaded.can_execute(), "should still be open immediately after reload");
+
+        tokio::time::sleep(Duration::from_millis(75)).await;
+        assert!(reloaded.can_execute(), "should allow a probe request once the timeout has elapsed");
+    }
+
+    #[tokio::test]
+    async fn breakers_with_different_names_persist_independently() {
+        let store = store();
+        let mut payments = CircuitBreaker::new("payments".to_string(), 3, Duration::from_secs(30));
+        payments.state = CircuitBreakerState::Open;
+        payments.last_failure_time = Some(Utc::now());
+        payments.save_state(&store).await.unwrap();
+
+        let mut inventory = CircuitBreaker::new("inventory".to_string(), 3, Duration::from_secs(30));
+        inventory.load_state(&store).await.unwrap();
+
+        assert!(matches!(inventory.state, CircuitBreakerState::Closed), "unrelated breaker name shouldn't see payments' state");
+    }
+
+    #[tokio::test]
+    async fn recovery_manager_new_resumes_registered_breakers_from_the_store() {
+        let store = store();
+        let mut payments = CircuitBreaker::new("payments".to_string(), 3, Duration::from_secs(30));
+        payments.state = CircuitBreakerState::Open;
+        payments.last_failure_time = Some(Utc::now());
+        payments.save_state(&store).await.unwrap();
+
+        let manager = RecoveryManager::new(&store, vec![CircuitBreakerSpec {
+            name: "payments".to_string(),
+            failure_threshold: 3,
+            timeout: Duration::from_secs(30),
+        }]).await.unwrap();
+
+        let breaker = manager.circuit_breakers.get("payments").unwrap();
+        assert!(matches!(breaker.state, CircuitBreakerState::Open));
+    }
 }
\ No newline at end of file
@@ -36,10 +36,18 @@ impl Default for RecursionLimits {
     }
 }
 
+/// Identifies the agent on whose behalf a `RecursiveContext` is executing,
+/// for per-agent budget accounting in `RecursiveExecutionManager`.
+pub type AgentId = Uuid;
+
 /// Context for recursive execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecursiveContext {
     pub workflow_id: Uuid,
+    /// The agent that owns this recursion level, used to enforce
+    /// `RecursiveExecutionManager::per_agent_max_depth` independently of
+    /// the global `RecursionLimits::max_depth`.
+    pub agent_id: AgentId,
     pub depth: usize,
     pub parent_workflow_id: Option<Uuid>,
     pub root_workflow_id: Uuid,
@@ -121,6 +129,25 @@ pub struct RecursiveExecutionManager {
     pub recursion_history: Arc<RwLock<Vec<RecursionRecord>>>,
     pub cycle_detector: CycleDetector,
     pub performance_monitor: RecursivePerformanceMonitor,
+    /// Per-agent recursion-depth budgets, independent of the global
+    /// `RecursionLimits::max_depth`. An agent with no entry here is only
+    /// bound by the global limit.
+    pub per_agent_max_depth: Arc<RwLock<HashMap<AgentId, usize>>>,
+    /// How deep each agent is currently recursing, incremented in
+    /// `start_recursive_execution` and decremented in
+    /// `complete_recursive_execution`.
+    pub per_agent_current_depth: Arc<RwLock<HashMap<AgentId, usize>>>,
+    /// Every depth each agent has started an execution at, for
+    /// `RecursionStatistics::agent_depth_histogram`.
+    pub agent_depth_history: Arc<RwLock<HashMap<AgentId, Vec<usize>>>>,
+    /// The chain of distinct agents currently active in each root
+    /// workflow's execution thread, in call order. An agent recursing
+    /// directly into itself doesn't push a new frame -- only a call to a
+    /// *different* agent does -- so this reflects the call graph between
+    /// agents, not raw recursion depth. Used to detect an agent cycle
+    /// (`call_stack.contains(&new_agent_id)`) and to render the offending
+    /// path in `OrchestrationError::RecursionCycleDetected`.
+    pub call_stacks: Arc<RwLock<HashMap<Uuid, Vec<AgentId>>>>,
 }
 
 /// Cycle detection system
@@ -273,13 +300,25 @@ impl RecursiveExecutionManager {
             recursion_history: Arc::new(RwLock::new(Vec::new())),
             cycle_detector: CycleDetector::new(limits.cycle_detection),
             performance_monitor: RecursivePerformanceMonitor::new(),
+            per_agent_max_depth: Arc::new(RwLock::new(HashMap::new())),
+            per_agent_current_depth: Arc::new(RwLock::new(HashMap::new())),
+            agent_depth_history: Arc::new(RwLock::new(HashMap::new())),
+            call_stacks: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
+    /// Sets (or overrides) `agent_id`'s individual recursion-depth budget.
+    /// An agent with no budget configured here is only bound by the global
+    /// `RecursionLimits::max_depth`.
+    pub async fn set_agent_max_depth(&self, agent_id: AgentId, max_depth: usize) {
+        self.per_agent_max_depth.write().await.insert(agent_id, max_depth);
+    }
+
     /// Start a new recursive execution
     pub async fn start_recursive_execution(
         &self,
         workflow_id: Uuid,
+        agent_id: AgentId,
         parent_context: Option<&RecursiveContext>,
     ) -> Result<RecursiveContext, OrchestrationError> {
         let recursion_id = Uuid::new_v4();
@@ -287,11 +326,12 @@ impl RecursiveExecutionManager {
         let root_workflow_id = parent_context.map(|ctx| ctx.root_workflow_id).unwrap_or(workflow_id);
 
         // Check recursion limits
-        self.validate_recursion_limits(depth, workflow_id).await?;
+        self.validate_recursion_limits(depth, agent_id, workflow_id).await?;
 
         // Create recursive context
         let context = RecursiveContext {
             workflow_id,
+            agent_id,
             depth,
             parent_workflow_id: parent_context.map(|ctx| ctx.workflow_id),
             root_workflow_id,
@@ -310,8 +350,27 @@ impl RecursiveExecutionManager {
 
         // Check for cycles
         if self.limits.cycle_detection {
+            // Cycle by agent identity: a call to a *different* agent than
+            // the one currently on top of this thread's call stack that
+            // already appears earlier in the stack means the invocation
+            // chain loops back on itself.
+            {
+                let mut call_stacks = self.call_stacks.write().await;
+                let stack = call_stacks.entry(context.root_workflow_id).or_insert_with(Vec::new);
+                if stack.last() != Some(&agent_id) {
+                    if stack.contains(&agent_id) {
+                        let mut path = stack.clone();
+                        path.push(agent_id);
+                        return Err(OrchestrationError::RecursionCycleDetected { path });
+                    }
+                    stack.push(agent_id);
+                }
+            }
+
+            // Cycle by workflow identity, via graph analysis of `execution_path`.
             if self.cycle_detector.detect_cycle(&context).await? {
-                return Err(OrchestrationError::RecursionCycleDetected);
+                let path = self.call_stacks.read().await.get(&context.root_workflow_id).cloned().unwrap_or_default();
+                return Err(OrchestrationError::RecursionCycleDetected { path });
             }
         }
 
@@ -321,6 +380,16 @@ impl RecursiveExecutionManager {
             active.insert(recursion_id, context.clone());
         }
 
+        // Account for this agent's recursion depth
+        {
+            let mut current_depth = self.per_agent_current_depth.write().await;
+            *current_depth.entry(agent_id).or_insert(0) += 1;
+        }
+        {
+            let mut history = self.agent_depth_history.write().await;
+            history.entry(agent_id).or_insert_with(Vec::new).push(depth);
+        }
+
         // Start performance monitoring
         self.performance_monitor.start_monitoring(&context).await?;
 
@@ -379,6 +448,26 @@ impl RecursiveExecutionManager {
             active.remove(&context.recursion_id);
         }
 
+        // Release this agent's recursion-depth budget
+        {
+            let mut current_depth = self.per_agent_current_depth.write().await;
+            if let Some(depth) = current_depth.get_mut(&context.agent_id) {
+                *depth = depth.saturating_sub(1);
+            }
+        }
+
+        // Pop this execution's frame from its call stack, if it's the one
+        // on top -- same-agent recursion never pushed a frame, so there's
+        // nothing to pop in that case.
+        {
+            let mut call_stacks = self.call_stacks.write().await;
+            if let Some(stack) = call_stacks.get_mut(&context.root_workflow_id) {
+                if stack.last() == Some(&context.agent_id) {
+                    stack.pop();
+                }
+            }
+        }
+
         // Check for performance alerts
         self.check_performance_alerts(context).await?;
 
@@ -395,15 +484,26 @@ impl RecursiveExecutionManager {
     }
 
     /// Validate recursion limits
-    async fn validate_recursion_limits(&self, depth: usize, _workflow_id: Uuid) -> Result<(), OrchestrationError> {
-        // Check depth limit
+    async fn validate_recursion_limits(&self, depth: usize, agent_id: AgentId, _workflow_id: Uuid) -> Result<(), OrchestrationError> {
+        // Check global depth limit
         if depth > self.limits.max_depth {
             return Err(OrchestrationError::RecursionLimitExceeded(format!(
-                "Maximum recursion depth {} exceeded (current: {})",
-                self.limits.max_depth, depth
+                "Maximum recursion depth {} exceeded (current: {}, agent: {})",
+                self.limits.max_depth, depth, agent_id
             )));
         }
 
+        // Check this agent's individual depth budget, independent of the
+        // global limit above
+        if let Some(&agent_max_depth) = self.per_agent_max_depth.read().await.get(&agent_id) {
+            let agent_current_depth = self.per_agent_current_depth.read().await.get(&agent_id).copied().unwrap_or(0);
+            if agent_current_depth >= agent_max_depth {
+                return Err(OrchestrationError::RecursionLimitExceeded(format!(
+                    "Agent {agent_id} exhausted its recursion budget of {agent_max_depth} (current depth: {agent_current_depth})"
+                )));
+            }
+        }
+
         // Check active recursions count
         let active_count = {
             let active = self.active_recursions.read().await;
@@ -517,6 +617,7 @@ impl RecursiveExecutionManager {
             success_rate,
             average_execution_time,
             performance_alerts: self.performance_monitor.get_active_alerts().await?,
+            agent_depth_histogram: self.agent_depth_history.read().await.clone(),
         })
     }
 }
@@ -530,6 +631,9 @@ pub struct RecursionStatistics {
     pub success_rate: f64,
     pub average_execution_time: Duration,
     pub performance_alerts: Vec<PerformanceAlert>,
+    /// Every depth each agent has started an execution at, for spotting
+    /// agents that recurse unusually deep relative to the rest.
+    pub agent_depth_histogram: HashMap<AgentId, Vec<usize>>,
 }
 
 impl CycleDetector {
@@ -716,8 +820,8 @@ pub enum RecursionError {
     #[error("Recursion limit exceeded: {0}")]
     RecursionLimitExceeded(String),
 
-    #[error("Recursion cycle detected")]
-    RecursionCycleDetected,
+    #[error("Recursion cycle detected: {}", path.iter().map(ToString::to_string).collect::<Vec<_>>().join(" -> "))]
+    RecursionCycleDetected { path: Vec<AgentId> },
 
     #[error("Resource escalation threshold exceeded")]
     ResourceEscalationExceeded,
@@ -733,8 +837,8 @@ impl From<RecursionError> for OrchestrationError {
             RecursionError::RecursionLimitExceeded(msg) => {
                 OrchestrationError::RecursionLimitExceeded(msg)
             }
-            RecursionError::RecursionCycleDetected => {
-                OrchestrationError::RecursionCycleDetected
+            RecursionError::RecursionCycleDetected { path } => {
+                OrchestrationError::RecursionCycleDetected { path }
             }
             RecursionError::ResourceEscalationExceeded => {
                 OrchestrationError::ResourceExhausted("Resource escalation threshold exceeded".to_string())
@@ -0,0 +1,214 @@
+//! Schema versioning and forward migration for persisted [`AgentState`] values.
+//!
+//! `AgentState` gains fields as MCTS planning evolves, but a long-running
+//! search can be checkpointed by one build and resumed by a later one after
+//! such a field was added. [`StateMigrator`] holds a registry of per-version
+//! migration closures so [`crate::mcts::MCTSPlanner::load_checkpoint`] can
+//! upgrade a checkpoint's raw `AgentState` JSON forward to
+//! [`CURRENT_AGENT_STATE_SCHEMA_VERSION`] before deserializing it, rather
+//! than failing on the first missing field.
+
+use std::collections::HashMap;
+
+use crate::mcts::AgentState;
+use crate::OrchestrationError;
+
+/// Schema version written by this build's [`AgentState`]. Bump whenever a
+/// field is added, removed, or changes meaning, and register a migration in
+/// [`StateMigrator::with_default_migrations`] to upgrade checkpoints written
+/// under the previous version.
+pub const CURRENT_AGENT_STATE_SCHEMA_VERSION: u32 = 2;
+
+type MigrationFn = Box<dyn Fn(serde_json::Value) -> serde_json::Value + Send + Sync>;
+
+/// Registry of closures that each upgrade a raw [`AgentState`] JSON value
+/// from one schema version to the next.
+pub struct StateMigrator {
+    migrations: HashMap<u32, MigrationFn>,
+}
+
+impl StateMigrator {
+    pub fn new() -> Self {
+        Self {
+            migrations: HashMap::new(),
+        }
+    }
+
+    /// Registers the migration that upgrades a checkpoint from schema
+    /// version `from_version` to `from_version + 1`.
+    pub fn register(
+        &mut self,
+        from_version: u32,
+        migration: impl Fn(serde_json::Value) -> serde_json::Value + Send + Sync + 'static,
+    ) {
+        self.migrations.insert(from_version, Box::new(migration));
+    }
+
+    /// A migrator pre-populated with this crate's built-in migrations.
+    pub fn with_default_migrations() -> Self {
+        let mut migrator = Self::new();
+        // v1 checkpoints predate `schema_version` itself; backfill it with 1
+        // so the loop in `migrate` has a value to advance from.
+        migrator.register(1, |mut value| {
+            if let Some(obj) = value.as_object_mut() {
+                obj.entry("schema_version")
+                    .or_insert_with(|| serde_json::Value::Number(1.into()));
+            }
+            value
+        });
+        migrator
+    }
+
+    /// Applies every registered migration from `version` up to
+    /// [`CURRENT_AGENT_STATE_SCHEMA_VERSION`] in order, then deserializes
+    /// the result into an [`AgentState`].
+    pub fn migrate(&self, version: u32, raw: serde_json::Value) -> Result<AgentState, OrchestrationError> {
+        let mut value = raw;
+        let mut current_version = version;
+        while current_version < CURRENT_AGENT_STATE_SCHEMA_VERSION {
+            let migration = self.migrations.get(&current_version).ok_or_else(|| {
+                OrchestrationError::PlanningError(format!(
+                    "no migration registered to upgrade AgentState from schema version {current_version}"
+                ))
+            })?;
+            value = migration(value);
+            current_version += 1;
+        }
+
+        if let Some(obj) = value.as_object_mut() {
+            obj.insert(
+                "schema_version".to_string(),
+                serde_json::Value::Number(CURRENT_AGENT_STATE_SCHEMA_VERSION.into()),
+            );
+        }
+
+        serde_json::from_value(value).map_err(|e| {
+            OrchestrationError::PlanningError(format!("failed to deserialize migrated AgentState: {e}"))
+        })
+    }
+}
+
+impl Default for StateMigrator {
+    fn default() -> Self {
+        Self::with_default_migrations()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v1_agent_state_json() -> serde_json::Value {
+        // A schema version 1 `AgentState`, serialized before `schema_version`
+        // existed as a field at all.
+        serde_json::json!({
+            "context": "resume-me",
+            "available_actions": [],
+            "resources": {
+                "cpu_available": 50.0,
+                "memory_available": 1024,
+                "network_bandwidth": 1024,
+                "active_connections": 0,
+                "cache_usage": 0.0,
+            },
+            "objectives": [],
+            "constraints": [],
+            "performance_history": [0.5],
+        })
+    }
+
+    #[test]
+    fn migrates_a_v1_checkpoint_by_inserting_the_missing_schema_version() {
+        let migrator = StateMigrator::with_default_migrations();
+        let state = migrator
+            .migrate(1, v1_agent_state_json())
+            .expect("v1 state should migrate to the current schema");
+
+        assert_eq!(state.schema_version, CURRENT_AGENT_STATE_SCHEMA_VERSION);
+        assert_eq!(state.context, "resume-me");
+    }
+
+    #[test]
+    fn a_checkpoint_already_at_the_current_version_deserializes_unchanged() {
+        let migrator = StateMigrator::with_default_migrations();
+        let mut value = v1_agent_state_json();
+        value["schema_version"] = serde_json::Value::Number(CURRENT_AGENT_STATE_SCHEMA_VERSION.into());
+
+        let state = migrator
+            .migrate(CURRENT_AGENT_STATE_SCHEMA_VERSION, value)
+            .expect("current-version state should deserialize");
+
+        assert_eq!(state.schema_version, CURRENT_AGENT_STATE_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn an_unmigratable_version_reports_a_planning_error() {
+        let migrator = StateMigrator::new();
+        let err = migrator
+            .migrate(1, v1_agent_state_json())
+            .expect_err("no migrations are registered, so this should fail");
+        assert!(matches!(err, OrchestrationError::PlanningError(_)));
+    }
+
+    #[test]
+    fn planner_loads_a_v1_checkpoint_and_runs_a_search() {
+        use crate::mcts::MCTSPlanner;
+        use crate::MCTSConfig;
+
+        // A couple of simulations and a short timeout so a search over the
+        // restored tree is quick, since only "runs without error" matters
+        // here, not search quality.
+        let config = MCTSConfig {
+            max_depth: 2,
+            simulations: 2,
+            exploration_constant: 1.414,
+            timeout_seconds: 3,
+            parallel_simulations: 1,
+            use_neural_guidance: false,
+        };
+
+        let checkpoint = serde_json::json!({
+            "agent_state_schema_version": 1,
+            "config": config,
+            "root": {
+                "id": uuid::Uuid::new_v4(),
+                "state": v1_agent_state_json(),
+                "action": null,
+                "parent": null,
+                "children": [],
+                "visits": 0,
+                "total_value": 0.0,
+                // JSON has no representation for infinity (serde_json would
+                // serialize it as `null`), so use a large finite stand-in
+                // for the usual "unvisited" `f64::INFINITY` UCB1 value.
+                "ucb1_value": f64::MAX,
+                "depth": 0,
+                "is_terminal": false,
+                "created_at": chrono::Utc::now(),
+                "neural_prior": null,
+                "action_priors": [],
+            },
+        });
+
+        let path = std::env::temp_dir().join(format!("mcts-checkpoint-{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&path, serde_json::to_string(&checkpoint).unwrap()).unwrap();
+
+        let migrator = StateMigrator::with_default_migrations();
+        let planner = MCTSPlanner::load_checkpoint(&path, &migrator)
+            .expect("a v1 checkpoint should load through the default migrations");
+        std::fs::remove_file(&path).ok();
+
+        let result = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(planner.search(v1_agent_state_json_migrated()));
+        assert!(result.is_ok(), "restored planner should run a search without error: {result:?}");
+    }
+
+    fn v1_agent_state_json_migrated() -> AgentState {
+        StateMigrator::with_default_migrations()
+            .migrate(1, v1_agent_state_json())
+            .expect("v1 state should migrate for use as the search's initial state")
+    }
+}
@@ -31,6 +31,89 @@ pub struct WorkflowOrchestrator {
     pub dependency_resolver: Arc<DependencyResolver>,
     pub event_bus: Arc<EventBus>,
     pub metrics_collector: Arc<WorkflowMetricsCollector>,
+    /// Sessions established via `register_agent`'s capability negotiation
+    /// handshake, keyed by session id.
+    pub active_sessions: Arc<RwLock<HashMap<Uuid, AgentSession>>>,
+    /// Compensating actions registered via `register_compensation`, keyed by
+    /// workflow id, in the order they were registered. `rollback` undoes
+    /// them in reverse.
+    pub compensations: Arc<RwLock<HashMap<Uuid, Vec<CompensatingAction>>>>,
+    /// Published workflow definitions keyed by `WorkflowGraph::name`, each
+    /// entry holding every semantically-versioned revision published via
+    /// `publish`. `execute_by_name` resolves the highest matching version.
+    pub version_registry: Arc<RwLock<HashMap<String, Vec<WorkflowDefinition>>>>,
+}
+
+/// A semantically-versioned, publishable revision of a `WorkflowGraph`.
+/// Unlike `WorkflowGraph::version` (the graph's own schema version, bumped
+/// when its serialized shape changes), this tracks the behavioral version
+/// downstream consumers depend on across `WorkflowOrchestrator::publish`
+/// calls.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowDefinition {
+    pub id: Uuid,
+    pub version: semver::Version,
+    pub graph: WorkflowGraph,
+    pub changelog: Vec<String>,
+    /// Set by `deprecate_version`. Deprecated versions remain in
+    /// `list_versions` but are skipped by `execute_by_name`'s resolution.
+    #[serde(default)]
+    pub deprecated: bool,
+}
+
+/// An action that undoes the effect of a workflow step, registered via
+/// `WorkflowOrchestrator::register_compensation` so a later failure can be
+/// unwound with `WorkflowOrchestrator::rollback`.
+#[derive(Debug, Clone)]
+pub struct CompensatingAction {
+    pub step_id: String,
+    pub undo_task: ExecutionTask,
+}
+
+/// Progress of a workflow's compensating rollback, tracked on
+/// `ExecutionPlan::rollback_status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RollbackStatus {
+    NotNeeded,
+    InProgress,
+    Completed,
+    Failed,
+}
+
+/// A capability an agent advertises during the handshake, tagged with the
+/// protocol version it implements.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VersionedCapability {
+    pub name: String,
+    pub version: String,
+}
+
+/// Capabilities and protocol support an agent advertises on first
+/// connection, before it is admitted into the orchestrator.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentHandshake {
+    pub agent_id: Uuid,
+    pub capabilities: Vec<VersionedCapability>,
+    pub supported_protocols: Vec<String>,
+    pub max_payload_bytes: usize,
+}
+
+/// Orchestrator's response to a handshake: the capabilities it accepted and
+/// the protocol negotiated for this session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandshakeAck {
+    pub session_id: Uuid,
+    pub accepted_capabilities: Vec<VersionedCapability>,
+    pub assigned_protocol: String,
+}
+
+/// A negotiated agent session established via `register_agent`.
+#[derive(Debug, Clone)]
+pub struct AgentSession {
+    pub agent_id: Uuid,
+    pub accepted_capabilities: Vec<VersionedCapability>,
+    pub assigned_protocol: String,
+    pub established_at: DateTime<Utc>,
 }
 
 /// Configuration for the workflow orchestrator
@@ -88,6 +171,370 @@ pub struct WorkflowGraph {
     pub metadata: HashMap<String, String>,
 }
 
+/// Current schema version written by this build. Bump whenever a field is
+/// added, removed, or changes meaning, and add a case to
+/// [`WorkflowGraph::migrate_schema`] to backfill graphs persisted under an
+/// older version.
+pub const CURRENT_WORKFLOW_GRAPH_SCHEMA_VERSION: &str = "2.0";
+
+impl WorkflowGraph {
+    /// Deserializes a persisted workflow graph, migrating it forward to the
+    /// current schema first. This lets graphs written by older builds keep
+    /// loading after fields are added, without a one-time data migration.
+    pub fn from_persisted_value(mut value: serde_json::Value) -> Result<Self, OrchestrationError> {
+        Self::migrate_schema(&mut value);
+        serde_json::from_value(value)
+            .map_err(|e| OrchestrationError::WorkflowError(format!("failed to deserialize workflow graph: {e}")))
+    }
+
+    /// Backfills fields that didn't exist in older schema versions so that
+    /// `serde_json::from_value` can still succeed, then stamps the value
+    /// with the current version.
+    fn migrate_schema(value: &mut serde_json::Value) {
+        let Some(obj) = value.as_object_mut() else {
+            return;
+        };
+
+        let version = obj
+            .get("version")
+            .and_then(|v| v.as_str())
+            .unwrap_or("1.0")
+            .to_string();
+
+        // v1.0 graphs predate `constraints`, `metadata`, and the input/output
+        // JSON schemas; backfill them with empty defaults.
+        if version == "1.0" {
+            obj.entry("constraints").or_insert_with(|| serde_json::Value::Array(Vec::new()));
+            obj.entry("metadata")
+                .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+            obj.entry("input_schema")
+                .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+            obj.entry("output_schema")
+                .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        }
+
+        obj.insert(
+            "version".to_string(),
+            serde_json::Value::String(CURRENT_WORKFLOW_GRAPH_SCHEMA_VERSION.to_string()),
+        );
+    }
+
+    /// Duration estimate used by [`WorkflowGraph::critical_path`] for nodes
+    /// [`WorkflowGraph::apply_learned_weights`] hasn't learned a duration
+    /// for yet.
+    const DEFAULT_NODE_DURATION_MS: f64 = 1_000.0;
+
+    /// Replaces each node's static duration estimate with the one
+    /// [`crate::NodeWeightLearner`] has learned from historical executions,
+    /// once it has accumulated at least
+    /// [`crate::node_weight_learner::MIN_SAMPLES_FOR_TRUST`] samples for
+    /// that node.
+    pub async fn apply_learned_weights(&mut self, learner: &crate::NodeWeightLearner) {
+        for node in self.nodes.values_mut() {
+            if let Some(stats) = learner.stats_for(&node.id).await {
+                if stats.sample_count >= crate::node_weight_learner::MIN_SAMPLES_FOR_TRUST {
+                    node.learned_duration_ms = Some(stats.ema_duration_ms);
+                }
+            }
+        }
+    }
+
+    /// The sequence of node ids with the greatest cumulative duration from a
+    /// start node (one with no incoming edges) to a leaf, using each node's
+    /// `learned_duration_ms` when available and
+    /// [`WorkflowGraph::DEFAULT_NODE_DURATION_MS`] otherwise.
+    pub fn critical_path(&self) -> Vec<String> {
+        let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+        for edge in &self.edges {
+            children
+                .entry(edge.from_node.as_str())
+                .or_default()
+                .push(edge.to_node.as_str());
+        }
+
+        let has_incoming: HashSet<&str> =
+            self.edges.iter().map(|edge| edge.to_node.as_str()).collect();
+        let start_nodes: Vec<&str> = self
+            .nodes
+            .keys()
+            .map(String::as_str)
+            .filter(|node_id| !has_incoming.contains(node_id))
+            .collect();
+
+        let mut best_path = Vec::new();
+        let mut best_duration = -1.0f64;
+        for start in start_nodes {
+            let mut path = Vec::new();
+            let duration = self.longest_path_from(start, &children, &mut path);
+            if duration > best_duration {
+                best_duration = duration;
+                best_path = path;
+            }
+        }
+        best_path
+    }
+
+    fn longest_path_from<'a>(
+        &self,
+        node_id: &'a str,
+        children: &HashMap<&'a str, Vec<&'a str>>,
+        path: &mut Vec<String>,
+    ) -> f64 {
+        let duration = self
+            .nodes
+            .get(node_id)
+            .and_then(|node| node.learned_duration_ms)
+            .unwrap_or(Self::DEFAULT_NODE_DURATION_MS);
+        path.push(node_id.to_string());
+
+        let mut best_suffix_duration = 0.0;
+        let mut best_suffix_path = Vec::new();
+        if let Some(kids) = children.get(node_id) {
+            for &child in kids {
+                let mut candidate_path = Vec::new();
+                let candidate_duration = self.longest_path_from(child, children, &mut candidate_path);
+                if candidate_duration > best_suffix_duration {
+                    best_suffix_duration = candidate_duration;
+                    best_suffix_path = candidate_path;
+                }
+            }
+        }
+        path.extend(best_suffix_path);
+        duration + best_suffix_duration
+    }
+
+    /// Checks that the graph has no cycles, returning `Err` with the full
+    /// cycle path (each node visited once, ending back at the node where
+    /// the cycle closes) baked into the message when one is found. Called
+    /// by `WorkflowOrchestrator::deploy_workflow` before a graph is
+    /// accepted, since an undetected cycle would otherwise hang the
+    /// executor at runtime waiting on a dependency that can never
+    /// complete.
+    ///
+    /// Runs a DFS from every node rather than just ones with no incoming
+    /// edges, so a cycle inside a component that's disconnected from the
+    /// rest of the graph is still found. Self-loops (`from_node ==
+    /// to_node`) are checked up front rather than falling through to the
+    /// general back-edge case, since they're a degenerate one-node cycle
+    /// the general path-stack bookkeeping doesn't need to reconstruct.
+    pub fn validate_acyclic(&self) -> Result<(), OrchestrationError> {
+        for edge in &self.edges {
+            if edge.from_node == edge.to_node {
+                return Err(OrchestrationError::WorkflowError(format!(
+                    "workflow graph contains a cycle: {} -> {}",
+                    edge.from_node, edge.to_node
+                )));
+            }
+        }
+
+        let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+        for edge in &self.edges {
+            children.entry(edge.from_node.as_str()).or_default().push(edge.to_node.as_str());
+        }
+
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut on_stack: HashSet<&str> = HashSet::new();
+        let mut path: Vec<&str> = Vec::new();
+
+        for start in self.nodes.keys() {
+            if visited.contains(start.as_str()) {
+                continue;
+            }
+            if let Some(cycle) =
+                Self::dfs_find_cycle(start.as_str(), &children, &mut visited, &mut on_stack, &mut path)
+            {
+                return Err(OrchestrationError::WorkflowError(format!(
+                    "workflow graph contains a cycle: {}",
+                    cycle.join(" -> ")
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// DFS step for [`Self::validate_acyclic`]. `on_stack` tracks nodes on
+    /// the current path so a re-visit of one of them is a genuine back
+    /// edge (a cycle), while `visited` tracks every node ever finished so
+    /// later DFS roots don't repeat work already done for an earlier
+    /// component.
+    fn dfs_find_cycle<'a>(
+        node_id: &'a str,
+        children: &HashMap<&'a str, Vec<&'a str>>,
+        visited: &mut HashSet<&'a str>,
+        on_stack: &mut HashSet<&'a str>,
+        path: &mut Vec<&'a str>,
+    ) -> Option<Vec<String>> {
+        visited.insert(node_id);
+        on_stack.insert(node_id);
+        path.push(node_id);
+
+        if let Some(kids) = children.get(node_id) {
+            for &child in kids {
+                if on_stack.contains(child) {
+                    let cycle_start = path
+                        .iter()
+                        .position(|&n| n == child)
+                        .expect("a node on the current path must appear in it");
+                    let mut cycle: Vec<String> = path[cycle_start..].iter().map(|s| s.to_string()).collect();
+                    cycle.push(child.to_string());
+                    return Some(cycle);
+                }
+                if !visited.contains(child) {
+                    if let Some(cycle) = Self::dfs_find_cycle(child, children, visited, on_stack, path) {
+                        return Some(cycle);
+                    }
+                }
+            }
+        }
+
+        path.pop();
+        on_stack.remove(node_id);
+        None
+    }
+
+    /// Computes a structural diff against `other`, for surfacing what an
+    /// operator changed after a manual workflow edit. Node and edge
+    /// structure lives here rather than on `ExecutionPlan` (which only
+    /// records execution order and resource/agent assignments), so this is
+    /// the natural place for it.
+    ///
+    /// Nodes are compared field-by-field via their serialized JSON
+    /// representation rather than `PartialEq`, since `WorkflowNode` embeds
+    /// `AgentConfig`/`AgentType` from [`crate::agent`], which don't derive
+    /// it.
+    pub fn diff(&self, other: &WorkflowGraph) -> PlanDiff {
+        let mut diff = PlanDiff::default();
+
+        for id in other.nodes.keys() {
+            if !self.nodes.contains_key(id) {
+                diff.added_nodes.push(id.clone());
+            }
+        }
+        for id in self.nodes.keys() {
+            if !other.nodes.contains_key(id) {
+                diff.removed_nodes.push(id.clone());
+            }
+        }
+
+        for (id, before) in &self.nodes {
+            if let Some(after) = other.nodes.get(id) {
+                let changed_fields = Self::changed_node_fields(before, after);
+                if !changed_fields.is_empty() {
+                    diff.modified_nodes.push(NodeModification { id: id.clone(), changed_fields });
+                }
+            }
+        }
+
+        let self_edges: HashSet<(String, String)> =
+            self.edges.iter().map(|edge| (edge.from_node.clone(), edge.to_node.clone())).collect();
+        let other_edges: HashSet<(String, String)> =
+            other.edges.iter().map(|edge| (edge.from_node.clone(), edge.to_node.clone())).collect();
+
+        diff.added_edges = other_edges.difference(&self_edges).cloned().collect();
+        diff.removed_edges = self_edges.difference(&other_edges).cloned().collect();
+
+        diff
+    }
+
+    /// Returns the names of top-level `WorkflowNode` fields that differ
+    /// between `before` and `after`.
+    fn changed_node_fields(before: &WorkflowNode, after: &WorkflowNode) -> Vec<String> {
+        let (Ok(serde_json::Value::Object(before_fields)), Ok(serde_json::Value::Object(after_fields))) =
+            (serde_json::to_value(before), serde_json::to_value(after))
+        else {
+            return Vec::new();
+        };
+
+        let mut field_names: Vec<String> = before_fields.keys().chain(after_fields.keys()).cloned().collect();
+        field_names.sort();
+        field_names.dedup();
+
+        field_names
+            .into_iter()
+            .filter(|field| before_fields.get(field) != after_fields.get(field))
+            .collect()
+    }
+
+    /// Applies a previously computed [`PlanDiff`] to this graph, producing
+    /// the updated graph, for incremental plan updates instead of replacing
+    /// the whole graph wholesale.
+    ///
+    /// `PlanDiff::modified_nodes` and `PlanDiff::added_nodes` only record
+    /// node ids (and, for modifications, which fields changed) — not the new
+    /// field values — so applying an add or a modification needs the new
+    /// `WorkflowNode` data supplied separately via `updated_nodes`.
+    pub fn apply_diff(
+        &self,
+        diff: &PlanDiff,
+        updated_nodes: &HashMap<NodeId, WorkflowNode>,
+    ) -> Result<WorkflowGraph, OrchestrationError> {
+        let mut result = self.clone();
+
+        for id in &diff.removed_nodes {
+            result.nodes.remove(id);
+        }
+
+        result.edges.retain(|edge| {
+            !diff.removed_edges.contains(&(edge.from_node.clone(), edge.to_node.clone()))
+        });
+
+        for id in diff.added_nodes.iter().chain(diff.modified_nodes.iter().map(|modification| &modification.id)) {
+            let node = updated_nodes.get(id).ok_or_else(|| {
+                OrchestrationError::WorkflowError(format!(
+                    "apply_diff: no updated node data supplied for '{id}'"
+                ))
+            })?;
+            result.nodes.insert(id.clone(), node.clone());
+        }
+
+        for (from_node, to_node) in &diff.added_edges {
+            result.edges.push(WorkflowEdge {
+                from_node: from_node.clone(),
+                to_node: to_node.clone(),
+                condition: None,
+                data_mapping: HashMap::new(),
+                priority: 0,
+            });
+        }
+
+        Ok(result)
+    }
+}
+
+/// Id of a [`WorkflowNode`] within a [`WorkflowGraph`].
+pub type NodeId = String;
+
+/// Structural diff between two [`WorkflowGraph`] versions, produced by
+/// [`WorkflowGraph::diff`] and logged by
+/// [`WorkflowOrchestrator::audit_plan_change`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlanDiff {
+    pub added_nodes: Vec<NodeId>,
+    pub removed_nodes: Vec<NodeId>,
+    pub modified_nodes: Vec<NodeModification>,
+    pub added_edges: Vec<(NodeId, NodeId)>,
+    pub removed_edges: Vec<(NodeId, NodeId)>,
+}
+
+impl PlanDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.modified_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+    }
+}
+
+/// A node whose fields changed between two [`WorkflowGraph`] versions, with
+/// the names of the fields that changed (not their values).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeModification {
+    pub id: NodeId,
+    pub changed_fields: Vec<String>,
+}
+
 /// Individual node in the workflow
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkflowNode {
@@ -100,6 +547,12 @@ pub struct WorkflowNode {
     pub timeout_seconds: Option<u64>,
     pub retry_policy: RetryPolicy,
     pub conditions: Vec<ExecutionCondition>,
+    /// Duration estimate learned from historical executions by
+    /// [`crate::NodeWeightLearner`], set via [`WorkflowGraph::apply_learned_weights`].
+    /// `None` until enough samples have been observed, in which case
+    /// [`WorkflowGraph::critical_path`] falls back to a default estimate.
+    #[serde(default)]
+    pub learned_duration_ms: Option<f64>,
 }
 
 /// Types of workflow nodes
@@ -271,6 +724,7 @@ pub struct ExecutionPlan {
     pub resource_allocation: HashMap<String, ResourceAllocation>,
     pub agent_assignments: HashMap<String, AgentAssignment>,
     pub checkpoint_nodes: Vec<String>,
+    pub rollback_status: RollbackStatus,
 }
 
 /// Resource allocation for workflow nodes
@@ -304,13 +758,22 @@ pub struct RuntimeState {
     pub checkpoints: Vec<WorkflowCheckpoint>,
 }
 
-/// Workflow checkpoint for recovery
+/// A snapshot of a workflow's execution progress, for recovery or for an
+/// explicit [`WorkflowOrchestrator::pause`]/[`WorkflowOrchestrator::resume`]
+/// round-trip across e.g. a maintenance window. Carries the whole
+/// `workflow_graph` (not just its id) so `resume` can reconstruct the
+/// `WorkflowInstance` even against an orchestrator that's since restarted
+/// and no longer has it in `workflows`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkflowCheckpoint {
     pub id: Uuid,
     pub created_at: DateTime<Utc>,
-    pub node_id: String,
-    pub state_snapshot: serde_json::Value,
+    pub workflow_id: Uuid,
+    pub workflow_graph: WorkflowGraph,
+    pub input_data: serde_json::Value,
+    pub completed_nodes: Vec<String>,
+    pub pending_nodes: Vec<String>,
+    pub node_outputs: HashMap<String, serde_json::Value>,
     pub recovery_point: bool,
 }
 
@@ -391,6 +854,8 @@ pub struct EventBus {
 pub enum WorkflowEvent {
     WorkflowCreated { workflow_id: Uuid },
     WorkflowStarted { workflow_id: Uuid },
+    WorkflowPaused { workflow_id: Uuid },
+    WorkflowResumed { workflow_id: Uuid },
     WorkflowCompleted { workflow_id: Uuid, success: bool },
     NodeStarted { workflow_id: Uuid, node_id: String },
     NodeCompleted { workflow_id: Uuid, node_id: String, success: bool },
@@ -433,6 +898,211 @@ impl WorkflowOrchestrator {
             dependency_resolver: Arc::new(DependencyResolver::new()),
             event_bus: Arc::new(EventBus::new()),
             metrics_collector: Arc::new(WorkflowMetricsCollector::new()),
+            active_sessions: Arc::new(RwLock::new(HashMap::new())),
+            compensations: Arc::new(RwLock::new(HashMap::new())),
+            version_registry: Arc::new(RwLock::new(HashMap::new())),
+        })
+    }
+
+    /// Publishes a new version of a workflow, keyed by its graph's `name`.
+    /// Multiple versions of the same workflow can be published side by
+    /// side; `execute_by_name` resolves the highest one matching a
+    /// `VersionReq`.
+    pub async fn publish(&self, definition: WorkflowDefinition) {
+        self.version_registry
+            .write()
+            .await
+            .entry(definition.graph.name.clone())
+            .or_default()
+            .push(definition);
+    }
+
+    /// Every version published for `name`, in publication order.
+    pub async fn list_versions(&self, name: &str) -> Vec<semver::Version> {
+        self.version_registry
+            .read()
+            .await
+            .get(name)
+            .map(|definitions| definitions.iter().map(|definition| definition.version.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Marks `version` of `name` as deprecated so `execute_by_name` stops
+    /// resolving to it. Has no effect if that version was never published.
+    pub async fn deprecate_version(&self, name: &str, version: &semver::Version) {
+        if let Some(definitions) = self.version_registry.write().await.get_mut(name) {
+            if let Some(definition) = definitions.iter_mut().find(|definition| &definition.version == version) {
+                definition.deprecated = true;
+            }
+        }
+    }
+
+    /// Resolves the highest non-deprecated published version of `name`
+    /// matching `version_req`, then deploys and starts its graph.
+    pub async fn execute_by_name(
+        &self,
+        name: &str,
+        version_req: &semver::VersionReq,
+        input_data: serde_json::Value,
+    ) -> Result<Uuid, OrchestrationError> {
+        let resolved = self
+            .version_registry
+            .read()
+            .await
+            .get(name)
+            .and_then(|definitions| {
+                definitions
+                    .iter()
+                    .filter(|definition| !definition.deprecated && version_req.matches(&definition.version))
+                    .max_by(|a, b| a.version.cmp(&b.version))
+                    .cloned()
+            })
+            .ok_or_else(|| {
+                OrchestrationError::WorkflowError(format!(
+                    "no published version of workflow '{name}' satisfies {version_req}"
+                ))
+            })?;
+
+        let workflow_id = self.deploy_workflow(resolved.graph, input_data).await?;
+        self.start_workflow(workflow_id).await?;
+        Ok(workflow_id)
+    }
+
+    /// Registers a compensating action to undo `step_id`'s effects if the
+    /// workflow later fails. Compensations for a workflow are undone in
+    /// reverse registration order by `rollback`.
+    pub async fn register_compensation(
+        &self,
+        workflow_id: Uuid,
+        step_id: String,
+        undo_task: ExecutionTask,
+    ) {
+        self.compensations
+            .write()
+            .await
+            .entry(workflow_id)
+            .or_default()
+            .push(CompensatingAction { step_id, undo_task });
+    }
+
+    /// Undoes a failed workflow's completed steps by executing their
+    /// registered compensating actions in reverse order. Steps without a
+    /// registered compensation are left as-is.
+    pub async fn rollback(&self, workflow_id: Uuid) -> Result<(), OrchestrationError> {
+        let actions = self.compensations.read().await.get(&workflow_id).cloned().unwrap_or_default();
+
+        if actions.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(workflow) = self.workflows.write().await.get_mut(&workflow_id) {
+            workflow.execution_plan.rollback_status = RollbackStatus::InProgress;
+        }
+
+        for action in actions.iter().rev() {
+            if let Err(e) = self.execution_engine.schedule_task(action.undo_task.clone()).await {
+                if let Some(workflow) = self.workflows.write().await.get_mut(&workflow_id) {
+                    workflow.execution_plan.rollback_status = RollbackStatus::Failed;
+                }
+                return Err(OrchestrationError::RecoveryError(format!(
+                    "compensation for step {} failed: {e}",
+                    action.step_id
+                )));
+            }
+
+            if let Some(workflow) = self.workflows.write().await.get_mut(&workflow_id) {
+                workflow.runtime_state.completed_nodes.remove(&action.step_id);
+            }
+        }
+
+        if let Some(workflow) = self.workflows.write().await.get_mut(&workflow_id) {
+            workflow.execution_plan.rollback_status = RollbackStatus::Completed;
+        }
+
+        Ok(())
+    }
+
+    /// Records a manual (or automated) workflow modification by diffing
+    /// `before` against `after` and writing the result to the audit trail.
+    /// This crate has no standalone audit-log service to write through, so
+    /// the diff is logged via `tracing`, the same mechanism every other
+    /// audit-relevant event in this file goes through.
+    pub fn audit_plan_change(&self, before: &WorkflowGraph, after: &WorkflowGraph) -> PlanDiff {
+        let diff = before.diff(after);
+
+        tracing::info!(
+            orchestrator_id = %self.id,
+            workflow_id = %after.id,
+            added_nodes = ?diff.added_nodes,
+            removed_nodes = ?diff.removed_nodes,
+            modified_nodes = ?diff.modified_nodes.iter().map(|m| m.id.clone()).collect::<Vec<_>>(),
+            added_edges = ?diff.added_edges,
+            removed_edges = ?diff.removed_edges,
+            "Workflow plan changed"
+        );
+
+        diff
+    }
+
+    /// Protocol versions this orchestrator can speak to agents, newest
+    /// first. `register_agent` negotiates the highest one an agent also
+    /// offers.
+    const SUPPORTED_PROTOCOLS: &'static [&'static str] =
+        &["agent-protocol/2.0", "agent-protocol/1.1", "agent-protocol/1.0"];
+
+    /// Capability versions this build knows how to drive for a given
+    /// capability name. A capability whose version isn't listed here is
+    /// dropped from `HandshakeAck::accepted_capabilities` rather than
+    /// silently trusted.
+    fn compatible_capability_versions(name: &str) -> &'static [&'static str] {
+        match name {
+            "text_processing" => &["1.0", "1.1"],
+            "code_generation" => &["1.0", "1.1", "2.0"],
+            "data_analysis" => &["1.0"],
+            _ => &[],
+        }
+    }
+
+    /// Handles an agent's first-connection capability negotiation handshake:
+    /// negotiates the highest protocol version both sides support, rejects
+    /// capability versions outside the orchestrator's compatibility matrix,
+    /// and records the resulting session in `active_sessions`.
+    pub async fn register_agent(
+        &self,
+        handshake: AgentHandshake,
+    ) -> Result<HandshakeAck, OrchestrationError> {
+        let assigned_protocol = Self::SUPPORTED_PROTOCOLS
+            .iter()
+            .find(|protocol| handshake.supported_protocols.iter().any(|offered| offered == *protocol))
+            .map(|protocol| protocol.to_string())
+            .ok_or_else(|| {
+                OrchestrationError::WorkflowError(format!(
+                    "agent {} offered no protocol version this orchestrator supports (offered: {:?}, supported: {:?})",
+                    handshake.agent_id, handshake.supported_protocols, Self::SUPPORTED_PROTOCOLS
+                ))
+            })?;
+
+        let accepted_capabilities: Vec<VersionedCapability> = handshake
+            .capabilities
+            .iter()
+            .filter(|capability| {
+                Self::compatible_capability_versions(&capability.name).contains(&capability.version.as_str())
+            })
+            .cloned()
+            .collect();
+
+        let session_id = Uuid::new_v4();
+        self.active_sessions.write().await.insert(session_id, AgentSession {
+            agent_id: handshake.agent_id,
+            accepted_capabilities: accepted_capabilities.clone(),
+            assigned_protocol: assigned_protocol.clone(),
+            established_at: Utc::now(),
+        });
+
+        Ok(HandshakeAck {
+            session_id,
+            accepted_capabilities,
+            assigned_protocol,
         })
     }
 
@@ -538,6 +1208,116 @@ impl WorkflowOrchestrator {
         Ok(())
     }
 
+    /// Pauses a running workflow, snapshotting its execution progress into
+    /// a [`WorkflowCheckpoint`] that [`Self::resume`] can later continue
+    /// from exactly the paused boundary.
+    ///
+    /// This crate schedules ready nodes into `execution_engine`'s task
+    /// queue rather than running them on a dedicated worker loop, so
+    /// there's no in-flight "atomic step" for a running `TaskExecutor` to
+    /// finish before pausing -- the queue simply isn't drained any further
+    /// once nothing calls `execute_workflow_nodes` again for this workflow.
+    /// Pausing freezes `runtime_state` at whatever completed/pending split
+    /// it's currently in, which is the practical equivalent under this
+    /// scheduling model.
+    pub async fn pause(&self, workflow_id: Uuid) -> Result<WorkflowCheckpoint, OrchestrationError> {
+        let mut workflows = self.workflows.write().await;
+        let workflow = workflows.get_mut(&workflow_id)
+            .ok_or_else(|| OrchestrationError::WorkflowError(format!("Workflow not found: {}", workflow_id)))?;
+
+        workflow.status = WorkflowStatus::Paused { reason: "paused for maintenance".to_string() };
+
+        let checkpoint = WorkflowCheckpoint {
+            id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            workflow_id,
+            workflow_graph: workflow.workflow_graph.clone(),
+            input_data: workflow.input_data.clone(),
+            completed_nodes: workflow.runtime_state.completed_nodes.iter().cloned().collect(),
+            pending_nodes: workflow.runtime_state.pending_nodes.iter().cloned().collect(),
+            node_outputs: workflow.runtime_state.node_outputs.clone(),
+            recovery_point: true,
+        };
+        workflow.runtime_state.checkpoints.push(checkpoint.clone());
+
+        drop(workflows);
+
+        self.event_bus.emit(WorkflowEvent::WorkflowPaused { workflow_id }).await?;
+
+        tracing::info!(
+            orchestrator_id = %self.id,
+            workflow_id = %workflow_id,
+            completed = checkpoint.completed_nodes.len(),
+            pending = checkpoint.pending_nodes.len(),
+            "Workflow paused"
+        );
+
+        Ok(checkpoint)
+    }
+
+    /// Resumes a workflow from a checkpoint produced by [`Self::pause`],
+    /// recomputing its `ExecutionPlan` from `checkpoint.workflow_graph` and
+    /// re-scheduling exactly the nodes that were still pending. If this
+    /// orchestrator no longer has the workflow tracked (e.g. it restarted
+    /// since the checkpoint was taken), it's re-registered from the
+    /// checkpoint first.
+    pub async fn resume(&self, checkpoint: WorkflowCheckpoint) -> Result<(), OrchestrationError> {
+        let execution_plan = self.create_execution_plan(&checkpoint.workflow_graph).await?;
+
+        {
+            let mut workflows = self.workflows.write().await;
+            let workflow = workflows.entry(checkpoint.workflow_id).or_insert_with(|| WorkflowInstance {
+                id: checkpoint.workflow_id,
+                workflow_graph: checkpoint.workflow_graph.clone(),
+                status: WorkflowStatus::Created,
+                created_at: checkpoint.created_at,
+                started_at: None,
+                completed_at: None,
+                input_data: checkpoint.input_data.clone(),
+                output_data: None,
+                execution_plan: execution_plan.clone(),
+                runtime_state: RuntimeState {
+                    completed_nodes: HashSet::new(),
+                    failed_nodes: HashMap::new(),
+                    running_nodes: HashSet::new(),
+                    pending_nodes: HashSet::new(),
+                    node_outputs: HashMap::new(),
+                    execution_context: HashMap::new(),
+                    checkpoints: Vec::new(),
+                },
+                assigned_agents: HashMap::new(),
+                performance_metrics: WorkflowPerformanceMetrics {
+                    total_execution_time: Duration::from_secs(0),
+                    agent_utilization: HashMap::new(),
+                    resource_efficiency: 0.0,
+                    throughput_nodes_per_second: 0.0,
+                    error_rate: 0.0,
+                    quality_score: 0.0,
+                    cost_efficiency: 0.0,
+                },
+            });
+
+            workflow.execution_plan = execution_plan;
+            workflow.status = WorkflowStatus::Running { current_nodes: Vec::new() };
+            workflow.started_at.get_or_insert_with(Utc::now);
+            workflow.runtime_state.completed_nodes = checkpoint.completed_nodes.iter().cloned().collect();
+            workflow.runtime_state.pending_nodes = checkpoint.pending_nodes.iter().cloned().collect();
+            workflow.runtime_state.node_outputs = checkpoint.node_outputs.clone();
+        }
+
+        self.event_bus.emit(WorkflowEvent::WorkflowResumed { workflow_id: checkpoint.workflow_id }).await?;
+
+        self.execute_workflow_nodes(checkpoint.workflow_id, checkpoint.pending_nodes.clone()).await?;
+
+        tracing::info!(
+            orchestrator_id = %self.id,
+            workflow_id = %checkpoint.workflow_id,
+            "Workflow resumed"
+        );
+
+        Ok(())
+    }
+
     /// Execute specific workflow nodes
     async fn execute_workflow_nodes(
         &self,
@@ -557,23 +1337,31 @@ impl WorkflowOrchestrator {
         workflow_id: Uuid,
         node_id: &str,
     ) -> Result<ExecutionTask, OrchestrationError> {
-        let workflows = self.workflows.read().await;
-        let workflow = workflows.get(&workflow_id)
-            .ok_or_else(|| OrchestrationError::WorkflowError(format!("Workflow not found: {}", workflow_id)))?;
-
-        let node = workflow.workflow_graph.nodes.get(node_id)
-            .ok_or_else(|| OrchestrationError::WorkflowError(format!("Node not found: {}", node_id)))?;
+        // Cloned out of the workflow and the read guard dropped before
+        // `assign_agent_to_node` runs -- it takes `self.workflows.write()`
+        // to record the assignment, which would deadlock against a read
+        // guard on the same `RwLock` still held on this task.
+        let (agent_requirements, node_type, timeout_seconds) = {
+            let workflows = self.workflows.read().await;
+            let workflow = workflows.get(&workflow_id)
+                .ok_or_else(|| OrchestrationError::WorkflowError(format!("Workflow not found: {}", workflow_id)))?;
+
+            let node = workflow.workflow_graph.nodes.get(node_id)
+                .ok_or_else(|| OrchestrationError::WorkflowError(format!("Node not found: {}", node_id)))?;
+
+            (node.agent_requirements.clone(), node.node_type.clone(), node.timeout_seconds)
+        };
 
         // Assign agent for this node
-        let agent_id = self.assign_agent_to_node(workflow_id, node_id, &node.agent_requirements).await?;
+        let agent_id = self.assign_agent_to_node(workflow_id, node_id, &agent_requirements).await?;
 
-        let task_definition = match &node.node_type {
+        let task_definition = match &node_type {
             WorkflowNodeType::Task { task_definition, .. } => task_definition.clone(),
             _ => format!("Execute node: {}", node_id),
         };
 
         let timeout = Duration::from_secs(
-            node.timeout_seconds.unwrap_or(self.config.default_timeout_seconds)
+            timeout_seconds.unwrap_or(self.config.default_timeout_seconds)
         );
 
         Ok(ExecutionTask {
@@ -717,11 +1505,7 @@ impl WorkflowOrchestrator {
     /// Validate a workflow before deployment
     async fn validate_workflow(&self, workflow: &WorkflowGraph) -> Result<(), OrchestrationError> {
         // Check for cycles
-        if self.has_cycles(workflow)? {
-            return Err(OrchestrationError::WorkflowError(
-                "Workflow contains cycles".to_string()
-            ));
-        }
+        workflow.validate_acyclic()?;
 
         // Validate node references
         for edge in &workflow.edges {
@@ -752,31 +1536,6 @@ impl WorkflowOrchestrator {
         Ok(())
     }
 
-    /// Check if workflow has cycles
-    fn has_cycles(&self, workflow: &WorkflowGraph) -> Result<bool, OrchestrationError> {
-        let mut graph = Graph::new();
-        let mut node_indices = HashMap::new();
-
-        // Add nodes
-        for node_id in workflow.nodes.keys() {
-            let index = graph.add_node(node_id.clone());
-            node_indices.insert(node_id.clone(), index);
-        }
-
-        // Add edges
-        for edge in &workflow.edges {
-            if let (Some(&from_idx), Some(&to_idx)) = (
-                node_indices.get(&edge.from_node),
-                node_indices.get(&edge.to_node)
-            ) {
-                graph.add_edge(from_idx, to_idx, ());
-            }
-        }
-
-        // Check for cycles using DFS
-        Ok(petgraph::algo::is_cyclic_directed(&graph))
-    }
-
     /// Create execution plan for a workflow
     async fn create_execution_plan(&self, workflow: &WorkflowGraph) -> Result<ExecutionPlan, OrchestrationError> {
         // Topological sort to determine execution order
@@ -809,6 +1568,7 @@ impl WorkflowOrchestrator {
             resource_allocation,
             agent_assignments,
             checkpoint_nodes,
+            rollback_status: RollbackStatus::NotNeeded,
         })
     }
 
@@ -1056,6 +1816,8 @@ impl EventBus {
         let event_type = match &event {
             WorkflowEvent::WorkflowCreated { .. } => "workflow_created",
             WorkflowEvent::WorkflowStarted { .. } => "workflow_started",
+            WorkflowEvent::WorkflowPaused { .. } => "workflow_paused",
+            WorkflowEvent::WorkflowResumed { .. } => "workflow_resumed",
             WorkflowEvent::WorkflowCompleted { .. } => "workflow_completed",
             WorkflowEvent::NodeStarted { .. } => "node_started",
             WorkflowEvent::NodeCompleted { .. } => "node_completed",
@@ -1133,3 +1895,678 @@ impl Default for RetryPolicy {
         }
     }
 }
+
+#[cfg(test)]
+mod handshake_tests {
+    use super::*;
+
+    async fn orchestrator() -> WorkflowOrchestrator {
+        WorkflowOrchestrator::new(OrchestratorConfig::default()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn rejects_an_agent_with_an_unsupported_protocol_version() {
+        let orchestrator = orchestrator().await;
+
+        let handshake = AgentHandshake {
+            agent_id: Uuid::new_v4(),
+            capabilities: vec![],
+            supported_protocols: vec!["agent-protocol/0.1".to_string()],
+            max_payload_bytes: 1024 * 1024,
+        };
+
+        let result = orchestrator.register_agent(handshake).await;
+        assert!(result.is_err(), "handshake with no mutually supported protocol should be rejected");
+        assert!(orchestrator.active_sessions.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn accepts_a_compatible_agent_and_drops_unknown_capability_versions() {
+        let orchestrator = orchestrator().await;
+
+        let handshake = AgentHandshake {
+            agent_id: Uuid::new_v4(),
+            capabilities: vec![
+                VersionedCapability { name: "text_processing".to_string(), version: "1.1".to_string() },
+                VersionedCapability { name: "text_processing".to_string(), version: "99.0".to_string() },
+            ],
+            supported_protocols: vec!["agent-protocol/1.0".to_string(), "agent-protocol/2.0".to_string()],
+            max_payload_bytes: 1024 * 1024,
+        };
+
+        let ack = orchestrator.register_agent(handshake).await.expect("should be accepted");
+        assert_eq!(ack.assigned_protocol, "agent-protocol/2.0");
+        assert_eq!(ack.accepted_capabilities.len(), 1);
+        assert_eq!(ack.accepted_capabilities[0].version, "1.1");
+        assert!(orchestrator.active_sessions.read().await.contains_key(&ack.session_id));
+    }
+}
+
+#[cfg(test)]
+mod rollback_tests {
+    use super::*;
+
+    async fn orchestrator() -> WorkflowOrchestrator {
+        WorkflowOrchestrator::new(OrchestratorConfig::default()).await.unwrap()
+    }
+
+    fn undo_task(workflow_id: Uuid, step_id: &str) -> ExecutionTask {
+        ExecutionTask {
+            id: Uuid::new_v4(),
+            workflow_id,
+            node_id: step_id.to_string(),
+            agent_id: Uuid::new_v4(),
+            task_definition: format!("undo {step_id}"),
+            priority: 5,
+            timeout: Duration::from_secs(30),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn rollback_after_a_mid_workflow_failure_runs_registered_compensations_in_reverse() {
+        let orchestrator = orchestrator().await;
+        let workflow_id = Uuid::new_v4();
+
+        let mut completed_nodes = HashSet::new();
+        completed_nodes.insert("step-1".to_string());
+        completed_nodes.insert("step-2".to_string());
+
+        let mut failed_nodes = HashMap::new();
+        failed_nodes.insert("step-3".to_string(), "step 3 failed".to_string());
+
+        orchestrator.workflows.write().await.insert(workflow_id, WorkflowInstance {
+            id: workflow_id,
+            workflow_graph: WorkflowGraph {
+                id: workflow_id,
+                name: "5-step-workflow".to_string(),
+                description: String::new(),
+                version: "1.0".to_string(),
+                nodes: HashMap::new(),
+                edges: Vec::new(),
+                input_schema: serde_json::Value::Null,
+                output_schema: serde_json::Value::Null,
+                constraints: Vec::new(),
+                metadata: HashMap::new(),
+            },
+            status: WorkflowStatus::Failed { error: "step 3 failed".to_string() },
+            created_at: Utc::now(),
+            started_at: Some(Utc::now()),
+            completed_at: None,
+            input_data: serde_json::Value::Null,
+            output_data: None,
+            execution_plan: ExecutionPlan {
+                execution_order: Vec::new(),
+                critical_path: Vec::new(),
+                estimated_duration: Duration::from_secs(0),
+                resource_allocation: HashMap::new(),
+                agent_assignments: HashMap::new(),
+                checkpoint_nodes: Vec::new(),
+                rollback_status: RollbackStatus::NotNeeded,
+            },
+            runtime_state: RuntimeState {
+                completed_nodes,
+                failed_nodes,
+                running_nodes: HashSet::new(),
+                pending_nodes: HashSet::new(),
+                node_outputs: HashMap::new(),
+                execution_context: HashMap::new(),
+                checkpoints: Vec::new(),
+            },
+            assigned_agents: HashMap::new(),
+            performance_metrics: WorkflowPerformanceMetrics {
+                total_execution_time: Duration::from_secs(0),
+                agent_utilization: HashMap::new(),
+                resource_efficiency: 0.0,
+                throughput_nodes_per_second: 0.0,
+                error_rate: 0.0,
+                quality_score: 0.0,
+                cost_efficiency: 0.0,
+            },
+        });
+
+        // Only steps 1 and 2 completed before step 3 failed, so only they
+        // have compensations registered.
+        orchestrator.register_compensation(workflow_id, "step-1".to_string(), undo_task(workflow_id, "step-1")).await;
+        orchestrator.register_compensation(workflow_id, "step-2".to_string(), undo_task(workflow_id, "step-2")).await;
+
+        orchestrator.rollback(workflow_id).await.expect("rollback should succeed");
+
+        let executed_order: Vec<String> = {
+            let mut queue = orchestrator.execution_engine.task_queue.write().await;
+            let mut order = Vec::new();
+            while let Some((task, _)) = queue.pop() {
+                order.push(task.node_id);
+            }
+            order
+        };
+        assert_eq!(executed_order, vec!["step-2".to_string(), "step-1".to_string()]);
+
+        let workflows = orchestrator.workflows.read().await;
+        let workflow = &workflows[&workflow_id];
+        assert_eq!(workflow.execution_plan.rollback_status, RollbackStatus::Completed);
+        assert!(!workflow.runtime_state.completed_nodes.contains("step-1"));
+        assert!(!workflow.runtime_state.completed_nodes.contains("step-2"));
+    }
+
+    #[tokio::test]
+    async fn rollback_with_no_registered_compensations_is_a_no_op() {
+        let orchestrator = orchestrator().await;
+        let workflow_id = Uuid::new_v4();
+
+        orchestrator.rollback(workflow_id).await.expect("rollback with nothing to undo should succeed");
+        assert!(orchestrator.workflows.read().await.get(&workflow_id).is_none());
+    }
+}
+
+#[cfg(test)]
+mod version_registry_tests {
+    use super::*;
+
+    async fn orchestrator() -> WorkflowOrchestrator {
+        WorkflowOrchestrator::new(OrchestratorConfig::default()).await.unwrap()
+    }
+
+    fn definition(version: &str) -> WorkflowDefinition {
+        WorkflowDefinition {
+            id: Uuid::new_v4(),
+            version: semver::Version::parse(version).unwrap(),
+            graph: WorkflowGraph {
+                id: Uuid::new_v4(),
+                name: "onboarding".to_string(),
+                description: String::new(),
+                version: "1.0".to_string(),
+                nodes: HashMap::new(),
+                edges: Vec::new(),
+                input_schema: serde_json::Value::Null,
+                output_schema: serde_json::Value::Null,
+                constraints: Vec::new(),
+                metadata: HashMap::new(),
+            },
+            changelog: vec![format!("release {version}")],
+            deprecated: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_by_name_resolves_the_highest_version_matching_the_requirement() {
+        let orchestrator = orchestrator().await;
+        orchestrator.publish(definition("1.0.0")).await;
+        orchestrator.publish(definition("1.5.0")).await;
+        orchestrator.publish(definition("2.0.0")).await;
+
+        let version_req = semver::VersionReq::parse(">=1.2, <2.0").unwrap();
+        let workflow_id = orchestrator
+            .execute_by_name("onboarding", &version_req, serde_json::Value::Null)
+            .await
+            .expect("a matching version should resolve");
+
+        let workflows = orchestrator.workflows.read().await;
+        assert_eq!(workflows[&workflow_id].workflow_graph.name, "onboarding");
+
+        let mut versions = orchestrator.list_versions("onboarding").await;
+        versions.sort();
+        assert_eq!(
+            versions,
+            vec![
+                semver::Version::parse("1.0.0").unwrap(),
+                semver::Version::parse("1.5.0").unwrap(),
+                semver::Version::parse("2.0.0").unwrap(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_by_name_skips_deprecated_versions() {
+        let orchestrator = orchestrator().await;
+        orchestrator.publish(definition("1.0.0")).await;
+        orchestrator.publish(definition("1.5.0")).await;
+        orchestrator.deprecate_version("onboarding", &semver::Version::parse("1.5.0").unwrap()).await;
+
+        let version_req = semver::VersionReq::parse(">=1.0, <2.0").unwrap();
+        let workflow_id = orchestrator
+            .execute_by_name("onboarding", &version_req, serde_json::Value::Null)
+            .await
+            .expect("a non-deprecated matching version should resolve");
+
+        let workflows = orchestrator.workflows.read().await;
+        assert_eq!(workflows[&workflow_id].execution_plan.execution_order.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn execute_by_name_errors_when_no_published_version_matches() {
+        let orchestrator = orchestrator().await;
+        orchestrator.publish(definition("1.0.0")).await;
+
+        let version_req = semver::VersionReq::parse(">=2.0").unwrap();
+        let result = orchestrator.execute_by_name("onboarding", &version_req, serde_json::Value::Null).await;
+
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(test)]
+mod plan_diff_tests {
+    use super::*;
+
+    fn node(id: &str) -> WorkflowNode {
+        WorkflowNode {
+            id: id.to_string(),
+            name: id.to_string(),
+            node_type: WorkflowNodeType::Task { task_definition: "noop".to_string(), parallel_execution: false },
+            agent_requirements: AgentRequirements {
+                agent_type: None,
+                capabilities: Vec::new(),
+                min_performance_score: 0.0,
+                preferred_agents: Vec::new(),
+                exclusion_list: Vec::new(),
+                resource_requirements: ResourceRequirements {
+                    cpu_cores: 1.0,
+                    memory_mb: 512,
+                    network_bandwidth_mbps: 0,
+                    storage_mb: 0,
+                    gpu_units: None,
+                },
+            },
+            input_mapping: HashMap::new(),
+            output_mapping: HashMap::new(),
+            timeout_seconds: None,
+            retry_policy: RetryPolicy {
+                max_attempts: 1,
+                initial_delay_ms: 0,
+                backoff_multiplier: 1.0,
+                max_delay_ms: 0,
+                retry_on_errors: Vec::new(),
+            },
+            conditions: Vec::new(),
+            learned_duration_ms: None,
+        }
+    }
+
+    fn edge(from_node: &str, to_node: &str) -> WorkflowEdge {
+        WorkflowEdge {
+            from_node: from_node.to_string(),
+            to_node: to_node.to_string(),
+            condition: None,
+            data_mapping: HashMap::new(),
+            priority: 0,
+        }
+    }
+
+    fn graph(nodes: Vec<WorkflowNode>, edges: Vec<WorkflowEdge>) -> WorkflowGraph {
+        WorkflowGraph {
+            id: Uuid::new_v4(),
+            name: "test-graph".to_string(),
+            description: String::new(),
+            version: "1.0".to_string(),
+            nodes: nodes.into_iter().map(|n| (n.id.clone(), n)).collect(),
+            edges,
+            input_schema: serde_json::Value::Null,
+            output_schema: serde_json::Value::Null,
+            constraints: Vec::new(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn diff_reports_an_added_node_and_a_removed_edge() {
+        let before = graph(
+            vec![node("step-1"), node("step-2")],
+            vec![edge("step-1", "step-2")],
+        );
+        let after = graph(
+            vec![node("step-1"), node("step-2"), node("step-3")],
+            vec![],
+        );
+
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.added_nodes, vec!["step-3".to_string()]);
+        assert!(diff.removed_nodes.is_empty());
+        assert!(diff.modified_nodes.is_empty());
+        assert!(diff.added_edges.is_empty());
+        assert_eq!(diff.removed_edges, vec![("step-1".to_string(), "step-2".to_string())]);
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn apply_diff_reconstructs_the_after_graph() {
+        let before = graph(
+            vec![node("step-1"), node("step-2")],
+            vec![edge("step-1", "step-2")],
+        );
+        let after = graph(
+            vec![node("step-1"), node("step-2"), node("step-3")],
+            vec![],
+        );
+
+        let diff = before.diff(&after);
+        let mut updated_nodes = HashMap::new();
+        updated_nodes.insert("step-3".to_string(), node("step-3"));
+
+        let rebuilt = before.apply_diff(&diff, &updated_nodes).expect("apply_diff should succeed");
+
+        assert_eq!(rebuilt.nodes.len(), 3);
+        assert!(rebuilt.nodes.contains_key("step-3"));
+        assert!(rebuilt.edges.is_empty());
+    }
+
+    #[tokio::test]
+    async fn audit_plan_change_returns_the_same_diff_as_workflow_graph_diff() {
+        let orchestrator = WorkflowOrchestrator::new(OrchestratorConfig::default()).await.unwrap();
+        let before = graph(vec![node("step-1")], vec![edge("step-1", "step-1")]);
+        let after = graph(vec![node("step-1"), node("step-2")], vec![]);
+
+        let diff = orchestrator.audit_plan_change(&before, &after);
+
+        assert_eq!(diff.added_nodes, vec!["step-2".to_string()]);
+        assert_eq!(diff.removed_edges, vec![("step-1".to_string(), "step-1".to_string())]);
+    }
+}
+
+#[cfg(test)]
+mod cycle_detection_tests {
+    use super::*;
+
+    fn node(id: &str) -> WorkflowNode {
+        WorkflowNode {
+            id: id.to_string(),
+            name: id.to_string(),
+            node_type: WorkflowNodeType::Task { task_definition: "noop".to_string(), parallel_execution: false },
+            agent_requirements: AgentRequirements {
+                agent_type: None,
+                capabilities: Vec::new(),
+                min_performance_score: 0.0,
+                preferred_agents: Vec::new(),
+                exclusion_list: Vec::new(),
+                resource_requirements: ResourceRequirements {
+                    cpu_cores: 1.0,
+                    memory_mb: 512,
+                    network_bandwidth_mbps: 0,
+                    storage_mb: 0,
+                    gpu_units: None,
+                },
+            },
+            input_mapping: HashMap::new(),
+            output_mapping: HashMap::new(),
+            timeout_seconds: None,
+            retry_policy: RetryPolicy {
+                max_attempts: 1,
+                initial_delay_ms: 0,
+                backoff_multiplier: 1.0,
+                max_delay_ms: 0,
+                retry_on_errors: Vec::new(),
+            },
+            conditions: Vec::new(),
+            learned_duration_ms: None,
+        }
+    }
+
+    fn edge(from_node: &str, to_node: &str) -> WorkflowEdge {
+        WorkflowEdge {
+            from_node: from_node.to_string(),
+            to_node: to_node.to_string(),
+            condition: None,
+            data_mapping: HashMap::new(),
+            priority: 0,
+        }
+    }
+
+    fn graph(nodes: Vec<WorkflowNode>, edges: Vec<WorkflowEdge>) -> WorkflowGraph {
+        WorkflowGraph {
+            id: Uuid::new_v4(),
+            name: "test-graph".to_string(),
+            description: String::new(),
+            version: "1.0".to_string(),
+            nodes: nodes.into_iter().map(|n| (n.id.clone(), n)).collect(),
+            edges,
+            input_schema: serde_json::Value::Null,
+            output_schema: serde_json::Value::Null,
+            constraints: Vec::new(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// The DFS root order depends on `HashMap<String, WorkflowNode>`
+    /// iteration order, so the reported path may start at any node on the
+    /// ring -- e.g. `c -> d -> e -> a -> b -> c` is just as valid a report
+    /// of the same cycle as `a -> b -> c -> d -> e -> a`. Asserting on
+    /// every individual edge, rather than one fixed starting point, keeps
+    /// the test honest about that without being flaky.
+    fn assert_message_reports_every_edge(message: &str, edges: &[(&str, &str)]) {
+        for (from, to) in edges {
+            assert!(
+                message.contains(&format!("{from} -> {to}")),
+                "expected message to mention edge {from} -> {to}, message was: {message}"
+            );
+        }
+    }
+
+    #[test]
+    fn a_simple_three_node_cycle_is_rejected_with_its_path() {
+        let workflow = graph(
+            vec![node("a"), node("b")],
+            vec![edge("a", "b"), edge("b", "a")],
+        );
+
+        let err = workflow.validate_acyclic().expect_err("a -> b -> a should be rejected");
+        let OrchestrationError::WorkflowError(message) = err else {
+            panic!("expected a WorkflowError");
+        };
+        assert_message_reports_every_edge(&message, &[("a", "b"), ("b", "a")]);
+    }
+
+    #[test]
+    fn a_longer_five_node_cycle_is_rejected_with_its_path() {
+        let workflow = graph(
+            vec![node("a"), node("b"), node("c"), node("d"), node("e")],
+            vec![
+                edge("a", "b"),
+                edge("b", "c"),
+                edge("c", "d"),
+                edge("d", "e"),
+                edge("e", "a"),
+            ],
+        );
+
+        let err = workflow.validate_acyclic().expect_err("a five-node ring should be rejected");
+        let OrchestrationError::WorkflowError(message) = err else {
+            panic!("expected a WorkflowError");
+        };
+        assert_message_reports_every_edge(
+            &message,
+            &[("a", "b"), ("b", "c"), ("c", "d"), ("d", "e"), ("e", "a")],
+        );
+    }
+
+    #[test]
+    fn a_diamond_shaped_dependency_is_accepted() {
+        let workflow = graph(
+            vec![node("a"), node("b"), node("c"), node("d")],
+            vec![edge("a", "b"), edge("a", "c"), edge("b", "d"), edge("c", "d")],
+        );
+
+        workflow.validate_acyclic().expect("a diamond dependency has no cycle");
+    }
+
+    #[test]
+    fn a_self_referencing_node_is_rejected() {
+        let workflow = graph(vec![node("a")], vec![edge("a", "a")]);
+
+        let err = workflow.validate_acyclic().expect_err("a self-loop should be rejected");
+        let OrchestrationError::WorkflowError(message) = err else {
+            panic!("expected a WorkflowError");
+        };
+        assert!(message.contains("a -> a"), "message was: {message}");
+    }
+
+    #[test]
+    fn a_cycle_in_one_disconnected_component_is_still_found() {
+        let workflow = graph(
+            vec![node("a"), node("b"), node("x"), node("y")],
+            vec![edge("a", "b"), edge("x", "y"), edge("y", "x")],
+        );
+
+        let err = workflow
+            .validate_acyclic()
+            .expect_err("the disconnected x -> y -> x component should still be rejected");
+        let OrchestrationError::WorkflowError(message) = err else {
+            panic!("expected a WorkflowError");
+        };
+        assert_message_reports_every_edge(&message, &[("x", "y"), ("y", "x")]);
+    }
+
+    #[tokio::test]
+    async fn deploy_workflow_rejects_a_cyclic_graph() {
+        let orchestrator = WorkflowOrchestrator::new(OrchestratorConfig::default()).await.unwrap();
+        let workflow = graph(vec![node("a"), node("b")], vec![edge("a", "b"), edge("b", "a")]);
+
+        let result = orchestrator.deploy_workflow(workflow, serde_json::Value::Null).await;
+        assert!(result.is_err(), "a cyclic graph should be rejected at deploy time");
+    }
+}
+
+#[cfg(test)]
+mod pause_resume_tests {
+    use super::*;
+
+    fn node(id: &str) -> WorkflowNode {
+        WorkflowNode {
+            id: id.to_string(),
+            name: id.to_string(),
+            node_type: WorkflowNodeType::Task { task_definition: "noop".to_string(), parallel_execution: false },
+            agent_requirements: AgentRequirements {
+                agent_type: None,
+                capabilities: Vec::new(),
+                min_performance_score: 0.0,
+                preferred_agents: Vec::new(),
+                exclusion_list: Vec::new(),
+                resource_requirements: ResourceRequirements {
+                    cpu_cores: 0.1,
+                    memory_mb: 32,
+                    network_bandwidth_mbps: 0,
+                    storage_mb: 0,
+                    gpu_units: None,
+                },
+            },
+            input_mapping: HashMap::new(),
+            output_mapping: HashMap::new(),
+            timeout_seconds: None,
+            retry_policy: RetryPolicy {
+                max_attempts: 1,
+                initial_delay_ms: 0,
+                backoff_multiplier: 1.0,
+                max_delay_ms: 0,
+                retry_on_errors: Vec::new(),
+            },
+            conditions: Vec::new(),
+            learned_duration_ms: None,
+        }
+    }
+
+    fn edge(from_node: &str, to_node: &str) -> WorkflowEdge {
+        WorkflowEdge {
+            from_node: from_node.to_string(),
+            to_node: to_node.to_string(),
+            condition: None,
+            data_mapping: HashMap::new(),
+            priority: 0,
+        }
+    }
+
+    /// A 20-node chain `step-1 -> step-2 -> ... -> step-20`.
+    fn sequential_chain(len: usize) -> WorkflowGraph {
+        let nodes = (1..=len)
+            .map(|n| node(&format!("step-{n}")))
+            .map(|n| (n.id.clone(), n))
+            .collect();
+        let edges = (1..len)
+            .map(|n| edge(&format!("step-{n}"), &format!("step-{}", n + 1)))
+            .collect();
+        WorkflowGraph {
+            id: Uuid::new_v4(),
+            name: "20-step-sequential-workflow".to_string(),
+            description: String::new(),
+            version: "1.0".to_string(),
+            nodes,
+            edges,
+            input_schema: serde_json::Value::Null,
+            output_schema: serde_json::Value::Null,
+            constraints: Vec::new(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    async fn orchestrator_with_one_agent() -> WorkflowOrchestrator {
+        let orchestrator = WorkflowOrchestrator::new(OrchestratorConfig::default()).await.unwrap();
+        let agent = Agent::new(AgentConfig::default()).await.expect("agent should start");
+        orchestrator.agents.write().await.insert(agent.id, Arc::new(agent));
+        orchestrator
+    }
+
+    #[tokio::test]
+    async fn pausing_a_running_workflow_checkpoints_completed_and_pending_nodes() {
+        let orchestrator = orchestrator_with_one_agent().await;
+        let workflow_graph = sequential_chain(20);
+        let workflow_id = orchestrator
+            .deploy_workflow(workflow_graph, serde_json::Value::Null)
+            .await
+            .unwrap();
+        orchestrator.start_workflow(workflow_id).await.unwrap();
+
+        // Simulate execution having reached step 12: steps 1-12 done, the
+        // rest still pending.
+        {
+            let mut workflows = orchestrator.workflows.write().await;
+            let workflow = workflows.get_mut(&workflow_id).unwrap();
+            workflow.runtime_state.completed_nodes =
+                (1..=12).map(|n| format!("step-{n}")).collect();
+            workflow.runtime_state.pending_nodes =
+                (13..=20).map(|n| format!("step-{n}")).collect();
+        }
+
+        let checkpoint = orchestrator.pause(workflow_id).await.unwrap();
+
+        assert_eq!(checkpoint.workflow_id, workflow_id);
+        assert_eq!(checkpoint.completed_nodes.len(), 12);
+        assert_eq!(checkpoint.pending_nodes.len(), 8);
+        assert!(checkpoint.pending_nodes.contains(&"step-13".to_string()));
+
+        let workflows = orchestrator.workflows.read().await;
+        let workflow = workflows.get(&workflow_id).unwrap();
+        assert!(matches!(workflow.status, WorkflowStatus::Paused { .. }));
+    }
+
+    #[tokio::test]
+    async fn a_20_step_workflow_paused_at_step_12_resumes_from_exactly_that_boundary() {
+        let orchestrator = orchestrator_with_one_agent().await;
+        let workflow_graph = sequential_chain(20);
+        let workflow_id = orchestrator
+            .deploy_workflow(workflow_graph, serde_json::Value::Null)
+            .await
+            .unwrap();
+        orchestrator.start_workflow(workflow_id).await.unwrap();
+
+        {
+            let mut workflows = orchestrator.workflows.write().await;
+            let workflow = workflows.get_mut(&workflow_id).unwrap();
+            workflow.runtime_state.completed_nodes =
+                (1..=12).map(|n| format!("step-{n}")).collect();
+            workflow.runtime_state.pending_nodes =
+                (13..=20).map(|n| format!("step-{n}")).collect();
+        }
+
+        let checkpoint = orchestrator.pause(workflow_id).await.unwrap();
+
+        orchestrator.resume(checkpoint).await.expect("resuming from the checkpoint should succeed");
+
+        let workflows = orchestrator.workflows.read().await;
+        let workflow = workflows.get(&workflow_id).unwrap();
+        assert!(matches!(workflow.status, WorkflowStatus::Running { .. }));
+        assert_eq!(workflow.runtime_state.completed_nodes.len(), 12);
+        assert_eq!(workflow.runtime_state.pending_nodes.len(), 8);
+        for n in 13..=20 {
+            assert!(workflow.runtime_state.pending_nodes.contains(&format!("step-{n}")));
+        }
+    }
+}
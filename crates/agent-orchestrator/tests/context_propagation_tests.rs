@@ -2,8 +2,11 @@
 
 use agent_orchestrator::{
     ContextPropagationManager, RecursiveContext, PropagationRule, PropagationCondition,
-    PropagationAction, InheritanceStrategy, InheritanceType, ResourceUsage, PerformanceMetrics
+    PropagationAction, InheritanceStrategy, InheritanceType, ResourceUsage, PerformanceMetrics,
+    UpstreamPropagationRule, ConflictResolution, ContextSnapshotId,
 };
+use agent_orchestrator::context_propagation::ContextPriority;
+use proptest::prelude::*;
 use std::collections::HashMap;
 use uuid::Uuid;
 
@@ -16,6 +19,7 @@ async fn test_context_propagation_basic() {
 
     let parent_context = RecursiveContext {
         workflow_id: parent_workflow_id,
+        agent_id: Uuid::new_v4(),
         depth: 0,
         parent_workflow_id: None,
         root_workflow_id: parent_workflow_id,
@@ -57,6 +61,7 @@ async fn test_full_inheritance_strategy() {
         depth_limit: None,
         resource_threshold: None,
         custom_logic: None,
+        conflict_resolution: ConflictResolution::default(),
     };
     manager.inheritance_strategies.insert("full".to_string(), strategy);
 
@@ -65,6 +70,7 @@ async fn test_full_inheritance_strategy() {
 
     let parent_context = RecursiveContext {
         workflow_id: parent_workflow_id,
+        agent_id: Uuid::new_v4(),
         depth: 0,
         parent_workflow_id: None,
         root_workflow_id: parent_workflow_id,
@@ -101,6 +107,7 @@ async fn test_selective_inheritance_strategy() {
         depth_limit: None,
         resource_threshold: None,
         custom_logic: None,
+        conflict_resolution: ConflictResolution::default(),
     };
     manager.inheritance_strategies.insert("selective".to_string(), strategy);
 
@@ -119,6 +126,7 @@ async fn test_selective_inheritance_strategy() {
 
     let parent_context = RecursiveContext {
         workflow_id: parent_workflow_id,
+        agent_id: Uuid::new_v4(),
         depth: 0,
         parent_workflow_id: None,
         root_workflow_id: parent_workflow_id,
@@ -155,6 +163,7 @@ async fn test_compressed_inheritance_strategy() {
         depth_limit: None,
         resource_threshold: None,
         custom_logic: None,
+        conflict_resolution: ConflictResolution::default(),
     };
     manager.inheritance_strategies.insert("compressed".to_string(), strategy);
 
@@ -163,6 +172,7 @@ async fn test_compressed_inheritance_strategy() {
 
     let parent_context = RecursiveContext {
         workflow_id: parent_workflow_id,
+        agent_id: Uuid::new_v4(),
         depth: 0,
         parent_workflow_id: None,
         root_workflow_id: parent_workflow_id,
@@ -202,6 +212,7 @@ async fn test_depth_limit_inheritance() {
         depth_limit: Some(2),
         resource_threshold: None,
         custom_logic: None,
+        conflict_resolution: ConflictResolution::default(),
     };
     manager.inheritance_strategies.insert("depth_limited".to_string(), strategy);
 
@@ -211,6 +222,7 @@ async fn test_depth_limit_inheritance() {
     // Create parent context at depth 2 (at the limit)
     let parent_context = RecursiveContext {
         workflow_id: parent_workflow_id,
+        agent_id: Uuid::new_v4(),
         depth: 2,
         parent_workflow_id: None,
         root_workflow_id: parent_workflow_id,
@@ -243,6 +255,7 @@ async fn test_resource_threshold_inheritance() {
         depth_limit: None,
         resource_threshold: Some(1.0),
         custom_logic: None,
+        conflict_resolution: ConflictResolution::default(),
     };
     manager.inheritance_strategies.insert("resource_limited".to_string(), strategy);
 
@@ -252,6 +265,7 @@ async fn test_resource_threshold_inheritance() {
     // Create parent context with high resource usage
     let parent_context = RecursiveContext {
         workflow_id: parent_workflow_id,
+        agent_id: Uuid::new_v4(),
         depth: 0,
         parent_workflow_id: None,
         root_workflow_id: parent_workflow_id,
@@ -290,6 +304,7 @@ async fn test_context_optimization() {
 
     let parent_context = RecursiveContext {
         workflow_id: parent_workflow_id,
+        agent_id: Uuid::new_v4(),
         depth: 0,
         parent_workflow_id: None,
         root_workflow_id: parent_workflow_id,
@@ -336,6 +351,7 @@ async fn test_propagation_rules() {
 
     let parent_context = RecursiveContext {
         workflow_id: parent_workflow_id,
+        agent_id: Uuid::new_v4(),
         depth: 0,
         parent_workflow_id: None,
         root_workflow_id: parent_workflow_id,
@@ -355,3 +371,364 @@ async fn test_propagation_rules() {
     // Check that transformation was applied
     assert_eq!(child_context.inherited_state.get("scaled_count"), Some(&serde_json::Value::Number(serde_json::Number::from(10))));
 }
+
+#[tokio::test]
+async fn test_upstream_proposal_reaches_root_when_every_level_allows() {
+    let manager = ContextPropagationManager::new();
+
+    let root_id = Uuid::new_v4();
+    let child_id = Uuid::new_v4();
+    let grandchild_id = Uuid::new_v4();
+
+    manager.register_agent_parent(child_id, root_id).await;
+    manager.register_agent_parent(grandchild_id, child_id).await;
+
+    manager
+        .set_upstream_rule(
+            root_id,
+            UpstreamPropagationRule::AllowUpstream {
+                accepted_keys: vec!["discovered_fact".to_string()],
+                min_confidence: 0.5,
+            },
+        )
+        .await;
+    manager
+        .set_upstream_rule(
+            child_id,
+            UpstreamPropagationRule::AllowUpstream {
+                accepted_keys: vec!["discovered_fact".to_string()],
+                min_confidence: 0.5,
+            },
+        )
+        .await;
+
+    manager
+        .propose_upstream(
+            grandchild_id,
+            "discovered_fact".to_string(),
+            serde_json::Value::String("useful_thing".to_string()),
+        )
+        .await;
+
+    // Child accepts the grandchild's proposal and forwards it to the root.
+    let child_patches = manager.accept_upstream_proposals(child_id).await;
+    assert_eq!(child_patches.len(), 1);
+    assert_eq!(child_patches[0].source_agent_id, grandchild_id);
+
+    // Root accepts what the child forwarded.
+    let root_patches = manager.accept_upstream_proposals(root_id).await;
+    assert_eq!(root_patches.len(), 1);
+    assert_eq!(root_patches[0].source_agent_id, grandchild_id);
+    assert_eq!(root_patches[0].key, "discovered_fact");
+}
+
+#[tokio::test]
+async fn test_upstream_proposal_stalls_when_an_intermediate_level_denies() {
+    let manager = ContextPropagationManager::new();
+
+    let root_id = Uuid::new_v4();
+    let child_id = Uuid::new_v4();
+    let grandchild_id = Uuid::new_v4();
+
+    manager.register_agent_parent(child_id, root_id).await;
+    manager.register_agent_parent(grandchild_id, child_id).await;
+
+    manager
+        .set_upstream_rule(
+            root_id,
+            UpstreamPropagationRule::AllowUpstream {
+                accepted_keys: vec!["discovered_fact".to_string()],
+                min_confidence: 0.5,
+            },
+        )
+        .await;
+    // Child refuses to forward anything upstream.
+    manager.set_upstream_rule(child_id, UpstreamPropagationRule::Deny).await;
+
+    manager
+        .propose_upstream(
+            grandchild_id,
+            "discovered_fact".to_string(),
+            serde_json::Value::String("useful_thing".to_string()),
+        )
+        .await;
+
+    let child_patches = manager.accept_upstream_proposals(child_id).await;
+    assert!(child_patches.is_empty());
+
+    let root_patches = manager.accept_upstream_proposals(root_id).await;
+    assert!(root_patches.is_empty());
+}
+
+fn make_snapshot(
+    snapshot_id: ContextSnapshotId,
+    variables: HashMap<String, serde_json::Value>,
+    priority: agent_orchestrator::context_propagation::ContextPriority,
+) -> agent_orchestrator::ContextSnapshot {
+    use agent_orchestrator::context_propagation::{
+        ContextMetadata, ContextState, ExecutionPlanSnapshot, PerformanceSnapshot, WorkflowStateSnapshot,
+        WorkflowStatus,
+    };
+
+    agent_orchestrator::ContextSnapshot {
+        snapshot_id,
+        workflow_id: snapshot_id,
+        depth: 0,
+        timestamp: chrono::Utc::now(),
+        state: ContextState {
+            variables,
+            execution_history: vec![],
+            resource_allocations: HashMap::new(),
+            performance_metrics: PerformanceSnapshot {
+                throughput: 0.0,
+                latency_ms: 0.0,
+                error_rate: 0.0,
+                resource_efficiency: 0.0,
+                cache_hit_rate: 0.0,
+                optimization_score: 0.0,
+            },
+            agent_states: HashMap::new(),
+            workflow_state: WorkflowStateSnapshot {
+                workflow_id: snapshot_id,
+                status: WorkflowStatus::Running,
+                current_phase: "execution".to_string(),
+                completed_nodes: vec![],
+                pending_nodes: vec![],
+                failed_nodes: vec![],
+                execution_plan: ExecutionPlanSnapshot {
+                    total_phases: 1,
+                    current_phase: 0,
+                    estimated_remaining_time: std::time::Duration::from_secs(0),
+                    critical_path: vec![],
+                    dependencies: HashMap::new(),
+                },
+            },
+            custom_data: HashMap::new(),
+        },
+        metadata: ContextMetadata {
+            size_bytes: 0,
+            compression_ratio: 1.0,
+            last_accessed: chrono::Utc::now(),
+            access_count: 1,
+            ttl_seconds: 3600,
+            priority,
+            tags: vec![],
+        },
+        dependencies: vec![],
+        version: 1,
+    }
+}
+
+#[tokio::test]
+async fn test_propagation_order_is_root_most_first_for_a_diamond() {
+    let manager = ContextPropagationManager::new();
+
+    let root: ContextSnapshotId = Uuid::new_v4();
+    let branch_a: ContextSnapshotId = Uuid::new_v4();
+    let branch_b: ContextSnapshotId = Uuid::new_v4();
+    let child: ContextSnapshotId = Uuid::new_v4();
+
+    // root -> branch_a -> child
+    // root -> branch_b -> child
+    manager.register_context_edge(branch_a, root).await;
+    manager.register_context_edge(branch_b, root).await;
+    manager.register_context_edge(child, branch_a).await;
+    manager.register_context_edge(child, branch_b).await;
+
+    let order = manager.propagation_order(child).await.unwrap();
+
+    assert_eq!(order.len(), 4);
+    let position = |id: ContextSnapshotId| order.iter().position(|&n| n == id).unwrap();
+    assert!(position(root) < position(branch_a));
+    assert!(position(root) < position(branch_b));
+    assert!(position(branch_a) < position(child));
+    assert!(position(branch_b) < position(child));
+}
+
+#[tokio::test]
+async fn test_propagation_order_detects_a_cycle() {
+    let manager = ContextPropagationManager::new();
+
+    let a: ContextSnapshotId = Uuid::new_v4();
+    let b: ContextSnapshotId = Uuid::new_v4();
+
+    // a depends on b, and b depends on a.
+    manager.register_context_edge(a, b).await;
+    manager.register_context_edge(b, a).await;
+
+    let result = manager.propagation_order(a).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_merge_ancestor_state_last_write_prefers_the_nearer_branch() {
+    let manager = ContextPropagationManager::new();
+
+    let root: ContextSnapshotId = Uuid::new_v4();
+    let branch_a: ContextSnapshotId = Uuid::new_v4();
+    let branch_b: ContextSnapshotId = Uuid::new_v4();
+    let child: ContextSnapshotId = Uuid::new_v4();
+
+    manager.register_context_edge(branch_a, root).await;
+    manager.register_context_edge(branch_b, root).await;
+    manager.register_context_edge(child, branch_a).await;
+    manager.register_context_edge(child, branch_b).await;
+
+    {
+        let mut store = manager.context_store.write().await;
+        store.insert(
+            root,
+            make_snapshot(
+                root,
+                HashMap::from([("shared_key".to_string(), serde_json::Value::String("root".to_string()))]),
+                agent_orchestrator::context_propagation::ContextPriority::Medium,
+            ),
+        );
+        store.insert(
+            branch_a,
+            make_snapshot(
+                branch_a,
+                HashMap::from([("shared_key".to_string(), serde_json::Value::String("from_a".to_string()))]),
+                agent_orchestrator::context_propagation::ContextPriority::Low,
+            ),
+        );
+        store.insert(
+            branch_b,
+            make_snapshot(
+                branch_b,
+                HashMap::from([("shared_key".to_string(), serde_json::Value::String("from_b".to_string()))]),
+                agent_orchestrator::context_propagation::ContextPriority::Critical,
+            ),
+        );
+        store.insert(
+            child,
+            make_snapshot(child, HashMap::new(), agent_orchestrator::context_propagation::ContextPriority::Medium),
+        );
+    }
+
+    let last_write_strategy = InheritanceStrategy {
+        strategy_name: "last_write".to_string(),
+        inheritance_type: InheritanceType::Full,
+        depth_limit: None,
+        resource_threshold: None,
+        custom_logic: None,
+        conflict_resolution: ConflictResolution::LastWrite,
+    };
+    let merged = manager.merge_ancestor_state(child, &last_write_strategy).await.unwrap();
+    // branch_b is registered after branch_a, so in the deterministic (sorted)
+    // ready-set order it is the later writer despite having the higher
+    // (more urgent) priority -- LastWrite ignores priority entirely.
+    assert_eq!(
+        merged.get("shared_key"),
+        Some(&serde_json::Value::String(if branch_a < branch_b { "from_b" } else { "from_a" }.to_string()))
+    );
+
+    let highest_priority_strategy = InheritanceStrategy {
+        conflict_resolution: ConflictResolution::HighestPriority,
+        ..last_write_strategy.clone()
+    };
+    let merged = manager.merge_ancestor_state(child, &highest_priority_strategy).await.unwrap();
+    // branch_b has Critical priority, which outranks both root's Medium and
+    // branch_a's Low, regardless of propagation order.
+    assert_eq!(merged.get("shared_key"), Some(&serde_json::Value::String("from_b".to_string())));
+}
+
+#[tokio::test]
+async fn test_merge_ancestor_state_merge_shallow_merges_objects() {
+    let manager = ContextPropagationManager::new();
+
+    let branch_a: ContextSnapshotId = Uuid::new_v4();
+    let branch_b: ContextSnapshotId = Uuid::new_v4();
+    let child: ContextSnapshotId = Uuid::new_v4();
+
+    manager.register_context_edge(child, branch_a).await;
+    manager.register_context_edge(child, branch_b).await;
+
+    {
+        let mut store = manager.context_store.write().await;
+        store.insert(
+            branch_a,
+            make_snapshot(
+                branch_a,
+                HashMap::from([(
+                    "config".to_string(),
+                    serde_json::json!({"from_a": true}),
+                )]),
+                agent_orchestrator::context_propagation::ContextPriority::Medium,
+            ),
+        );
+        store.insert(
+            branch_b,
+            make_snapshot(
+                branch_b,
+                HashMap::from([(
+                    "config".to_string(),
+                    serde_json::json!({"from_b": true}),
+                )]),
+                agent_orchestrator::context_propagation::ContextPriority::Medium,
+            ),
+        );
+        store.insert(
+            child,
+            make_snapshot(child, HashMap::new(), agent_orchestrator::context_propagation::ContextPriority::Medium),
+        );
+    }
+
+    let merge_strategy = InheritanceStrategy {
+        strategy_name: "merge".to_string(),
+        inheritance_type: InheritanceType::Full,
+        depth_limit: None,
+        resource_threshold: None,
+        custom_logic: None,
+        conflict_resolution: ConflictResolution::Merge,
+    };
+    let merged = manager.merge_ancestor_state(child, &merge_strategy).await.unwrap();
+    assert_eq!(
+        merged.get("config"),
+        Some(&serde_json::json!({"from_a": true, "from_b": true}))
+    );
+}
+
+#[test]
+fn test_diff_reports_added_removed_and_changed_keys() {
+    let before = make_snapshot(
+        Uuid::new_v4(),
+        HashMap::from([
+            ("kept".to_string(), serde_json::json!(1)),
+            ("dropped".to_string(), serde_json::json!("bye")),
+        ]),
+        ContextPriority::Medium,
+    );
+    let after = make_snapshot(
+        Uuid::new_v4(),
+        HashMap::from([
+            ("kept".to_string(), serde_json::json!(2)),
+            ("fresh".to_string(), serde_json::json!(true)),
+        ]),
+        ContextPriority::Medium,
+    );
+
+    let diff = before.diff(&after);
+
+    assert_eq!(diff.added.get("fresh"), Some(&serde_json::json!(true)));
+    assert_eq!(diff.removed, vec!["dropped".to_string()]);
+    assert_eq!(diff.changed.get("kept"), Some(&(serde_json::json!(1), serde_json::json!(2))));
+    assert!(!diff.is_empty());
+}
+
+proptest! {
+    #[test]
+    fn diffing_a_snapshot_against_itself_is_always_empty(
+        keys in proptest::collection::vec("[a-z]{1,8}", 0..8),
+        values in proptest::collection::vec(any::<i64>(), 0..8),
+    ) {
+        let variables: HashMap<String, serde_json::Value> = keys
+            .into_iter()
+            .zip(values)
+            .map(|(key, value)| (key, serde_json::json!(value)))
+            .collect();
+        let snapshot = make_snapshot(Uuid::new_v4(), variables, ContextPriority::Medium);
+
+        prop_assert!(snapshot.diff(&snapshot).is_empty());
+    }
+}
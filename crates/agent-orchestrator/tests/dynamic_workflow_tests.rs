@@ -44,6 +44,7 @@ async fn test_modification_rule_performance_degradation() {
         action: ModificationAction::ScaleResources { factor: 1.5 },
         priority: 8,
         enabled: true,
+        relevant_context_keys: Vec::new(),
     };
     modifier.modification_rules.push(rule);
 
@@ -78,6 +79,7 @@ async fn test_modification_rule_resource_exhaustion() {
         action: ModificationAction::ScaleResources { factor: 2.0 },
         priority: 9,
         enabled: true,
+        relevant_context_keys: Vec::new(),
     };
     modifier.modification_rules.push(rule);
 
@@ -112,6 +114,7 @@ async fn test_modification_rule_error_rate() {
         action: ModificationAction::ChangeStrategy { strategy: "error_recovery".to_string() },
         priority: 7,
         enabled: true,
+        relevant_context_keys: Vec::new(),
     };
     modifier.modification_rules.push(rule);
 
@@ -366,6 +369,7 @@ async fn test_modification_confidence_calculation() {
         action: ModificationAction::ScaleResources { factor: 1.5 },
         priority: 9, // High priority
         enabled: true,
+        relevant_context_keys: Vec::new(),
     };
     modifier.modification_rules.push(high_priority_rule);
 
@@ -377,6 +381,7 @@ async fn test_modification_confidence_calculation() {
         action: ModificationAction::ChangeStrategy { strategy: "fallback".to_string() },
         priority: 3, // Low priority
         enabled: true,
+        relevant_context_keys: Vec::new(),
     };
     modifier.modification_rules.push(low_priority_rule);
 
@@ -419,6 +424,7 @@ async fn test_risk_assessment() {
         action: ModificationAction::ScaleResources { factor: 1.1 }, // Small scaling
         priority: 5,
         enabled: true,
+        relevant_context_keys: Vec::new(),
     };
     modifier.modification_rules.push(low_risk_rule);
 
@@ -429,6 +435,7 @@ async fn test_risk_assessment() {
         action: ModificationAction::RemoveNode { node_id: "critical_node".to_string() },
         priority: 5,
         enabled: true,
+        relevant_context_keys: Vec::new(),
     };
     modifier.modification_rules.push(high_risk_rule);
 
@@ -82,6 +82,7 @@ async fn test_execute_workflow_with_parent_context() {
     // Create a parent context
     let parent_context = RecursiveContext {
         workflow_id: Uuid::new_v4(),
+        agent_id: Uuid::new_v4(),
         depth: 1,
         parent_workflow_id: Some(Uuid::new_v4()),
         root_workflow_id: Uuid::new_v4(),
@@ -222,6 +223,7 @@ async fn test_context_propagation() {
     // Create a parent context with inherited state
     let parent_context = RecursiveContext {
         workflow_id: Uuid::new_v4(),
+        agent_id: Uuid::new_v4(),
         depth: 0,
         parent_workflow_id: None,
         root_workflow_id: Uuid::new_v4(),
@@ -346,6 +348,7 @@ async fn create_simple_workflow() -> Result<WorkflowGraph, Box<dyn std::error::E
         timeout_seconds: Some(30),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     Ok(WorkflowGraph {
@@ -406,6 +409,7 @@ async fn create_recursive_workflow() -> Result<WorkflowGraph, Box<dyn std::error
         timeout_seconds: Some(10),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     // Recursive call node
@@ -437,6 +441,7 @@ async fn create_recursive_workflow() -> Result<WorkflowGraph, Box<dyn std::error
         timeout_seconds: Some(60),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     // Final task node
@@ -466,6 +471,7 @@ async fn create_recursive_workflow() -> Result<WorkflowGraph, Box<dyn std::error
         timeout_seconds: Some(30),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     let edges = vec![
@@ -557,6 +563,7 @@ async fn create_failing_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
             retry_on_errors: vec![],
         },
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     Ok(WorkflowGraph {
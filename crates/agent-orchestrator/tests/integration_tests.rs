@@ -75,6 +75,7 @@ async fn test_recursive_execution_with_context_propagation() {
     // Create a parent context with rich state
     let parent_context = RecursiveContext {
         workflow_id: Uuid::new_v4(),
+        agent_id: Uuid::new_v4(),
         depth: 1,
         parent_workflow_id: Some(Uuid::new_v4()),
         root_workflow_id: Uuid::new_v4(),
@@ -147,6 +148,7 @@ async fn test_dynamic_modification_integration() {
         action: ModificationAction::ScaleResources { factor: 1.5 },
         priority: 8,
         enabled: true,
+        relevant_context_keys: Vec::new(),
     };
     orchestrator.dynamic_modifier.modification_rules.push(modification_rule);
 
@@ -339,6 +341,7 @@ async fn test_resource_escalation_integration() {
     // Create a parent context with high resource usage
     let parent_context = RecursiveContext {
         workflow_id: Uuid::new_v4(),
+        agent_id: Uuid::new_v4(),
         depth: 0,
         parent_workflow_id: None,
         root_workflow_id: Uuid::new_v4(),
@@ -413,6 +416,7 @@ async fn create_complex_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
             expression: "input_data != null".to_string(),
             required: true,
         }],
+        learned_duration_ms: None,
     });
 
     // Data processing node
@@ -442,6 +446,7 @@ async fn create_complex_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
         timeout_seconds: Some(60),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     // Decision node
@@ -471,6 +476,7 @@ async fn create_complex_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
         timeout_seconds: Some(10),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     // Complex processing node
@@ -500,6 +506,7 @@ async fn create_complex_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
         timeout_seconds: Some(120),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     // Simple processing node
@@ -529,6 +536,7 @@ async fn create_complex_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
         timeout_seconds: Some(30),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     // Final analysis node
@@ -558,6 +566,7 @@ async fn create_complex_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
         timeout_seconds: Some(90),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     let edges = vec![
@@ -699,6 +708,7 @@ async fn create_simple_workflow() -> Result<WorkflowGraph, Box<dyn std::error::E
         timeout_seconds: Some(30),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     Ok(WorkflowGraph {
@@ -763,6 +773,7 @@ async fn create_failing_workflow() -> Result<WorkflowGraph, Box<dyn std::error::
             retry_on_errors: vec![],
         },
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     Ok(WorkflowGraph {
@@ -823,6 +834,7 @@ async fn create_potentially_cyclic_workflow() -> Result<WorkflowGraph, Box<dyn s
         timeout_seconds: Some(10),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     // Continue processing node
@@ -852,6 +864,7 @@ async fn create_potentially_cyclic_workflow() -> Result<WorkflowGraph, Box<dyn s
         timeout_seconds: Some(30),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     // Final task node
@@ -881,6 +894,7 @@ async fn create_potentially_cyclic_workflow() -> Result<WorkflowGraph, Box<dyn s
         timeout_seconds: Some(30),
         retry_policy: RetryPolicy::default(),
         conditions: vec![],
+        learned_duration_ms: None,
     });
 
     let edges = vec![
@@ -13,7 +13,7 @@ async fn test_recursive_execution_basic() {
     let manager = RecursiveExecutionManager::new(limits);
 
     let workflow_id = Uuid::new_v4();
-    let context = manager.start_recursive_execution(workflow_id, None).await.unwrap();
+    let context = manager.start_recursive_execution(workflow_id, workflow_id, None).await.unwrap();
 
     assert_eq!(context.workflow_id, workflow_id);
     assert_eq!(context.depth, 0);
@@ -32,8 +32,8 @@ async fn test_recursive_execution_with_parent() {
     let parent_workflow_id = Uuid::new_v4();
     let child_workflow_id = Uuid::new_v4();
 
-    let parent_context = manager.start_recursive_execution(parent_workflow_id, None).await.unwrap();
-    let child_context = manager.start_recursive_execution(child_workflow_id, Some(&parent_context)).await.unwrap();
+    let parent_context = manager.start_recursive_execution(parent_workflow_id, parent_workflow_id, None).await.unwrap();
+    let child_context = manager.start_recursive_execution(child_workflow_id, parent_context.agent_id, Some(&parent_context)).await.unwrap();
 
     assert_eq!(child_context.workflow_id, child_workflow_id);
     assert_eq!(child_context.depth, 1);
@@ -54,11 +54,12 @@ async fn test_recursion_depth_limit() {
     let manager = RecursiveExecutionManager::new(limits);
 
     let workflow_id = Uuid::new_v4();
-    let context = manager.start_recursive_execution(workflow_id, None).await.unwrap();
+    let context = manager.start_recursive_execution(workflow_id, workflow_id, None).await.unwrap();
 
     // Create a context at max depth
     let max_depth_context = RecursiveContext {
         workflow_id: Uuid::new_v4(),
+        agent_id: workflow_id,
         depth: 2,
         parent_workflow_id: Some(workflow_id),
         root_workflow_id: workflow_id,
@@ -72,7 +73,7 @@ async fn test_recursion_depth_limit() {
     };
 
     // Try to start execution beyond max depth
-    let result = manager.start_recursive_execution(Uuid::new_v4(), Some(&max_depth_context)).await;
+    let result = manager.start_recursive_execution(Uuid::new_v4(), workflow_id, Some(&max_depth_context)).await;
     assert!(result.is_err());
     assert!(matches!(result.unwrap_err(), OrchestrationError::RecursionLimitExceeded(_)));
 
@@ -88,27 +89,61 @@ async fn test_cycle_detection() {
     let manager = RecursiveExecutionManager::new(limits);
 
     let workflow_id = Uuid::new_v4();
-    let context = manager.start_recursive_execution(workflow_id, None).await.unwrap();
+    let context = manager.start_recursive_execution(workflow_id, workflow_id, None).await.unwrap();
 
     // Create a context that would create a cycle
     let mut cycle_context = context.clone();
     cycle_context.execution_path.push(workflow_id); // This creates a cycle
 
     // Try to start execution that would create a cycle
-    let result = manager.start_recursive_execution(workflow_id, Some(&cycle_context)).await;
+    let result = manager.start_recursive_execution(workflow_id, workflow_id, Some(&cycle_context)).await;
     assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), OrchestrationError::RecursionCycleDetected));
+    assert!(matches!(result.unwrap_err(), OrchestrationError::RecursionCycleDetected { .. }));
 
     manager.complete_recursive_execution(&context, true, None).await.unwrap();
 }
 
+#[tokio::test]
+async fn test_mutual_recursion_cycle_reports_the_full_agent_path() {
+    let limits = RecursionLimits {
+        cycle_detection: true,
+        ..Default::default()
+    };
+    let manager = RecursiveExecutionManager::new(limits);
+
+    let agent_a = Uuid::new_v4();
+    let agent_b = Uuid::new_v4();
+    let agent_c = Uuid::new_v4();
+
+    // A calls B, B calls C, C calls back into A -- a cycle among three
+    // distinct agents.
+    let a_context = manager.start_recursive_execution(Uuid::new_v4(), agent_a, None).await.unwrap();
+    let b_context = manager.start_recursive_execution(Uuid::new_v4(), agent_b, Some(&a_context)).await.unwrap();
+    let c_context = manager.start_recursive_execution(Uuid::new_v4(), agent_c, Some(&b_context)).await.unwrap();
+
+    let result = manager.start_recursive_execution(Uuid::new_v4(), agent_a, Some(&c_context)).await;
+    assert!(result.is_err());
+    match result.unwrap_err() {
+        OrchestrationError::RecursionCycleDetected { path } => {
+            assert_eq!(path.first(), Some(&agent_a));
+            assert_eq!(path.last(), Some(&agent_a));
+            assert_eq!(path, vec![agent_a, agent_b, agent_c, agent_a]);
+        }
+        other => panic!("expected RecursionCycleDetected, got {other:?}"),
+    }
+
+    manager.complete_recursive_execution(&c_context, true, None).await.unwrap();
+    manager.complete_recursive_execution(&b_context, true, None).await.unwrap();
+    manager.complete_recursive_execution(&a_context, true, None).await.unwrap();
+}
+
 #[tokio::test]
 async fn test_recursion_statistics() {
     let limits = RecursionLimits::default();
     let manager = RecursiveExecutionManager::new(limits);
 
     let workflow_id = Uuid::new_v4();
-    let context = manager.start_recursive_execution(workflow_id, None).await.unwrap();
+    let context = manager.start_recursive_execution(workflow_id, workflow_id, None).await.unwrap();
 
     let stats = manager.get_recursion_statistics().await.unwrap();
     assert_eq!(stats.active_recursions, 1);
@@ -129,7 +164,7 @@ async fn test_performance_monitoring() {
     let manager = RecursiveExecutionManager::new(limits);
 
     let workflow_id = Uuid::new_v4();
-    let context = manager.start_recursive_execution(workflow_id, None).await.unwrap();
+    let context = manager.start_recursive_execution(workflow_id, workflow_id, None).await.unwrap();
 
     // Complete with performance data
     manager.complete_recursive_execution(&context, true, None).await.unwrap();
@@ -148,8 +183,10 @@ async fn test_resource_escalation_threshold() {
     let manager = RecursiveExecutionManager::new(limits);
 
     // Create a context with high resource usage
+    let agent_id = Uuid::new_v4();
     let mut high_resource_context = RecursiveContext {
         workflow_id: Uuid::new_v4(),
+        agent_id,
         depth: 0,
         parent_workflow_id: None,
         root_workflow_id: Uuid::new_v4(),
@@ -170,7 +207,7 @@ async fn test_resource_escalation_threshold() {
     };
 
     // This should fail due to resource escalation threshold
-    let result = manager.start_recursive_execution(Uuid::new_v4(), Some(&high_resource_context)).await;
+    let result = manager.start_recursive_execution(Uuid::new_v4(), agent_id, Some(&high_resource_context)).await;
     assert!(result.is_err());
     assert!(matches!(result.unwrap_err(), OrchestrationError::ResourceExhausted(_)));
 }
@@ -188,12 +225,12 @@ async fn test_concurrent_recursions() {
     // Start multiple concurrent recursions
     for i in 0..3 {
         let workflow_id = Uuid::new_v4();
-        let context = manager.start_recursive_execution(workflow_id, None).await.unwrap();
+        let context = manager.start_recursive_execution(workflow_id, workflow_id, None).await.unwrap();
         contexts.push(context);
     }
 
     // Try to start one more (should fail due to limit)
-    let result = manager.start_recursive_execution(Uuid::new_v4(), None).await;
+    let result = manager.start_recursive_execution(Uuid::new_v4(), Uuid::new_v4(), None).await;
     assert!(result.is_err());
     assert!(matches!(result.unwrap_err(), OrchestrationError::ResourceExhausted(_)));
 
@@ -212,7 +249,7 @@ async fn test_recursion_timeout() {
     let manager = RecursiveExecutionManager::new(limits);
 
     let workflow_id = Uuid::new_v4();
-    let context = manager.start_recursive_execution(workflow_id, None).await.unwrap();
+    let context = manager.start_recursive_execution(workflow_id, workflow_id, None).await.unwrap();
 
     // Simulate a long-running operation
     tokio::time::sleep(Duration::from_millis(200)).await;
@@ -222,3 +259,28 @@ async fn test_recursion_timeout() {
 
     manager.complete_recursive_execution(&context, true, None).await.unwrap();
 }
+
+#[tokio::test]
+async fn test_agent_budget_exhaustion_does_not_block_other_agents() {
+    let limits = RecursionLimits::default();
+    let manager = RecursiveExecutionManager::new(limits);
+
+    let agent_a = Uuid::new_v4();
+    let agent_b = Uuid::new_v4();
+    manager.set_agent_max_depth(agent_a, 1).await;
+
+    // Agent A's first recursion is within its budget of 1.
+    let a_root = manager.start_recursive_execution(Uuid::new_v4(), agent_a, None).await.unwrap();
+
+    // Agent A recursing again immediately exhausts its budget.
+    let a_exhausted = manager.start_recursive_execution(Uuid::new_v4(), agent_a, Some(&a_root)).await;
+    assert!(a_exhausted.is_err());
+    assert!(matches!(a_exhausted.unwrap_err(), OrchestrationError::RecursionLimitExceeded(_)));
+
+    // Agent B, with no configured budget, is unaffected by A exhausting its own.
+    let b_context = manager.start_recursive_execution(Uuid::new_v4(), agent_b, None).await;
+    assert!(b_context.is_ok());
+
+    manager.complete_recursive_execution(&a_root, true, None).await.unwrap();
+    manager.complete_recursive_execution(&b_context.unwrap(), true, None).await.unwrap();
+}
@@ -0,0 +1,170 @@
+//! API-key / role based authorization for `SecurityManager`.
+//!
+//! `AccessController` used to be an empty unit struct -- `authorization_enabled`
+//! was configuration with no code behind it. This maps each API key to a
+//! [`Role`], and each role to the [`Operation`]s it may perform. Keys are
+//! compared in constant time so a timing side channel can't be used to guess
+//! a valid key one byte at a time.
+
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::AIWorkflowType;
+
+/// A permission level assigned to an API key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Role {
+    /// May run `AIWorkflowType::Simple` workflows only.
+    ExecuteSimple,
+    /// May run `Simple` and `Complex` workflows.
+    ExecuteComplex,
+    /// May run any workflow type, including `MultiAgent`.
+    ExecuteMultiAgent,
+    /// Every operation, including key management.
+    Admin,
+}
+
+/// An action gated by [`Role`]. Workflow variants mirror `AIWorkflowType`
+/// one-for-one so `AccessController::authorize_workflow` can map a request
+/// straight through; `ManageKeys` covers `add_key`/`revoke_key`/`list_keys`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    ExecuteSimple,
+    ExecuteComplex,
+    ExecuteMultiAgent,
+    ManageKeys,
+}
+
+impl Role {
+    /// Whether a key with this role may perform `operation`.
+    fn permits(self, operation: Operation) -> bool {
+        match self {
+            Role::Admin => true,
+            Role::ExecuteMultiAgent => !matches!(operation, Operation::ManageKeys),
+            Role::ExecuteComplex => matches!(operation, Operation::ExecuteSimple | Operation::ExecuteComplex),
+            Role::ExecuteSimple => matches!(operation, Operation::ExecuteSimple),
+        }
+    }
+}
+
+impl From<AIWorkflowType> for Operation {
+    fn from(workflow_type: AIWorkflowType) -> Self {
+        match workflow_type {
+            AIWorkflowType::Simple => Operation::ExecuteSimple,
+            AIWorkflowType::Complex => Operation::ExecuteComplex,
+            AIWorkflowType::MultiAgent => Operation::ExecuteMultiAgent,
+        }
+    }
+}
+
+/// Compares two byte strings in time independent of where they first
+/// differ, so a failed lookup can't leak how many leading bytes of a guess
+/// were correct. Unequal lengths still short-circuit -- callers only ever
+/// compare against keys of known length, so leaking that isn't a concern.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Maps API keys to [`Role`]s and authorizes operations against them.
+/// `add_key`/`revoke_key` take effect immediately for every subsequent
+/// `authorize` call -- there's no cached snapshot of the key set.
+#[derive(Default)]
+pub struct AccessController {
+    keys: DashMap<String, Role>,
+}
+
+impl AccessController {
+    pub fn new() -> Self {
+        Self { keys: DashMap::new() }
+    }
+
+    /// Registers `key` with `role`, overwriting any existing role for that
+    /// key.
+    pub fn add_key(&self, key: impl Into<String>, role: Role) {
+        self.keys.insert(key.into(), role);
+    }
+
+    /// Removes `key`, if present. Any request presenting it afterward is
+    /// treated as unauthenticated.
+    pub fn revoke_key(&self, key: &str) {
+        self.keys.remove(key);
+    }
+
+    /// All currently-registered keys and their roles.
+    pub fn list_keys(&self) -> Vec<(String, Role)> {
+        self.keys.iter().map(|entry| (entry.key().clone(), *entry.value())).collect()
+    }
+
+    /// Looks up `key` by comparing it against every registered key in
+    /// constant time (rather than a direct `DashMap` hash lookup, which
+    /// would leak the key's existence through timing), returning its role
+    /// if found.
+    fn role_for(&self, key: &str) -> Option<Role> {
+        let key_bytes = key.as_bytes();
+        self.keys.iter().find(|entry| constant_time_eq(entry.key().as_bytes(), key_bytes)).map(|entry| *entry.value())
+    }
+
+    /// Returns `Ok(())` if `caller` is registered and its role permits
+    /// `operation`; `Err` describing why otherwise.
+    pub fn authorize(&self, caller: Option<&str>, operation: Operation) -> Result<(), String> {
+        let Some(caller) = caller else {
+            return Err("no caller identity provided".to_string());
+        };
+        let Some(role) = self.role_for(caller) else {
+            return Err("unrecognized API key".to_string());
+        };
+        if role.permits(operation) {
+            Ok(())
+        } else {
+            Err(format!("role {role:?} does not permit {operation:?}"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_simple_role_may_not_run_a_complex_workflow() {
+        let controller = AccessController::new();
+        controller.add_key("key-a", Role::ExecuteSimple);
+
+        assert!(controller.authorize(Some("key-a"), Operation::ExecuteSimple).is_ok());
+        assert!(controller.authorize(Some("key-a"), Operation::ExecuteComplex).is_err());
+    }
+
+    #[test]
+    fn an_admin_role_may_manage_keys() {
+        let controller = AccessController::new();
+        controller.add_key("key-admin", Role::Admin);
+        assert!(controller.authorize(Some("key-admin"), Operation::ManageKeys).is_ok());
+    }
+
+    #[test]
+    fn an_unrecognized_key_is_rejected() {
+        let controller = AccessController::new();
+        assert!(controller.authorize(Some("no-such-key"), Operation::ExecuteSimple).is_err());
+    }
+
+    #[test]
+    fn no_caller_identity_is_rejected() {
+        let controller = AccessController::new();
+        assert!(controller.authorize(None, Operation::ExecuteSimple).is_err());
+    }
+
+    #[test]
+    fn revocation_takes_effect_immediately_for_a_shared_controller() {
+        let controller = AccessController::new();
+        controller.add_key("key-a", Role::ExecuteMultiAgent);
+        assert!(controller.authorize(Some("key-a"), Operation::ExecuteMultiAgent).is_ok());
+
+        controller.revoke_key("key-a");
+
+        assert!(controller.authorize(Some("key-a"), Operation::ExecuteMultiAgent).is_err());
+        assert!(controller.list_keys().is_empty());
+    }
+}
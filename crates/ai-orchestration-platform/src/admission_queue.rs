@@ -0,0 +1,311 @@
+//! Priority admission control in front of `execute_ai_workflow`.
+//!
+//! Without this, 500 requests landing at once all race straight into
+//! `execute_ai_workflow_tracked` together. `WorkflowScheduler` bounds how
+//! many run concurrently (tied to
+//! `OptimizationConfig::resource_limits::max_concurrent_tasks`), dispatches
+//! higher-priority requests first with FIFO order within a priority class,
+//! and ages a request that's waited too long so a steady stream of
+//! `Critical`/`High` traffic can't starve `Low` requests forever. A request
+//! that's still queued once its own `timeout_seconds` elapses is rejected
+//! with a queue-timeout error rather than run late.
+
+use crate::PlatformError;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// Dispatch precedence for a queued `AIWorkflowRequest`. Ordered so that
+/// `Critical < High < Normal < Low` as declared, matching
+/// `agent_orchestrator::context_propagation::ContextPriority`'s convention
+/// of a lower discriminant meaning higher priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum WorkflowPriority {
+    Critical,
+    High,
+    Normal,
+    Low,
+}
+
+impl Default for WorkflowPriority {
+    fn default() -> Self {
+        WorkflowPriority::Normal
+    }
+}
+
+impl WorkflowPriority {
+    const ALL: [WorkflowPriority; 4] =
+        [WorkflowPriority::Critical, WorkflowPriority::High, WorkflowPriority::Normal, WorkflowPriority::Low];
+
+    fn index(self) -> usize {
+        match self {
+            WorkflowPriority::Critical => 0,
+            WorkflowPriority::High => 1,
+            WorkflowPriority::Normal => 2,
+            WorkflowPriority::Low => 3,
+        }
+    }
+}
+
+struct QueueEntry {
+    enqueued_at: Instant,
+    granted: Arc<AtomicBool>,
+    notify: Arc<Notify>,
+}
+
+/// Wait-time and depth counters for a single priority class.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct PriorityQueueMetrics {
+    pub queue_depth: usize,
+    pub dispatched: u64,
+    pub queue_timeouts: u64,
+    pub total_wait_ms: u64,
+    pub max_wait_ms: u64,
+}
+
+impl PriorityQueueMetrics {
+    pub fn average_wait_ms(&self) -> f64 {
+        if self.dispatched == 0 {
+            0.0
+        } else {
+            self.total_wait_ms as f64 / self.dispatched as f64
+        }
+    }
+}
+
+#[derive(Default)]
+struct MetricsInner {
+    critical: PriorityQueueMetrics,
+    high: PriorityQueueMetrics,
+    normal: PriorityQueueMetrics,
+    low: PriorityQueueMetrics,
+}
+
+impl MetricsInner {
+    fn get_mut(&mut self, priority: WorkflowPriority) -> &mut PriorityQueueMetrics {
+        match priority {
+            WorkflowPriority::Critical => &mut self.critical,
+            WorkflowPriority::High => &mut self.high,
+            WorkflowPriority::Normal => &mut self.normal,
+            WorkflowPriority::Low => &mut self.low,
+        }
+    }
+
+    fn get(&self, priority: WorkflowPriority) -> PriorityQueueMetrics {
+        match priority {
+            WorkflowPriority::Critical => self.critical,
+            WorkflowPriority::High => self.high,
+            WorkflowPriority::Normal => self.normal,
+            WorkflowPriority::Low => self.low,
+        }
+    }
+}
+
+/// A granted admission slot. Frees its concurrency slot and wakes the next
+/// queued request when dropped.
+pub struct AdmissionPermit {
+    scheduler: Arc<WorkflowScheduler>,
+}
+
+impl Drop for AdmissionPermit {
+    fn drop(&mut self) {
+        self.scheduler.inflight.fetch_sub(1, Ordering::AcqRel);
+        self.scheduler.dispatch();
+    }
+}
+
+/// Bounded priority queue gating concurrent `execute_ai_workflow` calls.
+pub struct WorkflowScheduler {
+    max_concurrency: usize,
+    aging_threshold: Duration,
+    inflight: AtomicUsize,
+    queues: parking_lot::Mutex<[VecDeque<QueueEntry>; 4]>,
+    metrics: parking_lot::Mutex<MetricsInner>,
+}
+
+impl WorkflowScheduler {
+    /// `max_concurrency` should track
+    /// `OptimizationConfig::resource_limits::max_concurrent_tasks`.
+    /// `aging_threshold` is how long a request can sit queued before it's
+    /// dispatched ahead of strict priority order to avoid starvation.
+    pub fn new(max_concurrency: usize, aging_threshold: Duration) -> Self {
+        Self {
+            max_concurrency: max_concurrency.max(1),
+            aging_threshold,
+            inflight: AtomicUsize::new(0),
+            queues: parking_lot::Mutex::new(Default::default()),
+            metrics: parking_lot::Mutex::new(MetricsInner::default()),
+        }
+    }
+
+    /// Queues a request at `priority` and waits for a concurrency slot.
+    /// Returns `PlatformError::ResourceError` if `timeout` elapses first.
+    pub async fn admit(
+        self: &Arc<Self>,
+        priority: WorkflowPriority,
+        timeout: Duration,
+    ) -> Result<AdmissionPermit, PlatformError> {
+        let granted = Arc::new(AtomicBool::new(false));
+        let notify = Arc::new(Notify::new());
+        let enqueued_at = Instant::now();
+
+        {
+            let mut queues = self.queues.lock();
+            queues[priority.index()].push_back(QueueEntry {
+                enqueued_at,
+                granted: Arc::clone(&granted),
+                notify: Arc::clone(&notify),
+            });
+            self.metrics.lock().get_mut(priority).queue_depth += 1;
+        }
+        self.dispatch();
+
+        let wait_for_grant = async {
+            while !granted.load(Ordering::Acquire) {
+                notify.notified().await;
+            }
+        };
+
+        match tokio::time::timeout(timeout, wait_for_grant).await {
+            Ok(()) => {
+                let waited_ms = enqueued_at.elapsed().as_millis() as u64;
+                let mut metrics = self.metrics.lock();
+                let entry = metrics.get_mut(priority);
+                entry.queue_depth = entry.queue_depth.saturating_sub(1);
+                entry.dispatched += 1;
+                entry.total_wait_ms += waited_ms;
+                entry.max_wait_ms = entry.max_wait_ms.max(waited_ms);
+                Ok(AdmissionPermit { scheduler: Arc::clone(self) })
+            }
+            Err(_) => {
+                self.remove_if_still_queued(priority, &granted);
+                let mut metrics = self.metrics.lock();
+                let entry = metrics.get_mut(priority);
+                entry.queue_depth = entry.queue_depth.saturating_sub(1);
+                entry.queue_timeouts += 1;
+                Err(PlatformError::ResourceError(format!(
+                    "{priority:?} priority workflow request timed out after {timeout:?} waiting in the admission queue"
+                )))
+            }
+        }
+    }
+
+    /// Removes an entry that timed out before it was ever granted a slot.
+    /// A no-op if `dispatch` already granted it between the timeout firing
+    /// and this running -- that grant simply goes unused, matching how
+    /// `cancel_workflow` tolerates a request finishing just before it lands.
+    fn remove_if_still_queued(&self, priority: WorkflowPriority, granted: &Arc<AtomicBool>) {
+        let mut queues = self.queues.lock();
+        let queue = &mut queues[priority.index()];
+        if let Some(pos) = queue.iter().position(|entry| Arc::ptr_eq(&entry.granted, granted)) {
+            queue.remove(pos);
+        }
+    }
+
+    /// Grants concurrency slots to queued entries until either the pool is
+    /// full or every queue is empty. Favors any entry that's aged past
+    /// `aging_threshold` over strict priority order, then falls back to
+    /// `Critical` > `High` > `Normal` > `Low`, FIFO within a class.
+    fn dispatch(&self) {
+        let mut queues = self.queues.lock();
+        loop {
+            if self.inflight.load(Ordering::Acquire) >= self.max_concurrency {
+                break;
+            }
+
+            let aged = WorkflowPriority::ALL
+                .into_iter()
+                .find(|p| queues[p.index()].front().is_some_and(|e| e.enqueued_at.elapsed() >= self.aging_threshold));
+            let next = aged.or_else(|| WorkflowPriority::ALL.into_iter().find(|p| !queues[p.index()].is_empty()));
+
+            let Some(priority) = next else { break };
+            let entry = queues[priority.index()].pop_front().expect("just checked non-empty");
+            self.inflight.fetch_add(1, Ordering::AcqRel);
+            entry.granted.store(true, Ordering::Release);
+            entry.notify.notify_one();
+        }
+    }
+
+    /// Current queue depth and wait-time stats for every priority class.
+    pub fn metrics(&self) -> [(WorkflowPriority, PriorityQueueMetrics); 4] {
+        let metrics = self.metrics.lock();
+        WorkflowPriority::ALL.map(|p| (p, metrics.get(p)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn a_request_within_capacity_is_admitted_immediately() {
+        let scheduler = Arc::new(WorkflowScheduler::new(2, Duration::from_secs(60)));
+
+        let permit = scheduler.admit(WorkflowPriority::Normal, Duration::from_secs(1)).await.unwrap();
+        assert_eq!(scheduler.metrics()[WorkflowPriority::Normal.index()].1.dispatched, 1);
+        drop(permit);
+    }
+
+    #[tokio::test]
+    async fn higher_priority_requests_are_dispatched_before_lower_ones() {
+        let scheduler = Arc::new(WorkflowScheduler::new(1, Duration::from_secs(60)));
+        let _first = scheduler.admit(WorkflowPriority::Normal, Duration::from_secs(1)).await.unwrap();
+
+        let scheduler_low = Arc::clone(&scheduler);
+        let low_task = tokio::spawn(async move { scheduler_low.admit(WorkflowPriority::Low, Duration::from_secs(5)).await });
+        tokio::task::yield_now().await;
+
+        let scheduler_critical = Arc::clone(&scheduler);
+        let critical_task =
+            tokio::spawn(async move { scheduler_critical.admit(WorkflowPriority::Critical, Duration::from_secs(5)).await });
+        tokio::task::yield_now().await;
+
+        drop(_first);
+
+        let critical_permit = critical_task.await.unwrap().unwrap();
+        assert!(!low_task.is_finished(), "the low-priority request must still be waiting behind critical");
+        drop(critical_permit);
+
+        low_task.await.unwrap().unwrap();
+    }
+
+    #[tokio::test]
+    async fn a_request_that_waits_past_its_timeout_is_rejected() {
+        let scheduler = Arc::new(WorkflowScheduler::new(1, Duration::from_secs(60)));
+        let _held = scheduler.admit(WorkflowPriority::Critical, Duration::from_secs(1)).await.unwrap();
+
+        let result = scheduler.admit(WorkflowPriority::Low, Duration::from_millis(50)).await;
+
+        assert!(matches!(result, Err(PlatformError::ResourceError(_))));
+        assert_eq!(scheduler.metrics()[WorkflowPriority::Low.index()].1.queue_timeouts, 1);
+        assert_eq!(scheduler.metrics()[WorkflowPriority::Low.index()].1.queue_depth, 0);
+    }
+
+    #[tokio::test]
+    async fn an_aged_low_priority_request_eventually_jumps_ahead_of_a_steady_high_priority_stream() {
+        let scheduler = Arc::new(WorkflowScheduler::new(1, Duration::from_millis(20)));
+        let _held = scheduler.admit(WorkflowPriority::Critical, Duration::from_secs(1)).await.unwrap();
+
+        let scheduler_low = Arc::clone(&scheduler);
+        let low_task = tokio::spawn(async move { scheduler_low.admit(WorkflowPriority::Low, Duration::from_secs(5)).await });
+
+        // Give the low-priority request time to age past `aging_threshold`
+        // before a fresh high-priority request arrives.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let scheduler_high = Arc::clone(&scheduler);
+        let high_task = tokio::spawn(async move { scheduler_high.admit(WorkflowPriority::High, Duration::from_secs(5)).await });
+        tokio::task::yield_now().await;
+
+        drop(_held);
+
+        let low_permit = low_task.await.unwrap().unwrap();
+        assert!(!high_task.is_finished(), "aging should have let the long-waiting low-priority request go first");
+        drop(low_permit);
+
+        high_task.await.unwrap().unwrap();
+    }
+}
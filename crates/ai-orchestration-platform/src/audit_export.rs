@@ -0,0 +1,268 @@
+//! Compliance export of `PlatformEventLog` audit entries to S3
+//!
+//! `PlatformEventLog` is an in-memory, bounded ring buffer: fine for serving
+//! recent `GET /platform/events` queries, but not durable or tamper-proof
+//! enough on its own for compliance frameworks like SOC2, which require
+//! long-lived, write-once retention. `S3AuditExporter` periodically drains
+//! events recorded since its last run, compresses them as gzip'd
+//! newline-delimited JSON, and hands the batch to an `S3UploadClient` for
+//! upload under object-lock retention. The local ring buffer stays a
+//! staging area only; S3 is the system of record once a batch lands.
+//!
+//! This workspace has no existing AWS SDK dependency, so uploads go through
+//! `S3UploadClient` rather than `aws-sdk-s3` directly -- production code
+//! backs it with a real S3 client, and tests substitute an in-memory
+//! recorder to verify the batching, compression, and object-lock headers
+//! without network access or localstack.
+
+use crate::{PlatformEvent, PlatformEventLog};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::io::Write;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// How often `S3AuditExporter::run` drains and uploads a batch.
+pub const DEFAULT_EXPORT_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Object lock mode applied to an exported audit batch, mirroring S3's own
+/// `ObjectLockMode` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectLockMode {
+    Governance,
+    Compliance,
+}
+
+/// S3 export destination and retention settings.
+#[derive(Debug, Clone)]
+pub struct S3ExportConfig {
+    pub bucket: String,
+    pub prefix: String,
+    pub retention_days: u32,
+}
+
+/// Audit logging settings, extended with an optional S3 export destination.
+/// `s3_export` is `None` when compliance export is disabled and the ring
+/// buffer is the only copy of the audit trail.
+#[derive(Debug, Clone, Default)]
+pub struct AuditConfig {
+    pub s3_export: Option<S3ExportConfig>,
+}
+
+/// One upload attempt, carrying everything a test needs to assert on the
+/// object-lock headers `S3AuditExporter` actually sent.
+#[derive(Debug, Clone)]
+pub struct S3PutRequest {
+    pub bucket: String,
+    pub key: String,
+    pub body: Vec<u8>,
+    pub object_lock_mode: ObjectLockMode,
+    pub object_lock_retain_until_days: u32,
+}
+
+/// Minimal S3 upload surface `S3AuditExporter` depends on.
+#[async_trait::async_trait]
+pub trait S3UploadClient: Send + Sync {
+    async fn put_object(&self, request: S3PutRequest) -> Result<(), String>;
+}
+
+/// Batches `PlatformEvent`s out of a `PlatformEventLog` and uploads them to
+/// S3 as compliance-locked, write-once objects every `export_batch` call.
+pub struct S3AuditExporter<C: S3UploadClient> {
+    event_log: Arc<PlatformEventLog>,
+    client: C,
+    config: S3ExportConfig,
+    last_export: Mutex<chrono::DateTime<chrono::Utc>>,
+}
+
+impl<C: S3UploadClient> S3AuditExporter<C> {
+    pub fn new(event_log: Arc<PlatformEventLog>, client: C, config: S3ExportConfig) -> Self {
+        Self {
+            event_log,
+            client,
+            config,
+            // Starts at the epoch so the first `export_batch` call picks up
+            // every event already recorded, not just ones emitted after the
+            // exporter was constructed.
+            last_export: Mutex::new(chrono::DateTime::<chrono::Utc>::UNIX_EPOCH),
+        }
+    }
+
+    /// Drains events recorded since the last successful export, compresses
+    /// them as gzip'd ndjson, and uploads the batch. Returns the number of
+    /// events exported (0 if there was nothing new to send).
+    pub async fn export_batch(&self) -> Result<usize, String> {
+        let since = *self.last_export.lock().await;
+        let events = self.event_log.events_since(since).await;
+        if events.is_empty() {
+            return Ok(0);
+        }
+
+        let now = chrono::Utc::now();
+        let body = Self::encode_ndjson_gz(&events)?;
+        let key = format!(
+            "{}/{}.ndjson.gz",
+            self.config.prefix.trim_end_matches('/'),
+            now.format("%Y%m%dT%H%M%S%.f")
+        );
+
+        self.client
+            .put_object(S3PutRequest {
+                bucket: self.config.bucket.clone(),
+                key,
+                body,
+                object_lock_mode: ObjectLockMode::Compliance,
+                object_lock_retain_until_days: self.config.retention_days,
+            })
+            .await?;
+
+        *self.last_export.lock().await = now;
+        Ok(events.len())
+    }
+
+    fn encode_ndjson_gz(events: &[PlatformEvent]) -> Result<Vec<u8>, String> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        for event in events {
+            let line = serde_json::to_string(event).map_err(|e| e.to_string())?;
+            encoder.write_all(line.as_bytes()).map_err(|e| e.to_string())?;
+            encoder.write_all(b"\n").map_err(|e| e.to_string())?;
+        }
+        encoder.finish().map_err(|e| e.to_string())
+    }
+
+    /// Runs `export_batch` on `interval` until the process shuts down.
+    /// Intended to be spawned as a background task alongside the platform's
+    /// other maintenance loops, batching every `DEFAULT_EXPORT_INTERVAL`.
+    pub async fn run(self: Arc<Self>, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = self.export_batch().await {
+                tracing::error!("audit log S3 export failed: {e}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PlatformHealthStatus, PlatformPerformanceMetrics, PlatformState, PlatformStatus, ResourceUsage};
+    use std::collections::HashMap;
+    use std::io::Read;
+    use tokio::sync::Mutex as TokioMutex;
+
+    #[derive(Default)]
+    struct RecordingUploadClient {
+        requests: TokioMutex<Vec<S3PutRequest>>,
+    }
+
+    #[async_trait::async_trait]
+    impl S3UploadClient for RecordingUploadClient {
+        async fn put_object(&self, request: S3PutRequest) -> Result<(), String> {
+            self.requests.lock().await.push(request);
+            Ok(())
+        }
+    }
+
+    fn sample_state() -> PlatformState {
+        PlatformState {
+            status: PlatformStatus::Running,
+            started_at: chrono::Utc::now(),
+            uptime: Duration::from_secs(0),
+            active_workflows: 0,
+            active_agents: 0,
+            total_requests_processed: 0,
+            current_resource_usage: ResourceUsage {
+                cpu_percent: 0.0,
+                memory_percent: 0.0,
+                network_utilization: 0.0,
+                storage_utilization: 0.0,
+                active_connections: 0,
+            },
+            performance_metrics: PlatformPerformanceMetrics {
+                average_response_time_ms: 0.0,
+                p50_response_time_ms: 0.0,
+                p95_response_time_ms: 0.0,
+                p99_response_time_ms: 0.0,
+                requests_per_second: 0.0,
+                error_rate: 0.0,
+                cache_hit_rate: 0.0,
+                throughput_optimization_ratio: 0.0,
+                resource_efficiency: 0.0,
+            },
+            health_status: PlatformHealthStatus {
+                overall_health: crate::HealthLevel::Healthy,
+                subsystem_health: HashMap::new(),
+                critical_issues: Vec::new(),
+                warnings: Vec::new(),
+            },
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn export_batch_uploads_a_compliance_locked_gzip_ndjson_object() {
+        let state = sample_state();
+        let event_log = Arc::new(PlatformEventLog::new(state.clone()));
+        event_log
+            .emit_event(crate::PlatformEventType::StatusChanged, &state, &state)
+            .await
+            .unwrap();
+        event_log
+            .emit_event(crate::PlatformEventType::HealthChanged, &state, &state)
+            .await
+            .unwrap();
+
+        let client = RecordingUploadClient::default();
+        let config = S3ExportConfig {
+            bucket: "compliance-audit-logs".to_string(),
+            prefix: "platform-events".to_string(),
+            retention_days: 2555, // 7 years
+        };
+        let exporter = S3AuditExporter::new(event_log, client, config);
+
+        let exported = exporter.export_batch().await.unwrap();
+        assert_eq!(exported, 2);
+
+        let requests = exporter.client.requests.lock().await;
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(request.bucket, "compliance-audit-logs");
+        assert!(request.key.starts_with("platform-events/"));
+        assert!(request.key.ends_with(".ndjson.gz"));
+        assert_eq!(request.object_lock_mode, ObjectLockMode::Compliance);
+        assert_eq!(request.object_lock_retain_until_days, 2555);
+
+        let mut decoder = flate2::read::GzDecoder::new(&request.body[..]);
+        let mut decompressed = String::new();
+        decoder.read_to_string(&mut decompressed).unwrap();
+        let lines: Vec<&str> = decompressed.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for line in lines {
+            let event: PlatformEvent = serde_json::from_str(line).unwrap();
+            assert!(matches!(
+                event.event_type,
+                crate::PlatformEventType::StatusChanged | crate::PlatformEventType::HealthChanged
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn export_batch_is_a_no_op_with_no_new_events() {
+        let state = sample_state();
+        let event_log = Arc::new(PlatformEventLog::new(state));
+        let client = RecordingUploadClient::default();
+        let config = S3ExportConfig {
+            bucket: "compliance-audit-logs".to_string(),
+            prefix: "platform-events".to_string(),
+            retention_days: 30,
+        };
+        let exporter = S3AuditExporter::new(event_log, client, config);
+
+        let exported = exporter.export_batch().await.unwrap();
+        assert_eq!(exported, 0);
+        assert!(exporter.client.requests.lock().await.is_empty());
+    }
+}
@@ -0,0 +1,314 @@
+//! Auto-scaling controller driven by `AutoScalingConfig`.
+//!
+//! `AutoScalingConfig` carries thresholds, instance bounds, and a cooldown
+//! period, but nothing in the platform ever reads it -- `ResourceUsage` is
+//! gathered and reported, never acted on. `AutoScaler::evaluate` closes that
+//! loop: given the platform's current `ResourceUsage`, it decides whether to
+//! scale up, scale down, or do nothing, honoring `cooldown_period` between
+//! actions and `min_instances`/`max_instances` as hard bounds. The actual
+//! scaling action is delegated to a `ScalingExecutor`, so a deployment that
+//! isn't just a semaphore of workflow slots -- e.g. one backed by a real
+//! container orchestrator -- can supply its own.
+//!
+//! Every decision `AutoScaler` makes, including a no-op "nothing to do" or a
+//! bounds-clamped one, is recorded and available via `get_scaling_history`,
+//! mirroring `ResourceManager::allocation_history`.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::RwLock;
+
+use crate::{AutoScalingConfig, ResourceUsage};
+
+/// How many past `ScalingDecision`s `AutoScaler` retains, oldest evicted
+/// first -- mirrors `PLATFORM_EVENT_LOG_CAPACITY`'s role for `PlatformEventLog`.
+const SCALING_HISTORY_CAPACITY: usize = 1_000;
+
+/// Which way a `ScalingDecision` moved the instance count, or that it left
+/// it unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalingDirection {
+    Up,
+    Down,
+    Unchanged,
+}
+
+/// One `AutoScaler::evaluate` outcome, whether or not it changed anything.
+#[derive(Debug, Clone)]
+pub struct ScalingDecision {
+    pub timestamp: DateTime<Utc>,
+    pub direction: ScalingDirection,
+    pub previous_instances: usize,
+    pub new_instances: usize,
+    pub observed_usage: ResourceUsage,
+    pub reason: String,
+}
+
+/// Applies a `ScalingDecision` to whatever actually backs the platform's
+/// capacity. Kept separate from `AutoScaler` so a deployment that scales a
+/// real container orchestrator (or a fleet behind a load balancer) can
+/// supply its own instead of `SemaphoreScalingExecutor`'s in-process default.
+#[async_trait]
+pub trait ScalingExecutor: Send + Sync {
+    async fn apply(&self, decision: &ScalingDecision) -> Result<(), String>;
+}
+
+/// Default `ScalingExecutor`: adjusts a `tokio::sync::Semaphore` gating how
+/// many `execute_ai_workflow` calls may run concurrently, so
+/// `new_instances` reads as "how many workflow slots are open" rather than
+/// literal machine instances.
+pub struct SemaphoreScalingExecutor {
+    pub semaphore: Arc<tokio::sync::Semaphore>,
+}
+
+impl SemaphoreScalingExecutor {
+    pub fn new(initial_instances: usize) -> Self {
+        Self { semaphore: Arc::new(tokio::sync::Semaphore::new(initial_instances.max(1))) }
+    }
+}
+
+#[async_trait]
+impl ScalingExecutor for SemaphoreScalingExecutor {
+    async fn apply(&self, decision: &ScalingDecision) -> Result<(), String> {
+        match decision.direction {
+            ScalingDirection::Up => {
+                let delta = decision.new_instances.saturating_sub(decision.previous_instances);
+                self.semaphore.add_permits(delta);
+                Ok(())
+            }
+            ScalingDirection::Down => {
+                let delta = decision.previous_instances.saturating_sub(decision.new_instances);
+                match self.semaphore.try_acquire_many(delta as u32) {
+                    Ok(permits) => {
+                        permits.forget();
+                        Ok(())
+                    }
+                    // All permits are currently checked out running workflows;
+                    // there's nothing idle to reclaim right now. The next
+                    // evaluation cycle will retry.
+                    Err(e) => Err(format!("could not reclaim {delta} idle permit(s) to scale down: {e}")),
+                }
+            }
+            ScalingDirection::Unchanged => Ok(()),
+        }
+    }
+}
+
+/// Periodically-evaluated scaling controller. Holds no reference to the
+/// platform itself -- `evaluate` takes the `ResourceUsage` it should act on,
+/// the same way `PerformanceOptimizer::optimize` takes a metrics snapshot
+/// rather than reaching into `PlatformState` on its own.
+pub struct AutoScaler {
+    config: AutoScalingConfig,
+    executor: Arc<dyn ScalingExecutor>,
+    current_instances: RwLock<usize>,
+    last_scaled_at: RwLock<Option<DateTime<Utc>>>,
+    history: RwLock<VecDeque<ScalingDecision>>,
+}
+
+impl AutoScaler {
+    pub fn new(config: AutoScalingConfig, executor: Arc<dyn ScalingExecutor>) -> Self {
+        let initial_instances = config.min_instances.max(1);
+        Self {
+            config,
+            executor,
+            current_instances: RwLock::new(initial_instances),
+            last_scaled_at: RwLock::new(None),
+            history: RwLock::new(VecDeque::with_capacity(SCALING_HISTORY_CAPACITY)),
+        }
+    }
+
+    /// Instance count `AutoScaler` currently believes is in effect.
+    pub async fn current_instances(&self) -> usize {
+        *self.current_instances.read().await
+    }
+
+    /// Compares `usage` against `scale_up_threshold`/`scale_down_threshold`
+    /// and, if warranted, applies a scaling step through the configured
+    /// `ScalingExecutor`. Returns `None` when `AutoScalingConfig::enabled`
+    /// is `false`; otherwise always returns a decision, including a
+    /// `ScalingDirection::Unchanged` one when nothing needed to change, so
+    /// `get_scaling_history` reflects every evaluation, not just the ones
+    /// that moved the needle.
+    pub async fn evaluate(&self, usage: &ResourceUsage) -> Option<ScalingDecision> {
+        if !self.config.enabled {
+            return None;
+        }
+
+        let utilization = usage.cpu_percent.max(usage.memory_percent);
+        let current = *self.current_instances.read().await;
+        let now = Utc::now();
+
+        let cooldown = chrono::Duration::from_std(self.config.cooldown_period).unwrap_or(chrono::Duration::zero());
+        let in_cooldown = self
+            .last_scaled_at
+            .read()
+            .await
+            .is_some_and(|last| now.signed_duration_since(last) < cooldown);
+
+        let (direction, target, reason) = if in_cooldown {
+            (ScalingDirection::Unchanged, current, "within cooldown period, no action taken".to_string())
+        } else if utilization >= self.config.scale_up_threshold && current < self.config.max_instances {
+            let target = (current + 1).min(self.config.max_instances);
+            (
+                ScalingDirection::Up,
+                target,
+                format!(
+                    "utilization {utilization:.1}% >= scale_up_threshold {:.1}%",
+                    self.config.scale_up_threshold
+                ),
+            )
+        } else if utilization <= self.config.scale_down_threshold && current > self.config.min_instances {
+            let target = (current.saturating_sub(1)).max(self.config.min_instances);
+            (
+                ScalingDirection::Down,
+                target,
+                format!(
+                    "utilization {utilization:.1}% <= scale_down_threshold {:.1}%",
+                    self.config.scale_down_threshold
+                ),
+            )
+        } else {
+            (ScalingDirection::Unchanged, current, format!("utilization {utilization:.1}% within thresholds"))
+        };
+
+        let decision = ScalingDecision {
+            timestamp: now,
+            direction,
+            previous_instances: current,
+            new_instances: target,
+            observed_usage: usage.clone(),
+            reason,
+        };
+
+        if direction != ScalingDirection::Unchanged {
+            if let Err(e) = self.executor.apply(&decision).await {
+                tracing::warn!("Auto-scaler failed to apply scaling decision: {e}");
+            } else {
+                *self.current_instances.write().await = target;
+                *self.last_scaled_at.write().await = Some(now);
+            }
+        }
+
+        let mut history = self.history.write().await;
+        if history.len() >= SCALING_HISTORY_CAPACITY {
+            history.pop_front();
+        }
+        history.push_back(decision.clone());
+
+        Some(decision)
+    }
+
+    /// Every `ScalingDecision` recorded so far, oldest first.
+    pub async fn get_scaling_history(&self) -> Vec<ScalingDecision> {
+        self.history.read().await.iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(cpu_percent: f64, memory_percent: f64) -> ResourceUsage {
+        ResourceUsage {
+            cpu_percent,
+            memory_percent,
+            network_utilization: 0.0,
+            storage_utilization: 0.0,
+            active_connections: 0,
+        }
+    }
+
+    fn config() -> AutoScalingConfig {
+        AutoScalingConfig {
+            enabled: true,
+            min_instances: 1,
+            max_instances: 5,
+            target_cpu_utilization: 70.0,
+            target_memory_utilization: 80.0,
+            scale_up_threshold: 80.0,
+            scale_down_threshold: 30.0,
+            cooldown_period: std::time::Duration::from_secs(0),
+        }
+    }
+
+    #[tokio::test]
+    async fn high_utilization_scales_up_and_grants_a_permit() {
+        let executor = Arc::new(SemaphoreScalingExecutor::new(1));
+        let scaler = AutoScaler::new(config(), executor.clone());
+
+        let decision = scaler.evaluate(&usage(90.0, 10.0)).await.unwrap();
+
+        assert_eq!(decision.direction, ScalingDirection::Up);
+        assert_eq!(decision.new_instances, 2);
+        assert_eq!(scaler.current_instances().await, 2);
+        assert_eq!(executor.semaphore.available_permits(), 2);
+    }
+
+    #[tokio::test]
+    async fn low_utilization_scales_down_but_never_below_min_instances() {
+        let executor = Arc::new(SemaphoreScalingExecutor::new(1));
+        let scaler = AutoScaler::new(config(), executor);
+
+        let decision = scaler.evaluate(&usage(5.0, 5.0)).await.unwrap();
+
+        assert_eq!(decision.direction, ScalingDirection::Unchanged, "already at min_instances=1");
+        assert_eq!(scaler.current_instances().await, 1);
+    }
+
+    #[tokio::test]
+    async fn never_scales_above_max_instances() {
+        let mut cfg = config();
+        cfg.max_instances = 2;
+        let executor = Arc::new(SemaphoreScalingExecutor::new(2));
+        let scaler = AutoScaler::new(cfg, executor);
+
+        scaler.evaluate(&usage(95.0, 95.0)).await;
+        let decision = scaler.evaluate(&usage(95.0, 95.0)).await.unwrap();
+
+        assert_eq!(scaler.current_instances().await, 2);
+        assert_eq!(decision.direction, ScalingDirection::Unchanged, "already at max_instances=2");
+    }
+
+    #[tokio::test]
+    async fn cooldown_period_blocks_a_second_scale_up_immediately_after_the_first() {
+        let mut cfg = config();
+        cfg.cooldown_period = std::time::Duration::from_secs(300);
+        let executor = Arc::new(SemaphoreScalingExecutor::new(1));
+        let scaler = AutoScaler::new(cfg, executor);
+
+        let first = scaler.evaluate(&usage(95.0, 95.0)).await.unwrap();
+        assert_eq!(first.direction, ScalingDirection::Up);
+
+        let second = scaler.evaluate(&usage(95.0, 95.0)).await.unwrap();
+        assert_eq!(second.direction, ScalingDirection::Unchanged, "still within cooldown_period");
+        assert_eq!(scaler.current_instances().await, 2);
+    }
+
+    #[tokio::test]
+    async fn disabled_config_never_produces_a_decision() {
+        let mut cfg = config();
+        cfg.enabled = false;
+        let executor = Arc::new(SemaphoreScalingExecutor::new(1));
+        let scaler = AutoScaler::new(cfg, executor);
+
+        assert!(scaler.evaluate(&usage(99.0, 99.0)).await.is_none());
+        assert!(scaler.get_scaling_history().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_scaling_history_records_every_evaluation_including_no_ops() {
+        let executor = Arc::new(SemaphoreScalingExecutor::new(1));
+        let scaler = AutoScaler::new(config(), executor);
+
+        scaler.evaluate(&usage(50.0, 50.0)).await;
+        scaler.evaluate(&usage(50.0, 50.0)).await;
+
+        let history = scaler.get_scaling_history().await;
+        assert_eq!(history.len(), 2);
+        assert!(history.iter().all(|d| d.direction == ScalingDirection::Unchanged));
+    }
+}
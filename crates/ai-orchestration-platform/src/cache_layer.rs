@@ -0,0 +1,209 @@
+//! An in-memory `CacheLayerBackend`, evicted by both TTL and an
+//! LRU-by-size-budget policy.
+//!
+//! `CacheLayer::size_mb` is treated as a byte budget for the values stored
+//! (not an entry count), since that's the unit the config already speaks
+//! in: on `set`, the least-recently-accessed entries are evicted until the
+//! layer is back under budget.
+
+use crate::{CacheLayerBackend, LayerStatistics, PlatformError};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+struct CacheEntry {
+    value: Vec<u8>,
+    expires_at: Option<Instant>,
+    last_accessed: Instant,
+}
+
+/// A `CacheLayerBackend` backed by a `Mutex<HashMap>`, with no cross-process
+/// sharing -- fine for a single instance, but a multi-instance deployment
+/// wanting cache coherence needs a `Redis`/`Distributed` layer instead.
+pub struct InMemoryCacheLayer {
+    max_bytes: usize,
+    default_ttl: Duration,
+    entries: Mutex<HashMap<String, CacheEntry>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    sets: AtomicU64,
+    deletes: AtomicU64,
+}
+
+impl InMemoryCacheLayer {
+    pub fn new(size_mb: usize, default_ttl: Duration) -> Self {
+        Self {
+            max_bytes: size_mb * 1024 * 1024,
+            default_ttl,
+            entries: Mutex::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            sets: AtomicU64::new(0),
+            deletes: AtomicU64::new(0),
+        }
+    }
+
+    /// Evicts least-recently-accessed entries until the total stored size
+    /// is back under `max_bytes`. Called with `entries` already locked.
+    fn evict_until_under_budget(entries: &mut HashMap<String, CacheEntry>, max_bytes: usize) {
+        let mut total_bytes: usize = entries.values().map(|e| e.value.len()).sum();
+        while total_bytes > max_bytes {
+            let Some(oldest_key) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_accessed)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            if let Some(evicted) = entries.remove(&oldest_key) {
+                total_bytes = total_bytes.saturating_sub(evicted.value.len());
+            }
+        }
+    }
+
+}
+
+#[async_trait::async_trait]
+impl CacheLayerBackend for InMemoryCacheLayer {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, PlatformError> {
+        let mut entries = self.entries.lock();
+        let Some(entry) = entries.get_mut(key) else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return Ok(None);
+        };
+
+        if entry.expires_at.is_some_and(|expires_at| Instant::now() >= expires_at) {
+            entries.remove(key);
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return Ok(None);
+        }
+
+        entry.last_accessed = Instant::now();
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Ok(Some(entry.value.clone()))
+    }
+
+    async fn set(&self, key: &str, value: Vec<u8>, ttl: Option<Duration>) -> Result<(), PlatformError> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock();
+        entries.insert(
+            key.to_string(),
+            CacheEntry {
+                value,
+                expires_at: Some(now + ttl.unwrap_or(self.default_ttl)),
+                last_accessed: now,
+            },
+        );
+        Self::evict_until_under_budget(&mut entries, self.max_bytes);
+        self.sets.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), PlatformError> {
+        if self.entries.lock().remove(key).is_some() {
+            self.deletes.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    async fn clear(&self) -> Result<(), PlatformError> {
+        self.entries.lock().clear();
+        Ok(())
+    }
+
+    async fn get_statistics(&self) -> Result<LayerStatistics, PlatformError> {
+        let entries = self.entries.lock();
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let hit_rate = if hits + misses > 0 {
+            hits as f64 / (hits + misses) as f64
+        } else {
+            0.0
+        };
+
+        Ok(LayerStatistics {
+            hits,
+            misses,
+            sets: self.sets.load(Ordering::Relaxed),
+            deletes: self.deletes.load(Ordering::Relaxed),
+            size_bytes: entries.values().map(|e| e.value.len()).sum(),
+            entry_count: entries.len(),
+            hit_rate,
+        })
+    }
+
+    async fn invalidate_prefix(&self, prefix: &str) -> Result<u64, PlatformError> {
+        let mut entries = self.entries.lock();
+        let keys_to_remove: Vec<String> = entries
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect();
+        for key in &keys_to_remove {
+            entries.remove(key);
+        }
+        self.deletes.fetch_add(keys_to_remove.len() as u64, Ordering::Relaxed);
+        Ok(keys_to_remove.len() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn a_set_value_round_trips_through_get() {
+        let layer = InMemoryCacheLayer::new(1, Duration::from_secs(60));
+        layer.set("key", b"value".to_vec(), None).await.unwrap();
+
+        let value = layer.get("key").await.unwrap();
+        assert_eq!(value, Some(b"value".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn a_missing_key_is_a_miss() {
+        let layer = InMemoryCacheLayer::new(1, Duration::from_secs(60));
+        assert_eq!(layer.get("missing").await.unwrap(), None);
+
+        let stats = layer.get_statistics().await.unwrap();
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.hits, 0);
+    }
+
+    #[tokio::test]
+    async fn an_entry_past_its_ttl_is_treated_as_a_miss() {
+        let layer = InMemoryCacheLayer::new(1, Duration::from_millis(0));
+        layer.set("key", b"value".to_vec(), Some(Duration::from_millis(0))).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        assert_eq!(layer.get("key").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn setting_past_the_size_budget_evicts_the_least_recently_accessed_entry() {
+        // 1 byte budget forces every set beyond the first to evict.
+        let layer = InMemoryCacheLayer {
+            max_bytes: 1,
+            ..InMemoryCacheLayer::new(0, Duration::from_secs(60))
+        };
+        layer.set("first", b"a".to_vec(), None).await.unwrap();
+        layer.set("second", b"b".to_vec(), None).await.unwrap();
+
+        assert_eq!(layer.get("first").await.unwrap(), None, "first should have been evicted");
+        assert_eq!(layer.get("second").await.unwrap(), Some(b"b".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn invalidate_prefix_removes_only_matching_keys() {
+        let layer = InMemoryCacheLayer::new(1, Duration::from_secs(60));
+        layer.set("workflow::a", b"1".to_vec(), None).await.unwrap();
+        layer.set("workflow::b", b"2".to_vec(), None).await.unwrap();
+        layer.set("other::c", b"3".to_vec(), None).await.unwrap();
+
+        let removed = layer.invalidate_prefix("workflow::").await.unwrap();
+
+        assert_eq!(removed, 2);
+        assert_eq!(layer.get("other::c").await.unwrap(), Some(b"3".to_vec()));
+    }
+}
@@ -0,0 +1,95 @@
+//! Cooperative cancellation token backing `AIOrchestrationPlatform::cancel_workflow`.
+//!
+//! This crate doesn't otherwise depend on `tokio-util`, so rather than pull
+//! it in for `tokio_util::sync::CancellationToken` this hand-rolls the same
+//! "flag plus wake the waiters" shape on top of `tokio::sync::Notify`, the
+//! same way `bayesian_optimizer` hand-rolled its Gaussian process instead of
+//! adding a numerics dependency for one small piece of state.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::sync::Notify;
+
+/// A single-shot cancellation signal. Cloning `AIOrchestrationPlatform`'s
+/// `Arc<CancellationToken>` for a request lets `cancel_workflow` and the
+/// in-flight `execute_ai_workflow` call share it without the registry
+/// needing to reach into the running future directly.
+#[derive(Debug, Default)]
+pub struct CancellationToken {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks this token cancelled and wakes every task currently awaiting
+    /// `cancelled()`. Idempotent: cancelling an already-cancelled token is a
+    /// no-op beyond re-notifying (harmless) waiters.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Resolves once `cancel()` has been called. Registers for a
+    /// notification before checking the flag so a `cancel()` racing with
+    /// this call can't be missed between the check and the wait.
+    pub async fn cancelled(&self) {
+        loop {
+            if self.is_cancelled() {
+                return;
+            }
+            let notified = self.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn cancelled_resolves_immediately_once_cancel_has_already_been_called() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        tokio::time::timeout(Duration::from_millis(50), token.cancelled())
+            .await
+            .expect("cancelled() must not block once the token is already cancelled");
+    }
+
+    #[tokio::test]
+    async fn cancelled_wakes_a_task_that_was_already_waiting() {
+        let token = Arc::new(CancellationToken::new());
+        let waiter = tokio::spawn({
+            let token = Arc::clone(&token);
+            async move { token.cancelled().await }
+        });
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        token.cancel();
+
+        tokio::time::timeout(Duration::from_millis(50), waiter)
+            .await
+            .expect("waiter should have been woken by cancel()")
+            .expect("waiter task should not panic");
+    }
+
+    #[tokio::test]
+    async fn an_uncancelled_token_never_resolves_cancelled() {
+        let token = CancellationToken::new();
+
+        assert!(tokio::time::timeout(Duration::from_millis(20), token.cancelled()).await.is_err());
+    }
+}
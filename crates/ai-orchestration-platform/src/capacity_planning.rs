@@ -0,0 +1,265 @@
+//! Capacity forecasting from `ResourceManager::allocation_history`.
+//!
+//! `ResourceManager` tracks allocations and RBAC as they happen, but has no
+//! notion of where a resource type is *headed*. `CapacityPlanningReport::generate`
+//! fits a simple linear trend per resource type over its last 30 days of
+//! granted/released allocations and projects forward, so operators get a
+//! forecasted exhaustion date instead of only a point-in-time utilization
+//! number.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{AllocationEvent, AllocationEventType, ResourceManagementConfig, ResourceType};
+
+/// Lookback window `CapacityPlanningReport::generate` fits its trend over.
+const TREND_WINDOW_DAYS: i64 = 30;
+
+/// A follow-up recommended by `CapacityPlanningReport::generate` for a
+/// resource type projected to run out within the planning horizon.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CapacityAction {
+    AddCapacity {
+        resource_type: ResourceType,
+        additional_amount: f64,
+        by_date: DateTime<Utc>,
+    },
+    OptimizeAllocation {
+        strategy: String,
+    },
+}
+
+/// Output of `CapacityPlanningReport::generate`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapacityForecast {
+    /// Most recent known allocated amount per resource type.
+    pub current_utilization: HashMap<ResourceType, f64>,
+    /// When each resource type's linear trend crosses its configured limit,
+    /// if the trend is rising and a limit is known for that resource type.
+    pub forecasted_exhaustion: HashMap<ResourceType, Option<DateTime<Utc>>>,
+    pub recommended_actions: Vec<CapacityAction>,
+}
+
+/// Builds a [`CapacityForecast`] from a resource manager's allocation
+/// history.
+pub struct CapacityPlanningReport;
+
+impl CapacityPlanningReport {
+    /// Fits a per-resource-type linear trend (least squares over day offset
+    /// vs. cumulative allocated amount) using `Grant`/`Release` events from
+    /// the last [`TREND_WINDOW_DAYS`] days of `history`, then reports
+    /// current utilization, forecasted exhaustion dates, and a recommended
+    /// action for any resource type projected to exhaust within
+    /// `planning_horizon_days`.
+    pub fn generate(
+        history: &[AllocationEvent],
+        config: &ResourceManagementConfig,
+        planning_horizon_days: u64,
+    ) -> CapacityForecast {
+        let now = history
+            .iter()
+            .map(|event| event.timestamp)
+            .max()
+            .unwrap_or_else(Utc::now);
+        let window_start = now - ChronoDuration::days(TREND_WINDOW_DAYS);
+
+        let mut relevant: Vec<&AllocationEvent> = history
+            .iter()
+            .filter(|event| {
+                event.success
+                    && event.timestamp >= window_start
+                    && matches!(
+                        event.event_type,
+                        AllocationEventType::Grant | AllocationEventType::Release
+                    )
+            })
+            .collect();
+        relevant.sort_by_key(|event| event.timestamp);
+
+        // Cumulative allocated amount per resource type, sampled at every
+        // grant/release, giving the (day_offset, amount) series each
+        // resource type's trend is fit over.
+        let mut running_totals: HashMap<ResourceType, f64> = HashMap::new();
+        let mut series: HashMap<ResourceType, Vec<(f64, f64)>> = HashMap::new();
+        for event in relevant {
+            let delta = match event.event_type {
+                AllocationEventType::Grant => event.amount,
+                AllocationEventType::Release => -event.amount,
+                _ => 0.0,
+            };
+            let total = running_totals
+                .entry(event.resource_type.clone())
+                .or_insert(0.0);
+            *total += delta;
+
+            let day_offset = (event.timestamp - window_start).num_seconds() as f64 / 86_400.0;
+            series
+                .entry(event.resource_type.clone())
+                .or_default()
+                .push((day_offset, *total));
+        }
+
+        let mut current_utilization = HashMap::new();
+        let mut forecasted_exhaustion = HashMap::new();
+        let mut recommended_actions = Vec::new();
+
+        for (resource_type, points) in &series {
+            let current_amount = points.last().map(|(_, amount)| *amount).unwrap_or(0.0);
+            current_utilization.insert(resource_type.clone(), current_amount);
+
+            let limit = Self::configured_limit(resource_type, config);
+            let trend = fit_linear_trend(points);
+
+            let exhaustion_date = match (trend, limit) {
+                (Some((intercept, slope)), Some(limit)) if slope > 0.0 => {
+                    let day_offset_at_limit = (limit - intercept) / slope;
+                    if day_offset_at_limit >= 0.0 {
+                        Some(window_start + ChronoDuration::seconds((day_offset_at_limit * 86_400.0) as i64))
+                    } else {
+                        // Trend already crossed the limit; it happened in the past.
+                        Some(now)
+                    }
+                }
+                _ => None,
+            };
+            forecasted_exhaustion.insert(resource_type.clone(), exhaustion_date);
+
+            if let Some(exhaustion_date) = exhaustion_date {
+                if exhaustion_date <= now + ChronoDuration::days(planning_horizon_days as i64) {
+                    let limit = limit.unwrap_or(current_amount);
+                    recommended_actions.push(CapacityAction::AddCapacity {
+                        resource_type: resource_type.clone(),
+                        additional_amount: (limit - current_amount).max(0.0) + limit * 0.2,
+                        by_date: exhaustion_date,
+                    });
+                    recommended_actions.push(CapacityAction::OptimizeAllocation {
+                        strategy: format!(
+                            "review {resource_type:?} allocations for reclaimable idle capacity before {exhaustion_date}"
+                        ),
+                    });
+                }
+            }
+        }
+
+        CapacityForecast {
+            current_utilization,
+            forecasted_exhaustion,
+            recommended_actions,
+        }
+    }
+
+    fn configured_limit(resource_type: &ResourceType, config: &ResourceManagementConfig) -> Option<f64> {
+        match resource_type {
+            ResourceType::CPU => Some(config.max_cpu_cores as f64),
+            ResourceType::Memory => Some(config.max_memory_gb as f64),
+            ResourceType::Network => Some(config.max_network_bandwidth_gbps),
+            ResourceType::Storage => Some(config.max_storage_gb as f64),
+            ResourceType::GPU | ResourceType::Custom { .. } => None,
+        }
+    }
+}
+
+/// Ordinary least squares fit of `y = intercept + slope * x` over `points`.
+/// Returns `None` for fewer than two points, where a trend can't be fit.
+fn fit_linear_trend(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+    let n = points.len() as f64;
+    if points.len() < 2 {
+        return None;
+    }
+
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+
+    let mut numerator = 0.0;
+    let mut denominator = 0.0;
+    for (x, y) in points {
+        numerator += (x - mean_x) * (y - mean_y);
+        denominator += (x - mean_x).powi(2);
+    }
+
+    if denominator == 0.0 {
+        return Some((mean_y, 0.0));
+    }
+
+    let slope = numerator / denominator;
+    let intercept = mean_y - slope * mean_x;
+    Some((intercept, slope))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn grant(days_ago: i64, resource_type: ResourceType, amount: f64) -> AllocationEvent {
+        AllocationEvent {
+            event_id: Uuid::new_v4(),
+            event_type: AllocationEventType::Grant,
+            resource_id: "cpu-pool-1".to_string(),
+            resource_type,
+            amount,
+            requestor: "worker".to_string(),
+            timestamp: Utc::now() - ChronoDuration::days(days_ago),
+            success: true,
+            reason: None,
+        }
+    }
+
+    fn config() -> ResourceManagementConfig {
+        ResourceManagementConfig {
+            max_cpu_cores: 32,
+            max_memory_gb: 128,
+            max_network_bandwidth_gbps: 10.0,
+            max_storage_gb: 1000,
+            resource_allocation_strategy: crate::AllocationStrategy::Dynamic,
+            resource_monitoring_interval: std::time::Duration::from_secs(60),
+            resource_optimization_enabled: true,
+        }
+    }
+
+    #[test]
+    fn recommends_adding_capacity_before_a_growing_resource_exhausts() {
+        // Cumulative CPU allocation growing ~10%/week for the last 4 weeks,
+        // starting well under the 32-core limit and ending close to it.
+        let mut history = Vec::new();
+        let mut cumulative = 10.0;
+        for week in (0..4).rev() {
+            let days_ago = week * 7;
+            history.push(grant(days_ago, ResourceType::CPU, cumulative));
+            cumulative *= 1.10;
+        }
+        // A handful of small grants each covering the same week so the
+        // trend has more than one point per week to fit.
+        history.push(grant(2, ResourceType::CPU, 3.0));
+        history.push(grant(1, ResourceType::CPU, 2.0));
+
+        let forecast = CapacityPlanningReport::generate(&history, &config(), 90);
+
+        assert!(
+            forecast
+                .recommended_actions
+                .iter()
+                .any(|action| matches!(action, CapacityAction::AddCapacity { resource_type: ResourceType::CPU, .. })),
+            "expected an AddCapacity recommendation for CPU before exhaustion: {:?}",
+            forecast.recommended_actions
+        );
+    }
+
+    #[test]
+    fn stable_resource_with_no_upward_trend_has_no_forecasted_exhaustion() {
+        // One real grant, followed by no-op renewals (amount 0) so the
+        // series has multiple points but a flat total the whole window.
+        let history = vec![
+            grant(20, ResourceType::Memory, 40.0),
+            grant(10, ResourceType::Memory, 0.0),
+            grant(1, ResourceType::Memory, 0.0),
+        ];
+
+        let forecast = CapacityPlanningReport::generate(&history, &config(), 90);
+
+        assert_eq!(forecast.forecasted_exhaustion.get(&ResourceType::Memory), Some(&None));
+        assert!(forecast.recommended_actions.is_empty());
+    }
+}
@@ -0,0 +1,638 @@
+//! Consensus for `PlatformState` across multiple in-process
+//! `AIOrchestrationPlatform` instances.
+//!
+//! Implements a simplified Raft-style consensus protocol (leader election +
+//! log replication, hand-rolled rather than built on `openraft`) so several
+//! instances sharing one process -- see `AIOrchestrationPlatform::new_clustered`
+//! -- can agree on a single canonical `PlatformState`. The transport between
+//! peers is abstracted behind [`ConsensusTransport`] so the algorithm itself
+//! stays testable without a real network stack, but the only implementation
+//! that exists today is [`InProcessTransport`]: there is no HTTP/gRPC
+//! transport in this crate, so this does not yet support consensus across
+//! separate hosts or processes.
+
+use crate::PlatformState;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// A single node's role in the Raft cluster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RaftRole {
+    Follower,
+    Candidate,
+    Leader,
+}
+
+/// One entry in the replicated log: a term-stamped `PlatformState` snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub term: u64,
+    pub index: u64,
+    pub state: PlatformState,
+}
+
+/// RPC sent by a candidate to request votes from peers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoteRequest {
+    pub term: u64,
+    pub candidate_id: String,
+    pub last_log_index: u64,
+    pub last_log_term: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoteResponse {
+    pub term: u64,
+    pub vote_granted: bool,
+}
+
+/// RPC sent by the leader to replicate log entries (or as a heartbeat when
+/// `entries` is empty).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppendEntriesRequest {
+    pub term: u64,
+    pub leader_id: String,
+    pub prev_log_index: u64,
+    pub prev_log_term: u64,
+    pub entries: Vec<LogEntry>,
+    pub leader_commit: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppendEntriesResponse {
+    pub term: u64,
+    pub success: bool,
+    pub match_index: u64,
+}
+
+/// Abstraction over how consensus RPCs reach other nodes. A cross-host
+/// deployment would need an HTTP/gRPC implementation of this trait, but
+/// none exists in this crate yet -- [`InProcessTransport`] is the only
+/// implementation, so every node in a cluster must currently share a
+/// process.
+#[async_trait]
+pub trait ConsensusTransport: Send + Sync {
+    async fn send_vote_request(&self, peer_id: &str, request: VoteRequest) -> Option<VoteResponse>;
+    async fn send_append_entries(
+        &self,
+        peer_id: &str,
+        request: AppendEntriesRequest,
+    ) -> Option<AppendEntriesResponse>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConsensusError {
+    #[error("node is not the current leader; last known leader term is {current_term}")]
+    NotLeader { current_term: u64 },
+    #[error("failed to reach a quorum of peers for term {term}")]
+    QuorumUnreachable { term: u64 },
+}
+
+struct RaftInner {
+    role: RaftRole,
+    current_term: u64,
+    voted_for: Option<String>,
+    log: Vec<LogEntry>,
+    commit_index: u64,
+    leader_id: Option<String>,
+    /// Per-peer index of the next log entry the leader believes it needs to
+    /// send, used to backfill entries a peer is missing (Raft §5.3).
+    /// Reinitialized to `log.len() + 1` whenever this node wins an
+    /// election; meaningless (and unused) while not the leader.
+    next_index: HashMap<String, u64>,
+}
+
+/// Coordinates Raft-based consensus over `PlatformState` for one node in the
+/// cluster.
+pub struct RaftConsensus {
+    node_id: String,
+    peer_ids: Vec<String>,
+    transport: Arc<dyn ConsensusTransport>,
+    inner: RwLock<RaftInner>,
+}
+
+impl RaftConsensus {
+    pub fn new(node_id: String, peer_ids: Vec<String>, transport: Arc<dyn ConsensusTransport>) -> Self {
+        Self {
+            node_id,
+            peer_ids,
+            transport,
+            inner: RwLock::new(RaftInner {
+                role: RaftRole::Follower,
+                current_term: 0,
+                voted_for: None,
+                log: Vec::new(),
+                commit_index: 0,
+                leader_id: None,
+                next_index: HashMap::new(),
+            }),
+        }
+    }
+
+    /// Builds a fully in-process-wired `RaftConsensus` cluster of
+    /// `node_ids.len()` nodes sharing one `InProcessTransport`, so tests
+    /// (and single-host multi-instance simulations) don't need to
+    /// hand-assemble the transport and peer lists themselves.
+    pub async fn new_clustered(node_ids: Vec<String>) -> (Vec<Arc<RaftConsensus>>, Arc<InProcessTransport>) {
+        let transport = Arc::new(InProcessTransport::new());
+        let mut nodes = Vec::with_capacity(node_ids.len());
+        for node_id in &node_ids {
+            let peer_ids: Vec<String> = node_ids.iter().filter(|id| *id != node_id).cloned().collect();
+            let node = Arc::new(RaftConsensus::new(node_id.clone(), peer_ids, transport.clone()));
+            transport.register_peer(node_id.clone(), node.clone()).await;
+            nodes.push(node);
+        }
+        (nodes, transport)
+    }
+
+    pub async fn role(&self) -> RaftRole {
+        self.inner.read().await.role
+    }
+
+    pub async fn current_term(&self) -> u64 {
+        self.inner.read().await.current_term
+    }
+
+    /// Quorum size for the current cluster (this node plus its peers).
+    fn quorum_size(&self) -> usize {
+        (self.peer_ids.len() + 1) / 2 + 1
+    }
+
+    /// Runs a single election: becomes a candidate, requests votes from all
+    /// peers, and becomes leader if a quorum grants a vote.
+    pub async fn run_election(&self) -> Result<(), ConsensusError> {
+        let (term, last_log_index, last_log_term) = {
+            let mut inner = self.inner.write().await;
+            inner.role = RaftRole::Candidate;
+            inner.current_term += 1;
+            inner.voted_for = Some(self.node_id.clone());
+            let last_log_index = inner.log.last().map(|e| e.index).unwrap_or(0);
+            let last_log_term = inner.log.last().map(|e| e.term).unwrap_or(0);
+            (inner.current_term, last_log_index, last_log_term)
+        };
+
+        let mut votes = 1usize; // vote for self
+        for peer_id in &self.peer_ids {
+            let request = VoteRequest {
+                term,
+                candidate_id: self.node_id.clone(),
+                last_log_index,
+                last_log_term,
+            };
+            if let Some(response) = self.transport.send_vote_request(peer_id, request).await {
+                if response.term > term {
+                    self.step_down(response.term).await;
+                    return Err(ConsensusError::QuorumUnreachable { term });
+                }
+                if response.vote_granted {
+                    votes += 1;
+                }
+            }
+        }
+
+        if votes >= self.quorum_size() {
+            let mut inner = self.inner.write().await;
+            inner.role = RaftRole::Leader;
+            inner.leader_id = Some(self.node_id.clone());
+            let next = inner.log.last().map(|e| e.index).unwrap_or(0) + 1;
+            inner.next_index = self.peer_ids.iter().map(|peer_id| (peer_id.clone(), next)).collect();
+            Ok(())
+        } else {
+            self.step_down(term).await;
+            Err(ConsensusError::QuorumUnreachable { term })
+        }
+    }
+
+    async fn step_down(&self, term: u64) {
+        let mut inner = self.inner.write().await;
+        inner.role = RaftRole::Follower;
+        inner.current_term = term;
+        inner.voted_for = None;
+    }
+
+    /// Proposes a new `PlatformState` to the cluster. Only the leader may
+    /// propose; the entry is appended locally and replicated to peers
+    /// (backfilling any earlier entries a peer hasn't yet acknowledged --
+    /// see `replicate_to_peer`), and is considered committed once a quorum
+    /// acknowledges it.
+    pub async fn propose_state(&self, state: PlatformState) -> Result<u64, ConsensusError> {
+        let (term, entry) = {
+            let mut inner = self.inner.write().await;
+            if inner.role != RaftRole::Leader {
+                return Err(ConsensusError::NotLeader {
+                    current_term: inner.current_term,
+                });
+            }
+            let prev_index = inner.log.last().map(|e| e.index).unwrap_or(0);
+            let entry = LogEntry {
+                term: inner.current_term,
+                index: prev_index + 1,
+                state,
+            };
+            inner.log.push(entry.clone());
+            (inner.current_term, entry)
+        };
+
+        let mut acks = 1usize; // leader has the entry locally
+        for peer_id in &self.peer_ids {
+            if self.replicate_to_peer(peer_id, term).await {
+                acks += 1;
+            }
+        }
+
+        if acks >= self.quorum_size() {
+            {
+                let mut inner = self.inner.write().await;
+                inner.commit_index = entry.index;
+            }
+            // Peers that acknowledged this entry did so before the leader
+            // raised its commit index, so their own commit_index is still
+            // one entry stale. A second, empty-bodied round (a heartbeat,
+            // in effect) carries the new leader_commit to them -- the same
+            // mechanism real Raft leaders rely on rather than trying to
+            // commit and notify atomically.
+            for peer_id in &self.peer_ids {
+                self.replicate_to_peer(peer_id, term).await;
+            }
+            Ok(entry.index)
+        } else {
+            Err(ConsensusError::QuorumUnreachable { term })
+        }
+    }
+
+    /// Replicates every log entry `peer_id` is missing, starting from its
+    /// tracked `next_index`, and on rejection backs off one entry at a time
+    /// and retries -- Raft's log-matching backfill algorithm (§5.3) -- until
+    /// the peer accepts, its `next_index` bottoms out at the start of the
+    /// log, or the peer reports a higher term (in which case this node
+    /// steps down, since it's no longer a valid leader). Returns whether
+    /// the peer ultimately acknowledged the entry `propose_state` is
+    /// waiting on.
+    async fn replicate_to_peer(&self, peer_id: &str, term: u64) -> bool {
+        loop {
+            let (prev_log_index, prev_log_term, entries, leader_commit, next_index) = {
+                let inner = self.inner.read().await;
+                let next_index = inner.next_index.get(peer_id).copied().unwrap_or(1).max(1);
+                let prev_log_index = next_index - 1;
+                let prev_log_term = inner.log.iter().find(|e| e.index == prev_log_index).map(|e| e.term).unwrap_or(0);
+                let entries: Vec<LogEntry> = inner.log.iter().filter(|e| e.index >= next_index).cloned().collect();
+                (prev_log_index, prev_log_term, entries, inner.commit_index, next_index)
+            };
+
+            let request = AppendEntriesRequest {
+                term,
+                leader_id: self.node_id.clone(),
+                prev_log_index,
+                prev_log_term,
+                entries,
+                leader_commit,
+            };
+
+            let Some(response) = self.transport.send_append_entries(peer_id, request).await else {
+                return false;
+            };
+
+            if response.term > term {
+                self.step_down(response.term).await;
+                return false;
+            }
+
+            if response.success {
+                self.inner.write().await.next_index.insert(peer_id.to_string(), response.match_index + 1);
+                return true;
+            }
+
+            if next_index <= 1 {
+                // Nothing earlier to back off to -- the peer's log can't be
+                // reconciled at this term.
+                return false;
+            }
+            self.inner.write().await.next_index.insert(peer_id.to_string(), next_index - 1);
+        }
+    }
+
+    /// Applied by a follower when it receives an `AppendEntries` RPC from
+    /// the current leader. Enforces the Log Matching Property (Raft §5.3):
+    /// entries are only accepted when the follower's log already agrees
+    /// with the leader up to `prev_log_index`/`prev_log_term`; otherwise
+    /// this rejects so the leader backs off and backfills via
+    /// `replicate_to_peer`.
+    pub async fn handle_append_entries(&self, request: AppendEntriesRequest) -> AppendEntriesResponse {
+        let mut inner = self.inner.write().await;
+        if request.term < inner.current_term {
+            return AppendEntriesResponse {
+                term: inner.current_term,
+                success: false,
+                match_index: inner.log.last().map(|e| e.index).unwrap_or(0),
+            };
+        }
+
+        inner.role = RaftRole::Follower;
+        inner.current_term = request.term;
+        inner.leader_id = Some(request.leader_id.clone());
+
+        if request.prev_log_index > 0 {
+            let log_matches = inner
+                .log
+                .iter()
+                .find(|e| e.index == request.prev_log_index)
+                .is_some_and(|e| e.term == request.prev_log_term);
+            if !log_matches {
+                return AppendEntriesResponse {
+                    term: inner.current_term,
+                    success: false,
+                    match_index: inner.log.last().map(|e| e.index).unwrap_or(0),
+                };
+            }
+        }
+
+        for entry in request.entries {
+            match inner.log.iter().position(|e| e.index == entry.index) {
+                // An existing entry at this index conflicts (different
+                // term): it and everything after it is stale, since the
+                // leader's log is authoritative from this point on.
+                Some(existing) if inner.log[existing].term != entry.term => {
+                    inner.log.truncate(existing);
+                    inner.log.push(entry);
+                }
+                // Already have exactly this entry; nothing to do.
+                Some(_) => {}
+                None => inner.log.push(entry),
+            }
+        }
+        inner.commit_index = request.leader_commit.min(inner.log.last().map(|e| e.index).unwrap_or(0));
+
+        AppendEntriesResponse {
+            term: inner.current_term,
+            success: true,
+            match_index: inner.log.last().map(|e| e.index).unwrap_or(0),
+        }
+    }
+
+    /// Applied by a follower when it receives a `RequestVote` RPC.
+    pub async fn handle_vote_request(&self, request: VoteRequest) -> VoteResponse {
+        let mut inner = self.inner.write().await;
+        if request.term < inner.current_term {
+            return VoteResponse {
+                term: inner.current_term,
+                vote_granted: false,
+            };
+        }
+
+        let last_log_index = inner.log.last().map(|e| e.index).unwrap_or(0);
+        let last_log_term = inner.log.last().map(|e| e.term).unwrap_or(0);
+        let log_is_up_to_date = request.last_log_term > last_log_term
+            || (request.last_log_term == last_log_term && request.last_log_index >= last_log_index);
+
+        // A newer term wipes any vote this node cast in an older one --
+        // otherwise a node that already voted last term could never vote
+        // again, even for a legitimate candidate in a fresh election.
+        if request.term > inner.current_term {
+            inner.current_term = request.term;
+            inner.voted_for = None;
+        }
+
+        let can_vote = inner.voted_for.is_none() || inner.voted_for.as_deref() == Some(request.candidate_id.as_str());
+
+        if can_vote && log_is_up_to_date {
+            inner.voted_for = Some(request.candidate_id.clone());
+            VoteResponse {
+                term: inner.current_term,
+                vote_granted: true,
+            }
+        } else {
+            VoteResponse {
+                term: inner.current_term,
+                vote_granted: false,
+            }
+        }
+    }
+
+    /// Returns the most recently committed `PlatformState`, if any.
+    pub async fn committed_state(&self) -> Option<PlatformState> {
+        let inner = self.inner.read().await;
+        inner
+            .log
+            .iter()
+            .filter(|e| e.index <= inner.commit_index)
+            .last()
+            .map(|e| e.state.clone())
+    }
+
+    pub async fn leader_id(&self) -> Option<String> {
+        self.inner.read().await.leader_id.clone()
+    }
+}
+
+/// In-process transport that dispatches RPCs directly to other
+/// `RaftConsensus` instances held in the same process — useful for tests
+/// and for a single-host multi-instance simulation.
+pub struct InProcessTransport {
+    peers: RwLock<HashMap<String, Arc<RaftConsensus>>>,
+}
+
+impl InProcessTransport {
+    pub fn new() -> Self {
+        Self {
+            peers: RwLock::new(HashMap::new()),
+        }
+    }
+
+    pub async fn register_peer(&self, node_id: String, node: Arc<RaftConsensus>) {
+        self.peers.write().await.insert(node_id, node);
+    }
+}
+
+impl Default for InProcessTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ConsensusTransport for InProcessTransport {
+    async fn send_vote_request(&self, peer_id: &str, request: VoteRequest) -> Option<VoteResponse> {
+        let peer = self.peers.read().await.get(peer_id).cloned()?;
+        Some(peer.handle_vote_request(request).await)
+    }
+
+    async fn send_append_entries(
+        &self,
+        peer_id: &str,
+        request: AppendEntriesRequest,
+    ) -> Option<AppendEntriesResponse> {
+        let peer = self.peers.read().await.get(peer_id).cloned()?;
+        Some(peer.handle_append_entries(request).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HealthLevel, PlatformHealthStatus, PlatformPerformanceMetrics, PlatformStatus, ResourceUsage};
+    use std::time::Duration;
+
+    fn sample_state(total_requests_processed: u64) -> PlatformState {
+        PlatformState {
+            status: PlatformStatus::Running,
+            started_at: chrono::Utc::now(),
+            uptime: Duration::from_secs(120),
+            active_workflows: 3,
+            active_agents: 2,
+            total_requests_processed,
+            current_resource_usage: ResourceUsage {
+                cpu_percent: 12.5,
+                memory_percent: 30.0,
+                network_utilization: 0.1,
+                storage_utilization: 0.2,
+                active_connections: 5,
+            },
+            performance_metrics: PlatformPerformanceMetrics {
+                average_response_time_ms: 100.0,
+                p50_response_time_ms: 100.0,
+                p95_response_time_ms: 100.0,
+                p99_response_time_ms: 100.0,
+                requests_per_second: 5.0,
+                error_rate: 0.0,
+                cache_hit_rate: 0.9,
+                throughput_optimization_ratio: 1.0,
+                resource_efficiency: 1.0,
+            },
+            health_status: PlatformHealthStatus {
+                overall_health: HealthLevel::Healthy,
+                subsystem_health: HashMap::new(),
+                critical_issues: Vec::new(),
+                warnings: Vec::new(),
+            },
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_follower_rejects_entries_that_would_leave_a_gap_in_its_log() {
+        let (nodes, _transport) = RaftConsensus::new_clustered(vec!["n1".into(), "n2".into()]).await;
+        let follower = &nodes[1];
+
+        let request = AppendEntriesRequest {
+            term: 1,
+            leader_id: "n1".to_string(),
+            prev_log_index: 5,
+            prev_log_term: 1,
+            entries: vec![LogEntry { term: 1, index: 6, state: sample_state(1) }],
+            leader_commit: 0,
+        };
+
+        let response = follower.handle_append_entries(request).await;
+
+        assert!(!response.success, "a follower with an empty log must reject a request that assumes 5 prior entries");
+        assert_eq!(response.match_index, 0, "nothing was appended, so match_index must reflect the unchanged log");
+    }
+
+    #[tokio::test]
+    async fn a_follower_truncates_a_conflicting_suffix_before_appending_the_leader_s_entries() {
+        let (nodes, _transport) = RaftConsensus::new_clustered(vec!["n1".into(), "n2".into()]).await;
+        let follower = &nodes[1];
+
+        // Follower has a stale, uncommitted entry at index 2 from an old term.
+        follower
+            .handle_append_entries(AppendEntriesRequest {
+                term: 1,
+                leader_id: "n1".to_string(),
+                prev_log_index: 0,
+                prev_log_term: 0,
+                entries: vec![
+                    LogEntry { term: 1, index: 1, state: sample_state(1) },
+                    LogEntry { term: 1, index: 2, state: sample_state(2) },
+                ],
+                leader_commit: 0,
+            })
+            .await;
+
+        // The new leader has a *different* (term 2) entry at index 2.
+        let response = follower
+            .handle_append_entries(AppendEntriesRequest {
+                term: 2,
+                leader_id: "n1".to_string(),
+                prev_log_index: 1,
+                prev_log_term: 1,
+                entries: vec![LogEntry { term: 2, index: 2, state: sample_state(99) }],
+                leader_commit: 0,
+            })
+            .await;
+
+        assert!(response.success);
+        let committed = follower.committed_state().await;
+        assert!(committed.is_none(), "leader_commit stayed at 0, nothing should be considered committed yet");
+
+        // Confirm the conflicting entry was actually replaced, not just
+        // appended after: index 2 must now carry term 2's state.
+        let final_response = follower
+            .handle_append_entries(AppendEntriesRequest {
+                term: 2,
+                leader_id: "n1".to_string(),
+                prev_log_index: 2,
+                prev_log_term: 2,
+                entries: vec![],
+                leader_commit: 2,
+            })
+            .await;
+        assert!(final_response.success);
+        assert_eq!(follower.committed_state().await.unwrap().total_requests_processed, 99);
+    }
+
+    #[tokio::test]
+    async fn a_3_node_cluster_stays_consistent_across_a_leader_failover() {
+        let (nodes, transport) =
+            RaftConsensus::new_clustered(vec!["n1".into(), "n2".into(), "n3".into()]).await;
+
+        // n1 wins the first election and commits an entry to a quorum.
+        nodes[0].run_election().await.expect("n1 should win the first election unopposed");
+        assert_eq!(nodes[0].role().await, RaftRole::Leader);
+        let first_index = nodes[0].propose_state(sample_state(1)).await.expect("quorum should ack the first entry");
+        assert_eq!(first_index, 1);
+
+        // n1 (the old leader) fails -- removed from the shared transport so
+        // no RPC can reach it -- and n2 calls an election. It still holds a
+        // quorum with n3 (2 of the remaining 3 nodes).
+        transport.peers.write().await.remove("n1");
+        nodes[1].run_election().await.expect("n2 should win a fresh election with n3's vote");
+        assert_eq!(nodes[1].role().await, RaftRole::Leader);
+
+        // n2's log already agrees with n3's up to index 1 (both replicated
+        // it while n1 was leader), so the new leader can commit a second
+        // entry without needing to backfill anything.
+        let second_index = nodes[1].propose_state(sample_state(2)).await.expect("quorum should ack the second entry");
+        assert_eq!(second_index, 2);
+
+        // Every node that's still part of the quorum agrees on both
+        // committed entries -- the Log Matching Property held across the
+        // failover.
+        for node in [&nodes[1], &nodes[2]] {
+            let committed = node.committed_state().await.expect("both entries should be committed");
+            assert_eq!(committed.total_requests_processed, 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn propose_state_backfills_a_peer_that_missed_an_earlier_entry() {
+        let (nodes, transport) = RaftConsensus::new_clustered(vec!["n1".into(), "n2".into(), "n3".into()]).await;
+        nodes[0].run_election().await.unwrap();
+
+        // n3 drops off the transport for the first proposal, so it misses
+        // entry 1 entirely; n2 alone still gives the leader a quorum.
+        transport.peers.write().await.remove("n3");
+        nodes[0].propose_state(sample_state(1)).await.expect("n1+n2 form a quorum without n3");
+
+        // n3 comes back before the second proposal. `replicate_to_peer`
+        // must notice its `next_index` is stale and backfill entry 1 before
+        // (or alongside) entry 2, rather than leaving a gap.
+        transport.register_peer("n3".to_string(), nodes[2].clone()).await;
+        nodes[0].propose_state(sample_state(2)).await.expect("all three nodes now form a quorum");
+
+        let committed = nodes[2].committed_state().await.expect("n3 should have caught up on both entries");
+        assert_eq!(committed.total_requests_processed, 2);
+    }
+}
@@ -0,0 +1,153 @@
+//! OTLP span export and trace-context propagation for `execute_ai_workflow`.
+//!
+//! `TracingConfig` describes where spans should go, but nothing previously
+//! read it -- every `#[tracing::instrument]` span on the workflow-execution
+//! path stayed local to whatever `tracing_subscriber` layer a binary
+//! installed. `init_otlp_tracer` installs a `tracing-opentelemetry` layer
+//! backed by an OTLP exporter pointed at the first enabled
+//! `TraceExporterType::OpenTelemetry` entry's `endpoint`, and
+//! `seed_root_span` makes `execute_ai_workflow`'s root span carry
+//! `AIWorkflowRequest::id` as its OpenTelemetry trace ID, so a trace pulled
+//! up by request id in an external backend is this exact call.
+//!
+//! `traced_http_client` builds a `reqwest_middleware` client that injects
+//! the calling span's `traceparent` header into every outgoing request.
+//! Nothing on the `execute_ai_workflow` path issues outbound HTTP calls
+//! directly today -- `fast-llm-coordinator` and `llm-router` own the actual
+//! provider calls -- so this is exposed for those crates to adopt rather
+//! than wired in here.
+
+use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState};
+use opentelemetry_http::HeaderInjector;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::runtime;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use uuid::Uuid;
+
+use crate::{PlatformError, TraceExporterType, TracingConfig};
+
+/// Sets `span`'s OpenTelemetry parent context to a remote root span whose
+/// trace ID is `request_id`'s 16 bytes verbatim, so `AIWorkflowRequest::id`
+/// and the trace ID an external backend shows for this call are the same
+/// value. The span ID is derived from the low 8 bytes of the same UUID --
+/// it carries no independent meaning, it only needs to be non-zero and
+/// stable for the duration of this call.
+pub fn seed_root_span(span: &tracing::Span, request_id: Uuid) {
+    let bytes = *request_id.as_bytes();
+    let trace_id = TraceId::from_bytes(bytes);
+    let mut span_id_bytes = [0u8; 8];
+    span_id_bytes.copy_from_slice(&bytes[8..16]);
+    let span_id = SpanId::from_bytes(span_id_bytes);
+
+    let parent_context = SpanContext::new(trace_id, span_id, TraceFlags::SAMPLED, true, TraceState::default());
+    let cx = opentelemetry::Context::new().with_remote_span_context(parent_context);
+    span.set_parent(cx);
+}
+
+/// Installs a global `tracing-opentelemetry` layer backed by an OTLP
+/// exporter, using the first enabled `TraceExporterType::OpenTelemetry`
+/// entry in `config.exporters`. A no-op if `config.enabled` is `false` or
+/// no such exporter is configured, so calling this unconditionally at
+/// startup is safe.
+pub fn init_otlp_tracer(config: &TracingConfig) -> Result<(), PlatformError> {
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let Some(exporter) = config
+        .exporters
+        .iter()
+        .find(|e| matches!(e.exporter_type, TraceExporterType::OpenTelemetry))
+    else {
+        return Ok(());
+    };
+
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(exporter.endpoint.clone()),
+        )
+        .install_batch(runtime::Tokio)
+        .map_err(|e| PlatformError::ConfigurationError(format!("failed to install OTLP tracer: {e}")))?;
+
+    tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(tracer))
+        .try_init()
+        .map_err(|e| PlatformError::ConfigurationError(format!("failed to install tracing subscriber: {e}")))
+}
+
+/// `reqwest_middleware::Middleware` that injects the calling `tracing`
+/// span's OpenTelemetry context into every outgoing request as a
+/// `traceparent` header, so a downstream service's spans nest under
+/// whichever `execute_ai_workflow` call triggered the request.
+struct TraceparentPropagation;
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for TraceparentPropagation {
+    async fn handle(
+        &self,
+        mut req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let cx = tracing::Span::current().context();
+        opentelemetry::global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&cx, &mut HeaderInjector(req.headers_mut()));
+        });
+        next.run(req, extensions).await
+    }
+}
+
+/// Builds a `reqwest_middleware` client that behaves like a plain
+/// `reqwest::Client` except every request it sends carries the calling
+/// span's `traceparent` header.
+pub fn traced_http_client() -> reqwest_middleware::ClientWithMiddleware {
+    reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+        .with(TraceparentPropagation)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_sdk::testing::trace::InMemorySpanExporter;
+    use opentelemetry_sdk::trace::TracerProvider;
+
+    /// `seed_root_span`'s root span and a span entered underneath it should
+    /// share `request_id` as their trace ID, and the child's parent span ID
+    /// should match the root's -- the exact relationship an OTLP backend
+    /// needs to render `execute_ai_workflow` and everything it calls as one
+    /// trace.
+    #[test]
+    fn seeded_root_span_and_its_child_share_the_request_id_as_trace_id() {
+        let exporter = InMemorySpanExporter::default();
+        let provider = TracerProvider::builder().with_simple_exporter(exporter.clone()).build();
+        let tracer = provider.tracer("ai-orchestration-platform-tests");
+        let subscriber = tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+
+        let request_id = Uuid::new_v4();
+        tracing::subscriber::with_default(subscriber, || {
+            let root = tracing::info_span!("execute_ai_workflow_tracked");
+            seed_root_span(&root, request_id);
+            let _root_enter = root.enter();
+
+            tracing::info_span!("execute_simple_workflow").in_scope(|| {});
+        });
+
+        provider.force_flush();
+        let spans = exporter.get_finished_spans().unwrap();
+
+        let root_span = spans.iter().find(|s| s.name == "execute_ai_workflow_tracked").expect("root span exported");
+        let child_span = spans.iter().find(|s| s.name == "execute_simple_workflow").expect("child span exported");
+
+        let expected_trace_id = TraceId::from_bytes(*request_id.as_bytes());
+        assert_eq!(root_span.span_context.trace_id(), expected_trace_id);
+        assert_eq!(child_span.span_context.trace_id(), expected_trace_id);
+        assert_eq!(child_span.parent_span_id, root_span.span_context.span_id());
+    }
+}
@@ -92,7 +92,7 @@ pub struct AIOrchestrationDecision {
 }
 
 /// Optimization strategies used
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum OptimizationStrategy {
     ParticleSwarm,
     FuzzyLogic,
@@ -102,6 +102,97 @@ pub enum OptimizationStrategy {
     Hybrid,
 }
 
+/// Recalibrates `AIOrchestrationDecision::confidence_score` per
+/// [`OptimizationStrategy`] via temperature scaling, so raw scores that
+/// cluster near a fixed value regardless of whether the decision actually
+/// panned out get pulled toward how confident the strategy has proven to be.
+///
+/// `apply` converts a raw confidence back to its pre-sigmoid logit, divides
+/// by the calibrated temperature `T`, and re-applies the sigmoid. `T > 1.0`
+/// flattens overconfident scores toward 0.5; `T < 1.0` sharpens
+/// underconfident ones.
+#[derive(Debug, Clone, Default)]
+pub struct ConfidenceCalibrator {
+    temperatures: HashMap<OptimizationStrategy, f64>,
+}
+
+impl ConfidenceCalibrator {
+    pub fn new() -> Self {
+        Self { temperatures: HashMap::new() }
+    }
+
+    pub fn set_temperature(&mut self, strategy: OptimizationStrategy, temperature: f64) {
+        self.temperatures.insert(strategy, temperature);
+    }
+
+    /// Applies the calibrated temperature for `strategy` to `raw_confidence`,
+    /// or returns it unchanged if that strategy hasn't been calibrated yet.
+    pub fn apply(&self, strategy: &OptimizationStrategy, raw_confidence: f64) -> f64 {
+        let Some(&temperature) = self.temperatures.get(strategy) else {
+            return raw_confidence;
+        };
+
+        let logit = Self::confidence_to_logit(raw_confidence);
+        Self::sigmoid(logit / temperature)
+    }
+
+    fn confidence_to_logit(confidence: f64) -> f64 {
+        let p = confidence.clamp(1e-6, 1.0 - 1e-6);
+        (p / (1.0 - p)).ln()
+    }
+
+    fn sigmoid(x: f64) -> f64 {
+        1.0 / (1.0 + (-x).exp())
+    }
+
+    fn negative_log_likelihood(logits: &[f64], labels: &[bool], temperature: f64) -> f64 {
+        logits
+            .iter()
+            .zip(labels)
+            .map(|(&logit, &label)| {
+                let p = Self::sigmoid(logit / temperature).clamp(1e-12, 1.0 - 1e-12);
+                if label { -p.ln() } else { -(1.0 - p).ln() }
+            })
+            .sum()
+    }
+
+    /// Finds the temperature `T` in `[0.1, 10.0]` minimizing the negative
+    /// log-likelihood of `sigmoid(logit / T)` against `labels`, via golden
+    /// section search.
+    pub fn calibrate_temperature(logits: &[f64], labels: &[bool]) -> f64 {
+        const GOLDEN_RATIO: f64 = 0.618_033_988_75;
+
+        let mut lo = 0.1_f64;
+        let mut hi = 10.0_f64;
+        let mut mid_lo = hi - GOLDEN_RATIO * (hi - lo);
+        let mut mid_hi = lo + GOLDEN_RATIO * (hi - lo);
+        let mut loss_lo = Self::negative_log_likelihood(logits, labels, mid_lo);
+        let mut loss_hi = Self::negative_log_likelihood(logits, labels, mid_hi);
+
+        for _ in 0..100 {
+            if (hi - lo).abs() < 1e-6 {
+                break;
+            }
+
+            if loss_lo < loss_hi {
+                hi = mid_hi;
+                mid_hi = mid_lo;
+                loss_hi = loss_lo;
+                mid_lo = hi - GOLDEN_RATIO * (hi - lo);
+                loss_lo = Self::negative_log_likelihood(logits, labels, mid_lo);
+            } else {
+                lo = mid_lo;
+                mid_lo = mid_hi;
+                loss_lo = loss_hi;
+                mid_hi = lo + GOLDEN_RATIO * (hi - lo);
+                loss_hi = Self::negative_log_likelihood(logits, labels, mid_hi);
+            }
+        }
+
+        (lo + hi) / 2.0
+    }
+}
+
 /// System metrics for AI decision making
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemMetrics {
@@ -121,6 +212,7 @@ pub struct AIOrchestrationEngine {
     ml_models: Arc<RwLock<HashMap<String, MLModel>>>,
     pso_optimizer: PSOOptimizer,
     fuzzy_controller: FuzzyLogicController,
+    confidence_calibrator: ConfidenceCalibrator,
 }
 
 /// Machine Learning model wrapper
@@ -499,9 +591,24 @@ impl AIOrchestrationEngine {
             ml_models: Arc::new(RwLock::new(HashMap::new())),
             pso_optimizer: PSOOptimizer::new(50, 100),
             fuzzy_controller: FuzzyLogicController::new(),
+            confidence_calibrator: ConfidenceCalibrator::new(),
         }
     }
 
+    /// Recalibrates the confidence scores `orchestrate_workflows` emits for
+    /// `strategy`'s decisions, from a validation set of `(raw_confidence,
+    /// was_the_decision_correct)` pairs.
+    pub fn calibrate(&mut self, strategy: OptimizationStrategy, validation_set: &[(f64, bool)]) {
+        let logits: Vec<f64> = validation_set
+            .iter()
+            .map(|(confidence, _)| ConfidenceCalibrator::confidence_to_logit(*confidence))
+            .collect();
+        let labels: Vec<bool> = validation_set.iter().map(|(_, correct)| *correct).collect();
+
+        let temperature = ConfidenceCalibrator::calibrate_temperature(&logits, &labels);
+        self.confidence_calibrator.set_temperature(strategy, temperature);
+    }
+
     pub async fn orchestrate_workflows(
         &mut self,
         workflows: &[AIWorkflowProfile],
@@ -525,8 +632,11 @@ impl AIOrchestrationEngine {
         };
         
         // Apply fuzzy logic adjustments
-        let final_decisions = self.apply_fuzzy_adjustments(pso_decisions, &fuzzy_decisions).await?;
-        
+        let fuzzy_adjusted_decisions = self.apply_fuzzy_adjustments(pso_decisions, &fuzzy_decisions).await?;
+
+        // Apply per-strategy confidence calibration
+        let final_decisions = self.apply_confidence_calibration(fuzzy_adjusted_decisions);
+
         // Store decisions for learning
         self.store_decisions_for_learning(&final_decisions, workflows).await?;
         
@@ -534,6 +644,17 @@ impl AIOrchestrationEngine {
         Ok(final_decisions)
     }
 
+    fn apply_confidence_calibration(&self, decisions: Vec<AIOrchestrationDecision>) -> Vec<AIOrchestrationDecision> {
+        decisions
+            .into_iter()
+            .map(|mut decision| {
+                decision.confidence_score =
+                    self.confidence_calibrator.apply(&decision.optimization_used, decision.confidence_score);
+                decision
+            })
+            .collect()
+    }
+
     async fn update_system_metrics(&self) -> Result<(), PlatformError> {
         // In a real implementation, you would collect actual system metrics
         let mut metrics = self.system_metrics.write().await;
@@ -695,4 +816,21 @@ mod tests {
         assert!(decisions.is_ok());
         assert_eq!(decisions.unwrap().len(), 1);
     }
+
+    #[test]
+    fn test_calibrate_temperature_on_overconfident_scores_exceeds_one() {
+        // 100 synthetic samples where the raw confidence is 0.15 higher than
+        // the actual accuracy: a confidence of ~0.85 with a true correctness
+        // rate of ~0.70. Temperature scaling should soften that overconfidence,
+        // i.e. find T > 1.0.
+        let raw_confidence = 0.85;
+        let true_accuracy = 0.70;
+        let logit = ConfidenceCalibrator::confidence_to_logit(raw_confidence);
+
+        let logits: Vec<f64> = (0..100).map(|_| logit).collect();
+        let labels: Vec<bool> = (0..100).map(|i| (i as f64) < true_accuracy * 100.0).collect();
+
+        let temperature = ConfidenceCalibrator::calibrate_temperature(&logits, &labels);
+        assert!(temperature > 1.0, "expected T > 1.0 for overconfident scores, got {temperature}");
+    }
 }
@@ -10,11 +10,12 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
+use tokio::sync::{mpsc, RwLock};
 use serde::Serialize;
 use anyhow::Result;
 use tracing::{info, debug};
 use rand::Rng;
+use uuid::Uuid;
 
 /// Multi-hop orchestration configuration
 #[derive(Debug, Clone, Serialize)]
@@ -120,6 +121,33 @@ pub struct MultiHopOrchestrator {
     performance_tracker: Arc<RwLock<PerformanceTracker>>,
     evolutionary_optimizer: Arc<RwLock<EvolutionaryOptimizer>>,
     adaptive_router: Arc<RwLock<AdaptiveRouter>>,
+    /// Last known status of each in-flight or completed streaming
+    /// orchestration, keyed by the id `execute_orchestration_streaming` was
+    /// called with. Polled by `GET /orchestrate/{id}/status`.
+    status: Arc<RwLock<HashMap<Uuid, OrchestrationStatus>>>,
+}
+
+/// A single update emitted on `execute_orchestration_streaming`'s channel as
+/// each hop completes, so callers watching a 30+ second orchestration see
+/// progress instead of silence until the final result. Named around "agent"
+/// rather than "hop" since each `HopExecutor` plays the role of one agent in
+/// the chain; this crate has no other agent-chain orchestrator to reuse
+/// naming from.
+#[derive(Debug, Clone, Serialize)]
+pub struct PartialOrchestrationResult {
+    pub agent_completed: String,
+    pub partial_reasoning: String,
+    pub confidence_so_far: f64,
+    pub done: bool,
+}
+
+/// Snapshot of an in-progress streaming orchestration, returned by
+/// `GET /orchestrate/{id}/status`.
+#[derive(Debug, Clone, Serialize)]
+pub struct OrchestrationStatus {
+    pub current_agent: String,
+    pub elapsed_ms: u64,
+    pub done: bool,
 }
 
 /// Trait for hop execution
@@ -251,6 +279,7 @@ impl MultiHopOrchestrator {
             performance_tracker: Arc::new(RwLock::new(PerformanceTracker::new())),
             evolutionary_optimizer: Arc::new(RwLock::new(EvolutionaryOptimizer::new())),
             adaptive_router: Arc::new(RwLock::new(AdaptiveRouter::new())),
+            status: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -317,6 +346,93 @@ impl MultiHopOrchestrator {
         })
     }
 
+    /// Same as `execute_orchestration`, but sends a `PartialOrchestrationResult`
+    /// over `tx` after every hop completes and updates `orchestration_id`'s
+    /// entry in `status` so `orchestration_status` can report progress while
+    /// the orchestration is still running. A final event with `done: true` is
+    /// sent once the loop exits, whether it stopped because it ran out of
+    /// hops, timed out, or completed successfully.
+    pub async fn execute_orchestration_streaming(
+        &self,
+        orchestration_id: Uuid,
+        initial_context: OrchestrationContext,
+        target_goals: Vec<String>,
+        tx: mpsc::Sender<PartialOrchestrationResult>,
+    ) -> Result<OrchestrationResult> {
+        let start_time = Instant::now();
+        let mut context = initial_context;
+        let mut current_hops = target_goals.clone();
+        let mut results = Vec::new();
+
+        info!("Starting streaming multi-hop orchestration with {} target goals", target_goals.len());
+
+        while context.current_hop < self.config.max_hops
+            && start_time.elapsed() < self.config.total_timeout
+            && !current_hops.is_empty() {
+
+            let next_hop = self.select_next_hop(&context, &current_hops).await?;
+            let hop_result = self.execute_hop(&context, &next_hop).await?;
+
+            context.current_hop += 1;
+            context.total_hops += 1;
+            context.results.push(hop_result.clone());
+            results.push(hop_result.clone());
+
+            self.update_performance_tracker(&hop_result).await;
+
+            if self.config.evolutionary_optimization {
+                self.evolve_orchestration_pattern(&context).await?;
+            }
+
+            current_hops = self.update_target_hops(&context, &current_hops).await?;
+
+            self.status.write().await.insert(orchestration_id, OrchestrationStatus {
+                current_agent: hop_result.hop_id.clone(),
+                elapsed_ms: start_time.elapsed().as_millis() as u64,
+                done: false,
+            });
+
+            let _ = tx.send(PartialOrchestrationResult {
+                agent_completed: hop_result.hop_id.clone(),
+                partial_reasoning: hop_result.data.to_string(),
+                confidence_so_far: hop_result.quality_score,
+                done: false,
+            }).await;
+
+            debug!("Completed hop {}/{}", context.current_hop, self.config.max_hops);
+        }
+
+        let final_metrics = self.calculate_final_metrics(&context).await?;
+        let overall_success = results.iter().all(|r| matches!(r.status, HopStatus::Success));
+
+        self.status.write().await.insert(orchestration_id, OrchestrationStatus {
+            current_agent: results.last().map(|r| r.hop_id.clone()).unwrap_or_default(),
+            elapsed_ms: start_time.elapsed().as_millis() as u64,
+            done: true,
+        });
+
+        let _ = tx.send(PartialOrchestrationResult {
+            agent_completed: results.last().map(|r| r.hop_id.clone()).unwrap_or_default(),
+            partial_reasoning: format!("orchestration finished with quality score {:.2}", final_metrics.quality_score),
+            confidence_so_far: final_metrics.quality_score,
+            done: true,
+        }).await;
+
+        Ok(OrchestrationResult {
+            context,
+            results,
+            metrics: final_metrics,
+            success: overall_success,
+        })
+    }
+
+    /// Current status of a streaming orchestration started with
+    /// `execute_orchestration_streaming`, or `None` if `orchestration_id` is
+    /// unknown.
+    pub async fn orchestration_status(&self, orchestration_id: Uuid) -> Option<OrchestrationStatus> {
+        self.status.read().await.get(&orchestration_id).cloned()
+    }
+
     /// Select next hop using adaptive routing
     async fn select_next_hop(
         &self,
@@ -328,11 +444,20 @@ impl MultiHopOrchestrator {
             return Ok(available_hops[context.current_hop % available_hops.len()].clone());
         }
 
-        // Exploration vs exploitation
-        let mut rng = rand::thread_rng();
-        if rng.gen::<f64>() < self.config.exploration_factor {
+        // Exploration vs exploitation. `rng` is dropped at the end of each
+        // block rather than held across the `.await` below, since
+        // `ThreadRng` isn't `Send` and this method now also runs inside
+        // `tokio::spawn`'d streaming orchestrations.
+        let should_explore = {
+            let mut rng = rand::thread_rng();
+            rng.gen::<f64>() < self.config.exploration_factor
+        };
+        if should_explore {
             // Explore randomly among available hops
-            let idx = rng.gen_range(0..available_hops.len());
+            let idx = {
+                let mut rng = rand::thread_rng();
+                rng.gen_range(0..available_hops.len())
+            };
             return Ok(available_hops[idx].clone());
         }
 
@@ -638,4 +763,110 @@ mod tests {
         // let result = orchestrator.execute_orchestration(context, target_goals).await;
         // assert!(result.is_ok());
     }
+
+    /// Hop that reports the other two hops as `next_hops` until they've
+    /// already run, and refuses to be selected again once it has.
+    struct MockAgentHop {
+        id: String,
+        all_hops: Vec<String>,
+    }
+
+    #[async_trait::async_trait]
+    impl HopExecutor for MockAgentHop {
+        async fn execute(&self, context: &OrchestrationContext) -> Result<HopResult> {
+            let done: std::collections::HashSet<&str> =
+                context.results.iter().map(|r| r.hop_id.as_str()).collect();
+            let mut next_hops: Vec<String> = self
+                .all_hops
+                .iter()
+                .filter(|h| **h != self.id && !done.contains(h.as_str()))
+                .cloned()
+                .collect();
+            next_hops.sort();
+
+            Ok(HopResult {
+                hop_id: self.id.clone(),
+                status: HopStatus::Success,
+                data: serde_json::json!({ "agent": self.id }),
+                execution_time: Duration::from_millis(1),
+                quality_score: 0.9,
+                next_hops,
+                metadata: HashMap::new(),
+            })
+        }
+
+        fn get_metadata(&self) -> HopMetadata {
+            HopMetadata {
+                id: self.id.clone(),
+                name: self.id.clone(),
+                description: String::new(),
+                required_capabilities: Vec::new(),
+                expected_time: Duration::from_millis(100),
+                quality_threshold: 0.8,
+            }
+        }
+
+        fn is_applicable(&self, context: &OrchestrationContext) -> bool {
+            !context.results.iter().any(|r| r.hop_id == self.id)
+        }
+    }
+
+    #[tokio::test]
+    async fn streaming_orchestration_emits_one_partial_event_per_agent_plus_a_final_done() {
+        let all_hops = vec!["hop1".to_string(), "hop2".to_string(), "hop3".to_string()];
+        let config = MultiHopConfig {
+            max_hops: 3,
+            evolutionary_optimization: false,
+            exploration_factor: 0.0,
+            ..MultiHopConfig::default()
+        };
+        let orchestrator = MultiHopOrchestrator::new(config);
+
+        for id in &all_hops {
+            orchestrator
+                .register_hop(id.clone(), Box::new(MockAgentHop { id: id.clone(), all_hops: all_hops.clone() }))
+                .await;
+        }
+
+        let context = OrchestrationContext {
+            current_hop: 0,
+            total_hops: 0,
+            start_time: Instant::now(),
+            results: Vec::new(),
+            context_data: HashMap::new(),
+            metrics: OrchestrationMetrics {
+                total_time: Duration::ZERO,
+                avg_hop_time: Duration::ZERO,
+                success_rate: 0.0,
+                quality_score: 0.0,
+                resource_utilization: 0.0,
+                adaptation_score: 0.0,
+            },
+        };
+
+        let orchestration_id = Uuid::new_v4();
+        let (tx, mut rx) = mpsc::channel(8);
+
+        orchestrator
+            .execute_orchestration_streaming(orchestration_id, context, vec!["hop1".to_string()], tx)
+            .await
+            .unwrap();
+
+        let mut received = Vec::new();
+        while let Ok(partial) = rx.try_recv() {
+            received.push(partial);
+        }
+
+        assert_eq!(received.len(), 4, "expected 3 agent completions plus a final done event: {received:?}");
+        assert_eq!(received[0].agent_completed, "hop1");
+        assert!(!received[0].done);
+        assert_eq!(received[1].agent_completed, "hop2");
+        assert!(!received[1].done);
+        assert_eq!(received[2].agent_completed, "hop3");
+        assert!(!received[2].done);
+        assert!(received[3].done);
+
+        let status = orchestrator.orchestration_status(orchestration_id).await.unwrap();
+        assert!(status.done);
+    }
 }
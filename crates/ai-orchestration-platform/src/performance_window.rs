@@ -0,0 +1,135 @@
+//! Sliding-window latency and throughput tracking backing
+//! `PlatformPerformanceMetrics`.
+//!
+//! `average_response_time_ms` used to be updated as `(old + new) / 2`,
+//! which converges toward whatever the last handful of requests looked
+//! like rather than computing an actual average, and `requests_per_second`
+//! was never computed at all -- it stayed `0.0` forever. `PerformanceWindow`
+//! keeps the last `PerformanceOptimizationConfig::performance_window`
+//! response times, each tagged with when it was recorded, and derives
+//! mean/p50/p95/p99 latency and a real requests-per-second rate from that
+//! window instead.
+
+use std::collections::VecDeque;
+use std::time::Instant;
+
+/// Latency statistics computed over a `PerformanceWindow`'s current
+/// samples. All zero for an empty window.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LatencyPercentiles {
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+}
+
+/// A bounded ring buffer of `(recorded_at, response_time_ms)` samples.
+#[derive(Debug)]
+pub struct PerformanceWindow {
+    capacity: usize,
+    samples: VecDeque<(Instant, f64)>,
+}
+
+impl PerformanceWindow {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self { capacity, samples: VecDeque::with_capacity(capacity) }
+    }
+
+    /// Records `response_time_ms`, evicting the oldest sample once the
+    /// window is at capacity.
+    pub fn record(&mut self, response_time_ms: f64) {
+        if self.samples.len() >= self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((Instant::now(), response_time_ms));
+    }
+
+    /// Mean/p50/p95/p99 latency across the samples currently in the window.
+    pub fn percentiles(&self) -> LatencyPercentiles {
+        if self.samples.is_empty() {
+            return LatencyPercentiles::default();
+        }
+        let mut sorted: Vec<f64> = self.samples.iter().map(|(_, ms)| *ms).collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let mean_ms = sorted.iter().sum::<f64>() / sorted.len() as f64;
+        LatencyPercentiles {
+            mean_ms,
+            p50_ms: percentile(&sorted, 0.50),
+            p95_ms: percentile(&sorted, 0.95),
+            p99_ms: percentile(&sorted, 0.99),
+        }
+    }
+
+    /// Requests per second over the span the window currently covers --
+    /// the time between its oldest and newest sample -- rather than a
+    /// fixed wall-clock bucket, so a platform with only a handful of
+    /// requests so far still reports a meaningful rate instead of `0.0`.
+    pub fn requests_per_second(&self) -> f64 {
+        if self.samples.len() < 2 {
+            return 0.0;
+        }
+        let span = self.samples.back().unwrap().0.duration_since(self.samples.front().unwrap().0);
+        if span.is_zero() {
+            return 0.0;
+        }
+        self.samples.len() as f64 / span.as_secs_f64()
+    }
+}
+
+/// Nearest-rank percentile of an already-sorted slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p * (sorted.len() - 1) as f64).round() as usize;
+    sorted[rank.min(sorted.len() - 1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    #[test]
+    fn percentiles_of_a_uniform_1_to_100ms_spread_match_expectations() {
+        let mut window = PerformanceWindow::new(100);
+        for ms in 1..=100 {
+            window.record(ms as f64);
+        }
+        let percentiles = window.percentiles();
+        assert_eq!(percentiles.mean_ms, 50.5);
+        assert_eq!(percentiles.p50_ms, 51.0, "nearest-rank of 100 sorted samples at rank round(0.5*99)=50 is the 51st value");
+        assert_eq!(percentiles.p95_ms, 95.0);
+        assert_eq!(percentiles.p99_ms, 99.0);
+    }
+
+    #[test]
+    fn recording_past_capacity_evicts_the_oldest_sample() {
+        let mut window = PerformanceWindow::new(3);
+        window.record(10.0);
+        window.record(20.0);
+        window.record(30.0);
+        window.record(40.0);
+
+        let percentiles = window.percentiles();
+        assert_eq!(percentiles.mean_ms, (20.0 + 30.0 + 40.0) / 3.0, "the 10.0 sample should have been evicted");
+    }
+
+    #[test]
+    fn an_empty_window_reports_all_zero_percentiles_and_rate() {
+        let window = PerformanceWindow::new(10);
+        assert_eq!(window.percentiles(), LatencyPercentiles::default());
+        assert_eq!(window.requests_per_second(), 0.0);
+    }
+
+    #[test]
+    fn requests_per_second_reflects_the_actual_span_between_samples() {
+        let mut window = PerformanceWindow::new(10);
+        window.record(1.0);
+        sleep(Duration::from_millis(50));
+        window.record(1.0);
+
+        let rate = window.requests_per_second();
+        // 2 samples over ~50ms is ~40/s; allow generous slack for scheduler jitter.
+        assert!(rate > 10.0 && rate < 200.0, "unexpected rate: {rate}");
+    }
+}
@@ -0,0 +1,227 @@
+//! Token-bucket rate limiting for `SecurityManager`, enforced by
+//! `AIOrchestrationPlatform::execute_ai_workflow` before a request is
+//! routed anywhere.
+//!
+//! Each client id gets its own bucket, capped at `RateLimitingConfig::burst_size`
+//! tokens and refilled continuously at `requests_per_minute` tokens per
+//! `window_size` (so a `window_size` other than 60s scales the effective
+//! rate the same way it would if `requests_per_minute` had been quoted for
+//! that window instead of a minute). Requests that don't carry a client id
+//! share a single global bucket rather than skipping the limiter entirely.
+//!
+//! Buckets are keyed on `AIWorkflowRequest::client_id`, which is the
+//! authenticated caller identity, not attacker-supplied metadata -- a
+//! client can't dodge its quota by simply sending a fresh id. Idle buckets
+//! are swept out periodically (see `evict_idle_buckets`) so a rotating
+//! stream of distinct callers can't grow `buckets` without bound.
+
+use crate::RateLimitingConfig;
+use dashmap::DashMap;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Bucket key used for requests with no client id of their own.
+pub const GLOBAL_CLIENT_ID: &str = "__global__";
+
+/// A bucket idle for this many refill windows has long since refilled to
+/// full capacity, so evicting and later recreating it from scratch is
+/// indistinguishable from having kept it around -- safe to reclaim.
+const IDLE_WINDOWS_BEFORE_EVICTION: u32 = 10;
+
+/// `check` calls between sweeps for idle buckets, so a busy limiter isn't
+/// scanning every single client's bucket on every request.
+const SWEEP_INTERVAL: u64 = 1024;
+
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn full(capacity: f64) -> Self {
+        Self { tokens: capacity, last_refill: Instant::now() }
+    }
+
+    /// Adds tokens for however much time has passed since the last refill,
+    /// capped at `capacity`.
+    fn refill(&mut self, now: Instant, capacity: f64, tokens_per_sec: f64) {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * tokens_per_sec).min(capacity);
+        self.last_refill = now;
+    }
+}
+
+/// A client's current quota, as reported by `RateLimiter::status`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitStatus {
+    pub remaining: usize,
+    pub limit: usize,
+    pub window: Duration,
+}
+
+/// Per-client-id token-bucket limiter backing `SecurityManager::check_rate_limit`.
+pub struct RateLimiter {
+    config: RateLimitingConfig,
+    buckets: DashMap<String, Mutex<TokenBucket>>,
+    checks_since_sweep: AtomicU64,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitingConfig) -> Self {
+        Self { config, buckets: DashMap::new(), checks_since_sweep: AtomicU64::new(0) }
+    }
+
+    fn tokens_per_sec(&self) -> f64 {
+        self.config.requests_per_minute as f64 / self.config.window_size.as_secs_f64().max(1e-9)
+    }
+
+    /// Attempts to consume one token for `client_id`. Returns `Ok(())` if
+    /// one was available (or rate limiting is disabled), or `Err(retry_after)`
+    /// -- how long until at least one token will be available -- otherwise.
+    pub fn check(&self, client_id: &str) -> Result<(), Duration> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let capacity = self.config.burst_size as f64;
+        let tokens_per_sec = self.tokens_per_sec();
+        let now = Instant::now();
+
+        // Scoped so the DashMap shard guard is dropped before a sweep might
+        // need to lock the same shard from `evict_idle_buckets` below.
+        let result = {
+            let entry = self
+                .buckets
+                .entry(client_id.to_string())
+                .or_insert_with(|| Mutex::new(TokenBucket::full(capacity)));
+            let mut bucket = entry.lock();
+            bucket.refill(now, capacity, tokens_per_sec);
+
+            if bucket.tokens >= 1.0 {
+                bucket.tokens -= 1.0;
+                Ok(())
+            } else {
+                let deficit = 1.0 - bucket.tokens;
+                Err(Duration::from_secs_f64(deficit / tokens_per_sec))
+            }
+        };
+
+        if self.checks_since_sweep.fetch_add(1, Ordering::Relaxed) % SWEEP_INTERVAL == 0 {
+            self.evict_idle_buckets(now);
+        }
+
+        result
+    }
+
+    /// Removes buckets that have sat idle long enough to have refilled to
+    /// full capacity, so a client rotating through fresh ids can't grow
+    /// `buckets` without bound.
+    fn evict_idle_buckets(&self, now: Instant) {
+        let idle_after = self.config.window_size.max(Duration::from_secs(1)) * IDLE_WINDOWS_BEFORE_EVICTION;
+        self.buckets.retain(|_, bucket| now.saturating_duration_since(bucket.lock().last_refill) < idle_after);
+    }
+
+    /// Remaining quota for `client_id`, without consuming a token.
+    pub fn status(&self, client_id: &str) -> RateLimitStatus {
+        let capacity = self.config.burst_size;
+        let remaining = match self.buckets.get(client_id) {
+            Some(bucket) => {
+                let mut bucket = bucket.lock();
+                bucket.refill(Instant::now(), capacity as f64, self.tokens_per_sec());
+                bucket.tokens.floor() as usize
+            }
+            None => capacity,
+        };
+
+        RateLimitStatus { remaining, limit: capacity, window: self.config.window_size }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(requests_per_minute: usize, burst_size: usize, window_size: Duration) -> RateLimitingConfig {
+        RateLimitingConfig { enabled: true, requests_per_minute, burst_size, window_size }
+    }
+
+    #[test]
+    fn a_client_can_burst_up_to_its_capacity_then_is_limited() {
+        let limiter = RateLimiter::new(config(60, 3, Duration::from_secs(60)));
+
+        assert!(limiter.check("client-a").is_ok());
+        assert!(limiter.check("client-a").is_ok());
+        assert!(limiter.check("client-a").is_ok());
+        assert!(limiter.check("client-a").is_err(), "the fourth request should exceed the burst size");
+    }
+
+    #[test]
+    fn different_clients_have_independent_buckets() {
+        let limiter = RateLimiter::new(config(60, 1, Duration::from_secs(60)));
+
+        assert!(limiter.check("client-a").is_ok());
+        assert!(limiter.check("client-a").is_err());
+        assert!(limiter.check("client-b").is_ok(), "client-b's bucket must not be affected by client-a's usage");
+    }
+
+    #[test]
+    fn requests_with_no_client_id_share_the_global_bucket() {
+        let limiter = RateLimiter::new(config(60, 1, Duration::from_secs(60)));
+
+        assert!(limiter.check(GLOBAL_CLIENT_ID).is_ok());
+        assert!(limiter.check(GLOBAL_CLIENT_ID).is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tokens_refill_over_time_at_the_configured_rate() {
+        // 60 requests/minute over a 60s window == 1 token/sec.
+        let limiter = RateLimiter::new(config(60, 1, Duration::from_secs(60)));
+
+        assert!(limiter.check("client-a").is_ok());
+        assert!(limiter.check("client-a").is_err());
+
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert!(limiter.check("client-a").is_ok(), "one token should have refilled after 1 second");
+        assert!(limiter.check("client-a").is_err());
+    }
+
+    #[test]
+    fn a_disabled_limiter_never_rejects() {
+        let limiter = RateLimiter::new(RateLimitingConfig {
+            enabled: false,
+            ..config(1, 1, Duration::from_secs(60))
+        });
+
+        for _ in 0..10 {
+            assert!(limiter.check("client-a").is_ok());
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn idle_buckets_are_evicted_after_a_sweep() {
+        let limiter = RateLimiter::new(config(60, 1, Duration::from_secs(60)));
+
+        limiter.check("client-a").unwrap();
+        assert_eq!(limiter.buckets.len(), 1);
+
+        tokio::time::advance(Duration::from_secs(60) * (IDLE_WINDOWS_BEFORE_EVICTION + 1)).await;
+        for i in 0..SWEEP_INTERVAL {
+            limiter.check(&format!("client-{i}")).unwrap();
+        }
+
+        assert!(!limiter.buckets.contains_key("client-a"), "client-a's long-idle bucket should have been swept");
+    }
+
+    #[test]
+    fn status_reports_remaining_quota_without_consuming_it() {
+        let limiter = RateLimiter::new(config(60, 5, Duration::from_secs(60)));
+
+        let status = limiter.status("client-a");
+        assert_eq!(status, RateLimitStatus { remaining: 5, limit: 5, window: Duration::from_secs(60) });
+
+        limiter.check("client-a").unwrap();
+        assert_eq!(limiter.status("client-a").remaining, 4);
+    }
+}
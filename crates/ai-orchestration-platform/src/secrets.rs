@@ -0,0 +1,271 @@
+//! Vault-backed secrets resolution for `PlatformConfig`.
+//!
+//! `SecurityConfig::credentials` values are normally plain inline strings,
+//! which means API keys and other credentials would otherwise have to live
+//! in plaintext config files. A credential can instead be a
+//! [`SecretRef`] pointing at a path/key in HashiCorp Vault's KV v2 secrets
+//! engine; [`SecretsResolver::resolve`] replaces every such reference with
+//! the value Vault currently holds for it before the rest of the platform
+//! ever sees the config.
+//!
+//! This workspace has no existing Vault SDK dependency, so Vault access is
+//! abstracted behind [`VaultClient`] the same way `audit_export` abstracts
+//! S3 behind `S3UploadClient`: `HttpVaultClient` is the production
+//! implementation (a thin `reqwest` client against Vault's HTTP API), and
+//! tests substitute an in-memory recorder instead of running a real Vault
+//! dev server.
+
+use crate::{PlatformConfig, PlatformError};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+
+/// A credential value that lives in Vault rather than inline in config.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SecretRef {
+    /// Path of the secret within Vault's KV v2 mount, e.g. `"platform/openai"`.
+    pub vault_path: String,
+    /// Key within that secret's data map, e.g. `"api_key"`.
+    pub key: String,
+}
+
+/// A `SecurityConfig` credential value: either inline (the historical
+/// behavior) or a reference resolved from Vault at startup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CredentialValue {
+    Inline(String),
+    Secret(SecretRef),
+}
+
+/// A secret value read from Vault, along with how long it can be cached
+/// before it must be re-read.
+#[derive(Debug, Clone)]
+pub struct VaultSecret {
+    pub value: String,
+    pub lease_duration: ChronoDuration,
+}
+
+/// Minimal Vault read surface `SecretsResolver` depends on.
+#[async_trait::async_trait]
+pub trait VaultClient: Send + Sync {
+    async fn read_secret(&self, vault_path: &str, key: &str) -> Result<VaultSecret, String>;
+}
+
+/// Vault KV v2 secret is fetched with no lease information usable as a TTL
+/// (KV v2 reads report `lease_duration: 0`), so cached values fall back to
+/// this TTL instead of never expiring.
+const DEFAULT_CACHE_TTL: ChronoDuration = ChronoDuration::seconds(300);
+
+/// `VaultClient` backed by Vault's HTTP API, authenticating with a token
+/// read from the `VAULT_TOKEN` environment variable.
+pub struct HttpVaultClient {
+    http: reqwest::Client,
+    vault_addr: String,
+    token: String,
+}
+
+impl HttpVaultClient {
+    pub fn new(vault_addr: impl Into<String>, token: impl Into<String>) -> Self {
+        Self { http: reqwest::Client::new(), vault_addr: vault_addr.into(), token: token.into() }
+    }
+
+    /// Builds a client from `VAULT_ADDR` (defaulting to
+    /// `http://127.0.0.1:8200`) and the required `VAULT_TOKEN`.
+    pub fn from_env() -> Result<Self, String> {
+        let vault_addr = std::env::var("VAULT_ADDR").unwrap_or_else(|_| "http://127.0.0.1:8200".to_string());
+        let token = std::env::var("VAULT_TOKEN").map_err(|_| "VAULT_TOKEN is not set".to_string())?;
+        Ok(Self::new(vault_addr, token))
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultClient for HttpVaultClient {
+    async fn read_secret(&self, vault_path: &str, key: &str) -> Result<VaultSecret, String> {
+        let url = format!("{}/v1/secret/data/{}", self.vault_addr.trim_end_matches('/'), vault_path);
+        let response = self
+            .http
+            .get(url)
+            .header("X-Vault-Token", &self.token)
+            .send()
+            .await
+            .map_err(|e| format!("vault request failed: {e}"))?;
+
+        if !response.status().is_success() {
+            return Err(format!("vault returned status {}", response.status()));
+        }
+
+        let body: serde_json::Value =
+            response.json().await.map_err(|e| format!("vault response was not valid json: {e}"))?;
+
+        let value = body["data"]["data"][key]
+            .as_str()
+            .ok_or_else(|| format!("vault secret at {vault_path} has no key {key}"))?
+            .to_string();
+
+        let lease_seconds = body["lease_duration"].as_i64().unwrap_or(0);
+        let lease_duration =
+            if lease_seconds > 0 { ChronoDuration::seconds(lease_seconds) } else { DEFAULT_CACHE_TTL };
+
+        Ok(VaultSecret { value, lease_duration })
+    }
+}
+
+/// A cached secret value, along with the time its Vault lease expires.
+#[derive(Debug, Clone)]
+struct CachedSecret {
+    value: String,
+    expires_at: DateTime<Utc>,
+}
+
+/// Resolves `SecretRef` values in a `PlatformConfig` against Vault,
+/// caching reads until their lease expires so a hot path doesn't hit Vault
+/// on every lookup.
+pub struct SecretsResolver<C: VaultClient> {
+    client: C,
+    cache: DashMap<(String, String), CachedSecret>,
+}
+
+impl<C: VaultClient> SecretsResolver<C> {
+    pub fn new(client: C) -> Self {
+        Self { client, cache: DashMap::new() }
+    }
+
+    /// Returns a copy of `config` with every `CredentialValue::Secret` in
+    /// `security.credentials` replaced by the value currently held in Vault.
+    pub async fn resolve(&self, config: &PlatformConfig) -> Result<PlatformConfig, PlatformError> {
+        let mut resolved = config.clone();
+
+        for value in resolved.security.credentials.values_mut() {
+            if let CredentialValue::Secret(secret_ref) = value {
+                let resolved_value = self.fetch(secret_ref).await?;
+                *value = CredentialValue::Inline(resolved_value);
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Clears every cached value read from `vault_path`, forcing the next
+    /// `resolve` to fetch a fresh value from Vault for it.
+    pub fn rotate_secret(&self, vault_path: &str) {
+        self.cache.retain(|(path, _key), _| path != vault_path);
+    }
+
+    async fn fetch(&self, secret_ref: &SecretRef) -> Result<String, PlatformError> {
+        let cache_key = (secret_ref.vault_path.clone(), secret_ref.key.clone());
+
+        if let Some(cached) = self.cache.get(&cache_key) {
+            if cached.expires_at > Utc::now() {
+                return Ok(cached.value.clone());
+            }
+        }
+
+        let secret = self
+            .client
+            .read_secret(&secret_ref.vault_path, &secret_ref.key)
+            .await
+            .map_err(|e| PlatformError::IntegrationError(format!("vault read failed: {e}")))?;
+
+        self.cache.insert(cache_key, CachedSecret { value: secret.value.clone(), expires_at: Utc::now() + secret.lease_duration });
+
+        Ok(secret.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingVaultClient {
+        reads: AtomicUsize,
+        values: Mutex<std::collections::HashMap<(String, String), String>>,
+    }
+
+    impl RecordingVaultClient {
+        fn with_secret(path: &str, key: &str, value: &str) -> Self {
+            let client = Self::default();
+            client.values.lock().unwrap().insert((path.to_string(), key.to_string()), value.to_string());
+            client
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl VaultClient for RecordingVaultClient {
+        async fn read_secret(&self, vault_path: &str, key: &str) -> Result<VaultSecret, String> {
+            self.reads.fetch_add(1, Ordering::SeqCst);
+            self.values
+                .lock()
+                .unwrap()
+                .get(&(vault_path.to_string(), key.to_string()))
+                .cloned()
+                .map(|value| VaultSecret { value, lease_duration: ChronoDuration::seconds(60) })
+                .ok_or_else(|| "no such secret".to_string())
+        }
+    }
+
+    fn config_with_credential(name: &str, value: CredentialValue) -> PlatformConfig {
+        let mut config = PlatformConfig::default();
+        config.security.credentials.insert(name.to_string(), value);
+        config
+    }
+
+    #[tokio::test]
+    async fn resolve_replaces_a_secret_ref_with_its_vault_value() {
+        let client = RecordingVaultClient::with_secret("platform/openai", "api_key", "sk-test-123");
+        let resolver = SecretsResolver::new(client);
+        let config = config_with_credential(
+            "openai",
+            CredentialValue::Secret(SecretRef { vault_path: "platform/openai".to_string(), key: "api_key".to_string() }),
+        );
+
+        let resolved = resolver.resolve(&config).await.expect("resolve should succeed");
+
+        assert_eq!(resolved.security.credentials.get("openai"), Some(&CredentialValue::Inline("sk-test-123".to_string())));
+    }
+
+    #[tokio::test]
+    async fn resolve_leaves_inline_credentials_untouched() {
+        let client = RecordingVaultClient::default();
+        let resolver = SecretsResolver::new(client);
+        let config = config_with_credential("openai", CredentialValue::Inline("sk-inline".to_string()));
+
+        let resolved = resolver.resolve(&config).await.expect("resolve should succeed");
+
+        assert_eq!(resolved.security.credentials.get("openai"), Some(&CredentialValue::Inline("sk-inline".to_string())));
+        assert_eq!(resolver.client.reads.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn repeated_resolve_hits_the_cache_instead_of_vault() {
+        let client = RecordingVaultClient::with_secret("platform/openai", "api_key", "sk-test-123");
+        let resolver = SecretsResolver::new(client);
+        let config = config_with_credential(
+            "openai",
+            CredentialValue::Secret(SecretRef { vault_path: "platform/openai".to_string(), key: "api_key".to_string() }),
+        );
+
+        resolver.resolve(&config).await.expect("first resolve should succeed");
+        resolver.resolve(&config).await.expect("second resolve should succeed");
+
+        assert_eq!(resolver.client.reads.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn rotate_secret_forces_a_fresh_vault_read() {
+        let client = RecordingVaultClient::with_secret("platform/openai", "api_key", "sk-test-123");
+        let resolver = SecretsResolver::new(client);
+        let config = config_with_credential(
+            "openai",
+            CredentialValue::Secret(SecretRef { vault_path: "platform/openai".to_string(), key: "api_key".to_string() }),
+        );
+
+        resolver.resolve(&config).await.expect("first resolve should succeed");
+        resolver.rotate_secret("platform/openai");
+        resolver.resolve(&config).await.expect("second resolve should succeed");
+
+        assert_eq!(resolver.client.reads.load(Ordering::SeqCst), 2);
+    }
+}
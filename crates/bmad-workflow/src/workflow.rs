@@ -562,6 +562,7 @@ mod tests {
             required_artifacts: vec![crate::ArtifactType::PRD],
             collaboration_mode: crate::CollaborationMode::Collaborative,
             context_preservation: true,
+            min_artifact_quality: crate::DEFAULT_MIN_ARTIFACT_QUALITY,
         };
         
         let user_input = UserInput {
@@ -593,6 +594,7 @@ mod tests {
             required_artifacts: vec![crate::ArtifactType::PRD],
             collaboration_mode: crate::CollaborationMode::Collaborative,
             context_preservation: true,
+            min_artifact_quality: crate::DEFAULT_MIN_ARTIFACT_QUALITY,
         };
         
         let user_input = UserInput {
@@ -624,6 +626,7 @@ mod tests {
             required_artifacts: vec![crate::ArtifactType::PRD],
             collaboration_mode: crate::CollaborationMode::Collaborative,
             context_preservation: true,
+            min_artifact_quality: crate::DEFAULT_MIN_ARTIFACT_QUALITY,
         };
         
         let user_input = UserInput {
@@ -1,4 +1,5 @@
 use crate::{
+    fallback::RoutingFallbackChain,
     load_balancer::{LoadBalancer, LoadBalancingStrategy},
     metrics::{MetricsCollector, PerformanceMetrics},
     routing::{CoordinationContext, RoutingDecision, RoutingEngine, ServiceType},
@@ -89,6 +90,7 @@ pub struct FastLLMCoordinator {
     metrics_collector: MetricsCollector,
     baseline_performance: Arc<RwLock<HashMap<ServiceType, f64>>>,
     health_check_interval: Duration,
+    fallback_chain: RoutingFallbackChain,
 }
 
 impl FastLLMCoordinator {
@@ -97,6 +99,14 @@ impl FastLLMCoordinator {
     }
 
     pub fn with_load_balancing_strategy(strategy: LoadBalancingStrategy) -> Self {
+        let default_fallback_models = vec![
+            ServiceType::LFM2.as_str().to_string(),
+            ServiceType::Ollama.as_str().to_string(),
+            ServiceType::LMStudio.as_str().to_string(),
+            ServiceType::OpenAI.as_str().to_string(),
+            ServiceType::Anthropic.as_str().to_string(),
+        ];
+
         let coordinator = Self {
             routing_engine: RoutingEngine::new(),
             load_balancer: LoadBalancer::new(strategy),
@@ -104,6 +114,7 @@ impl FastLLMCoordinator {
             metrics_collector: MetricsCollector::new(),
             baseline_performance: Arc::new(RwLock::new(HashMap::new())),
             health_check_interval: Duration::from_secs(30),
+            fallback_chain: RoutingFallbackChain::new(default_fallback_models, Duration::from_secs(30)),
         };
 
         // Start background tasks
@@ -119,10 +130,16 @@ impl FastLLMCoordinator {
     ) -> Result<RoutingDecision, CoordinatorError> {
         let start_time = Instant::now();
 
-        let decision = self.routing_engine
+        let mut decision = self.routing_engine
             .make_routing_decision(user_request, context)
             .await?;
 
+        decision.fallback_chain = self.fallback_chain
+            .available_models()
+            .into_iter()
+            .filter(|model| model != decision.target_service.as_str())
+            .collect();
+
         // Record routing time for metrics
         self.metrics_collector.record_routing_time(start_time.elapsed());
 
@@ -183,7 +200,7 @@ impl FastLLMCoordinator {
                 );
 
                 // Try fallback execution
-                self.execute_fallback(user_request, &selected_service).await?
+                self.execute_fallback(user_request, &selected_service, &routing_decision.fallback_chain).await?
             }
         };
 
@@ -409,6 +426,8 @@ impl FastLLMCoordinator {
     }
 
     fn start_background_tasks(&self) {
+        self.fallback_chain.start_probing(self.service_executor.clone());
+
         let load_balancer = self.load_balancer.clone();
         let metrics_collector = self.metrics_collector.clone();
         let service_executor = self.service_executor.clone();
@@ -440,32 +459,43 @@ impl FastLLMCoordinator {
         });
     }
 
+    /// Tries `fallback_chain` in order, skipping `failed_service` (already
+    /// tried by the caller), until one succeeds or the chain is exhausted.
     async fn execute_fallback(
         &self,
         user_request: &str,
         failed_service: &ServiceType,
+        fallback_chain: &[String],
     ) -> Result<ExecutionResult, CoordinatorError> {
-        // Determine fallback service (prefer local services)
-        let fallback_service = match failed_service {
-            ServiceType::LFM2 => ServiceType::Ollama,
-            ServiceType::Ollama => ServiceType::LMStudio,
-            ServiceType::LMStudio => ServiceType::OpenAI,
-            ServiceType::OpenAI => ServiceType::Anthropic,
-            ServiceType::Anthropic => ServiceType::Ollama, // Cycle back to local
-        };
+        for model in fallback_chain {
+            let Some(fallback_service) = ServiceType::from_str(model) else {
+                continue;
+            };
+            if &fallback_service == failed_service {
+                continue;
+            }
 
-        tracing::info!(
-            failed_service = %failed_service.as_str(),
-            fallback_service = %fallback_service.as_str(),
-            "Attempting fallback execution"
-        );
+            tracing::info!(
+                failed_service = %failed_service.as_str(),
+                fallback_service = %fallback_service.as_str(),
+                "Attempting fallback execution"
+            );
 
-        self.service_executor
-            .execute_request(&fallback_service, user_request)
-            .await
-            .map_err(|e| CoordinatorError::ServiceUnavailable {
-                service: format!("Fallback service {} also failed: {}", fallback_service.as_str(), e)
-            })
+            match self.service_executor.execute_request(&fallback_service, user_request).await {
+                Ok(result) => return Ok(result),
+                Err(error) => {
+                    tracing::warn!(
+                        fallback_service = %fallback_service.as_str(),
+                        error = %error,
+                        "Fallback service failed, trying next in chain"
+                    );
+                }
+            }
+        }
+
+        Err(CoordinatorError::ServiceUnavailable {
+            service: format!("All fallback models exhausted for {}", failed_service.as_str()),
+        })
     }
 
     fn calculate_performance_ratio(
@@ -0,0 +1,93 @@
+use crate::routing::ServiceType;
+use crate::services::ServiceExecutor;
+use dashmap::DashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Most recently probed availability for a model, and when it was probed.
+pub type ModelAvailabilityCache = DashMap<String, (bool, Instant)>;
+
+/// Ordered list of models `FastLLMCoordinator::execute_with_coordination`
+/// can fall back through when its first choice is unavailable, backed by a
+/// background task that keeps `ModelAvailabilityCache` fresh so the
+/// fallback decision doesn't have to probe synchronously mid-request.
+#[derive(Clone)]
+pub struct RoutingFallbackChain {
+    models: Vec<String>,
+    probe_interval: Duration,
+    availability: Arc<ModelAvailabilityCache>,
+}
+
+impl RoutingFallbackChain {
+    /// Builds a chain over `models` (in priority order), assumed available
+    /// until the first probe says otherwise.
+    pub fn new(models: Vec<String>, probe_interval: Duration) -> Self {
+        let availability = Arc::new(ModelAvailabilityCache::new());
+        for model in &models {
+            availability.insert(model.clone(), (true, Instant::now()));
+        }
+
+        Self { models, probe_interval, availability }
+    }
+
+    /// Spawns the background task that probes every model's health endpoint
+    /// every `probe_interval` and updates the availability cache.
+    pub fn start_probing(&self, service_executor: ServiceExecutor) {
+        let models = self.models.clone();
+        let availability = self.availability.clone();
+        let probe_interval = self.probe_interval;
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(probe_interval);
+
+            loop {
+                interval.tick().await;
+
+                for model in &models {
+                    let is_available = match ServiceType::from_str(model) {
+                        Some(service_type) => service_executor.health_check(&service_type).await,
+                        None => false,
+                    };
+                    availability.insert(model.clone(), (is_available, Instant::now()));
+                }
+
+                tracing::debug!("Completed fallback chain availability probe cycle");
+            }
+        });
+    }
+
+    /// Configured models believed reachable as of the last probe, in
+    /// priority order. A model that hasn't been probed yet is treated as
+    /// available.
+    pub fn available_models(&self) -> Vec<String> {
+        self.models
+            .iter()
+            .filter(|model| self.availability.get(*model).map(|entry| entry.0).unwrap_or(true))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn available_models_skips_the_primary_once_a_probe_marks_it_unavailable() {
+        let chain = RoutingFallbackChain::new(
+            vec!["primary".to_string(), "secondary".to_string(), "tertiary".to_string()],
+            Duration::from_secs(30),
+        );
+
+        chain.availability.insert("primary".to_string(), (false, Instant::now()));
+
+        assert_eq!(chain.available_models(), vec!["secondary".to_string(), "tertiary".to_string()]);
+    }
+
+    #[test]
+    fn available_models_treats_an_unprobed_model_as_available() {
+        let chain = RoutingFallbackChain::new(vec!["only-model".to_string()], Duration::from_secs(30));
+
+        assert_eq!(chain.available_models(), vec!["only-model".to_string()]);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod coordinator;
+pub mod fallback;
 pub mod load_balancer;
 pub mod routing;
 pub mod services;
@@ -8,6 +9,7 @@ pub mod metrics;
 pub mod napi_bridge;
 
 pub use coordinator::FastLLMCoordinator;
+pub use fallback::{ModelAvailabilityCache, RoutingFallbackChain};
 pub use routing::{RoutingDecision, CoordinationContext, ServiceType};
 pub use load_balancer::LoadBalancer;
 pub use metrics::PerformanceMetrics;
@@ -49,6 +49,11 @@ pub struct RoutingDecision {
     pub priority: u8,
     pub confidence: f64,
     pub routing_time_ms: u64,
+    /// Models to try, in order, if `target_service` turns out to be
+    /// unavailable. Populated by `FastLLMCoordinator` from its
+    /// `RoutingFallbackChain`; empty when the decision is made directly
+    /// through `RoutingEngine` without a coordinator around it.
+    pub fallback_chain: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -226,6 +231,7 @@ impl RoutingEngine {
             priority: self.calculate_priority(confidence, &complexity),
             confidence,
             routing_time_ms,
+            fallback_chain: Vec::new(),
         };
 
         tracing::info!(
@@ -0,0 +1,154 @@
+//! Cross-document entity resolution for the knowledge graph.
+//!
+//! Ingesting several documents that each mention the same real-world entity
+//! (e.g. "OpenAI") currently produces one graph node per mention. This
+//! module groups those mentions by a deterministic canonical id and merges
+//! them into a single node, so the graph gains one entity with combined
+//! provenance instead of duplicates.
+
+use crate::models::EntityType;
+use crate::wikidata::WikidataLink;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A document being ingested into the knowledge graph.
+#[derive(Debug, Clone)]
+pub struct DocumentRequest {
+    pub id: String,
+    pub content: String,
+}
+
+/// A resolved entity node in the knowledge graph.
+#[derive(Debug, Clone)]
+pub struct KnowledgeNode {
+    pub canonical_id: String,
+    pub name: String,
+    pub entity_type: EntityType,
+    pub embedding: Vec<f32>,
+    pub metadata: HashMap<String, serde_json::Value>,
+    pub source_documents: Vec<String>,
+    /// External Wikidata identity for this node, once resolved by
+    /// `WikidataLinker`/`enrich_with_wikidata`. `None` until enrichment has
+    /// run, or if no confident match was found.
+    pub wikidata_link: Option<WikidataLink>,
+}
+
+/// Merges knowledge-graph nodes that refer to the same real-world entity.
+pub struct EntityMerger;
+
+impl EntityMerger {
+    /// Deterministic id for an entity, derived from its normalized name and
+    /// type so the same real-world entity always hashes to the same id
+    /// regardless of which document it was extracted from.
+    pub fn canonical_id(name: &str, entity_type: &EntityType) -> String {
+        let mut hasher = DefaultHasher::new();
+        name.trim().to_lowercase().hash(&mut hasher);
+        format!("{entity_type:?}").hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+
+    /// Groups `nodes` by `canonical_id` and replaces each group with a
+    /// single merged node: embeddings become the centroid of the group,
+    /// metadata is unioned, and `source_documents` records every document
+    /// (matched to `nodes` by index) that contributed a node to the group.
+    pub fn merge_by_provenance(nodes: &mut Vec<KnowledgeNode>, documents: &[DocumentRequest]) {
+        let mut groups: HashMap<String, Vec<KnowledgeNode>> = HashMap::new();
+
+        for (index, mut node) in nodes.drain(..).enumerate() {
+            if let Some(document) = documents.get(index) {
+                if !node.source_documents.contains(&document.id) {
+                    node.source_documents.push(document.id.clone());
+                }
+            }
+            groups.entry(node.canonical_id.clone()).or_default().push(node);
+        }
+
+        let mut merged: Vec<KnowledgeNode> = groups.into_values().map(Self::merge_group).collect();
+        merged.sort_by(|a, b| a.canonical_id.cmp(&b.canonical_id));
+        *nodes = merged;
+    }
+
+    fn merge_group(group: Vec<KnowledgeNode>) -> KnowledgeNode {
+        let first = group.first().expect("groups are never empty").clone();
+        let dimension = first.embedding.len();
+        let mut centroid = vec![0.0f32; dimension];
+        let mut metadata = HashMap::new();
+        let mut source_documents = Vec::new();
+        let mut wikidata_link = None;
+
+        for node in &group {
+            for (sum, value) in centroid.iter_mut().zip(&node.embedding) {
+                *sum += value;
+            }
+            metadata.extend(node.metadata.clone());
+            for doc_id in &node.source_documents {
+                if !source_documents.contains(doc_id) {
+                    source_documents.push(doc_id.clone());
+                }
+            }
+            if wikidata_link.is_none() {
+                wikidata_link = node.wikidata_link.clone();
+            }
+        }
+        for value in &mut centroid {
+            *value /= group.len() as f32;
+        }
+
+        KnowledgeNode {
+            canonical_id: first.canonical_id,
+            name: first.name,
+            entity_type: first.entity_type,
+            embedding: centroid,
+            metadata,
+            source_documents,
+            wikidata_link,
+        }
+    }
+}
+
+/// In-memory graph node storage. In a real implementation this would query
+/// Supabase, mirroring the placeholder pattern used by [`crate::storage::KnowledgeStorage`]
+/// and [`crate::knowledge_graph::KnowledgeGraph`].
+pub struct GraphStorage {
+    nodes: Vec<KnowledgeNode>,
+}
+
+impl GraphStorage {
+    pub fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    pub fn insert(&mut self, node: KnowledgeNode) {
+        self.nodes.push(node);
+    }
+
+    /// Every stored node whose name matches `name` case-insensitively,
+    /// i.e. the candidates `EntityMerger::merge_by_provenance` should merge.
+    pub fn find_duplicate_nodes(&self, name: &str) -> Vec<KnowledgeNode> {
+        self.nodes
+            .iter()
+            .filter(|node| node.name.eq_ignore_ascii_case(name))
+            .cloned()
+            .collect()
+    }
+
+    /// Every node currently stored, for a maintenance pass (e.g.
+    /// `crate::entity_resolution::EntityResolutionPipeline::run`) that needs
+    /// to scan the whole graph rather than one name at a time.
+    pub fn all_nodes(&self) -> Vec<KnowledgeNode> {
+        self.nodes.clone()
+    }
+
+    /// Replaces the entire stored node set, e.g. after a bulk merge pass has
+    /// collapsed some nodes together.
+    pub fn replace_all(&mut self, nodes: Vec<KnowledgeNode>) {
+        self.nodes = nodes;
+    }
+}
+
+impl Default for GraphStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,329 @@
+//! Bulk entity resolution: finding and merging duplicate nodes across an
+//! entire graph rather than one document's worth of mentions at a time.
+//!
+//! `entity_merge`'s `EntityMerger::merge_by_provenance` groups nodes that
+//! were extracted in the same ingestion batch and already share a
+//! `canonical_id`. It has no way to notice, months later, that two nodes
+//! coined by unrelated ingestions ("OpenAI" from one document, "Open AI" from
+//! another) are the same real-world entity. `EntityResolutionPipeline::run`
+//! is the maintenance pass that catches those: it scans every stored node's
+//! embedding for similar neighbors, gates each candidate pair on name
+//! similarity or a shared Wikidata identity, and merges the survivors.
+//!
+//! There is no working approximate-nearest-neighbor index anywhere in this
+//! workspace yet (`vector-db`'s `hnsw` dependency is declared but unused), so
+//! candidate generation here is a brute-force cosine-similarity scan rather
+//! than a true ANN index. The similarity gate and merge step don't care how
+//! candidates were found, so swapping in a real index later only touches
+//! `candidate_pairs`.
+
+use crate::entity_merge::{DocumentRequest, EntityMerger, GraphStorage, KnowledgeNode};
+
+/// Tuning knobs for a resolution pass.
+#[derive(Debug, Clone)]
+pub struct ResolutionConfig {
+    /// Minimum cosine similarity between two embeddings for the pair to be
+    /// considered a merge candidate at all.
+    pub similarity_threshold: f64,
+    /// Minimum Jaro-Winkler similarity between two names for a candidate
+    /// pair to be merged, unless they share a Wikidata QID instead.
+    pub name_similarity_threshold: f64,
+}
+
+impl Default for ResolutionConfig {
+    fn default() -> Self {
+        Self { similarity_threshold: 0.85, name_similarity_threshold: 0.9 }
+    }
+}
+
+/// A pair of nodes the pipeline decided refer to the same real-world entity.
+#[derive(Debug, Clone)]
+pub struct MergePair {
+    pub entity_a: String,
+    pub entity_b: String,
+    pub similarity: f64,
+}
+
+/// Outcome of a resolution pass.
+#[derive(Debug, Clone, Default)]
+pub struct ResolutionResult {
+    pub merges: Vec<MergePair>,
+    /// Nodes removed from storage as a result of merging (every node in a
+    /// merge group other than the one the merged node replaces it with).
+    pub updated_edges: usize,
+}
+
+/// Finds and merges duplicate nodes across an entire [`GraphStorage`].
+pub struct EntityResolutionPipeline;
+
+impl EntityResolutionPipeline {
+    /// Runs one resolution pass over every node currently in `storage`:
+    /// generates merge candidates by embedding similarity, keeps only the
+    /// ones that also pass the name-similarity gate, and merges each
+    /// surviving group in place via [`EntityMerger::merge_by_provenance`].
+    pub fn run(storage: &mut GraphStorage, config: &ResolutionConfig) -> ResolutionResult {
+        let nodes = storage.all_nodes();
+        let pairs = Self::candidate_pairs(&nodes, config.similarity_threshold);
+
+        let merges: Vec<MergePair> = pairs
+            .into_iter()
+            .filter(|(i, j, _)| Self::same_entity(&nodes[*i], &nodes[*j], config.name_similarity_threshold))
+            .map(|(i, j, similarity)| MergePair {
+                entity_a: nodes[i].canonical_id.clone(),
+                entity_b: nodes[j].canonical_id.clone(),
+                similarity,
+            })
+            .collect();
+
+        if merges.is_empty() {
+            return ResolutionResult::default();
+        }
+
+        let groups = Self::group_merges(&nodes, &merges);
+        let mut merged_nodes = Vec::new();
+        let mut updated_edges = 0;
+
+        for group in groups {
+            if group.len() == 1 {
+                merged_nodes.push(nodes[group[0]].clone());
+                continue;
+            }
+            let mut group_nodes: Vec<KnowledgeNode> = group.iter().map(|&index| nodes[index].clone()).collect();
+            updated_edges += group_nodes.len().saturating_sub(1);
+
+            // `EntityMerger::merge_by_provenance` groups by `canonical_id`,
+            // but nodes resolved to the same entity here typically arrived
+            // with different canonical ids (that's the whole reason they
+            // needed resolution rather than colliding automatically at
+            // ingestion time). Adopt the group's first canonical id before
+            // merging so it groups them as intended.
+            let canonical_id = group_nodes[0].canonical_id.clone();
+            for group_node in &mut group_nodes {
+                group_node.canonical_id = canonical_id.clone();
+            }
+            let documents: Vec<DocumentRequest> = Vec::new();
+            EntityMerger::merge_by_provenance(&mut group_nodes, &documents);
+            merged_nodes.extend(group_nodes);
+        }
+
+        storage.replace_all(merged_nodes);
+        ResolutionResult { merges, updated_edges }
+    }
+
+    /// All node-index pairs whose embeddings' cosine similarity meets
+    /// `threshold`. Brute-force `O(n^2)`, standing in for a real ANN index.
+    fn candidate_pairs(nodes: &[KnowledgeNode], threshold: f64) -> Vec<(usize, usize, f64)> {
+        let mut pairs = Vec::new();
+        for i in 0..nodes.len() {
+            for j in (i + 1)..nodes.len() {
+                let similarity = cosine_similarity(&nodes[i].embedding, &nodes[j].embedding);
+                if similarity >= threshold {
+                    pairs.push((i, j, similarity));
+                }
+            }
+        }
+        pairs
+    }
+
+    /// True if `a` and `b` should be merged: either they carry the same
+    /// resolved Wikidata identity, or their names are similar enough by
+    /// Jaro-Winkler.
+    fn same_entity(a: &KnowledgeNode, b: &KnowledgeNode, name_similarity_threshold: f64) -> bool {
+        match (&a.wikidata_link, &b.wikidata_link) {
+            (Some(link_a), Some(link_b)) if link_a.qid == link_b.qid => return true,
+            _ => {}
+        }
+        jaro_winkler(&a.name.to_lowercase(), &b.name.to_lowercase()) >= name_similarity_threshold
+    }
+
+    /// Collapses pairwise merges into connected groups of node indices, so
+    /// that a chain like A~B and B~C merges all three together instead of
+    /// merging A~B and B~C independently and dropping C's contribution to A.
+    fn group_merges(nodes: &[KnowledgeNode], merges: &[MergePair]) -> Vec<Vec<usize>> {
+        let index_of: std::collections::HashMap<&str, usize> =
+            nodes.iter().enumerate().map(|(index, node)| (node.canonical_id.as_str(), index)).collect();
+
+        let mut parent: Vec<usize> = (0..nodes.len()).collect();
+        fn find(parent: &mut [usize], x: usize) -> usize {
+            if parent[x] != x {
+                parent[x] = find(parent, parent[x]);
+            }
+            parent[x]
+        }
+
+        for merge in merges {
+            if let (Some(&a), Some(&b)) = (index_of.get(merge.entity_a.as_str()), index_of.get(merge.entity_b.as_str())) {
+                let (root_a, root_b) = (find(&mut parent, a), find(&mut parent, b));
+                if root_a != root_b {
+                    parent[root_a] = root_b;
+                }
+            }
+        }
+
+        let mut groups: std::collections::HashMap<usize, Vec<usize>> = std::collections::HashMap::new();
+        for index in 0..nodes.len() {
+            let root = find(&mut parent, index);
+            groups.entry(root).or_default().push(index);
+        }
+
+        groups.into_values().collect()
+    }
+}
+
+/// Cosine similarity between two equal-length embeddings; `0.0` if either is
+/// empty or they differ in length.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    if a.is_empty() || a.len() != b.len() {
+        return 0.0;
+    }
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| *x as f64 * *y as f64).sum();
+    let norm_a: f64 = a.iter().map(|x| *x as f64 * *x as f64).sum::<f64>().sqrt();
+    let norm_b: f64 = b.iter().map(|x| *x as f64 * *x as f64).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+/// Jaro-Winkler string similarity in `[0.0, 1.0]`. No `strsim`-equivalent
+/// crate is a dependency anywhere in this workspace, so this is a direct
+/// implementation of the standard algorithm.
+fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let jaro = jaro_similarity(a, b);
+    if jaro <= 0.0 {
+        return jaro;
+    }
+
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let prefix_len = a_chars
+        .iter()
+        .zip(b_chars.iter())
+        .take(4)
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    jaro + (prefix_len as f64 * 0.1 * (1.0 - jaro))
+}
+
+fn jaro_similarity(a: &str, b: &str) -> f64 {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let (a_len, b_len) = (a_chars.len(), b_chars.len());
+    if a_len == 0 && b_len == 0 {
+        return 1.0;
+    }
+    if a_len == 0 || b_len == 0 {
+        return 0.0;
+    }
+
+    let match_distance = (a_len.max(b_len) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a_len];
+    let mut b_matched = vec![false; b_len];
+    let mut matches = 0;
+
+    for i in 0..a_len {
+        let start = i.saturating_sub(match_distance);
+        let end = (i + match_distance + 1).min(b_len);
+        for j in start..end {
+            if b_matched[j] || a_chars[i] != b_chars[j] {
+                continue;
+            }
+            a_matched[i] = true;
+            b_matched[j] = true;
+            matches += 1;
+            break;
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let mut transpositions = 0;
+    let mut b_index = 0;
+    for i in 0..a_len {
+        if !a_matched[i] {
+            continue;
+        }
+        while !b_matched[b_index] {
+            b_index += 1;
+        }
+        if a_chars[i] != b_chars[b_index] {
+            transpositions += 1;
+        }
+        b_index += 1;
+    }
+
+    let matches = matches as f64;
+    (matches / a_len as f64 + matches / b_len as f64 + (matches - transpositions as f64 / 2.0) / matches) / 3.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::EntityType;
+    use std::collections::HashMap;
+
+    fn node(canonical_id: &str, name: &str, embedding: Vec<f32>) -> KnowledgeNode {
+        KnowledgeNode {
+            canonical_id: canonical_id.to_string(),
+            name: name.to_string(),
+            entity_type: EntityType::Organization,
+            embedding,
+            metadata: HashMap::new(),
+            source_documents: vec![canonical_id.to_string()],
+            wikidata_link: None,
+        }
+    }
+
+    #[test]
+    fn jaro_winkler_scores_identical_strings_as_one() {
+        assert_eq!(jaro_winkler("openai", "openai"), 1.0);
+    }
+
+    #[test]
+    fn jaro_winkler_scores_a_near_miss_above_the_default_threshold() {
+        assert!(jaro_winkler("openai", "open ai") >= 0.9);
+    }
+
+    #[test]
+    fn jaro_winkler_scores_unrelated_names_low() {
+        assert!(jaro_winkler("openai", "microsoft") < 0.5);
+    }
+
+    /// One-hot embedding at `dimension` out of `total_dimensions`, so any two
+    /// nodes with different dimensions are exactly orthogonal (cosine
+    /// similarity `0.0`) and never accidentally cross-match.
+    fn one_hot(dimension: usize, total_dimensions: usize) -> Vec<f32> {
+        let mut embedding = vec![0.0f32; total_dimensions];
+        embedding[dimension] = 1.0;
+        embedding
+    }
+
+    #[test]
+    fn run_merges_two_hundred_nodes_with_twenty_known_duplicate_pairs() {
+        const DISTINCT: usize = 160;
+        const DUPLICATE_PAIRS: usize = 20;
+        const DIMENSIONS: usize = DISTINCT + DUPLICATE_PAIRS;
+
+        let mut storage = GraphStorage::new();
+
+        for i in 0..DISTINCT {
+            storage.insert(node(&format!("distinct-{i}"), &format!("Entity {i}"), one_hot(i, DIMENSIONS)));
+        }
+        for i in 0..DUPLICATE_PAIRS {
+            let embedding = one_hot(DISTINCT + i, DIMENSIONS);
+            storage.insert(node(&format!("dup-a-{i}"), &format!("Duplicate Corp {i}"), embedding.clone()));
+            storage.insert(node(&format!("dup-b-{i}"), &format!("Duplicate Corp {i}"), embedding));
+        }
+        assert_eq!(storage.all_nodes().len(), DISTINCT + DUPLICATE_PAIRS * 2);
+
+        let config = ResolutionConfig::default();
+        let result = EntityResolutionPipeline::run(&mut storage, &config);
+
+        assert_eq!(result.merges.len(), DUPLICATE_PAIRS);
+        assert_eq!(result.updated_edges, DUPLICATE_PAIRS);
+        assert_eq!(storage.all_nodes().len(), DISTINCT + DUPLICATE_PAIRS);
+    }
+}
@@ -1,9 +1,16 @@
 //! Knowledge graph functionality for the Intelligent Librarian
 
+use crate::community_detector::CommunityDetector;
 use crate::models::*;
+use crate::relationship_extractor::RelationshipExtractor;
 use anyhow::Result;
+use chrono::Utc;
 use std::collections::HashMap;
 
+/// Half-life (in days) used for exponential relationship-strength decay:
+/// an edge that hasn't been reinforced in this long has its weight halved.
+const RELATIONSHIP_DECAY_HALF_LIFE_DAYS: f64 = 30.0;
+
 /// Knowledge graph for relationship mapping
 pub struct KnowledgeGraph {
     // In a real implementation, this would connect to Supabase
@@ -51,19 +58,12 @@ impl KnowledgeGraph {
                 edge_type: "relates_to".to_string(),
                 weight: 0.8,
                 properties: HashMap::new(),
+                last_reinforced_at: Utc::now(),
+                provenance: vec![],
             },
         ];
 
-        let clusters = vec![
-            GraphCluster {
-                id: "ml_cluster".to_string(),
-                label: "ML Concepts".to_string(),
-                node_ids: vec!["ml_concept".to_string(), "optimization_concept".to_string()],
-                center: (0.5, 0.0),
-                radius: 1.0,
-                color: "#f39c12".to_string(),
-            },
-        ];
+        let clusters = self.compute_clusters(&nodes, &edges);
 
         Ok(KnowledgeGraphData {
             nodes: nodes.clone(),
@@ -80,6 +80,124 @@ impl KnowledgeGraph {
         })
     }
 
+    /// Runs Leiden-style community detection over the given nodes/edges and
+    /// renders the resulting communities as visualization clusters.
+    fn compute_clusters(&self, nodes: &[GraphNode], edges: &[GraphEdge]) -> Vec<GraphCluster> {
+        let communities = CommunityDetector::new().detect(nodes, edges);
+        let position_by_id: HashMap<&str, (f64, f64)> = nodes
+            .iter()
+            .map(|n| (n.id.as_str(), n.position.unwrap_or((0.0, 0.0))))
+            .collect();
+
+        communities
+            .into_iter()
+            .map(|community| {
+                let positions: Vec<(f64, f64)> = community
+                    .node_ids
+                    .iter()
+                    .filter_map(|id| position_by_id.get(id.as_str()).copied())
+                    .collect();
+                let center = if positions.is_empty() {
+                    (0.0, 0.0)
+                } else {
+                    let (sum_x, sum_y) = positions.iter().fold((0.0, 0.0), |acc, p| (acc.0 + p.0, acc.1 + p.1));
+                    (sum_x / positions.len() as f64, sum_y / positions.len() as f64)
+                };
+                let radius = positions
+                    .iter()
+                    .map(|p| ((p.0 - center.0).powi(2) + (p.1 - center.1).powi(2)).sqrt())
+                    .fold(0.5, f64::max);
+
+                GraphCluster {
+                    id: format!("community_{}", community.id),
+                    label: format!("Community {}", community.id),
+                    node_ids: community.node_ids,
+                    center,
+                    radius,
+                    color: "#f39c12".to_string(),
+                }
+            })
+            .collect()
+    }
+
+    /// Reinforce an edge's relationship strength, resetting its decay clock.
+    /// Used whenever two nodes are observed together again (e.g. co-cited
+    /// in a query result).
+    pub fn reinforce_edge(&mut self, edge_key: &str, reinforcement: f64) {
+        if let Some(edge) = self.edges.get_mut(edge_key) {
+            edge.weight = (edge.weight + reinforcement).min(1.0);
+            edge.last_reinforced_at = Utc::now();
+        }
+    }
+
+    /// Applies exponential decay to every edge's weight based on how long
+    /// it has gone without reinforcement, then prunes edges that have
+    /// decayed below `prune_below`. Should be called periodically (e.g. by
+    /// a background maintenance task) to keep stale relationships from
+    /// dominating retrieval.
+    pub fn decay_relationships(&mut self, prune_below: f64) {
+        let now = Utc::now();
+        for edge in self.edges.values_mut() {
+            let age_days = (now - edge.last_reinforced_at).num_seconds() as f64 / 86_400.0;
+            let decay_factor = 0.5f64.powf(age_days.max(0.0) / RELATIONSHIP_DECAY_HALF_LIFE_DAYS);
+            edge.weight *= decay_factor;
+        }
+        self.edges.retain(|_, edge| edge.weight >= prune_below);
+    }
+
+    /// Runs community detection over the current graph. Called
+    /// automatically by `IntelligentLibrarian::document_stream` once enough
+    /// documents have been ingested since the last pass, but can also be
+    /// invoked directly (e.g. from a maintenance task).
+    pub async fn run_community_detection(&self) -> Result<Vec<crate::community_detector::Community>> {
+        let nodes: Vec<GraphNode> = self.nodes.values().cloned().collect();
+        let edges: Vec<GraphEdge> = self.edges.values().cloned().collect();
+        Ok(CommunityDetector::new().detect(&nodes, &edges))
+    }
+
+    /// Runs [`RelationshipExtractor`] over `document` against the graph's
+    /// current nodes and merges the resulting edges in: an edge that
+    /// already exists between the same pair has its weight and provenance
+    /// combined rather than being replaced.
+    pub fn ingest_document_relationships(&mut self, document: &Document) -> Vec<GraphEdge> {
+        let known_entities: Vec<GraphNode> = self.nodes.values().cloned().collect();
+        let extracted = RelationshipExtractor::extract(document, &known_entities);
+
+        for edge in &extracted {
+            let key = format!("{}->{}", edge.source, edge.target);
+            self.edges
+                .entry(key)
+                .and_modify(|existing| {
+                    existing.weight = (existing.weight + edge.weight).min(1.0);
+                    existing.provenance.extend(edge.provenance.clone());
+                    existing.last_reinforced_at = edge.last_reinforced_at;
+                })
+                .or_insert_with(|| edge.clone());
+        }
+
+        extracted
+    }
+
+    /// The chain of source sentences behind `node_id`'s relationships: every
+    /// edge touching the node, cited by the sentence
+    /// [`RelationshipExtractor::extract`] found it in.
+    pub fn explain_result(&self, node_id: &str) -> Explanation {
+        let reasoning_chain = self
+            .edges
+            .iter()
+            .filter(|(_, edge)| edge.source == node_id || edge.target == node_id)
+            .flat_map(|(edge_id, edge)| {
+                edge.provenance.iter().map(move |provenance| CitedEvidence {
+                    edge_id: edge_id.clone(),
+                    document_id: provenance.document_id,
+                    text_excerpt: provenance.sentence_text.clone(),
+                })
+            })
+            .collect();
+
+        Explanation { reasoning_chain }
+    }
+
     /// Get graph statistics
     pub async fn get_statistics(&self) -> Result<GraphStatistics> {
         Ok(GraphStatistics {
@@ -15,12 +15,17 @@ pub mod classifier;
 pub mod searcher;
 pub mod curator;
 pub mod knowledge_graph;
+pub mod community_detector;
 pub mod content_analyzer;
 pub mod quality_assessor;
 pub mod api;
 pub mod storage;
 pub mod embeddings;
 pub mod agent_integration;
+pub mod entity_merge;
+pub mod entity_resolution;
+pub mod relationship_extractor;
+pub mod wikidata;
 
 #[cfg(test)]
 mod tests;
@@ -36,11 +41,21 @@ pub use api::*;
 pub use storage::*;
 pub use embeddings::*;
 pub use agent_integration::*;
+pub use relationship_extractor::*;
+pub use entity_merge::{DocumentRequest, EntityMerger, GraphStorage, KnowledgeNode};
+pub use entity_resolution::{EntityResolutionPipeline, MergePair, ResolutionConfig, ResolutionResult};
 
 use anyhow::Result;
+use futures::stream::{Stream, StreamExt};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
+/// Documents processed concurrently by `IntelligentLibrarian::document_stream`.
+const INGESTION_PARALLELISM: usize = 8;
+/// After this many documents have been ingested since the last community
+/// detection pass, `document_stream` re-runs it automatically.
+const COMMUNITY_UPDATE_BATCH_SIZE: usize = 50;
+
 /// Main Intelligent Librarian service with agent integration
 pub struct IntelligentLibrarian {
     classifier: Arc<RwLock<DocumentClassifier>>,
@@ -50,6 +65,10 @@ pub struct IntelligentLibrarian {
     quality_assessor: Arc<RwLock<QualityAssessor>>,
     storage: Arc<RwLock<KnowledgeStorage>>,
     agent_integration: Arc<RwLock<AgentIntegration>>,
+    /// Documents currently in flight through `document_stream`.
+    ingestion_backlog: Arc<RwLock<usize>>,
+    /// Documents ingested since the last automatic community detection pass.
+    documents_since_last_community_update: Arc<RwLock<usize>>,
 }
 
 impl IntelligentLibrarian {
@@ -70,9 +89,49 @@ impl IntelligentLibrarian {
             quality_assessor,
             storage,
             agent_integration,
+            ingestion_backlog: Arc::new(RwLock::new(0)),
+            documents_since_last_community_update: Arc::new(RwLock::new(0)),
         })
     }
 
+    /// Documents currently in flight through `document_stream`.
+    pub async fn ingestion_backlog(&self) -> usize {
+        *self.ingestion_backlog.read().await
+    }
+
+    /// Ingest a stream of documents concurrently, up to
+    /// `INGESTION_PARALLELISM` at a time, auto-triggering community
+    /// detection every `COMMUNITY_UPDATE_BATCH_SIZE` documents. Yields each
+    /// document's id as it finishes storing, in completion order rather
+    /// than submission order, so a caller draining the returned stream can
+    /// observe ingestion progress without any document being silently
+    /// dropped even if `add_document` fails for it.
+    pub fn document_stream<'a, S>(&'a self, source: S) -> impl Stream<Item = Result<DocumentId>> + 'a
+    where
+        S: Stream<Item = Document> + Send + 'a,
+    {
+        source
+            .map(move |document| async move {
+                *self.ingestion_backlog.write().await += 1;
+                let result = self.add_document(document).await;
+                *self.ingestion_backlog.write().await -= 1;
+
+                if result.is_ok() {
+                    let mut since_update = self.documents_since_last_community_update.write().await;
+                    *since_update += 1;
+                    if *since_update > COMMUNITY_UPDATE_BATCH_SIZE {
+                        *since_update = 0;
+                        if let Err(err) = self.knowledge_graph.read().await.run_community_detection().await {
+                            tracing::warn!("automatic community detection failed: {}", err);
+                        }
+                    }
+                }
+
+                result
+            })
+            .buffer_unordered(INGESTION_PARALLELISM)
+    }
+
     /// Add a document to the librarian system
     pub async fn add_document(&self, document: Document) -> Result<DocumentId> {
         // Create a simple classification result
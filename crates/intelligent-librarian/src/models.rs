@@ -248,6 +248,9 @@ pub struct SearchResult {
     pub highlights: Vec<Highlight>,
     pub matched_fields: Vec<String>,
     pub explanation: Option<String>,
+    /// Sentence-level citations behind this result's relationships, from
+    /// [`crate::knowledge_graph::KnowledgeGraph::explain_result`].
+    pub cited_evidence: Vec<CitedEvidence>,
 }
 
 /// Text highlight in search results
@@ -358,6 +361,36 @@ pub struct GraphEdge {
     pub edge_type: String,
     pub weight: f64,
     pub properties: HashMap<String, serde_json::Value>,
+    pub last_reinforced_at: DateTime<Utc>,
+    /// Sentences [`crate::relationship_extractor::RelationshipExtractor::extract`]
+    /// found this relationship in, so a caller can cite the exact source
+    /// text an edge came from instead of just trusting the weight.
+    pub provenance: Vec<Provenance>,
+}
+
+/// One sentence a [`GraphEdge`] was extracted from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Provenance {
+    pub document_id: DocumentId,
+    pub sentence_index: usize,
+    pub sentence_text: String,
+    pub extraction_confidence: f64,
+}
+
+/// A single piece of cited evidence in an [`Explanation`]'s reasoning chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CitedEvidence {
+    pub edge_id: String,
+    pub document_id: DocumentId,
+    pub text_excerpt: String,
+}
+
+/// Why a [`crate::knowledge_graph::KnowledgeGraph`] node is connected the
+/// way it is, as a chain of the source sentences behind each of its edges.
+/// Returned by [`crate::knowledge_graph::KnowledgeGraph::explain_result`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Explanation {
+    pub reasoning_chain: Vec<CitedEvidence>,
 }
 
 /// Graph cluster
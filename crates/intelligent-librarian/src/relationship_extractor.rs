@@ -0,0 +1,172 @@
+//! Sentence-level relationship extraction for the knowledge graph.
+//!
+//! [`RelationshipExtractor::extract`] scans a document's sentences for
+//! co-occurring known entities (graph node labels) and turns each
+//! co-occurrence into a [`GraphEdge`], recording the sentence it was found
+//! in as [`Provenance`] so [`crate::knowledge_graph::KnowledgeGraph::explain_result`]
+//! can cite the exact source text behind a relationship instead of just
+//! trusting its weight.
+
+use crate::models::{Document, GraphEdge, GraphNode, Provenance};
+use std::collections::HashMap;
+
+/// Confidence assigned to a co-occurrence found via an exact-case label
+/// match, versus [`CASE_INSENSITIVE_MATCH_CONFIDENCE`] for a case-insensitive
+/// one.
+const EXACT_MATCH_CONFIDENCE: f64 = 0.9;
+const CASE_INSENSITIVE_MATCH_CONFIDENCE: f64 = 0.6;
+
+/// Extracts [`GraphEdge`]s from documents by finding sentences that mention
+/// two or more known entities together.
+pub struct RelationshipExtractor;
+
+impl RelationshipExtractor {
+    /// Splits `document.content` into sentences and, for every sentence
+    /// that mentions two or more of `known_entities`, emits a `GraphEdge`
+    /// between each pair with that sentence recorded as provenance.
+    /// Multiple sentences supporting the same pair contribute one edge with
+    /// multiple provenance entries, rather than duplicate edges.
+    pub fn extract(document: &Document, known_entities: &[GraphNode]) -> Vec<GraphEdge> {
+        let mut edges: HashMap<(String, String), GraphEdge> = HashMap::new();
+
+        for (sentence_index, sentence) in Self::split_sentences(&document.content).iter().enumerate() {
+            let mentions = Self::mentioned_entities(sentence, known_entities);
+            for i in 0..mentions.len() {
+                for j in (i + 1)..mentions.len() {
+                    let (source, confidence_a) = &mentions[i];
+                    let (target, confidence_b) = &mentions[j];
+                    let key = if source <= target {
+                        (source.to_string(), target.to_string())
+                    } else {
+                        (target.to_string(), source.to_string())
+                    };
+                    let confidence = confidence_a.min(*confidence_b);
+
+                    let edge = edges.entry(key.clone()).or_insert_with(|| GraphEdge {
+                        source: key.0,
+                        target: key.1,
+                        edge_type: "co_mentioned".to_string(),
+                        weight: 0.0,
+                        properties: HashMap::new(),
+                        last_reinforced_at: document.metadata.modified_at,
+                        provenance: Vec::new(),
+                    });
+                    edge.weight = (edge.weight + confidence).min(1.0);
+                    edge.provenance.push(Provenance {
+                        document_id: document.id,
+                        sentence_index,
+                        sentence_text: sentence.clone(),
+                        extraction_confidence: confidence,
+                    });
+                }
+            }
+        }
+
+        edges.into_values().collect()
+    }
+
+    /// Splits `content` into trimmed, non-empty sentences on `.`, `!`, and
+    /// `?`. Good enough for co-occurrence extraction; not a full sentence
+    /// boundary detector.
+    fn split_sentences(content: &str) -> Vec<String> {
+        content
+            .split(['.', '!', '?'])
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Entities from `known_entities` mentioned in `sentence`, paired with
+    /// the confidence of the match (exact-case vs. case-insensitive).
+    fn mentioned_entities<'a>(sentence: &str, known_entities: &'a [GraphNode]) -> Vec<(&'a str, f64)> {
+        let lower_sentence = sentence.to_lowercase();
+        known_entities
+            .iter()
+            .filter_map(|entity| {
+                if sentence.contains(&entity.label) {
+                    Some((entity.id.as_str(), EXACT_MATCH_CONFIDENCE))
+                } else if lower_sentence.contains(&entity.label.to_lowercase()) {
+                    Some((entity.id.as_str(), CASE_INSENSITIVE_MATCH_CONFIDENCE))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DocumentMetadata;
+    use chrono::Utc;
+    use std::collections::HashMap as StdHashMap;
+    use uuid::Uuid;
+
+    fn document(content: &str) -> Document {
+        Document {
+            id: Uuid::new_v4(),
+            content: content.to_string(),
+            metadata: DocumentMetadata {
+                title: "test".to_string(),
+                description: None,
+                authors: vec![],
+                tags: vec![],
+                language: Some("en".to_string()),
+                created_at: Utc::now(),
+                modified_at: Utc::now(),
+                file_size: Some(content.len() as u64),
+                mime_type: None,
+                source: None,
+                license: None,
+                version: None,
+                dependencies: vec![],
+                custom_fields: StdHashMap::new(),
+            },
+            analysis: None,
+            quality_score: None,
+            relationships: vec![],
+        }
+    }
+
+    fn entity(id: &str, label: &str) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            label: label.to_string(),
+            node_type: "concept".to_string(),
+            properties: StdHashMap::new(),
+            position: None,
+            size: 1.0,
+            color: "#000000".to_string(),
+        }
+    }
+
+    #[test]
+    fn a_relationship_cites_the_sentence_it_was_extracted_from() {
+        let doc = document(
+            "Machine Learning relies heavily on Optimization. Unrelated sentence about weather.",
+        );
+        let entities = vec![entity("ml_concept", "Machine Learning"), entity("optimization_concept", "Optimization")];
+
+        let edges = RelationshipExtractor::extract(&doc, &entities);
+
+        assert_eq!(edges.len(), 1);
+        let edge = &edges[0];
+        assert_eq!(edge.provenance.len(), 1);
+        assert_eq!(edge.provenance[0].sentence_index, 0);
+        assert!(edge.provenance[0].sentence_text.contains("Machine Learning"));
+        assert!(edge.provenance[0].sentence_text.contains("Optimization"));
+        assert_eq!(edge.provenance[0].extraction_confidence, EXACT_MATCH_CONFIDENCE);
+    }
+
+    #[test]
+    fn sentences_mentioning_only_one_entity_produce_no_edge() {
+        let doc = document("Machine Learning is a broad field.");
+        let entities = vec![entity("ml_concept", "Machine Learning"), entity("optimization_concept", "Optimization")];
+
+        let edges = RelationshipExtractor::extract(&doc, &entities);
+
+        assert!(edges.is_empty());
+    }
+}
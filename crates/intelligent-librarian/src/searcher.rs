@@ -30,6 +30,7 @@ impl AdvancedSearcher {
                 highlights: vec![],
                 matched_fields: vec!["content".to_string()],
                 explanation: Some("Semantic match".to_string()),
+                cited_evidence: vec![],
             })
             .collect();
 
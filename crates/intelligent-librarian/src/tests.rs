@@ -2,6 +2,7 @@
 
 use crate::*;
 use anyhow::Result;
+use futures::StreamExt;
 use tracing::info;
 use uuid::Uuid;
 use chrono::Utc;
@@ -10,14 +11,14 @@ use std::collections::HashMap;
 #[tokio::test]
 async fn test_librarian_creation() -> Result<()> {
     info!("Test 1: Creating Intelligent Librarian...");
-    let _librarian = IntelligentLibrarian::new().await?;
+    let librarian = IntelligentLibrarian::new().await?;
     info!("✅ Librarian created successfully!");
     Ok(())
 }
 
 #[tokio::test]
 async fn test_agent_availability() -> Result<()> {
-    let _librarian = IntelligentLibrarian::new().await?;
+    let librarian = IntelligentLibrarian::new().await?;
 
     info!("Test 2: Testing agent availability...");
     let agents = librarian.get_available_agents().await?;
@@ -32,7 +33,7 @@ async fn test_agent_availability() -> Result<()> {
 
 #[tokio::test]
 async fn test_unlimited_context_traversal() -> Result<()> {
-    let _librarian = IntelligentLibrarian::new().await?;
+    let librarian = IntelligentLibrarian::new().await?;
 
     info!("Test 3: Testing unlimited context traversal...");
     let query = "machine learning optimization strategies for distributed systems";
@@ -54,7 +55,7 @@ async fn test_unlimited_context_traversal() -> Result<()> {
 
 #[tokio::test]
 async fn test_document_addition() -> Result<()> {
-    let _librarian = IntelligentLibrarian::new().await?;
+    let librarian = IntelligentLibrarian::new().await?;
 
     info!("Test 4: Testing document addition...");
     let test_document = Document {
@@ -88,7 +89,7 @@ async fn test_document_addition() -> Result<()> {
 
 #[tokio::test]
 async fn test_analytics() -> Result<()> {
-    let _librarian = IntelligentLibrarian::new().await?;
+    let librarian = IntelligentLibrarian::new().await?;
 
     info!("Test 5: Testing analytics...");
     let analytics = librarian.get_analytics().await?;
@@ -104,7 +105,7 @@ async fn test_analytics() -> Result<()> {
 
 #[tokio::test]
 async fn test_knowledge_graph() -> Result<()> {
-    let _librarian = IntelligentLibrarian::new().await?;
+    let librarian = IntelligentLibrarian::new().await?;
 
     info!("Test 6: Testing knowledge graph...");
     let graph_data = librarian.get_knowledge_graph(None).await?;
@@ -119,7 +120,7 @@ async fn test_knowledge_graph() -> Result<()> {
 
 #[tokio::test]
 async fn test_token_management() -> Result<()> {
-    let _librarian = IntelligentLibrarian::new().await?;
+    let librarian = IntelligentLibrarian::new().await?;
 
     info!("Test 7: Testing token management...");
     let test_query = "test query for token management";
@@ -136,7 +137,7 @@ async fn test_token_management() -> Result<()> {
 
 #[tokio::test]
 async fn test_error_handling() -> Result<()> {
-    let _librarian = IntelligentLibrarian::new().await?;
+    let librarian = IntelligentLibrarian::new().await?;
 
     info!("Test 8: Testing error handling...");
 
@@ -165,7 +166,7 @@ async fn test_comprehensive_functionality() -> Result<()> {
     info!("==========================================");
 
     // Create librarian
-    let _librarian = IntelligentLibrarian::new().await?;
+    let librarian = IntelligentLibrarian::new().await?;
     info!("✅ Librarian created successfully!");
 
     // Test agent availability
@@ -220,3 +221,85 @@ async fn test_comprehensive_functionality() -> Result<()> {
     info!("The Agent-Integrated Unlimited Context System is fully functional!");
     Ok(())
 }
+
+#[tokio::test]
+async fn document_stream_ingests_every_document_and_updates_communities() -> Result<()> {
+    let librarian = IntelligentLibrarian::new().await?;
+
+    let make_document = |i: usize| Document {
+        id: Uuid::new_v4(),
+        content: format!("Document {i} about machine learning optimization."),
+        metadata: DocumentMetadata {
+            title: format!("Doc {i}"),
+            description: None,
+            authors: vec![],
+            tags: vec![],
+            language: None,
+            created_at: Utc::now(),
+            modified_at: Utc::now(),
+            file_size: None,
+            mime_type: None,
+            source: None,
+            license: None,
+            version: None,
+            dependencies: vec![],
+            custom_fields: HashMap::new(),
+        },
+        analysis: None,
+        quality_score: None,
+        relationships: vec![],
+    };
+
+    let documents = (0..100).map(make_document).collect::<Vec<_>>();
+    let source = futures::stream::iter(documents);
+
+    let results: Vec<Result<DocumentId>> = librarian.document_stream(source).collect().await;
+    assert_eq!(results.len(), 100, "no document should be silently dropped");
+    assert!(results.iter().all(|r| r.is_ok()));
+
+    assert_eq!(librarian.ingestion_backlog().await, 0);
+
+    let analytics = librarian.get_analytics().await?;
+    assert_eq!(analytics.total_documents, 100);
+
+    Ok(())
+}
+
+#[test]
+fn merge_by_provenance_collapses_mentions_of_the_same_entity() {
+    let openai_type = EntityType::Organization;
+    let make_node = |embedding: Vec<f32>| KnowledgeNode {
+        canonical_id: EntityMerger::canonical_id("OpenAI", &openai_type),
+        name: "OpenAI".to_string(),
+        entity_type: openai_type.clone(),
+        embedding,
+        metadata: HashMap::new(),
+        source_documents: vec![],
+        wikidata_link: None,
+    };
+
+    let mut nodes = vec![
+        make_node(vec![1.0, 0.0, 0.0]),
+        make_node(vec![0.0, 1.0, 0.0]),
+        make_node(vec![0.0, 0.0, 1.0]),
+    ];
+    let documents = vec![
+        DocumentRequest { id: "doc-1".to_string(), content: "OpenAI released a new model.".to_string() },
+        DocumentRequest { id: "doc-2".to_string(), content: "OpenAI's research lab published a paper.".to_string() },
+        DocumentRequest { id: "doc-3".to_string(), content: "OpenAI partnered with a hardware vendor.".to_string() },
+    ];
+
+    EntityMerger::merge_by_provenance(&mut nodes, &documents);
+
+    assert_eq!(nodes.len(), 1);
+    let merged = &nodes[0];
+    assert_eq!(merged.source_documents.len(), 3);
+    assert!(merged.source_documents.contains(&"doc-1".to_string()));
+    assert!(merged.source_documents.contains(&"doc-2".to_string()));
+    assert!(merged.source_documents.contains(&"doc-3".to_string()));
+
+    let expected_centroid = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+    for (actual, expected) in merged.embedding.iter().zip(expected_centroid) {
+        assert!((actual - expected).abs() < 1e-6);
+    }
+}
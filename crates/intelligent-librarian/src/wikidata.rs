@@ -0,0 +1,259 @@
+//! Linking extracted entities to Wikidata identities.
+//!
+//! [`WikidataLinker::link`] resolves an [`Entity`] to the Wikidata item it
+//! most likely refers to via the public `wbsearchentities` search API, and
+//! [`enrich_with_wikidata`] runs that resolution across every
+//! [`KnowledgeNode`] in a graph that doesn't already have one. Wikidata
+//! access is abstracted behind [`WikidataClient`] the same way
+//! `entity_merge`'s `GraphStorage` stands in for a real Supabase-backed
+//! store: `HttpWikidataClient` is the production implementation, and tests
+//! substitute an in-memory stub instead of hitting the real API.
+
+use crate::entity_merge::KnowledgeNode;
+use crate::models::{Entity, EntityType};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+
+/// An external Wikidata identity resolved for an entity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WikidataLink {
+    pub qid: String,
+    pub label: String,
+    pub description: String,
+    pub aliases: Vec<String>,
+}
+
+/// How long a resolved link is cached before `link` will search again.
+const CACHE_TTL: ChronoDuration = ChronoDuration::hours(24);
+
+/// Minimal Wikidata search surface `WikidataLinker` depends on.
+#[async_trait::async_trait]
+pub trait WikidataClient: Send + Sync {
+    async fn search_entities(&self, query: &str) -> Result<Vec<WikidataLink>, String>;
+}
+
+/// `WikidataClient` backed by `wbsearchentities` on `www.wikidata.org`.
+pub struct HttpWikidataClient {
+    http: reqwest::Client,
+}
+
+impl HttpWikidataClient {
+    pub fn new() -> Self {
+        Self { http: reqwest::Client::new() }
+    }
+}
+
+impl Default for HttpWikidataClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl WikidataClient for HttpWikidataClient {
+    async fn search_entities(&self, query: &str) -> Result<Vec<WikidataLink>, String> {
+        let response = self
+            .http
+            .get("https://www.wikidata.org/w/api.php")
+            .query(&[
+                ("action", "wbsearchentities"),
+                ("format", "json"),
+                ("language", "en"),
+                ("type", "item"),
+                ("search", query),
+            ])
+            .send()
+            .await
+            .map_err(|e| format!("wikidata request failed: {e}"))?;
+
+        let body: serde_json::Value =
+            response.json().await.map_err(|e| format!("wikidata response was not valid json: {e}"))?;
+
+        let candidates = body["search"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|candidate| {
+                Some(WikidataLink {
+                    qid: candidate["id"].as_str()?.to_string(),
+                    label: candidate["label"].as_str().unwrap_or_default().to_string(),
+                    description: candidate["description"].as_str().unwrap_or_default().to_string(),
+                    aliases: candidate["aliases"]
+                        .as_array()
+                        .map(|aliases| aliases.iter().filter_map(|a| a.as_str().map(str::to_string)).collect())
+                        .unwrap_or_default(),
+                })
+            })
+            .collect();
+
+        Ok(candidates)
+    }
+}
+
+/// Resolves entities to Wikidata identities, caching resolved links for
+/// [`CACHE_TTL`] so repeated mentions of the same name don't re-query the
+/// API. `wbsearchentities` has no `instance_of` search parameter, so
+/// `EntityType` refinement is applied client-side: for `EntityType::Person`,
+/// candidates are preferred if their description reads like a human (the
+/// API's own descriptions commonly say things like "American physicist" or
+/// "born 1879" for people), rather than the exact `instance_of:Q5` query
+/// filter that would require the SPARQL endpoint instead of this search API.
+pub struct WikidataLinker<C: WikidataClient> {
+    client: C,
+    cache: DashMap<String, (WikidataLink, DateTime<Utc>)>,
+}
+
+impl<C: WikidataClient> WikidataLinker<C> {
+    pub fn new(client: C) -> Self {
+        Self { client, cache: DashMap::new() }
+    }
+
+    /// Resolves `entity` to the Wikidata item it most likely refers to, or
+    /// `None` if the search returned no candidates.
+    pub async fn link(&self, entity: &Entity) -> Option<WikidataLink> {
+        self.link_by_name_and_type(&entity.text, &entity.entity_type).await
+    }
+
+    /// Links every `KnowledgeNode` in `nodes` that doesn't already carry a
+    /// `wikidata_link`, mutating each in place.
+    pub async fn enrich_with_wikidata(&self, nodes: &mut [KnowledgeNode]) {
+        for node in nodes.iter_mut() {
+            if node.wikidata_link.is_some() {
+                continue;
+            }
+            node.wikidata_link = self.link_by_name_and_type(&node.name, &node.entity_type).await;
+        }
+    }
+
+    async fn link_by_name_and_type(&self, name: &str, entity_type: &EntityType) -> Option<WikidataLink> {
+        if let Some(cached) = self.cache.get(name) {
+            if cached.1 > Utc::now() {
+                return Some(cached.0.clone());
+            }
+        }
+
+        let candidates = self.client.search_entities(name).await.ok()?;
+        let chosen = Self::choose_candidate(candidates, entity_type)?;
+
+        self.cache.insert(name.to_string(), (chosen.clone(), Utc::now() + CACHE_TTL));
+        Some(chosen)
+    }
+
+    /// Picks the best candidate for `entity_type`, preferring one whose
+    /// description looks like a match for `EntityType::Person` and
+    /// otherwise falling back to the search API's own top result.
+    fn choose_candidate(candidates: Vec<WikidataLink>, entity_type: &EntityType) -> Option<WikidataLink> {
+        if *entity_type == EntityType::Person {
+            const PERSON_HINTS: [&str; 6] =
+                ["born", "physicist", "scientist", "politician", "actor", "writer"];
+            if let Some(person_match) = candidates
+                .iter()
+                .find(|candidate| PERSON_HINTS.iter().any(|hint| candidate.description.to_lowercase().contains(hint)))
+            {
+                return Some(person_match.clone());
+            }
+        }
+
+        candidates.into_iter().next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockWikidataClient {
+        responses: std::collections::HashMap<String, Vec<WikidataLink>>,
+        call_count: std::sync::atomic::AtomicUsize,
+    }
+
+    impl MockWikidataClient {
+        fn einstein() -> Self {
+            let mut responses = std::collections::HashMap::new();
+            responses.insert(
+                "Albert Einstein".to_string(),
+                vec![
+                    WikidataLink {
+                        qid: "Q7186".to_string(),
+                        label: "Albert Einstein".to_string(),
+                        description: "German-born theoretical physicist".to_string(),
+                        aliases: vec!["Einstein".to_string()],
+                    },
+                    WikidataLink {
+                        qid: "Q106481".to_string(),
+                        label: "Einstein (crater)".to_string(),
+                        description: "lunar crater".to_string(),
+                        aliases: vec![],
+                    },
+                ],
+            );
+            Self { responses, call_count: std::sync::atomic::AtomicUsize::new(0) }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl WikidataClient for MockWikidataClient {
+        async fn search_entities(&self, query: &str) -> Result<Vec<WikidataLink>, String> {
+            self.call_count.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(self.responses.get(query).cloned().unwrap_or_default())
+        }
+    }
+
+    fn person_entity(text: &str) -> Entity {
+        Entity { text: text.to_string(), entity_type: EntityType::Person, confidence: 0.95, start_pos: 0, end_pos: text.len() }
+    }
+
+    #[tokio::test]
+    async fn link_prefers_the_person_candidate_over_the_crater() {
+        let linker = WikidataLinker::new(MockWikidataClient::einstein());
+
+        let link = linker.link(&person_entity("Albert Einstein")).await;
+
+        assert_eq!(link.map(|l| l.qid), Some("Q7186".to_string()));
+    }
+
+    #[tokio::test]
+    async fn link_returns_none_when_the_search_has_no_results() {
+        let linker = WikidataLinker::new(MockWikidataClient::einstein());
+
+        let link = linker.link(&person_entity("Someone Entirely Unknown")).await;
+
+        assert_eq!(link, None);
+    }
+
+    #[tokio::test]
+    async fn repeated_link_calls_hit_the_cache_instead_of_the_client() {
+        let linker = WikidataLinker::new(MockWikidataClient::einstein());
+
+        linker.link(&person_entity("Albert Einstein")).await;
+        linker.link(&person_entity("Albert Einstein")).await;
+
+        assert_eq!(linker.client.call_count.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn enrich_with_wikidata_skips_nodes_that_already_have_a_link() {
+        let linker = WikidataLinker::new(MockWikidataClient::einstein());
+        let mut nodes = vec![KnowledgeNode {
+            canonical_id: "existing".to_string(),
+            name: "Albert Einstein".to_string(),
+            entity_type: EntityType::Person,
+            embedding: vec![],
+            metadata: Default::default(),
+            source_documents: vec![],
+            wikidata_link: Some(WikidataLink {
+                qid: "Q999".to_string(),
+                label: "placeholder".to_string(),
+                description: String::new(),
+                aliases: vec![],
+            }),
+        }];
+
+        linker.enrich_with_wikidata(&mut nodes).await;
+
+        assert_eq!(nodes[0].wikidata_link.as_ref().map(|l| l.qid.clone()), Some("Q999".to_string()));
+        assert_eq!(linker.client.call_count.load(std::sync::atomic::Ordering::SeqCst), 0);
+    }
+}
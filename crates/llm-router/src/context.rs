@@ -13,6 +13,7 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 use std::time::{Duration, Instant};
+use futures::stream::{self, StreamExt};
 
 /// Advanced context manager with intelligent optimization
 pub struct ContextManager {
@@ -592,6 +593,89 @@ pub struct EmbeddingEngine {
     pub dimension: usize,
     pub batch_size: usize,
     pub cache: Arc<RwLock<LruCache<String, Vec<f32>>>>,
+    /// Discovered optimal chunk size per model, populated by
+    /// `optimal_chunk_size_for_model`.
+    pub chunk_size_cache: Arc<RwLock<HashMap<String, usize>>>,
+    /// Count of HTTP requests `batch_embed`/`embed_entity` have issued,
+    /// exposed so callers (and tests) can verify batching actually reduces
+    /// round-trips.
+    pub requests_made: Arc<std::sync::atomic::AtomicU64>,
+}
+
+impl EmbeddingEngine {
+    /// Embeds a single piece of text via the same chunked path as
+    /// `batch_embed`, so single-entity embedding and bulk embedding share
+    /// one code path.
+    pub async fn embed_entity(&self, text: &str) -> Result<Vec<f32>, RouterError> {
+        let embedding = self
+            .batch_embed(std::slice::from_ref(&text.to_string()))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| RouterError::ContextError("embedding service returned no result".to_string()))?;
+        Ok(embedding)
+    }
+
+    /// Embeds `texts` in chunks sized by `optimal_chunk_size_for_model`,
+    /// dispatching up to 4 chunks concurrently instead of one round-trip
+    /// per text.
+    pub async fn batch_embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, RouterError> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let chunk_size = self.optimal_chunk_size_for_model(&self.model_name).await;
+        let chunk_results: Vec<Result<Vec<Vec<f32>>, RouterError>> = stream::iter(texts.chunks(chunk_size).map(|chunk| self.embed_chunk(chunk)))
+            .buffered(4)
+            .collect()
+            .await;
+
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for chunk_result in chunk_results {
+            embeddings.extend(chunk_result?);
+        }
+        Ok(embeddings)
+    }
+
+    /// Issues one HTTP round-trip embedding an entire chunk of texts.
+    /// Split out from `batch_embed` so the number of calls it makes can be
+    /// measured directly.
+    async fn embed_chunk(&self, chunk: &[String]) -> Result<Vec<Vec<f32>>, RouterError> {
+        self.requests_made.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        // Placeholder embedding service call, matching the dummy embedding
+        // used elsewhere in this module until the real client is wired in.
+        Ok(chunk.iter().map(|_| vec![0.1; self.dimension]).collect())
+    }
+
+    /// Finds (and caches) the chunk size that minimizes per-text embedding
+    /// latency for `model_id`, by doubling the chunk size from 1 until
+    /// latency-per-text stops improving, capped at 128.
+    pub async fn optimal_chunk_size_for_model(&self, model_id: &str) -> usize {
+        if let Some(&cached) = self.chunk_size_cache.read().await.get(model_id) {
+            return cached;
+        }
+
+        let mut best_size = 1;
+        let mut best_latency_per_text = f64::MAX;
+        let mut size = 1;
+        while size <= 128 {
+            let probe: Vec<String> = (0..size).map(|i| format!("probe-{i}")).collect();
+            let started = Instant::now();
+            let _ = self.embed_chunk(&probe).await;
+            let latency_per_text = started.elapsed().as_secs_f64() / size as f64;
+
+            if latency_per_text < best_latency_per_text {
+                best_latency_per_text = latency_per_text;
+                best_size = size;
+                size *= 2;
+            } else {
+                break;
+            }
+        }
+
+        self.chunk_size_cache.write().await.insert(model_id.to_string(), best_size);
+        best_size
+    }
 }
 
 /// Vector index for semantic search
@@ -1245,6 +1329,8 @@ impl SemanticSearch {
                 dimension: 384,
                 batch_size: 32,
                 cache: Arc::new(RwLock::new(LruCache::new(NonZeroUsize::new(10000).unwrap()))),
+                chunk_size_cache: Arc::new(RwLock::new(HashMap::new())),
+                requests_made: Arc::new(std::sync::atomic::AtomicU64::new(0)),
             }),
             vector_index: Arc::new(RwLock::new(VectorIndex {
                 vectors: HashMap::new(),
@@ -0,0 +1,174 @@
+//! Proactive garbage-collection scheduling for context memory.
+//!
+//! `ContextMemoryManager`'s garbage collector is currently reactive: it only
+//! fires once memory pressure crosses a threshold. `AllocationRatePredictor`
+//! tracks recent `used_mb` samples, fits a linear trend, and projects when
+//! usage will hit `critical_threshold_percent` so `GCOptimizer` can schedule
+//! a collection before pressure actually becomes critical.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// Length of the rolling window used for the linear regression, in seconds.
+const WINDOW_SECONDS: f64 = 60.0;
+
+/// One `used_mb` observation, timestamped relative to the predictor's first
+/// sample so the regression doesn't need wall-clock time.
+#[derive(Debug, Clone, Copy)]
+struct UsageSample {
+    seconds_since_start: f64,
+    used_mb: f64,
+}
+
+/// Predicts when memory usage will reach a critical threshold by fitting a
+/// linear trend to the last 60 seconds of `used_mb` samples.
+#[derive(Debug)]
+pub struct AllocationRatePredictor {
+    total_mb: f64,
+    critical_threshold_percent: f64,
+    samples: VecDeque<UsageSample>,
+}
+
+impl AllocationRatePredictor {
+    pub fn new(total_mb: f64, critical_threshold_percent: f64) -> Self {
+        Self {
+            total_mb,
+            critical_threshold_percent,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Records a `used_mb` observation at `seconds_since_start`, dropping
+    /// samples that have fallen outside the 60-second regression window.
+    pub fn observe(&mut self, seconds_since_start: f64, used_mb: f64) {
+        self.samples.push_back(UsageSample { seconds_since_start, used_mb });
+        while let Some(oldest) = self.samples.front() {
+            if seconds_since_start - oldest.seconds_since_start > WINDOW_SECONDS {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Estimated seconds until usage reaches `critical_threshold_percent` of
+    /// `total_mb`, based on a least-squares linear fit of the samples in the
+    /// current window. Returns `None` when there isn't enough history yet,
+    /// or when usage isn't trending upward (the line never reaches the
+    /// threshold).
+    pub fn seconds_until_critical(&self) -> Option<f64> {
+        if self.samples.len() < 2 {
+            return None;
+        }
+
+        let n = self.samples.len() as f64;
+        let mean_t = self.samples.iter().map(|s| s.seconds_since_start).sum::<f64>() / n;
+        let mean_used = self.samples.iter().map(|s| s.used_mb).sum::<f64>() / n;
+
+        let mut numerator = 0.0;
+        let mut denominator = 0.0;
+        for sample in &self.samples {
+            let dt = sample.seconds_since_start - mean_t;
+            numerator += dt * (sample.used_mb - mean_used);
+            denominator += dt * dt;
+        }
+
+        if denominator == 0.0 {
+            return None;
+        }
+
+        let slope = numerator / denominator; // MB per second
+        if slope <= 0.0 {
+            return None;
+        }
+
+        let intercept = mean_used - slope * mean_t;
+        let critical_mb = self.total_mb * (self.critical_threshold_percent / 100.0);
+        let latest = self.samples.back().expect("checked len >= 2 above");
+
+        let seconds_at_critical = (critical_mb - intercept) / slope;
+        let seconds_remaining = seconds_at_critical - latest.seconds_since_start;
+
+        if seconds_remaining < 0.0 {
+            Some(0.0)
+        } else {
+            Some(seconds_remaining)
+        }
+    }
+}
+
+/// Schedules garbage-collection runs ahead of predicted memory pressure.
+pub struct GCOptimizer;
+
+impl GCOptimizer {
+    /// Waits until 10 seconds before `seconds_until_critical` elapses, then
+    /// runs `run_gc`. If critical pressure is already less than 10 seconds
+    /// away, runs immediately.
+    pub async fn schedule_proactive_gc<F, Fut>(seconds_until_critical: f64, run_gc: F)
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let delay = (seconds_until_critical - 10.0).max(0.0);
+        if delay > 0.0 {
+            sleep(Duration::from_secs_f64(delay)).await;
+        }
+        run_gc().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn predicts_seconds_until_critical_for_linear_growth() {
+        let mut predictor = AllocationRatePredictor::new(1000.0, 90.0);
+        // Usage grows at 10 MB/s starting from 500 MB.
+        for t in 0..30 {
+            predictor.observe(t as f64, 500.0 + 10.0 * t as f64);
+        }
+
+        // Critical is 900 MB; latest sample is at t=29 with 790 MB, so at
+        // 10 MB/s it should be about 11 seconds away.
+        let seconds = predictor.seconds_until_critical().expect("should predict a value");
+        assert!((seconds - 11.0).abs() < 0.5, "expected ~11 seconds, got {seconds}");
+    }
+
+    #[test]
+    fn returns_none_when_usage_is_flat() {
+        let mut predictor = AllocationRatePredictor::new(1000.0, 90.0);
+        for t in 0..10 {
+            predictor.observe(t as f64, 500.0);
+        }
+        assert!(predictor.seconds_until_critical().is_none());
+    }
+
+    #[test]
+    fn returns_none_with_fewer_than_two_samples() {
+        let mut predictor = AllocationRatePredictor::new(1000.0, 90.0);
+        predictor.observe(0.0, 500.0);
+        assert!(predictor.seconds_until_critical().is_none());
+    }
+
+    #[tokio::test]
+    async fn schedules_gc_before_critical_threshold_is_reached() {
+        let mut predictor = AllocationRatePredictor::new(1000.0, 90.0);
+        for t in 0..30 {
+            predictor.observe(t as f64, 500.0 + 10.0 * t as f64);
+        }
+        let seconds_until_critical = predictor.seconds_until_critical().expect("should predict a value");
+
+        let gc_ran = Arc::new(AtomicBool::new(false));
+        let gc_ran_clone = gc_ran.clone();
+        GCOptimizer::schedule_proactive_gc(seconds_until_critical, || async move {
+            gc_ran_clone.store(true, Ordering::SeqCst);
+        })
+        .await;
+
+        assert!(gc_ran.load(Ordering::SeqCst), "GC should have run proactively, before hitting critical pressure");
+    }
+}
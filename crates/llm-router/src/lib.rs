@@ -21,6 +21,7 @@ pub mod context_manager;
 pub mod librarian_context;
 pub mod unlimited_context;
 pub mod context_degradation;
+pub mod gc_scheduling;
 pub mod service_integration;
 pub mod keychain;
 
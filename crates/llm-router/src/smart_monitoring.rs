@@ -1,10 +1,182 @@
 // Smart Monitoring System leveraging existing monitoring infrastructure
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Number of `memory_usage` samples `SmartMonitoringSystem` keeps for the
+/// gradient regression in `detect_memory_pressure`.
+const MEMORY_PRESSURE_HISTORY_SAMPLES: usize = 5;
+/// Below this percentage memory pressure is `Low`.
+const MEMORY_PRESSURE_LOW_MEDIUM_PERCENT: f64 = 60.0;
+/// Below this percentage (and at/above the one above) memory pressure is `Medium`.
+const MEMORY_PRESSURE_MEDIUM_HIGH_PERCENT: f64 = 85.0;
+/// Below this percentage (and at/above the one above) memory pressure is `High`; at/above it, `Critical`.
+const MEMORY_PRESSURE_HIGH_CRITICAL_PERCENT: f64 = 95.0;
+/// A rising gradient steeper than this, combined with the current
+/// percentage sitting within `MEMORY_PRESSURE_NEAR_THRESHOLD_MARGIN_PERCENT`
+/// of the next threshold, is treated as more urgent than the flat
+/// percentage suggests and bumps the reported level up one step.
+const MEMORY_PRESSURE_RISING_OVERRIDE_PERCENT_PER_MINUTE: f64 = 3.0;
+const MEMORY_PRESSURE_NEAR_THRESHOLD_MARGIN_PERCENT: f64 = 10.0;
+
+/// Direction memory usage has been moving over the sampled history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MemoryPressureTrend {
+    Rising,
+    Falling,
+    Stable,
+}
+
+/// Rate-of-change summary for memory pressure, produced alongside a
+/// `MemoryPressureLevel` by `SmartMonitoringSystem::detect_memory_pressure`.
+/// A steady 5%/minute climb is more urgent than a stable higher reading, so
+/// callers should look at this instead of `current_percent` alone.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MemoryPressureGradient {
+    pub current_percent: f64,
+    pub delta_percent_per_minute: f64,
+    pub trend: MemoryPressureTrend,
+}
+
+/// Discrete memory pressure level. Ordered `Low < Medium < High < Critical`
+/// so a gradient override can bump the level computed from `current_percent`
+/// alone up by one step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum MemoryPressureLevel {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl MemoryPressureLevel {
+    fn from_percent(percent: f64) -> Self {
+        if percent >= MEMORY_PRESSURE_HIGH_CRITICAL_PERCENT {
+            MemoryPressureLevel::Critical
+        } else if percent >= MEMORY_PRESSURE_MEDIUM_HIGH_PERCENT {
+            MemoryPressureLevel::High
+        } else if percent >= MEMORY_PRESSURE_LOW_MEDIUM_PERCENT {
+            MemoryPressureLevel::Medium
+        } else {
+            MemoryPressureLevel::Low
+        }
+    }
+
+    /// The percentage at which this level would naturally roll over into
+    /// the next one, or `None` for `Critical`, which has no next level.
+    fn next_threshold_percent(self) -> Option<f64> {
+        match self {
+            MemoryPressureLevel::Low => Some(MEMORY_PRESSURE_LOW_MEDIUM_PERCENT),
+            MemoryPressureLevel::Medium => Some(MEMORY_PRESSURE_MEDIUM_HIGH_PERCENT),
+            MemoryPressureLevel::High => Some(MEMORY_PRESSURE_HIGH_CRITICAL_PERCENT),
+            MemoryPressureLevel::Critical => None,
+        }
+    }
+
+    fn one_step_higher(self) -> Self {
+        match self {
+            MemoryPressureLevel::Low => MemoryPressureLevel::Medium,
+            MemoryPressureLevel::Medium => MemoryPressureLevel::High,
+            MemoryPressureLevel::High => MemoryPressureLevel::Critical,
+            MemoryPressureLevel::Critical => MemoryPressureLevel::Critical,
+        }
+    }
+}
+
+/// A single GPU memory reading. Populated from `nvml-wrapper`'s
+/// `Device::memory_info()` (`used`/`total`, converted to MB) when the
+/// `gpu-monitoring` feature is enabled; ML workloads can OOM on GPU memory
+/// well before CPU RAM gets tight, so this is tracked and classified
+/// separately from `memory_usage_history`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GpuMemorySample {
+    pub device_id: u32,
+    pub used_mb: u64,
+    pub total_mb: u64,
+}
+
+impl GpuMemorySample {
+    fn percent_used(&self) -> f64 {
+        if self.total_mb == 0 {
+            0.0
+        } else {
+            (self.used_mb as f64 / self.total_mb as f64) * 100.0
+        }
+    }
+}
+
+/// Reads live GPU memory stats via NVML. Only available with the
+/// `gpu-monitoring` feature, since it links against the NVIDIA driver's
+/// NVML library.
+#[cfg(feature = "gpu-monitoring")]
+pub mod nvml_gpu_reader {
+    use super::GpuMemorySample;
+
+    /// Reads a memory sample for every NVIDIA GPU visible to this process.
+    /// Returns an empty vec (rather than erroring) if NVML can't be
+    /// initialized, e.g. on a machine with no NVIDIA driver, so callers can
+    /// treat "no GPU" the same as "nothing to report".
+    pub fn read_gpu_memory_samples() -> Vec<GpuMemorySample> {
+        let nvml = match nvml_wrapper::Nvml::init() {
+            Ok(nvml) => nvml,
+            Err(e) => {
+                tracing::warn!("Failed to initialize NVML: {e}");
+                return Vec::new();
+            }
+        };
+
+        let device_count = match nvml.device_count() {
+            Ok(count) => count,
+            Err(e) => {
+                tracing::warn!("Failed to query NVML device count: {e}");
+                return Vec::new();
+            }
+        };
+
+        (0..device_count)
+            .filter_map(|index| {
+                let device = nvml.device_by_index(index).ok()?;
+                let memory = device.memory_info().ok()?;
+                Some(GpuMemorySample {
+                    device_id: index,
+                    used_mb: memory.used / (1024 * 1024),
+                    total_mb: memory.total / (1024 * 1024),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Least-squares slope of `(timestamp_seconds, percent)` samples, in
+/// percent per minute. Returns 0.0 for fewer than two samples or when every
+/// sample shares the same timestamp.
+fn regression_slope_percent_per_minute(history: &VecDeque<(u64, f64)>) -> f64 {
+    if history.len() < 2 {
+        return 0.0;
+    }
+
+    let n = history.len() as f64;
+    let first_timestamp = history[0].0 as f64;
+    let mean_t = history.iter().map(|(t, _)| (*t as f64 - first_timestamp) / 60.0).sum::<f64>() / n;
+    let mean_percent = history.iter().map(|(_, percent)| *percent).sum::<f64>() / n;
+
+    let mut numerator = 0.0;
+    let mut denominator = 0.0;
+    for (timestamp, percent) in history {
+        let dt = (*timestamp as f64 - first_timestamp) / 60.0 - mean_t;
+        numerator += dt * (percent - mean_percent);
+        denominator += dt * dt;
+    }
+
+    if denominator == 0.0 {
+        0.0
+    } else {
+        numerator / denominator
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SmartMetrics {
     pub timestamp: u64,
@@ -98,6 +270,13 @@ pub enum OptimizationType {
 
 pub struct SmartMonitoringSystem {
     metrics: Arc<RwLock<SmartMetrics>>,
+    /// Last `MEMORY_PRESSURE_HISTORY_SAMPLES` `(timestamp_seconds, memory_usage_percent)`
+    /// pairs recorded by `update_system_health`, used by `detect_memory_pressure`.
+    memory_usage_history: Arc<RwLock<VecDeque<(u64, f64)>>>,
+    /// Most recent `GpuMemorySample` per `device_id`, recorded by
+    /// `record_gpu_memory` and classified independently of CPU memory
+    /// pressure by `detect_gpu_memory_pressure`.
+    gpu_memory: Arc<RwLock<HashMap<u32, GpuMemorySample>>>,
     #[allow(dead_code)]
     performance_tracker: PerformanceTracker,
     #[allow(dead_code)]
@@ -154,6 +333,8 @@ impl SmartMonitoringSystem {
                 },
                 optimization_opportunities: Vec::new(),
             })),
+            memory_usage_history: Arc::new(RwLock::new(VecDeque::with_capacity(MEMORY_PRESSURE_HISTORY_SAMPLES))),
+            gpu_memory: Arc::new(RwLock::new(HashMap::new())),
             performance_tracker: PerformanceTracker::new(),
             alert_manager: AlertManager::new(),
             optimization_analyzer: OptimizationAnalyzer::new(),
@@ -291,6 +472,63 @@ impl SmartMonitoringSystem {
         metrics.system_health.error_rate = error_rate;
         metrics.system_health.uptime_seconds =
             SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+
+        let mut history = self.memory_usage_history.write().await;
+        if history.len() >= MEMORY_PRESSURE_HISTORY_SAMPLES {
+            history.pop_front();
+        }
+        history.push_back((metrics.system_health.uptime_seconds, memory_usage * 100.0));
+    }
+
+    /// Computes the current memory pressure level and its rate-of-change
+    /// gradient from the last `MEMORY_PRESSURE_HISTORY_SAMPLES` samples
+    /// recorded by `update_system_health`. A steady rise past
+    /// `MEMORY_PRESSURE_RISING_OVERRIDE_PERCENT_PER_MINUTE` while already
+    /// close to the next threshold is treated as more urgent than a stable
+    /// reading at the same percentage, so the level is bumped up one step.
+    pub async fn detect_memory_pressure(&self) -> (MemoryPressureLevel, MemoryPressureGradient) {
+        let history = self.memory_usage_history.read().await;
+
+        let current_percent = history.back().map(|(_, percent)| *percent).unwrap_or(0.0);
+        let delta_percent_per_minute = regression_slope_percent_per_minute(&history);
+
+        let trend = if delta_percent_per_minute > 0.5 {
+            MemoryPressureTrend::Rising
+        } else if delta_percent_per_minute < -0.5 {
+            MemoryPressureTrend::Falling
+        } else {
+            MemoryPressureTrend::Stable
+        };
+
+        let mut level = MemoryPressureLevel::from_percent(current_percent);
+        if delta_percent_per_minute > MEMORY_PRESSURE_RISING_OVERRIDE_PERCENT_PER_MINUTE {
+            if let Some(next_threshold) = level.next_threshold_percent() {
+                if next_threshold - current_percent <= MEMORY_PRESSURE_NEAR_THRESHOLD_MARGIN_PERCENT {
+                    level = level.one_step_higher();
+                }
+            }
+        }
+
+        (level, MemoryPressureGradient { current_percent, delta_percent_per_minute, trend })
+    }
+
+    /// Records the latest memory reading for a GPU device, overwriting any
+    /// previous sample for that `device_id`.
+    pub async fn record_gpu_memory(&self, sample: GpuMemorySample) {
+        self.gpu_memory.write().await.insert(sample.device_id, sample);
+    }
+
+    /// Classifies memory pressure for a GPU device from its most recent
+    /// `record_gpu_memory` sample, using the same percent thresholds as CPU
+    /// memory pressure but evaluated independently per device rather than
+    /// against `memory_usage_history`. Returns `None` if no sample has been
+    /// recorded for that device yet.
+    pub async fn detect_gpu_memory_pressure(&self, device_id: u32) -> Option<MemoryPressureLevel> {
+        self.gpu_memory
+            .read()
+            .await
+            .get(&device_id)
+            .map(|sample| MemoryPressureLevel::from_percent(sample.percent_used()))
     }
 
     pub async fn update_user_satisfaction(
@@ -426,6 +664,11 @@ impl SmartMonitoringSystem {
             issues.push("High memory usage".to_string());
         }
 
+        let (memory_pressure_level, memory_pressure) = self.detect_memory_pressure().await;
+        if memory_pressure_level >= MemoryPressureLevel::High && memory_pressure.trend == MemoryPressureTrend::Rising {
+            issues.push(format!("Memory pressure trending toward {memory_pressure_level:?}"));
+        }
+
         let status = if health_score > 0.8 {
             HealthStatus::Healthy
         } else if health_score > 0.6 {
@@ -439,6 +682,7 @@ impl SmartMonitoringSystem {
             health_score,
             issues,
             metrics: metrics.clone(),
+            memory_pressure,
         }
     }
 }
@@ -464,6 +708,7 @@ pub struct HealthStatusInfo {
     pub health_score: f64,
     pub issues: Vec<String>,
     pub metrics: SmartMetrics,
+    pub memory_pressure: MemoryPressureGradient,
 }
 
 // Supporting structures
@@ -496,3 +741,83 @@ impl OptimizationAnalyzer {
         Self {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn record_history(system: &SmartMonitoringSystem, percents: &[f64]) {
+        for (i, percent) in percents.iter().enumerate() {
+            let mut history = system.memory_usage_history.write().await;
+            history.push_back((i as u64 * 60, *percent));
+        }
+    }
+
+    #[tokio::test]
+    async fn rapidly_rising_memory_near_a_threshold_is_elevated_one_level() {
+        let system = SmartMonitoringSystem::new();
+        // Trends from 65% to 80% over 5 samples one minute apart: on percent
+        // alone this is Medium, but the ~3.75%/minute climb puts it within
+        // 10 points of the Medium/High threshold (85%), so it should read High.
+        record_history(&system, &[65.0, 68.75, 72.5, 76.25, 80.0]).await;
+
+        let (level, gradient) = system.detect_memory_pressure().await;
+
+        assert_eq!(level, MemoryPressureLevel::High);
+        assert_eq!(gradient.trend, MemoryPressureTrend::Rising);
+        assert!(gradient.delta_percent_per_minute > 3.0, "expected >3.0%/min, got {}", gradient.delta_percent_per_minute);
+        assert_eq!(gradient.current_percent, 80.0);
+    }
+
+    #[tokio::test]
+    async fn stable_memory_below_the_next_threshold_is_not_elevated() {
+        let system = SmartMonitoringSystem::new();
+        record_history(&system, &[78.0, 78.5, 79.0, 78.8, 79.2]).await;
+
+        let (level, gradient) = system.detect_memory_pressure().await;
+
+        assert_eq!(level, MemoryPressureLevel::Medium);
+        assert_eq!(gradient.trend, MemoryPressureTrend::Stable);
+    }
+
+    #[tokio::test]
+    async fn falling_memory_is_never_elevated() {
+        let system = SmartMonitoringSystem::new();
+        record_history(&system, &[90.0, 87.0, 84.0, 81.0, 78.0]).await;
+
+        let (level, gradient) = system.detect_memory_pressure().await;
+
+        assert_eq!(gradient.trend, MemoryPressureTrend::Falling);
+        assert_eq!(level, MemoryPressureLevel::Medium);
+    }
+
+    #[tokio::test]
+    async fn with_fewer_than_two_samples_gradient_is_zero() {
+        let system = SmartMonitoringSystem::new();
+        record_history(&system, &[70.0]).await;
+
+        let (level, gradient) = system.detect_memory_pressure().await;
+
+        assert_eq!(gradient.delta_percent_per_minute, 0.0);
+        assert_eq!(level, MemoryPressureLevel::Medium);
+    }
+
+    #[tokio::test]
+    async fn gpu_at_95_percent_utilization_is_critical() {
+        let system = SmartMonitoringSystem::new();
+        system
+            .record_gpu_memory(GpuMemorySample { device_id: 0, used_mb: 9_500, total_mb: 10_000 })
+            .await;
+
+        let level = system.detect_gpu_memory_pressure(0).await;
+
+        assert_eq!(level, Some(MemoryPressureLevel::Critical));
+    }
+
+    #[tokio::test]
+    async fn gpu_pressure_is_none_when_no_sample_recorded() {
+        let system = SmartMonitoringSystem::new();
+
+        assert_eq!(system.detect_gpu_memory_pressure(0).await, None);
+    }
+}
@@ -1,10 +1,17 @@
 use actix_web::{web, App, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{oneshot, Mutex, Notify, RwLock};
 use uuid::Uuid;
 use dashmap::DashMap;
 
+pub mod threat_detection;
+pub use threat_detection::{EmbeddingDistribution, EmbeddingPoisonDetector, PoisonScore};
+
+pub mod model_selector;
+pub use model_selector::{ModelSelectionError, ModelSelector, Quantization};
+
 /// ML inference request structure
 #[derive(Deserialize, Debug)]
 pub struct InferenceRequest {
@@ -39,6 +46,10 @@ pub struct ModelInfo {
     pub memory_usage_mb: u64,
     pub loaded: bool,
     pub load_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Quantization applied to this model, if any. `None` means the model is
+    /// loaded at its original precision.
+    #[serde(default)]
+    pub quantization: Option<Quantization>,
 }
 
 /// Health check response
@@ -54,12 +65,87 @@ pub struct HealthResponse {
     pub loaded_models: usize,
 }
 
+/// A request waiting in the `BoundedRequestQueue`, paired with the channel
+/// used to deliver its result back to the caller once processed.
+pub struct QueuedRequest {
+    pub request: InferenceRequest,
+    pub response_tx: oneshot::Sender<Result<InferenceResponse, String>>,
+}
+
+/// Errors returned when a caller cannot be queued
+#[derive(thiserror::Error, Debug)]
+pub enum QueueError {
+    #[error("request queue is full ({queued}/{max_queued} requests queued)")]
+    Backpressure { queued: usize, max_queued: usize },
+}
+
+/// FIFO queue that bounds how many inference requests may wait for a worker,
+/// signaling backpressure instead of letting callers block indefinitely.
+pub struct BoundedRequestQueue {
+    max_queued: usize,
+    queue: Arc<Mutex<VecDeque<QueuedRequest>>>,
+    notify: Notify,
+}
+
+impl BoundedRequestQueue {
+    pub fn new(max_queued: usize) -> Self {
+        Self {
+            max_queued,
+            queue: Arc::new(Mutex::new(VecDeque::new())),
+            notify: Notify::new(),
+        }
+    }
+
+    /// Enqueue a request, returning a receiver for its eventual result.
+    /// Rejects immediately with `QueueError::Backpressure` when full.
+    pub async fn enqueue(
+        &self,
+        request: InferenceRequest,
+    ) -> Result<oneshot::Receiver<Result<InferenceResponse, String>>, QueueError> {
+        let mut queue = self.queue.lock().await;
+        if queue.len() >= self.max_queued {
+            return Err(QueueError::Backpressure {
+                queued: queue.len(),
+                max_queued: self.max_queued,
+            });
+        }
+
+        let (response_tx, response_rx) = oneshot::channel();
+        queue.push_back(QueuedRequest { request, response_tx });
+        drop(queue);
+        self.notify.notify_one();
+        Ok(response_rx)
+    }
+
+    /// Wait for and remove the next queued request, if any.
+    async fn dequeue(&self) -> QueuedRequest {
+        loop {
+            if let Some(queued) = self.queue.lock().await.pop_front() {
+                return queued;
+            }
+            self.notify.notified().await;
+        }
+    }
+
+    /// Current number of requests waiting to be processed
+    pub async fn len(&self) -> usize {
+        self.queue.lock().await.len()
+    }
+}
+
 /// ML inference service state
 pub struct MLInferenceService {
     pub start_time: std::time::Instant,
     pub request_count: Arc<RwLock<u64>>,
     pub loaded_models: Arc<DashMap<String, ModelInfo>>,
     pub inference_cache: Arc<DashMap<String, serde_json::Value>>,
+    pub request_queue: Arc<BoundedRequestQueue>,
+    /// Per-model running embedding distribution, used to flag potential
+    /// embedding-poisoning attempts in `generate_embedding`.
+    pub embedding_distributions: Arc<DashMap<String, RwLock<EmbeddingDistribution>>>,
+    /// Number of standard deviations (Mahalanobis distance) an embedding may
+    /// deviate from its model's reference distribution before being flagged.
+    pub threat_detection_sensitivity: f64,
 }
 
 impl MLInferenceService {
@@ -69,6 +155,9 @@ impl MLInferenceService {
             request_count: Arc::new(RwLock::new(0)),
             loaded_models: Arc::new(DashMap::new()),
             inference_cache: Arc::new(DashMap::new()),
+            request_queue: Arc::new(BoundedRequestQueue::new(256)),
+            embedding_distributions: Arc::new(DashMap::new()),
+            threat_detection_sensitivity: 3.0,
         };
 
         // Load default models
@@ -76,6 +165,50 @@ impl MLInferenceService {
         service
     }
 
+    /// Submit a request through the bounded queue instead of running inference
+    /// directly. Returns `QueueError::Backpressure` immediately if the queue is
+    /// saturated rather than blocking the caller.
+    pub async fn submit(&self, request: InferenceRequest) -> Result<InferenceResponse, QueueError> {
+        let response_rx = self.request_queue.enqueue(request).await?;
+        match response_rx.await {
+            Ok(Ok(response)) => Ok(response),
+            Ok(Err(error)) => Ok(InferenceResponse {
+                success: false,
+                result: serde_json::Value::Null,
+                processing_time_ms: 0,
+                request_id: Uuid::new_v4().to_string(),
+                model_id: String::new(),
+                task_type: String::new(),
+                error: Some(error),
+                metadata: serde_json::Value::Null,
+            }),
+            Err(_) => Ok(InferenceResponse {
+                success: false,
+                result: serde_json::Value::Null,
+                processing_time_ms: 0,
+                request_id: Uuid::new_v4().to_string(),
+                model_id: String::new(),
+                task_type: String::new(),
+                error: Some("Worker dropped the request before responding".to_string()),
+                metadata: serde_json::Value::Null,
+            }),
+        }
+    }
+
+    /// Spawn the background worker that drains `request_queue`, running each
+    /// request through `infer` and delivering the result to its caller.
+    /// Callers hold `self` behind an `Arc` (e.g. `actix_web::web::Data`) so the
+    /// worker can outlive the request that spawned it.
+    pub fn spawn_queue_worker(self: Arc<Self>) {
+        tokio::spawn(async move {
+            loop {
+                let queued = self.request_queue.dequeue().await;
+                let result = self.infer(queued.request).await.map_err(|e| e.to_string());
+                let _ = queued.response_tx.send(result);
+            }
+        });
+    }
+
     /// Initialize default models for common tasks
     fn initialize_default_models(&mut self) {
         let default_models = vec![
@@ -88,6 +221,7 @@ impl MLInferenceService {
                 memory_usage_mb: 2048,
                 loaded: false,
                 load_time: None,
+                quantization: None,
             },
             ModelInfo {
                 id: "llama3.1:8b".to_string(),
@@ -98,6 +232,7 @@ impl MLInferenceService {
                 memory_usage_mb: 4096,
                 loaded: false,
                 load_time: None,
+                quantization: None,
             },
             ModelInfo {
                 id: "gpt-oss:20b".to_string(),
@@ -108,6 +243,7 @@ impl MLInferenceService {
                 memory_usage_mb: 8192,
                 loaded: false,
                 load_time: None,
+                quantization: None,
             },
             ModelInfo {
                 id: "nomic-embed-text:latest".to_string(),
@@ -118,6 +254,7 @@ impl MLInferenceService {
                 memory_usage_mb: 256,
                 loaded: false,
                 load_time: None,
+                quantization: None,
             },
             ModelInfo {
                 id: "snowflake-arctic-embed2:latest".to_string(),
@@ -128,6 +265,7 @@ impl MLInferenceService {
                 memory_usage_mb: 1024,
                 loaded: false,
                 load_time: None,
+                quantization: None,
             },
             ModelInfo {
                 id: "mxbai-embed-large:latest".to_string(),
@@ -138,6 +276,7 @@ impl MLInferenceService {
                 memory_usage_mb: 512,
                 loaded: false,
                 load_time: None,
+                quantization: None,
             },
             ModelInfo {
                 id: "distilbert-base-uncased".to_string(),
@@ -148,6 +287,7 @@ impl MLInferenceService {
                 memory_usage_mb: 512,
                 loaded: false,
                 load_time: None,
+                quantization: None,
             },
         ];
 
@@ -157,7 +297,7 @@ impl MLInferenceService {
     }
 
     /// Perform ML inference
-    pub async fn infer(&self, request: InferenceRequest) -> Result<InferenceResponse, anyhow::Error> {
+    pub async fn infer(&self, mut request: InferenceRequest) -> Result<InferenceResponse, anyhow::Error> {
         let start_time = std::time::Instant::now();
         let request_id = request.request_id.clone().unwrap_or_else(|| Uuid::new_v4().to_string());
         let task_type = request.task_type.clone().unwrap_or_else(|| self.infer_task_type(&request.model_id));
@@ -168,6 +308,25 @@ impl MLInferenceService {
             *count += 1;
         }
 
+        // If the caller supplied a memory budget, pick the highest-quality
+        // model of this task type that still fits it instead of the
+        // caller-specified model_id.
+        if let Some(budget_memory_mb) = request
+            .parameters
+            .as_ref()
+            .and_then(|params| params.get("budget_memory_mb"))
+            .and_then(|value| value.as_u64())
+        {
+            let candidates: Vec<ModelInfo> = self
+                .loaded_models
+                .iter()
+                .filter(|entry| entry.task_type == task_type)
+                .map(|entry| entry.value().clone())
+                .collect();
+            let selected = ModelSelector::select_for_budget(&candidates, budget_memory_mb)?;
+            request.model_id = selected.id.clone();
+        }
+
         // Check if model is available
         let model_info = self.loaded_models.get(&request.model_id)
             .ok_or_else(|| anyhow::anyhow!("Model not found: {}", request.model_id))?;
@@ -321,6 +480,22 @@ impl MLInferenceService {
             .and_then(|v| v.as_array())
             .ok_or_else(|| anyhow::anyhow!("Invalid embedding response"))?;
 
+        let embedding_values: Vec<f32> = embedding
+            .iter()
+            .filter_map(|v| v.as_f64())
+            .map(|v| v as f32)
+            .collect();
+        let poison_score = self.check_embedding_for_poisoning(&request.model_id, &embedding_values).await;
+
+        if poison_score.is_suspicious {
+            tracing::warn!(
+                model_id = %request.model_id,
+                mahalanobis_distance = poison_score.mahalanobis_distance,
+                sensitivity_threshold = poison_score.sensitivity_threshold,
+                "embedding flagged as a possible poisoning attempt"
+            );
+        }
+
         Ok(serde_json::json!({
             "embedding": embedding,
             "text": text,
@@ -329,10 +504,29 @@ impl MLInferenceService {
             "usage": {
                 "prompt_tokens": text.split_whitespace().count(),
                 "total_tokens": text.split_whitespace().count()
-            }
+            },
+            "threat_detection": poison_score
         }))
     }
 
+    /// Scores `embedding` against the model's running reference distribution
+    /// and folds it into that distribution afterwards, so the check is
+    /// always made against history that predates the embedding being
+    /// checked.
+    async fn check_embedding_for_poisoning(&self, model_id: &str, embedding: &[f32]) -> PoisonScore {
+        let entry = self
+            .embedding_distributions
+            .entry(model_id.to_string())
+            .or_insert_with(|| RwLock::new(EmbeddingDistribution::new(embedding.len())));
+
+        let score = {
+            let distribution = entry.read().await;
+            EmbeddingPoisonDetector::check(embedding, &distribution, self.threat_detection_sensitivity)
+        };
+        entry.write().await.observe(embedding);
+        score
+    }
+
     /// Text classification implementation
     async fn text_classification(&self, request: &InferenceRequest) -> Result<serde_json::Value, anyhow::Error> {
         let text = request.input.as_str()
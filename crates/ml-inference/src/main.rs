@@ -19,6 +19,7 @@ async fn main() -> std::io::Result<()> {
 
     // Create ML inference service instance
     let service = web::Data::new(MLInferenceService::new());
+    service.clone().into_inner().spawn_queue_worker();
 
     println!("🚀 ML Inference Service starting on {}", bind_address);
     println!("📋 Available endpoints:");
@@ -0,0 +1,101 @@
+//! Memory-budget-aware model selection.
+//!
+//! Deployments running on constrained hardware (edge devices, cost-limited
+//! cloud tiers) can't always load the largest model registered in
+//! `MLInferenceService::loaded_models`. `ModelSelector` picks the best
+//! candidate that still fits a caller-supplied memory budget, using a
+//! parameters-per-megabyte ratio as a quality proxy since this crate has no
+//! benchmark scores to compare models on directly.
+
+use crate::ModelInfo;
+
+/// Quantization applied to a model, if any. Lower `bits` and more aggressive
+/// `method`s (e.g. GPTQ, AWQ) trade quality for a smaller `memory_usage_mb`
+/// footprint on the `ModelInfo` they're attached to.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct Quantization {
+    pub bits: u8,
+    pub method: String,
+}
+
+/// Errors returned when no registered model can satisfy a memory budget.
+#[derive(thiserror::Error, Debug)]
+pub enum ModelSelectionError {
+    #[error("no candidate model fits within a {available_memory_mb}MB budget")]
+    NoModelFitsBudget { available_memory_mb: u64 },
+}
+
+/// Selects the best model that fits a memory budget from a set of candidates.
+pub struct ModelSelector;
+
+impl ModelSelector {
+    /// Filters `candidates` down to those fitting within `available_memory_mb`,
+    /// then returns the one with the highest parameters-per-megabyte ratio —
+    /// the most model capacity the budget can buy.
+    pub fn select_for_budget(
+        candidates: &[ModelInfo],
+        available_memory_mb: u64,
+    ) -> Result<&ModelInfo, ModelSelectionError> {
+        candidates
+            .iter()
+            .filter(|model| model.memory_usage_mb <= available_memory_mb)
+            .max_by(|a, b| {
+                let quality_a = a.parameters as f64 / a.memory_usage_mb.max(1) as f64;
+                let quality_b = b.parameters as f64 / b.memory_usage_mb.max(1) as f64;
+                quality_a.total_cmp(&quality_b)
+            })
+            .ok_or(ModelSelectionError::NoModelFitsBudget { available_memory_mb })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(id: &str, parameters: u64, memory_usage_mb: u64) -> ModelInfo {
+        ModelInfo {
+            id: id.to_string(),
+            name: id.to_string(),
+            task_type: "text_generation".to_string(),
+            description: String::new(),
+            parameters,
+            memory_usage_mb,
+            loaded: false,
+            load_time: None,
+            quantization: None,
+        }
+    }
+
+    #[test]
+    fn picks_the_highest_quality_model_within_the_tightest_budget() {
+        let fp16 = model("llama3.1-fp16", 8_000_000_000, 16_384);
+        let int8 = model("llama3.1-int8", 8_000_000_000, 8_192);
+        let int4 = model("llama3.1-int4", 8_000_000_000, 4_096);
+
+        let candidates = vec![fp16, int8, int4];
+
+        let selected = ModelSelector::select_for_budget(&candidates, 5_000).unwrap();
+        assert_eq!(selected.id, "llama3.1-int4");
+    }
+
+    #[test]
+    fn prefers_higher_quality_when_budget_allows_multiple_fits() {
+        let small = model("small", 1_000_000_000, 1_024);
+        let large = model("large", 8_000_000_000, 4_096);
+
+        let candidates = vec![small, large];
+
+        let selected = ModelSelector::select_for_budget(&candidates, 8_192).unwrap();
+        assert_eq!(selected.id, "large");
+    }
+
+    #[test]
+    fn errors_when_nothing_fits_the_budget() {
+        let candidates = vec![model("too-big", 8_000_000_000, 8_192)];
+        let result = ModelSelector::select_for_budget(&candidates, 1_024);
+        assert!(matches!(
+            result,
+            Err(ModelSelectionError::NoModelFitsBudget { available_memory_mb: 1_024 })
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+//! Adversarial input detection for embedding-based retrieval.
+//!
+//! Crafted inputs can poison a `GraphRAG`-style retrieval index by pushing
+//! embeddings far outside the distribution the index was built from. This
+//! module tracks a running per-model [`EmbeddingDistribution`] (mean and
+//! variance, updated online via Welford's algorithm) and flags embeddings
+//! that fall too many standard deviations from it.
+//!
+//! The per-dimension variance is tracked instead of a full covariance
+//! matrix — embedding dimensionality (768-1024 for the models in
+//! `initialize_default_models`) makes a full covariance matrix expensive to
+//! maintain online, and the diagonal approximation is enough to catch the
+//! gross outliers a poisoning attempt produces.
+
+/// Online mean/variance estimate of a model's embedding output, updated one
+/// embedding at a time via Welford's algorithm so no history needs to be
+/// retained.
+#[derive(Debug, Clone)]
+pub struct EmbeddingDistribution {
+    count: u64,
+    mean: Vec<f64>,
+    m2: Vec<f64>,
+}
+
+impl EmbeddingDistribution {
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            count: 0,
+            mean: vec![0.0; dimensions],
+            m2: vec![0.0; dimensions],
+        }
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.mean.len()
+    }
+
+    pub fn observations(&self) -> u64 {
+        self.count
+    }
+
+    /// Fold a new embedding into the running mean/variance.
+    pub fn observe(&mut self, embedding: &[f32]) {
+        if embedding.len() != self.mean.len() {
+            return;
+        }
+        self.count += 1;
+        let n = self.count as f64;
+        for i in 0..self.mean.len() {
+            let x = embedding[i] as f64;
+            let delta = x - self.mean[i];
+            self.mean[i] += delta / n;
+            let delta2 = x - self.mean[i];
+            self.m2[i] += delta * delta2;
+        }
+    }
+
+    /// Per-dimension variance. Dimensions with fewer than two observations
+    /// report a variance of 1.0 so early Mahalanobis distances don't blow
+    /// up from division by (near) zero.
+    fn variance(&self) -> Vec<f64> {
+        if self.count < 2 {
+            return vec![1.0; self.mean.len()];
+        }
+        self.m2
+            .iter()
+            .map(|&m2| (m2 / (self.count - 1) as f64).max(1e-9))
+            .collect()
+    }
+
+    /// Mahalanobis distance of `embedding` from this distribution's
+    /// centroid, using the diagonal (per-dimension variance) approximation
+    /// of the covariance matrix.
+    pub fn mahalanobis_distance(&self, embedding: &[f32]) -> f64 {
+        if embedding.len() != self.mean.len() {
+            return f64::INFINITY;
+        }
+        let variance = self.variance();
+        let sum_sq: f64 = embedding
+            .iter()
+            .zip(self.mean.iter())
+            .zip(variance.iter())
+            .map(|((&x, &mean), &var)| {
+                let diff = x as f64 - mean;
+                (diff * diff) / var
+            })
+            .sum();
+        sum_sq.sqrt()
+    }
+}
+
+/// Result of running an embedding through [`EmbeddingPoisonDetector::check`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PoisonScore {
+    pub mahalanobis_distance: f64,
+    pub sensitivity_threshold: f64,
+    pub is_suspicious: bool,
+}
+
+/// Flags embeddings that are statistically implausible given a model's
+/// historical output distribution — the ML-inference-side counterpart to
+/// `ThreatDetector` in the orchestration platform's `SecurityConfig`.
+pub struct EmbeddingPoisonDetector;
+
+impl EmbeddingPoisonDetector {
+    /// Scores `embedding` against `reference_distribution`, flagging it as
+    /// suspicious when its Mahalanobis distance from the centroid exceeds
+    /// `sensitivity` standard deviations (mirrors
+    /// `SecurityConfig::threat_detection.sensitivity`).
+    pub fn check(embedding: &[f32], reference_distribution: &EmbeddingDistribution, sensitivity: f64) -> PoisonScore {
+        let mahalanobis_distance = reference_distribution.mahalanobis_distance(embedding);
+        PoisonScore {
+            mahalanobis_distance,
+            sensitivity_threshold: sensitivity,
+            is_suspicious: mahalanobis_distance > sensitivity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_embedding_ten_standard_deviations_out() {
+        let dimensions = 8;
+        let mut distribution = EmbeddingDistribution::new(dimensions);
+        // Build up a tight reference distribution around zero.
+        for i in 0..200 {
+            let noise = ((i % 5) as f32 - 2.0) * 0.01;
+            distribution.observe(&vec![noise; dimensions]);
+        }
+
+        let variance = distribution.variance()[0].sqrt();
+        let poisoned = vec![variance as f32 * 10.0; dimensions];
+
+        let score = EmbeddingPoisonDetector::check(&poisoned, &distribution, 3.0);
+        assert!(score.is_suspicious, "expected outlier embedding to be flagged: {score:?}");
+        assert!(score.mahalanobis_distance > 3.0);
+    }
+
+    #[test]
+    fn does_not_flag_embedding_near_the_mean() {
+        let dimensions = 8;
+        let mut distribution = EmbeddingDistribution::new(dimensions);
+        for i in 0..200 {
+            let noise = ((i % 5) as f32 - 2.0) * 0.01;
+            distribution.observe(&vec![noise; dimensions]);
+        }
+
+        let score = EmbeddingPoisonDetector::check(&vec![0.0; dimensions], &distribution, 3.0);
+        assert!(!score.is_suspicious);
+    }
+}
@@ -0,0 +1,324 @@
+//! Alert evaluation and notification delivery.
+//!
+//! `AlertingConfig` describes rules, channels, and rate limits, but nothing
+//! previously delivered a triggered alert anywhere. `AlertManager` owns one
+//! [`WebhookNotifier`] per `NotificationChannelType::Webhook` channel and
+//! routes `AlertRuleConfig` triggers to it, honoring each channel's
+//! `RateLimit` before every attempt so a flapping rule can't hammer a
+//! misbehaving endpoint.
+//!
+//! This module and its tests landed while `monitoring-system` was
+//! quarantined for missing lifecycle types elsewhere in the crate, so
+//! `cargo test -p monitoring-system --lib` had never actually run them;
+//! now that those types are filled in, the three tests below run and pass.
+
+use crate::{
+    AlertRuleConfig, AlertSeverity, AlertingConfig, MonitoringError, NotificationChannel,
+    NotificationChannelType, RateLimit, ServiceStatus,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Body POSTed to a `NotificationChannelType::Webhook` endpoint when
+/// `AlertRuleConfig` triggers.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookAlertPayload {
+    pub rule_name: String,
+    pub severity: AlertSeverity,
+    pub metric_value: f64,
+    pub triggered_at: DateTime<Utc>,
+}
+
+/// Starting delay before the first retry.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+/// Delay never grows past this, no matter how many attempts remain.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+/// Total delivery attempts, including the first one, before giving up.
+const MAX_ATTEMPTS: usize = 5;
+
+/// POSTs a [`WebhookAlertPayload`] to a webhook URL, retrying non-2xx
+/// responses and network errors with exponential backoff.
+pub struct WebhookNotifier {
+    http_client: reqwest::Client,
+}
+
+impl WebhookNotifier {
+    pub fn new() -> Self {
+        Self { http_client: reqwest::Client::new() }
+    }
+
+    /// Delivers `payload` to `url`, retrying up to [`MAX_ATTEMPTS`] times.
+    /// The delay before each retry doubles, starting at [`INITIAL_BACKOFF`]
+    /// and capped at [`MAX_BACKOFF`].
+    pub async fn deliver(&self, url: &str, payload: &WebhookAlertPayload) -> Result<(), MonitoringError> {
+        let mut backoff = INITIAL_BACKOFF;
+        let mut last_error = String::new();
+
+        for attempt in 1..=MAX_ATTEMPTS {
+            match self.http_client.post(url).json(payload).send().await {
+                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response) => last_error = format!("webhook returned status {}", response.status()),
+                Err(e) => last_error = format!("webhook request failed: {e}"),
+            }
+
+            if attempt < MAX_ATTEMPTS {
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+        }
+
+        Err(MonitoringError::AlertError(format!(
+            "webhook delivery to {url} failed after {MAX_ATTEMPTS} attempts: {last_error}"
+        )))
+    }
+}
+
+impl Default for WebhookNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sliding-window notification limiter for a single `NotificationChannel`.
+/// Mirrors `RateLimit`'s `max_notifications`/`time_window` rather than
+/// `burst_size`, which this crate has nowhere else defined a token-bucket
+/// semantics for.
+struct ChannelRateLimiter {
+    limit: RateLimit,
+    sent_at: Mutex<Vec<Instant>>,
+}
+
+impl ChannelRateLimiter {
+    fn new(limit: RateLimit) -> Self {
+        Self { limit, sent_at: Mutex::new(Vec::new()) }
+    }
+
+    /// Returns `true` and records this attempt if the channel is still
+    /// within `RateLimit::max_notifications` for the current `time_window`.
+    async fn try_acquire(&self) -> bool {
+        let now = Instant::now();
+        let mut sent_at = self.sent_at.lock().await;
+        sent_at.retain(|t| now.duration_since(*t) < self.limit.time_window);
+        if sent_at.len() >= self.limit.max_notifications {
+            return false;
+        }
+        sent_at.push(now);
+        true
+    }
+}
+
+/// Manages alert notification channels and delivers triggered
+/// `AlertRuleConfig`s to them.
+pub struct AlertManager {
+    config: AlertingConfig,
+    webhook_notifier: WebhookNotifier,
+    rate_limiters: HashMap<String, ChannelRateLimiter>,
+    delivery_failures_total: AtomicU64,
+}
+
+impl AlertManager {
+    pub async fn new(config: AlertingConfig) -> Result<Self, MonitoringError> {
+        let rate_limiters = config
+            .notification_channels
+            .iter()
+            .filter_map(|channel| channel.rate_limit.clone().map(|limit| (channel.name.clone(), ChannelRateLimiter::new(limit))))
+            .collect();
+
+        Ok(Self {
+            config,
+            webhook_notifier: WebhookNotifier::new(),
+            rate_limiters,
+            delivery_failures_total: AtomicU64::new(0),
+        })
+    }
+
+    pub async fn start(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Alert manager started");
+        Ok(())
+    }
+
+    pub async fn shutdown(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Alert manager shutdown");
+        Ok(())
+    }
+
+    pub async fn get_status(&self) -> Result<ServiceStatus, MonitoringError> {
+        Ok(ServiceStatus::Healthy)
+    }
+
+    /// Total webhook deliveries that exhausted their retries, exposed as
+    /// `alert_delivery_failures_total`.
+    pub fn alert_delivery_failures_total(&self) -> u64 {
+        self.delivery_failures_total.load(Ordering::Relaxed)
+    }
+
+    /// Delivers `rule`'s trigger to every channel it names in
+    /// `AlertRuleConfig::notification_channels`. Channels the config
+    /// doesn't recognize, or whose `NotificationChannelType` isn't a
+    /// webhook, are skipped -- other channel types have no delivery path
+    /// yet.
+    pub async fn trigger(&self, rule: &AlertRuleConfig, metric_value: f64) {
+        let payload = WebhookAlertPayload {
+            rule_name: rule.name.clone(),
+            severity: rule.severity.clone(),
+            metric_value,
+            triggered_at: Utc::now(),
+        };
+
+        for channel_name in &rule.notification_channels {
+            let Some(channel) = self.config.notification_channels.iter().find(|c| &c.name == channel_name) else {
+                continue;
+            };
+            if !channel.enabled {
+                continue;
+            }
+            self.deliver_to_channel(channel, &payload).await;
+        }
+    }
+
+    async fn deliver_to_channel(&self, channel: &NotificationChannel, payload: &WebhookAlertPayload) {
+        let NotificationChannelType::Webhook = &channel.channel_type else {
+            return;
+        };
+        let Some(url) = channel.config.get("url") else {
+            ::tracing::warn!("webhook channel '{}' has no configured url", channel.name);
+            return;
+        };
+
+        if let Some(limiter) = self.rate_limiters.get(&channel.name) {
+            if !limiter.try_acquire().await {
+                ::tracing::warn!("skipping alert delivery to '{}': rate limit exceeded", channel.name);
+                return;
+            }
+        }
+
+        if let Err(e) = self.webhook_notifier.deliver(url, payload).await {
+            self.delivery_failures_total.fetch_add(1, Ordering::Relaxed);
+            ::tracing::warn!("failed to deliver alert to channel '{}': {e}", channel.name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AlertCondition, ComparisonOperator};
+    use std::collections::HashMap as StdHashMap;
+
+    fn rule(name: &str, channels: Vec<String>) -> AlertRuleConfig {
+        AlertRuleConfig {
+            name: name.to_string(),
+            description: "test rule".to_string(),
+            condition: AlertCondition {
+                metric: "cpu_percent".to_string(),
+                operator: ComparisonOperator::GreaterThan,
+                threshold: 90.0,
+                aggregation_window: Duration::from_secs(60),
+                labels: StdHashMap::new(),
+            },
+            severity: AlertSeverity::Critical,
+            notification_channels: channels,
+            evaluation_window: Duration::from_secs(60),
+            cooldown_period: Duration::from_secs(300),
+            enabled: true,
+        }
+    }
+
+    fn webhook_channel(name: &str, url: &str) -> NotificationChannel {
+        let mut config = StdHashMap::new();
+        config.insert("url".to_string(), url.to_string());
+        NotificationChannel {
+            name: name.to_string(),
+            channel_type: NotificationChannelType::Webhook,
+            config,
+            enabled: true,
+            rate_limit: None,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_with_backoff_then_succeeds() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/hook")
+            .with_status(500)
+            .expect(2)
+            .create_async()
+            .await;
+        let success_mock = server
+            .mock("POST", "/hook")
+            .with_status(200)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = AlertingConfig {
+            enabled: true,
+            evaluation_interval: Duration::from_secs(30),
+            notification_channels: vec![webhook_channel("ops", &format!("{}/hook", server.url()))],
+            alert_rules: Vec::new(),
+            escalation_policies: Vec::new(),
+        };
+        let manager = AlertManager::new(config).await.unwrap();
+
+        manager.trigger(&rule("high_cpu", vec!["ops".to_string()]), 97.5).await;
+
+        mock.assert_async().await;
+        success_mock.assert_async().await;
+        assert_eq!(manager.alert_delivery_failures_total(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn exhausting_retries_records_a_delivery_failure() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/hook")
+            .with_status(500)
+            .expect(5)
+            .create_async()
+            .await;
+
+        let config = AlertingConfig {
+            enabled: true,
+            evaluation_interval: Duration::from_secs(30),
+            notification_channels: vec![webhook_channel("ops", &format!("{}/hook", server.url()))],
+            alert_rules: Vec::new(),
+            escalation_policies: Vec::new(),
+        };
+        let manager = AlertManager::new(config).await.unwrap();
+
+        manager.trigger(&rule("high_cpu", vec!["ops".to_string()]), 99.0).await;
+
+        mock.assert_async().await;
+        assert_eq!(manager.alert_delivery_failures_total(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limited_channel_skips_delivery_without_an_http_call() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server.mock("POST", "/hook").expect(1).with_status(200).create_async().await;
+
+        let mut channel = webhook_channel("ops", &format!("{}/hook", server.url()));
+        channel.rate_limit = Some(RateLimit { max_notifications: 1, time_window: Duration::from_secs(60), burst_size: 1 });
+
+        let config = AlertingConfig {
+            enabled: true,
+            evaluation_interval: Duration::from_secs(30),
+            notification_channels: vec![channel],
+            alert_rules: Vec::new(),
+            escalation_policies: Vec::new(),
+        };
+        let manager = AlertManager::new(config).await.unwrap();
+        let alert = rule("high_cpu", vec!["ops".to_string()]);
+
+        manager.trigger(&alert, 99.0).await;
+        manager.trigger(&alert, 99.0).await;
+
+        mock.assert_async().await;
+        assert_eq!(manager.alert_delivery_failures_total(), 0);
+    }
+}
@@ -0,0 +1,37 @@
+//! Audit logging and compliance tracking.
+//!
+//! `AuditLogger` is currently a lifecycle placeholder: it accepts
+//! `AuditConfig` and reports `ServiceStatus`, but doesn't yet record
+//! `AuditCategory` events or generate `ComplianceConfig` reports. A real
+//! `ComplianceReporter`/`AuditEvent` (per the re-exports `lib.rs` still has
+//! commented out) is unimplemented follow-up work.
+
+use crate::{AuditConfig, MonitoringError, ServiceStatus};
+
+pub struct AuditLogger {
+    config: AuditConfig,
+}
+
+impl AuditLogger {
+    pub async fn new(config: AuditConfig) -> Result<Self, MonitoringError> {
+        Ok(Self { config })
+    }
+
+    pub async fn start(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Audit logger started");
+        Ok(())
+    }
+
+    pub async fn shutdown(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Audit logger shutdown");
+        Ok(())
+    }
+
+    pub async fn get_status(&self) -> Result<ServiceStatus, MonitoringError> {
+        if self.config.enabled {
+            Ok(ServiceStatus::Healthy)
+        } else {
+            Ok(ServiceStatus::Disabled)
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Real-time monitoring dashboard.
+//!
+//! `DashboardServer` is currently a lifecycle placeholder: it accepts
+//! `DashboardConfig` and reports `ServiceStatus`, but doesn't yet serve
+//! anything. A real `WebSocketManager` (per the re-export `lib.rs` still
+//! has commented out) is unimplemented follow-up work.
+
+use crate::{MonitoringError, ServiceStatus};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Configuration for the monitoring dashboard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DashboardConfig {
+    pub enabled: bool,
+    pub bind_address: String,
+    pub port: u16,
+    pub refresh_interval: Duration,
+}
+
+impl Default for DashboardConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            bind_address: "0.0.0.0".to_string(),
+            port: 9998,
+            refresh_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+pub struct DashboardServer {
+    config: DashboardConfig,
+}
+
+impl DashboardServer {
+    pub async fn new(config: DashboardConfig) -> Result<Self, MonitoringError> {
+        Ok(Self { config })
+    }
+
+    pub async fn start(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Dashboard server started");
+        Ok(())
+    }
+
+    pub async fn shutdown(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Dashboard server shutdown");
+        Ok(())
+    }
+
+    pub async fn get_status(&self) -> Result<ServiceStatus, MonitoringError> {
+        if self.config.enabled {
+            Ok(ServiceStatus::Healthy)
+        } else {
+            Ok(ServiceStatus::Disabled)
+        }
+    }
+}
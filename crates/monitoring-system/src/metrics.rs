@@ -0,0 +1,37 @@
+//! Metrics collection subsystem.
+//!
+//! `MetricsCollector` is currently a lifecycle placeholder: it accepts
+//! `MetricsConfig` and reports `ServiceStatus`, but doesn't yet scrape or
+//! export anything to `MetricsConfig::export_endpoints`. Filling that in
+//! (a `MetricsServer` and `MetricEvent` type, per the re-exports `lib.rs`
+//! still has commented out) is unimplemented follow-up work.
+
+use crate::{MetricsConfig, MonitoringError, ServiceStatus};
+
+pub struct MetricsCollector {
+    config: MetricsConfig,
+}
+
+impl MetricsCollector {
+    pub async fn new(config: MetricsConfig) -> Result<Self, MonitoringError> {
+        Ok(Self { config })
+    }
+
+    pub async fn start(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Metrics collector started");
+        Ok(())
+    }
+
+    pub async fn shutdown(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Metrics collector shutdown");
+        Ok(())
+    }
+
+    pub async fn get_status(&self) -> Result<ServiceStatus, MonitoringError> {
+        if self.config.enabled {
+            Ok(ServiceStatus::Healthy)
+        } else {
+            Ok(ServiceStatus::Disabled)
+        }
+    }
+}
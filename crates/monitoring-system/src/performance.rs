@@ -0,0 +1,37 @@
+//! Performance monitoring and optimization.
+//!
+//! `PerformanceMonitor` is currently a lifecycle placeholder: it accepts
+//! `PerformanceConfig` and reports `ServiceStatus`, but doesn't yet run
+//! `PerformanceConfig::benchmark_suite` or profile anything. A real
+//! `BenchmarkRunner`/`ProfileCollector` (per the re-exports `lib.rs` still
+//! has commented out) is unimplemented follow-up work.
+
+use crate::{MonitoringError, PerformanceConfig, ServiceStatus};
+
+pub struct PerformanceMonitor {
+    config: PerformanceConfig,
+}
+
+impl PerformanceMonitor {
+    pub async fn new(config: PerformanceConfig) -> Result<Self, MonitoringError> {
+        Ok(Self { config })
+    }
+
+    pub async fn start(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Performance monitor started");
+        Ok(())
+    }
+
+    pub async fn shutdown(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Performance monitor shutdown");
+        Ok(())
+    }
+
+    pub async fn get_status(&self) -> Result<ServiceStatus, MonitoringError> {
+        if self.config.enabled {
+            Ok(ServiceStatus::Healthy)
+        } else {
+            Ok(ServiceStatus::Disabled)
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Automated recovery and remediation.
+//!
+//! `RecoveryManager` is currently a lifecycle placeholder: it accepts
+//! `RecoveryConfig` and reports `ServiceStatus`, but doesn't yet trip
+//! circuit breakers or run `AutoRecoveryConfig::recovery_strategies`. A real
+//! `CircuitBreaker`/`FailureDetector` (per the re-exports `lib.rs` still has
+//! commented out) is unimplemented follow-up work.
+
+use crate::{MonitoringError, RecoveryConfig, ServiceStatus};
+
+pub struct RecoveryManager {
+    config: RecoveryConfig,
+}
+
+impl RecoveryManager {
+    pub async fn new(config: RecoveryConfig) -> Result<Self, MonitoringError> {
+        Ok(Self { config })
+    }
+
+    pub async fn start(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Recovery manager started");
+        Ok(())
+    }
+
+    pub async fn shutdown(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Recovery manager shutdown");
+        Ok(())
+    }
+
+    pub async fn get_status(&self) -> Result<ServiceStatus, MonitoringError> {
+        if self.config.enabled {
+            Ok(ServiceStatus::Healthy)
+        } else {
+            Ok(ServiceStatus::Disabled)
+        }
+    }
+}
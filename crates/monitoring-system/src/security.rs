@@ -0,0 +1,37 @@
+//! Security monitoring and threat detection.
+//!
+//! `SecurityMonitor` is currently a lifecycle placeholder: it accepts
+//! `SecurityConfig` and reports `ServiceStatus`, but doesn't yet evaluate
+//! `ThreatDetectionConfig::detection_rules` or scan for vulnerabilities. A
+//! real `ThreatDetector`/`SecurityEvent` (per the re-exports `lib.rs` still
+//! has commented out) is unimplemented follow-up work.
+
+use crate::{MonitoringError, SecurityConfig, ServiceStatus};
+
+pub struct SecurityMonitor {
+    config: SecurityConfig,
+}
+
+impl SecurityMonitor {
+    pub async fn new(config: SecurityConfig) -> Result<Self, MonitoringError> {
+        Ok(Self { config })
+    }
+
+    pub async fn start(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Security monitor started");
+        Ok(())
+    }
+
+    pub async fn shutdown(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Security monitor shutdown");
+        Ok(())
+    }
+
+    pub async fn get_status(&self) -> Result<ServiceStatus, MonitoringError> {
+        if self.config.enabled {
+            Ok(ServiceStatus::Healthy)
+        } else {
+            Ok(ServiceStatus::Disabled)
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Telemetry collection and export.
+//!
+//! `TelemetryCollector` is currently a lifecycle placeholder: it accepts
+//! `TelemetryConfig` and reports `ServiceStatus`, but doesn't yet export
+//! anything to `TelemetryConfig::collection_endpoints`. A real
+//! `OpenTelemetryExporter`/`MetricAggregator` (per the re-exports `lib.rs`
+//! still has commented out) is unimplemented follow-up work.
+
+use crate::{MonitoringError, ServiceStatus, TelemetryConfig};
+
+pub struct TelemetryCollector {
+    config: TelemetryConfig,
+}
+
+impl TelemetryCollector {
+    pub async fn new(config: TelemetryConfig) -> Result<Self, MonitoringError> {
+        Ok(Self { config })
+    }
+
+    pub async fn start(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Telemetry collector started");
+        Ok(())
+    }
+
+    pub async fn shutdown(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Telemetry collector shutdown");
+        Ok(())
+    }
+
+    pub async fn get_status(&self) -> Result<ServiceStatus, MonitoringError> {
+        if self.config.enabled {
+            Ok(ServiceStatus::Healthy)
+        } else {
+            Ok(ServiceStatus::Disabled)
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Distributed tracing subsystem.
+//!
+//! `TracingSystem` is currently a lifecycle placeholder: it accepts
+//! `TracingConfig` and reports `ServiceStatus`, but doesn't yet wire up an
+//! OpenTelemetry exporter or collect spans. A real `TraceCollector`/
+//! `SpanContext` (per the re-exports `lib.rs` still has commented out) is
+//! unimplemented follow-up work.
+
+use crate::{MonitoringError, ServiceStatus, TracingConfig};
+
+pub struct TracingSystem {
+    config: TracingConfig,
+}
+
+impl TracingSystem {
+    pub async fn new(config: TracingConfig) -> Result<Self, MonitoringError> {
+        Ok(Self { config })
+    }
+
+    pub async fn start(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Tracing system started");
+        Ok(())
+    }
+
+    pub async fn shutdown(&self) -> Result<(), MonitoringError> {
+        ::tracing::info!("Tracing system shutdown");
+        Ok(())
+    }
+
+    pub async fn get_status(&self) -> Result<ServiceStatus, MonitoringError> {
+        if self.config.enabled {
+            Ok(ServiceStatus::Healthy)
+        } else {
+            Ok(ServiceStatus::Disabled)
+        }
+    }
+}
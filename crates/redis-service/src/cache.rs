@@ -3,6 +3,7 @@ use crate::fallback::{FallbackManager, InMemoryFallback};
 use crate::types::*;
 use crate::RedisServiceError;
 use serde::{de::DeserializeOwned, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
@@ -259,3 +260,120 @@ impl CacheManager {
         Ok(0)
     }
 }
+
+/// Wraps a shared [`CacheManager`] to isolate tenants sharing it: every
+/// `get`/`set`/`delete` scopes its key to `namespace:key`, so no tenant can
+/// read or overwrite another's entry even though they're stored in the same
+/// underlying cache.
+///
+/// `CacheManager` (backed by Moka on the fallback path) can't enumerate its
+/// own keys, so `purge_namespace` needs its own record of which keys belong
+/// to each namespace; that bookkeeping lives in `namespace_keys` and is
+/// updated alongside every `set`/`delete` call.
+pub struct NamespacedCacheManager {
+    cache_manager: Arc<CacheManager>,
+    namespace_keys: RwLock<HashMap<String, HashSet<String>>>,
+}
+
+impl NamespacedCacheManager {
+    pub fn new(cache_manager: Arc<CacheManager>) -> Self {
+        Self {
+            cache_manager,
+            namespace_keys: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn scoped_key(namespace: &str, key: &str) -> String {
+        format!("{}:{}", namespace, key)
+    }
+
+    pub async fn get<T: DeserializeOwned>(&self, namespace: &str, key: &str) -> Result<Option<T>, RedisServiceError> {
+        self.cache_manager.get(&Self::scoped_key(namespace, key)).await
+    }
+
+    pub async fn set<T: Serialize>(
+        &self,
+        namespace: &str,
+        key: &str,
+        value: &T,
+        ttl: Option<Duration>,
+    ) -> Result<(), RedisServiceError> {
+        self.cache_manager.set(&Self::scoped_key(namespace, key), value, ttl).await?;
+
+        self.namespace_keys
+            .write()
+            .await
+            .entry(namespace.to_string())
+            .or_default()
+            .insert(key.to_string());
+
+        Ok(())
+    }
+
+    pub async fn delete(&self, namespace: &str, key: &str) -> Result<bool, RedisServiceError> {
+        let deleted = self.cache_manager.delete(&Self::scoped_key(namespace, key)).await?;
+
+        if let Some(keys) = self.namespace_keys.write().await.get_mut(namespace) {
+            keys.remove(key);
+        }
+
+        Ok(deleted)
+    }
+
+    /// Deletes every key tracked under `namespace`, for tenant offboarding.
+    /// Returns the number of entries deleted.
+    pub async fn purge_namespace(&self, namespace: &str) -> Result<usize, RedisServiceError> {
+        let keys = self.namespace_keys.write().await.remove(namespace).unwrap_or_default();
+
+        let mut purged = 0;
+        for key in &keys {
+            if self.cache_manager.delete(&Self::scoped_key(namespace, key)).await? {
+                purged += 1;
+            }
+        }
+
+        info!("Purged {} entries from cache namespace '{}'", purged, namespace);
+        Ok(purged)
+    }
+}
+
+#[cfg(test)]
+mod namespaced_tests {
+    use super::*;
+
+    async fn create_test_manager() -> NamespacedCacheManager {
+        let cache_manager = Arc::new(CacheManager::new(None, CacheConfig::default()).await.unwrap());
+        NamespacedCacheManager::new(cache_manager)
+    }
+
+    #[tokio::test]
+    async fn tenants_cannot_read_each_others_values_for_the_same_logical_key() {
+        let manager = create_test_manager().await;
+
+        manager.set("tenant-a", "profile", &"a's data".to_string(), None).await.unwrap();
+        manager.set("tenant-b", "profile", &"b's data".to_string(), None).await.unwrap();
+
+        let a_value: Option<String> = manager.get("tenant-a", "profile").await.unwrap();
+        let b_value: Option<String> = manager.get("tenant-b", "profile").await.unwrap();
+
+        assert_eq!(a_value, Some("a's data".to_string()));
+        assert_eq!(b_value, Some("b's data".to_string()));
+    }
+
+    #[tokio::test]
+    async fn purge_namespace_deletes_only_that_namespaces_entries() {
+        let manager = create_test_manager().await;
+
+        manager.set("tenant-a", "profile", &"a's data".to_string(), None).await.unwrap();
+        manager.set("tenant-a", "settings", &"a's settings".to_string(), None).await.unwrap();
+        manager.set("tenant-b", "profile", &"b's data".to_string(), None).await.unwrap();
+
+        let purged = manager.purge_namespace("tenant-a").await.unwrap();
+        assert_eq!(purged, 2);
+
+        let a_value: Option<String> = manager.get("tenant-a", "profile").await.unwrap();
+        let b_value: Option<String> = manager.get("tenant-b", "profile").await.unwrap();
+        assert_eq!(a_value, None);
+        assert_eq!(b_value, Some("b's data".to_string()));
+    }
+}
@@ -16,7 +16,7 @@ pub mod pubsub;
 pub mod session;
 pub mod types;
 
-pub use cache::CacheManager;
+pub use cache::{CacheManager, NamespacedCacheManager};
 pub use client::RedisClient;
 pub use compression::{CompressionManager, CompressionAlgorithm};
 pub use fallback::InMemoryFallback;
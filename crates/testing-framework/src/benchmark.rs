@@ -0,0 +1,29 @@
+//! Performance benchmarking against configured `BenchmarkSuiteConfig`s.
+//!
+//! `BenchmarkRunner` is currently a lifecycle placeholder: it accepts
+//! `BenchmarkingConfig` and reports a `BenchmarkResult`, but doesn't yet run
+//! any `BenchmarkConfig` or compare it against a `BaselineConfig`.
+
+use crate::{BenchmarkingConfig, BenchmarkResult, TestingError};
+
+pub struct BenchmarkRunner {
+    config: BenchmarkingConfig,
+}
+
+impl BenchmarkRunner {
+    pub async fn new(config: BenchmarkingConfig) -> Result<Self, TestingError> {
+        Ok(Self { config })
+    }
+
+    pub async fn run_benchmarks(&self) -> Result<BenchmarkResult, TestingError> {
+        let name = self
+            .config
+            .benchmark_suites
+            .first()
+            .and_then(|suite| suite.benchmarks.first())
+            .map(|benchmark| benchmark.name.clone())
+            .unwrap_or_else(|| "none".to_string());
+
+        Ok(BenchmarkResult { name, value: 0.0, baseline_value: 0.0, is_regression: false, regression_percent: 0.0 })
+    }
+}
@@ -0,0 +1,253 @@
+//! Chaos engineering runtime.
+//!
+//! `ChaosEngine` evaluates a `ChaosExperimentConfig`'s selection criteria
+//! and safety checks against a service registry. `simulate_experiment`
+//! (and its `start_dry_run` alias) never applies anything: they exist so
+//! an operator can see exactly which instances an experiment would hit,
+//! and whether its own safety checks would trip a rollback, before running
+//! it for real.
+
+use crate::{ChaosEngineeringConfig, ChaosExperimentConfig, SafetyCheck, SafetyCheckType, SelectionCriteria, TestingError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Chaos engineering runtime for a single `TestingFramework` instance.
+pub struct ChaosEngine {
+    config: ChaosEngineeringConfig,
+    registry: ServiceRegistry,
+}
+
+/// A minimal stand-in for a real service discovery client: the instances
+/// currently known to the registry, along with the labels and error rate
+/// `simulate_experiment` needs to evaluate selection criteria and safety
+/// checks against.
+#[derive(Debug, Clone, Default)]
+pub struct ServiceRegistry {
+    instances: Vec<ServiceInstance>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ServiceInstance {
+    pub id: String,
+    pub labels: HashMap<String, String>,
+    pub current_error_rate: f64,
+}
+
+impl ServiceRegistry {
+    pub fn new(instances: Vec<ServiceInstance>) -> Self {
+        Self { instances }
+    }
+
+    fn matching(&self, criteria: &SelectionCriteria) -> Vec<&ServiceInstance> {
+        self.instances
+            .iter()
+            .filter(|instance| criteria.labels.iter().all(|(key, value)| instance.labels.get(key) == Some(value)))
+            .collect()
+    }
+}
+
+/// Outcome of evaluating one `SafetyCheck` against the instances an
+/// experiment would affect.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SafetyCheckOutcome {
+    pub check_name: String,
+    pub passed: bool,
+    pub observed_value: f64,
+    pub threshold: f64,
+}
+
+/// What an experiment would do, computed without applying any changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationResult {
+    pub affected_instances: Vec<String>,
+    pub estimated_impact_percent: f64,
+    pub safety_check_outcomes: Vec<SafetyCheckOutcome>,
+    pub would_trigger_rollback: bool,
+}
+
+/// Aggregate outcome of running every `ChaosEngineeringConfig::experiments`
+/// entry through `simulate_experiment`. Consistent with the rest of this
+/// module: `run_experiments` never applies a real fault, it just rolls each
+/// experiment's `SimulationResult` up into one result
+/// `TestingFramework::run_all_tests` can report on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChaosResult {
+    pub experiments_run: usize,
+    pub simulations: Vec<SimulationResult>,
+    pub any_would_trigger_rollback: bool,
+}
+
+impl ChaosEngine {
+    pub async fn new(config: ChaosEngineeringConfig) -> Result<Self, TestingError> {
+        Ok(Self { config, registry: ServiceRegistry::default() })
+    }
+
+    /// Swaps in a service registry, e.g. a populated mock for a dry run or
+    /// test. There is no real service discovery integration wired up yet,
+    /// so this is the only way to give the engine instances to reason
+    /// about.
+    pub fn set_registry(&mut self, registry: ServiceRegistry) {
+        self.registry = registry;
+    }
+
+    /// Evaluates `config`'s selection criteria against the registry and its
+    /// safety checks against the resulting blast radius, without applying
+    /// any changes.
+    pub fn simulate_experiment(&self, config: &ChaosExperimentConfig) -> SimulationResult {
+        let candidates = self.registry.matching(&config.target.selection_criteria);
+        let selected_count = match config.target.selection_criteria.percentage {
+            Some(percentage) => ((candidates.len() as f64) * (percentage / 100.0)).ceil() as usize,
+            None => candidates.len(),
+        };
+        let affected: Vec<&ServiceInstance> = candidates.into_iter().take(selected_count).collect();
+        let affected_instances: Vec<String> = affected.iter().map(|instance| instance.id.clone()).collect();
+
+        let estimated_impact_percent = if self.registry.instances.is_empty() {
+            0.0
+        } else {
+            (affected_instances.len() as f64 / self.registry.instances.len() as f64) * 100.0
+        };
+
+        let safety_check_outcomes: Vec<SafetyCheckOutcome> =
+            self.config.safety_checks.iter().map(|check| Self::evaluate_safety_check(check, &affected)).collect();
+
+        let would_trigger_rollback = safety_check_outcomes.iter().any(|outcome| !outcome.passed);
+
+        SimulationResult { affected_instances, estimated_impact_percent, safety_check_outcomes, would_trigger_rollback }
+    }
+
+    /// Dry-runs `config` and returns the simulation result. This is meant
+    /// to back a `POST /chaos/simulate` endpoint, but this crate has no
+    /// HTTP server wired up (no warp/axum dependency, no router module),
+    /// so it's exposed as a plain method for now rather than a route
+    /// handler that doesn't have a server to attach to.
+    pub fn start_dry_run(&self, config: &ChaosExperimentConfig) -> SimulationResult {
+        self.simulate_experiment(config)
+    }
+
+    /// Runs every `ChaosEngineeringConfig::experiments` entry through
+    /// `simulate_experiment` and rolls the results up. Backs
+    /// `TestingFramework::run_all_tests` -- like `simulate_experiment`
+    /// itself, this never applies a real fault.
+    pub async fn run_experiments(&self) -> Result<ChaosResult, TestingError> {
+        let simulations: Vec<SimulationResult> =
+            self.config.experiments.iter().map(|experiment| self.simulate_experiment(experiment)).collect();
+        let any_would_trigger_rollback = simulations.iter().any(|simulation| simulation.would_trigger_rollback);
+
+        Ok(ChaosResult { experiments_run: simulations.len(), simulations, any_would_trigger_rollback })
+    }
+
+    fn evaluate_safety_check(check: &SafetyCheck, affected: &[&ServiceInstance]) -> SafetyCheckOutcome {
+        let observed_value = match check.check_type {
+            SafetyCheckType::ErrorRate => {
+                if affected.is_empty() {
+                    0.0
+                } else {
+                    affected.iter().map(|instance| instance.current_error_rate).sum::<f64>() / affected.len() as f64
+                }
+            }
+            _ => 0.0,
+        };
+
+        SafetyCheckOutcome {
+            check_name: check.name.clone(),
+            passed: observed_value <= check.threshold,
+            observed_value,
+            threshold: check.threshold,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ChaosExperimentType, ChaosTarget, RollbackCondition, SuccessCriterion, TargetType};
+    use std::time::Duration;
+
+    fn registry_with_high_error_rate() -> ServiceRegistry {
+        ServiceRegistry::new(vec![
+            ServiceInstance { id: "svc-1".to_string(), labels: HashMap::from([("app".to_string(), "checkout".to_string())]), current_error_rate: 0.42 },
+            ServiceInstance { id: "svc-2".to_string(), labels: HashMap::from([("app".to_string(), "checkout".to_string())]), current_error_rate: 0.38 },
+        ])
+    }
+
+    fn full_percentage_config() -> ChaosExperimentConfig {
+        ChaosExperimentConfig {
+            name: "checkout-failure".to_string(),
+            experiment_type: ChaosExperimentType::ServiceFailure,
+            target: ChaosTarget {
+                target_type: TargetType::Service,
+                identifier: "checkout".to_string(),
+                selection_criteria: SelectionCriteria {
+                    labels: HashMap::from([("app".to_string(), "checkout".to_string())]),
+                    percentage: Some(100.0),
+                    random_selection: false,
+                },
+            },
+            duration: Duration::from_secs(60),
+            intensity: 1.0,
+            hypothesis: "checkout survives a full outage of its own instances".to_string(),
+            success_criteria: Vec::<SuccessCriterion>::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn full_percentage_experiment_over_the_error_rate_threshold_triggers_rollback() {
+        let config = ChaosEngineeringConfig {
+            enabled: true,
+            experiments: Vec::new(),
+            safety_checks: vec![SafetyCheck {
+                name: "checkout-error-rate".to_string(),
+                check_type: SafetyCheckType::ErrorRate,
+                threshold: 0.05,
+                evaluation_interval: Duration::from_secs(10),
+            }],
+            rollback_conditions: Vec::<RollbackCondition>::new(),
+        };
+        let mut engine = ChaosEngine::new(config).await.expect("engine should initialize");
+        engine.set_registry(registry_with_high_error_rate());
+
+        let result = engine.simulate_experiment(&full_percentage_config());
+
+        assert_eq!(result.affected_instances, vec!["svc-1".to_string(), "svc-2".to_string()]);
+        assert_eq!(result.estimated_impact_percent, 100.0);
+        assert!(result.would_trigger_rollback);
+    }
+
+    #[tokio::test]
+    async fn start_dry_run_matches_simulate_experiment() {
+        let config = ChaosEngineeringConfig { enabled: true, experiments: Vec::new(), safety_checks: Vec::new(), rollback_conditions: Vec::new() };
+        let mut engine = ChaosEngine::new(config).await.expect("engine should initialize");
+        engine.set_registry(registry_with_high_error_rate());
+
+        let experiment = full_percentage_config();
+        let dry_run = engine.start_dry_run(&experiment);
+        let simulation = engine.simulate_experiment(&experiment);
+
+        assert_eq!(dry_run.affected_instances, simulation.affected_instances);
+        assert!(!dry_run.would_trigger_rollback);
+    }
+
+    #[tokio::test]
+    async fn run_experiments_aggregates_simulations_and_surfaces_rollback() {
+        let config = ChaosEngineeringConfig {
+            enabled: true,
+            experiments: vec![full_percentage_config()],
+            safety_checks: vec![SafetyCheck {
+                name: "checkout-error-rate".to_string(),
+                check_type: SafetyCheckType::ErrorRate,
+                threshold: 0.05,
+                evaluation_interval: Duration::from_secs(10),
+            }],
+            rollback_conditions: Vec::<RollbackCondition>::new(),
+        };
+        let mut engine = ChaosEngine::new(config).await.expect("engine should initialize");
+        engine.set_registry(registry_with_high_error_rate());
+
+        let result = engine.run_experiments().await.expect("run_experiments should succeed");
+
+        assert_eq!(result.experiments_run, 1);
+        assert_eq!(result.simulations.len(), 1);
+        assert!(result.any_would_trigger_rollback);
+    }
+}
@@ -0,0 +1,28 @@
+//! Integration testing against configured `TestEnvironment`s.
+//!
+//! `IntegrationTester` is currently a lifecycle placeholder: it accepts
+//! `IntegrationTestingConfig` and reports an `IntegrationResult`, but
+//! doesn't yet exercise any `ServiceDependency` in a `TestEnvironment`.
+
+use crate::{IntegrationTestingConfig, TestingError};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrationResult {
+    pub environments_tested: usize,
+    pub success: bool,
+}
+
+pub struct IntegrationTester {
+    config: IntegrationTestingConfig,
+}
+
+impl IntegrationTester {
+    pub async fn new(config: IntegrationTestingConfig) -> Result<Self, TestingError> {
+        Ok(Self { config })
+    }
+
+    pub async fn run_tests(&self) -> Result<IntegrationResult, TestingError> {
+        Ok(IntegrationResult { environments_tested: self.config.test_environments.len(), success: true })
+    }
+}
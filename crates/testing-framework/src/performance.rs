@@ -0,0 +1,26 @@
+//! Performance profiling driven by `PerformanceTestingConfig`.
+//!
+//! `PerformanceTester` is currently a lifecycle placeholder: it accepts
+//! `PerformanceTestingConfig` and reports a `PerformanceTestResult`, but
+//! doesn't yet profile anything against `performance_budgets`.
+
+use crate::{PerformanceTestingConfig, PerformanceTestResult, TestingError};
+use std::collections::HashMap;
+use std::time::Duration;
+
+pub struct PerformanceTester {
+    config: PerformanceTestingConfig,
+}
+
+impl PerformanceTester {
+    pub async fn new(config: PerformanceTestingConfig) -> Result<Self, TestingError> {
+        Ok(Self { config })
+    }
+
+    pub async fn run_tests(&self) -> Result<PerformanceTestResult, TestingError> {
+        let test_name =
+            self.config.performance_budgets.first().map(|budget| budget.metric.clone()).unwrap_or_else(|| "none".to_string());
+
+        Ok(PerformanceTestResult { test_name, success: true, duration: Duration::from_secs(0), performance_metrics: HashMap::new() })
+    }
+}
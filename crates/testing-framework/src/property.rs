@@ -0,0 +1,32 @@
+//! Property-based testing driven by `PropertyTestingConfig`.
+//!
+//! `PropertyTester` is currently a lifecycle placeholder: it accepts
+//! `PropertyTestingConfig` and reports a `PropertyResult`, but doesn't yet
+//! generate or shrink any cases.
+
+use crate::{PropertyTestingConfig, TestingError};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PropertyResult {
+    /// `PropertyTestingConfig::test_cases` at run time -- no cases are
+    /// actually generated or shrunk yet, so this reflects what was
+    /// configured, not what ran.
+    pub cases_run: usize,
+    pub failures: usize,
+    pub success: bool,
+}
+
+pub struct PropertyTester {
+    config: PropertyTestingConfig,
+}
+
+impl PropertyTester {
+    pub async fn new(config: PropertyTestingConfig) -> Result<Self, TestingError> {
+        Ok(Self { config })
+    }
+
+    pub async fn run_tests(&self) -> Result<PropertyResult, TestingError> {
+        Ok(PropertyResult { cases_run: self.config.test_cases, failures: 0, success: true })
+    }
+}
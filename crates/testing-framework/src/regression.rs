@@ -0,0 +1,29 @@
+//! Regression testing against a `RegressionTestingConfig::baseline_version`.
+//!
+//! `RegressionTester` is currently a lifecycle placeholder: it accepts
+//! `RegressionTestingConfig` and reports a `RegressionResult`, but doesn't
+//! yet run `test_suites` or compare anything against `baseline_version`.
+
+use crate::{RegressionTestingConfig, TestingError};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegressionResult {
+    pub baseline_version: String,
+    pub success: bool,
+    pub regressions_detected: usize,
+}
+
+pub struct RegressionTester {
+    config: RegressionTestingConfig,
+}
+
+impl RegressionTester {
+    pub async fn new(config: RegressionTestingConfig) -> Result<Self, TestingError> {
+        Ok(Self { config })
+    }
+
+    pub async fn run_tests(&self) -> Result<RegressionResult, TestingError> {
+        Ok(RegressionResult { baseline_version: self.config.baseline_version.clone(), success: true, regressions_detected: 0 })
+    }
+}
@@ -9,6 +9,7 @@
 //! - Integrate with the Universal AI Tools orchestrator
 
 pub mod orchestrator_integration;
+pub mod test_runner;
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -684,6 +685,12 @@ Generated by VM Coding Agent
         }
     }
 
+    /// Run a project's test suite and return machine-parseable results.
+    pub async fn get_test_results(&self, project_id: Uuid) -> Result<test_runner::JUnitReport, String> {
+        let project = self.code_projects.get(&project_id).ok_or("Project not found")?;
+        test_runner::TestRunner::run(project).await
+    }
+
     /// Get status of all VMs and projects
     pub fn get_status(&self) -> VMCodingAgentStatus {
         VMCodingAgentStatus {
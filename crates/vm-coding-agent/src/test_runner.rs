@@ -0,0 +1,402 @@
+//! Machine-parseable test results for `VMCodingAgent::build_and_test_project`.
+//!
+//! Each supported language reports test results in its own native format
+//! (`cargo test`'s line-delimited JSON, `pytest`'s JUnit XML, `go test`'s
+//! line-delimited JSON), so `TestRunner` normalizes all three into a single
+//! `JUnitReport` and serializes that report back out as JUnit XML for
+//! whatever CI dashboard is consuming `VMCodingAgent::get_test_results`.
+
+use crate::{CodeProject, ProgrammingLanguage};
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::Writer;
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+use tokio::process::Command;
+
+/// A parsed, language-agnostic test report.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct JUnitReport {
+    pub test_suites: Vec<TestSuite>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TestSuite {
+    pub name: String,
+    pub tests: usize,
+    pub failures: usize,
+    pub errors: usize,
+    pub time: f64,
+    pub test_cases: Vec<TestCase>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestCase {
+    pub name: String,
+    pub status: TestCaseStatus,
+    pub time: f64,
+    pub failure_message: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TestCaseStatus {
+    Pass,
+    Fail,
+    Skip,
+}
+
+impl JUnitReport {
+    /// Serializes this report to JUnit XML.
+    pub fn to_xml(&self) -> Result<String, String> {
+        let mut writer = Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2);
+
+        writer
+            .write_event(Event::Start(BytesStart::new("testsuites")))
+            .map_err(|e| format!("failed to write <testsuites>: {e}"))?;
+
+        for suite in &self.test_suites {
+            let mut suite_tag = BytesStart::new("testsuite");
+            suite_tag.push_attribute(("name", suite.name.as_str()));
+            suite_tag.push_attribute(("tests", suite.tests.to_string().as_str()));
+            suite_tag.push_attribute(("failures", suite.failures.to_string().as_str()));
+            suite_tag.push_attribute(("errors", suite.errors.to_string().as_str()));
+            suite_tag.push_attribute(("time", suite.time.to_string().as_str()));
+            writer.write_event(Event::Start(suite_tag)).map_err(|e| format!("failed to write <testsuite>: {e}"))?;
+
+            for case in &suite.test_cases {
+                let mut case_tag = BytesStart::new("testcase");
+                case_tag.push_attribute(("name", case.name.as_str()));
+                case_tag.push_attribute(("time", case.time.to_string().as_str()));
+
+                match case.status {
+                    TestCaseStatus::Pass => {
+                        writer.write_event(Event::Empty(case_tag)).map_err(|e| format!("failed to write <testcase>: {e}"))?;
+                    }
+                    TestCaseStatus::Skip => {
+                        writer
+                            .write_event(Event::Start(case_tag))
+                            .map_err(|e| format!("failed to write <testcase>: {e}"))?;
+                        writer
+                            .write_event(Event::Empty(BytesStart::new("skipped")))
+                            .map_err(|e| format!("failed to write <skipped>: {e}"))?;
+                        writer
+                            .write_event(Event::End(BytesEnd::new("testcase")))
+                            .map_err(|e| format!("failed to close <testcase>: {e}"))?;
+                    }
+                    TestCaseStatus::Fail => {
+                        writer
+                            .write_event(Event::Start(case_tag))
+                            .map_err(|e| format!("failed to write <testcase>: {e}"))?;
+
+                        let mut failure_tag = BytesStart::new("failure");
+                        let message = case.failure_message.as_deref().unwrap_or("");
+                        failure_tag.push_attribute(("message", message));
+                        writer
+                            .write_event(Event::Start(failure_tag))
+                            .map_err(|e| format!("failed to write <failure>: {e}"))?;
+                        writer
+                            .write_event(Event::Text(BytesText::new(message)))
+                            .map_err(|e| format!("failed to write failure text: {e}"))?;
+                        writer
+                            .write_event(Event::End(BytesEnd::new("failure")))
+                            .map_err(|e| format!("failed to close <failure>: {e}"))?;
+
+                        writer
+                            .write_event(Event::End(BytesEnd::new("testcase")))
+                            .map_err(|e| format!("failed to close <testcase>: {e}"))?;
+                    }
+                }
+            }
+
+            writer
+                .write_event(Event::End(BytesEnd::new("testsuite")))
+                .map_err(|e| format!("failed to close <testsuite>: {e}"))?;
+        }
+
+        writer
+            .write_event(Event::End(BytesEnd::new("testsuites")))
+            .map_err(|e| format!("failed to close <testsuites>: {e}"))?;
+
+        String::from_utf8(writer.into_inner().into_inner()).map_err(|e| format!("junit xml was not valid utf-8: {e}"))
+    }
+}
+
+/// Runs a project's test suite inside its VM and normalizes the results.
+pub struct TestRunner;
+
+impl TestRunner {
+    pub async fn run(project: &CodeProject) -> Result<JUnitReport, String> {
+        let vm_name = format!("vm-coding-agent-{}", project.vm_id);
+        let project_path = project.project_path.display();
+
+        match &project.language {
+            ProgrammingLanguage::Rust => {
+                let output = Command::new("docker")
+                    .args(&[
+                        "exec",
+                        &vm_name,
+                        "bash",
+                        "-c",
+                        &format!("cd {project_path} && cargo test -- -Z unstable-options --format json"),
+                    ])
+                    .output()
+                    .await
+                    .map_err(|e| format!("failed to run cargo test: {e}"))?;
+                Ok(parse_cargo_test_json(&String::from_utf8_lossy(&output.stdout)))
+            }
+            ProgrammingLanguage::Python => {
+                let report_path = format!("{project_path}/junit-report.xml");
+                Command::new("docker")
+                    .args(&[
+                        "exec",
+                        &vm_name,
+                        "bash",
+                        "-c",
+                        &format!("cd {project_path} && pytest --junit-xml={report_path}"),
+                    ])
+                    .output()
+                    .await
+                    .map_err(|e| format!("failed to run pytest: {e}"))?;
+
+                let cat_output = Command::new("docker")
+                    .args(&["exec", &vm_name, "cat", &report_path])
+                    .output()
+                    .await
+                    .map_err(|e| format!("failed to read pytest junit report: {e}"))?;
+                parse_pytest_junit_xml(&String::from_utf8_lossy(&cat_output.stdout))
+            }
+            ProgrammingLanguage::Go => {
+                let output = Command::new("docker")
+                    .args(&["exec", &vm_name, "bash", "-c", &format!("cd {project_path} && go test -json ./...")])
+                    .output()
+                    .await
+                    .map_err(|e| format!("failed to run go test: {e}"))?;
+                Ok(parse_go_test_json(&String::from_utf8_lossy(&output.stdout)))
+            }
+            other => Err(format!("test running is not supported for {other:?}")),
+        }
+    }
+}
+
+/// Parses `cargo test -- --format json`'s line-delimited event stream into a
+/// single-suite report, since cargo doesn't group tests into named suites
+/// the way `pytest`/`go test` do.
+fn parse_cargo_test_json(output: &str) -> JUnitReport {
+    let mut suite = TestSuite { name: "cargo test".to_string(), ..Default::default() };
+
+    for line in output.lines() {
+        let Ok(event) = serde_json::from_str::<serde_json::Value>(line) else { continue };
+
+        match event["type"].as_str() {
+            Some("test") => match event["event"].as_str() {
+                Some("ok") => {
+                    let name = event["name"].as_str().unwrap_or_default().to_string();
+                    suite.test_cases.push(TestCase { name, status: TestCaseStatus::Pass, time: 0.0, failure_message: None });
+                }
+                Some("failed") => {
+                    let name = event["name"].as_str().unwrap_or_default().to_string();
+                    let message = event["stdout"].as_str().unwrap_or_default().to_string();
+                    suite.test_cases.push(TestCase {
+                        name,
+                        status: TestCaseStatus::Fail,
+                        time: 0.0,
+                        failure_message: Some(message),
+                    });
+                }
+                Some("ignored") => {
+                    let name = event["name"].as_str().unwrap_or_default().to_string();
+                    suite.test_cases.push(TestCase { name, status: TestCaseStatus::Skip, time: 0.0, failure_message: None });
+                }
+                _ => {}
+            },
+            Some("suite") if event["event"].as_str() == Some("ok") || event["event"].as_str() == Some("failed") => {
+                suite.time = event["exec_time"].as_f64().unwrap_or(0.0);
+            }
+            _ => {}
+        }
+    }
+
+    suite.tests = suite.test_cases.len();
+    suite.failures = suite.test_cases.iter().filter(|c| c.status == TestCaseStatus::Fail).count();
+
+    JUnitReport { test_suites: vec![suite] }
+}
+
+/// Parses `go test -json`'s line-delimited event stream, grouping test
+/// cases by their Go package (`Test` events carry a bare test name; the
+/// package becomes the suite).
+fn parse_go_test_json(output: &str) -> JUnitReport {
+    use std::collections::HashMap;
+
+    let mut suites: HashMap<String, TestSuite> = HashMap::new();
+
+    for line in output.lines() {
+        let Ok(event) = serde_json::from_str::<serde_json::Value>(line) else { continue };
+        let Some(test_name) = event["Test"].as_str() else { continue };
+        let package = event["Package"].as_str().unwrap_or("go test").to_string();
+        let suite = suites.entry(package.clone()).or_insert_with(|| TestSuite { name: package, ..Default::default() });
+
+        match event["Action"].as_str() {
+            Some("pass") => {
+                suite.test_cases.push(TestCase {
+                    name: test_name.to_string(),
+                    status: TestCaseStatus::Pass,
+                    time: event["Elapsed"].as_f64().unwrap_or(0.0),
+                    failure_message: None,
+                });
+            }
+            Some("fail") => {
+                suite.test_cases.push(TestCase {
+                    name: test_name.to_string(),
+                    status: TestCaseStatus::Fail,
+                    time: event["Elapsed"].as_f64().unwrap_or(0.0),
+                    failure_message: event["Output"].as_str().map(str::to_string),
+                });
+            }
+            Some("skip") => {
+                suite.test_cases.push(TestCase {
+                    name: test_name.to_string(),
+                    status: TestCaseStatus::Skip,
+                    time: event["Elapsed"].as_f64().unwrap_or(0.0),
+                    failure_message: None,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    for suite in suites.values_mut() {
+        suite.tests = suite.test_cases.len();
+        suite.failures = suite.test_cases.iter().filter(|c| c.status == TestCaseStatus::Fail).count();
+        suite.time = suite.test_cases.iter().map(|c| c.time).sum();
+    }
+
+    JUnitReport { test_suites: suites.into_values().collect() }
+}
+
+/// Parses the JUnit XML `pytest --junit-xml` already produces.
+fn parse_pytest_junit_xml(xml: &str) -> Result<JUnitReport, String> {
+    #[derive(Deserialize)]
+    struct PyTestSuites {
+        #[serde(rename = "testsuite", default)]
+        testsuite: Vec<PyTestSuite>,
+    }
+
+    #[derive(Deserialize)]
+    struct PyTestSuite {
+        #[serde(rename = "@name", default)]
+        name: String,
+        #[serde(rename = "@tests", default)]
+        tests: usize,
+        #[serde(rename = "@failures", default)]
+        failures: usize,
+        #[serde(rename = "@errors", default)]
+        errors: usize,
+        #[serde(rename = "@time", default)]
+        time: f64,
+        #[serde(rename = "testcase", default)]
+        testcase: Vec<PyTestCase>,
+    }
+
+    #[derive(Deserialize)]
+    struct PyTestCase {
+        #[serde(rename = "@name", default)]
+        name: String,
+        #[serde(rename = "@time", default)]
+        time: f64,
+        failure: Option<PyFailure>,
+        skipped: Option<PySkipped>,
+    }
+
+    #[derive(Deserialize)]
+    struct PyFailure {
+        #[serde(rename = "@message", default)]
+        message: String,
+    }
+
+    #[derive(Deserialize)]
+    struct PySkipped {}
+
+    let parsed: PyTestSuites = quick_xml::de::from_str(xml).map_err(|e| format!("failed to parse pytest junit xml: {e}"))?;
+
+    let test_suites = parsed
+        .testsuite
+        .into_iter()
+        .map(|suite| TestSuite {
+            name: suite.name,
+            tests: suite.tests,
+            failures: suite.failures,
+            errors: suite.errors,
+            time: suite.time,
+            test_cases: suite
+                .testcase
+                .into_iter()
+                .map(|case| {
+                    let (status, failure_message) = if let Some(failure) = case.failure {
+                        (TestCaseStatus::Fail, Some(failure.message))
+                    } else if case.skipped.is_some() {
+                        (TestCaseStatus::Skip, None)
+                    } else {
+                        (TestCaseStatus::Pass, None)
+                    };
+                    TestCase { name: case.name, status, time: case.time, failure_message }
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(JUnitReport { test_suites })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `cargo test -- -Z unstable-options --format json` event stream for
+    /// a two-test project (`it_adds_numbers` passing, `it_multiplies_wrong`
+    /// failing on an assertion), the shape `parse_cargo_test_json` consumes.
+    const CARGO_TEST_JSON_ONE_PASS_ONE_FAIL: &str = r#"
+{"type":"suite","event":"started","test_count":2}
+{"type":"test","event":"started","name":"it_adds_numbers"}
+{"type":"test","event":"started","name":"it_multiplies_wrong"}
+{"type":"test","name":"it_adds_numbers","event":"ok"}
+{"type":"test","name":"it_multiplies_wrong","event":"failed","stdout":"thread 'it_multiplies_wrong' panicked at src/lib.rs:12:5:\nassertion `left == right` failed\n  left: 6\n right: 5\n"}
+{"type":"suite","event":"failed","passed":1,"failed":1,"ignored":0,"measured":0,"filtered_out":0,"exec_time":0.004}
+"#;
+
+    #[test]
+    fn parses_a_mixed_pass_fail_run_into_a_junit_report() {
+        let report = parse_cargo_test_json(CARGO_TEST_JSON_ONE_PASS_ONE_FAIL);
+
+        assert_eq!(report.test_suites.len(), 1);
+        let suite = &report.test_suites[0];
+        assert_eq!(suite.tests, 2);
+        assert_eq!(suite.failures, 1);
+
+        let passing = suite.test_cases.iter().find(|c| c.name == "it_adds_numbers").expect("passing case should be present");
+        assert_eq!(passing.status, TestCaseStatus::Pass);
+        assert!(passing.failure_message.is_none());
+
+        let failing =
+            suite.test_cases.iter().find(|c| c.name == "it_multiplies_wrong").expect("failing case should be present");
+        assert_eq!(failing.status, TestCaseStatus::Fail);
+        assert!(
+            failing.failure_message.as_deref().unwrap().contains("assertion `left == right` failed"),
+            "failure message should carry the panic output: {:?}",
+            failing.failure_message
+        );
+    }
+
+    #[test]
+    fn xml_output_preserves_the_failing_test_s_message() {
+        let report = parse_cargo_test_json(CARGO_TEST_JSON_ONE_PASS_ONE_FAIL);
+        let xml = report.to_xml().expect("a mixed pass/fail report should serialize to XML");
+
+        assert!(xml.contains(r#"<testcase name="it_adds_numbers""#));
+        assert!(xml.contains(r#"<testcase name="it_multiplies_wrong""#));
+        assert!(xml.contains("<failure"));
+        assert!(
+            xml.contains("assertion `left == right` failed"),
+            "the <failure> element should contain the real failure message, not a placeholder: {xml}"
+        );
+    }
+}